@@ -1,3 +1,4 @@
+use crate::gradient;
 use crate::Color;
 
 /// The background of some element.
@@ -5,7 +6,9 @@ use crate::Color;
 pub enum Background {
     /// A solid color
     Color(Color),
-    // TODO: Add gradient and image variants
+    /// A linear gradient relative to the bounds of the element
+    Gradient(gradient::Linear),
+    // TODO: Add image variant
 }
 
 impl From<Color> for Background {
@@ -19,3 +22,15 @@ impl From<Color> for Option<Background> {
         Some(Background::from(color))
     }
 }
+
+impl From<gradient::Linear> for Background {
+    fn from(gradient: gradient::Linear) -> Self {
+        Background::Gradient(gradient)
+    }
+}
+
+impl From<gradient::Linear> for Option<Background> {
+    fn from(gradient: gradient::Linear) -> Self {
+        Some(Background::from(gradient))
+    }
+}
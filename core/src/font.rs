@@ -1,13 +1,98 @@
+//! Describe the family, weight, stretch and style of some text.
+use std::hash::{Hash, Hasher};
+
 /// A font.
-#[derive(Debug, Clone, Copy)]
-pub enum Font {
+///
+/// A [`Font`] is resolved by the renderer against the fonts embedded in the
+/// application and the fonts installed in the system. Characters missing in
+/// the resolved font are drawn using a chain of fallback fonts.
+///
+/// System fonts and fallback fonts are only available when the
+/// `default_system_font` feature of the renderer is enabled. Otherwise, any
+/// [`Font`] not embedded in the application is drawn with the default font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Font {
+    /// The [`Family`] of the [`Font`].
+    pub family: Family,
+
+    /// The [`Weight`] of the [`Font`].
+    pub weight: Weight,
+
+    /// The [`Stretch`] of the [`Font`].
+    pub stretch: Stretch,
+
+    /// The [`Style`] of the [`Font`].
+    pub style: Style,
+}
+
+impl Font {
     /// The default font.
     ///
     /// This is normally a font configured in a renderer or loaded from the
     /// system.
+    pub const DEFAULT: Font = Font {
+        family: Family::Default,
+        weight: Weight::Normal,
+        stretch: Stretch::Normal,
+        style: Style::Normal,
+    };
+
+    /// Creates a [`Font`] of the given [`Family`].
+    pub const fn with_family(family: Family) -> Self {
+        Self {
+            family,
+            ..Self::DEFAULT
+        }
+    }
+
+    /// Creates a [`Font`] of the system family with the given name.
+    pub const fn with_name(name: &'static str) -> Self {
+        Self::with_family(Family::Name(name))
+    }
+
+    /// Creates a [`Font`] embedded in the application, identified by the
+    /// given name.
+    pub const fn external(name: &'static str, bytes: &'static [u8]) -> Self {
+        Self::with_family(Family::External { name, bytes })
+    }
+
+    /// Sets the [`Weight`] of the [`Font`].
+    pub const fn weight(self, weight: Weight) -> Self {
+        Self { weight, ..self }
+    }
+
+    /// Sets the [`Stretch`] of the [`Font`].
+    pub const fn stretch(self, stretch: Stretch) -> Self {
+        Self { stretch, ..self }
+    }
+
+    /// Sets the [`Style`] of the [`Font`].
+    pub const fn style(self, style: Style) -> Self {
+        Self { style, ..self }
+    }
+
+    /// Returns true if the [`Font`] is the default one, with no weight,
+    /// stretch nor style applied.
+    pub fn is_default(&self) -> bool {
+        *self == Self::DEFAULT
+    }
+}
+
+/// The family of a [`Font`].
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Family {
+    /// The default family.
+    ///
+    /// This is normally a font configured in a renderer or loaded from the
+    /// system.
+    #[default]
     Default,
 
-    /// An external font.
+    /// A font embedded in the application.
+    ///
+    /// External fonts are identified by their name. Their bytes are drawn as
+    /// they are, so every weight or style needs its own bytes: for instance,
+    /// `Font::external("Inter", INTER_BOLD).weight(Weight::Bold)`.
     External {
         /// The name of the external font
         name: &'static str,
@@ -15,10 +100,98 @@ pub enum Font {
         /// The bytes of the external font
         bytes: &'static [u8],
     },
+
+    /// The name of a family installed in the system, like "Helvetica".
+    Name(&'static str),
+
+    /// Serif fonts represent the formal text style for a script.
+    Serif,
+
+    /// Glyphs in sans-serif fonts have stroke endings that are plain.
+    SansSerif,
+
+    /// Glyphs in cursive fonts generally use a more informal script style.
+    Cursive,
+
+    /// Fantasy fonts are primarily decorative fonts that contain playful
+    /// representations of characters.
+    Fantasy,
+
+    /// The sole criterion of a monospace font is that all glyphs have the
+    /// same fixed width.
+    Monospace,
+}
+
+impl PartialEq for Family {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::External { name, .. },
+                Self::External {
+                    name: other_name, ..
+                },
+            ) => name == other_name,
+            (Self::Name(name), Self::Name(other_name)) => name == other_name,
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
 }
 
-impl Default for Font {
-    fn default() -> Font {
-        Font::Default
+impl Eq for Family {}
+
+impl Hash for Family {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        match self {
+            Self::External { name, .. } | Self::Name(name) => name.hash(state),
+            _ => {}
+        }
     }
 }
+
+/// The weight of a [`Font`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[allow(missing_docs)]
+pub enum Weight {
+    Thin,
+    ExtraLight,
+    Light,
+    #[default]
+    Normal,
+    Medium,
+    Semibold,
+    Bold,
+    ExtraBold,
+    Black,
+}
+
+/// The width of a [`Font`] compared to its normal width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[allow(missing_docs)]
+pub enum Stretch {
+    UltraCondensed,
+    ExtraCondensed,
+    Condensed,
+    SemiCondensed,
+    #[default]
+    Normal,
+    SemiExpanded,
+    Expanded,
+    ExtraExpanded,
+    UltraExpanded,
+}
+
+/// The slant of a [`Font`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Style {
+    /// An upright font.
+    #[default]
+    Normal,
+
+    /// A font designed to be slanted, usually cursive.
+    Italic,
+
+    /// A slanted version of an upright font.
+    Oblique,
+}
@@ -0,0 +1,493 @@
+//! Fill shapes and backgrounds with colors that transition progressively.
+use crate::{Color, Point, Rectangle, Size};
+
+/// A fill which transitions colors progressively along a direction, either
+/// linearly, radially, or conically.
+///
+/// The positions of a [`Gradient`] are relative to the top-left corner of
+/// the bounds it fills. A [`Gradient`] can have up to 8 [`ColorStop`]s.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gradient {
+    /// A linear gradient interpolates colors along a [`Direction`].
+    Linear(Linear),
+    /// A radial gradient interpolates colors from its `center` outwards to
+    /// its `radius`.
+    Radial(Radial),
+    /// A conic gradient interpolates colors around its `center`, starting at
+    /// its `angle`.
+    Conic(Conic),
+}
+
+impl Gradient {
+    /// Creates a new [`Linear`] gradient with the given [`Direction`].
+    pub fn linear(direction: impl Into<Direction>) -> Linear {
+        Linear::new(direction)
+    }
+
+    /// Creates a new [`Radial`] gradient with the given center and radius.
+    pub fn radial(center: Point, radius: f32) -> Radial {
+        Radial::new(center, radius)
+    }
+
+    /// Creates a new [`Conic`] gradient with the given center and starting
+    /// angle, in radians.
+    pub fn conic(center: Point, angle: f32) -> Conic {
+        Conic::new(center, angle)
+    }
+
+    /// Returns the [`ColorStop`]s of the [`Gradient`], sorted by offset.
+    pub fn stops(&self) -> impl Iterator<Item = ColorStop> + '_ {
+        let stops = match self {
+            Gradient::Linear(linear) => &linear.stops,
+            Gradient::Radial(radial) => &radial.stops,
+            Gradient::Conic(conic) => &conic.stops,
+        };
+
+        stops.iter().flatten().copied()
+    }
+
+    /// Scales the alpha channel of the [`Gradient`] by the given factor.
+    pub fn mul_alpha(mut self, alpha_multiplier: f32) -> Self {
+        let stops = match &mut self {
+            Gradient::Linear(linear) => &mut linear.stops,
+            Gradient::Radial(radial) => &mut radial.stops,
+            Gradient::Conic(conic) => &mut conic.stops,
+        };
+
+        for stop in stops.iter_mut().flatten() {
+            stop.color.a *= alpha_multiplier;
+        }
+
+        self
+    }
+}
+
+impl From<Linear> for Gradient {
+    fn from(linear: Linear) -> Self {
+        Self::Linear(linear)
+    }
+}
+
+impl From<Radial> for Gradient {
+    fn from(radial: Radial) -> Self {
+        Self::Radial(radial)
+    }
+}
+
+impl From<Conic> for Gradient {
+    fn from(conic: Conic) -> Self {
+        Self::Conic(conic)
+    }
+}
+
+/// A point along the gradient vector where the specified [`color`] is unmixed.
+///
+/// [`color`]: Self::color
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorStop {
+    /// Offset along the gradient vector.
+    pub offset: f32,
+
+    /// The color of the gradient at the specified [`offset`].
+    ///
+    /// [`offset`]: Self::offset
+    pub color: Color,
+}
+
+/// The direction of a [`Linear`] gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    /// An angle, in radians.
+    ///
+    /// An angle of `0.0` goes from left to right and angles grow clockwise.
+    /// The first and last offsets match the corners of the filled bounds,
+    /// like in CSS.
+    Angle(f32),
+
+    /// A line between two points.
+    Between {
+        /// The point where the gradient begins.
+        start: Point,
+        /// The point where the gradient ends.
+        end: Point,
+    },
+}
+
+impl From<f32> for Direction {
+    fn from(angle: f32) -> Self {
+        Self::Angle(angle)
+    }
+}
+
+impl From<(Point, Point)> for Direction {
+    fn from((start, end): (Point, Point)) -> Self {
+        Self::Between { start, end }
+    }
+}
+
+impl From<Position> for Direction {
+    fn from(position: Position) -> Self {
+        match position {
+            Position::Absolute { start, end } => Self::Between { start, end },
+            Position::Relative {
+                top_left,
+                size,
+                start,
+                end,
+            } => Self::Between {
+                start: start.to_absolute(top_left, size),
+                end: end.to_absolute(top_left, size),
+            },
+        }
+    }
+}
+
+/// A linear gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Linear {
+    /// The [`Direction`] of the gradient.
+    pub direction: Direction,
+
+    /// The [`ColorStop`]s of the gradient, sorted by offset.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Linear {
+    /// Creates a new [`Linear`] gradient with the given [`Direction`].
+    pub fn new(direction: impl Into<Direction>) -> Self {
+        Self {
+            direction: direction.into(),
+            stops: [None; 8],
+        }
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the
+    /// gradient.
+    ///
+    /// Any `offset` that is not between `0.0` and `1.0` will be ignored, as
+    /// well as any stop added after the 8th one.
+    ///
+    /// Any stop added with the same offset as an existing stop will replace
+    /// it.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th one will be ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Scales the alpha channel of the [`Linear`] gradient by the given
+    /// factor.
+    pub fn mul_alpha(mut self, alpha_multiplier: f32) -> Self {
+        for stop in self.stops.iter_mut().flatten() {
+            stop.color.a *= alpha_multiplier;
+        }
+
+        self
+    }
+
+    /// Returns the start and end points of the [`Linear`] gradient when
+    /// filling the given bounds.
+    pub fn points(&self, bounds: Rectangle) -> (Point, Point) {
+        match self.direction {
+            Direction::Angle(angle) => {
+                let (sin, cos) = angle.sin_cos();
+                let center = bounds.center();
+
+                let half_length = (bounds.width * cos).abs() / 2.0
+                    + (bounds.height * sin).abs() / 2.0;
+
+                (
+                    Point::new(
+                        center.x - cos * half_length,
+                        center.y - sin * half_length,
+                    ),
+                    Point::new(
+                        center.x + cos * half_length,
+                        center.y + sin * half_length,
+                    ),
+                )
+            }
+            Direction::Between { start, end } => (
+                Point::new(start.x + bounds.x, start.y + bounds.y),
+                Point::new(end.x + bounds.x, end.y + bounds.y),
+            ),
+        }
+    }
+}
+
+/// A radial gradient.
+///
+/// A `radius` that is not positive fills everything with the first
+/// [`ColorStop`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The point where the radial gradient begins.
+    pub center: Point,
+
+    /// The distance from the `center` where the radial gradient ends.
+    pub radius: f32,
+
+    /// The [`ColorStop`]s along the radius of the gradient, sorted by offset.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Radial {
+    /// Creates a new [`Radial`] gradient with the given center and radius.
+    pub fn new(center: Point, radius: f32) -> Self {
+        Self {
+            center,
+            radius,
+            stops: [None; 8],
+        }
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the
+    /// gradient.
+    ///
+    /// An offset of `0.0` is the center of the gradient and `1.0` its
+    /// radius. The same rules as [`Linear::add_stop`] apply.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th one will be ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+}
+
+/// A conic gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic {
+    /// The point the conic gradient turns around.
+    pub center: Point,
+
+    /// The angle where the conic gradient begins, in radians.
+    ///
+    /// An angle of `0.0` points to the right and angles grow clockwise.
+    pub angle: f32,
+
+    /// The [`ColorStop`]s along a full turn of the gradient, sorted by
+    /// offset.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Conic {
+    /// Creates a new [`Conic`] gradient with the given center and starting
+    /// angle, in radians.
+    pub fn new(center: Point, angle: f32) -> Self {
+        Self {
+            center,
+            angle,
+            stops: [None; 8],
+        }
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the
+    /// gradient.
+    ///
+    /// An offset of `0.0` is the starting angle of the gradient and `1.0` a
+    /// full turn. The same rules as [`Linear::add_stop`] apply.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th one will be ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+}
+
+/// Inserts a new stop in the sorted `stops` of a gradient.
+fn add_stop(stops: &mut [Option<ColorStop>; 8], offset: f32, color: Color) {
+    if !(offset.is_finite() && (0.0..=1.0).contains(&offset)) {
+        return;
+    }
+
+    let new = ColorStop { offset, color };
+
+    for i in 0..stops.len() {
+        match stops[i] {
+            Some(stop) if stop.offset == offset => {
+                stops[i] = Some(new);
+                break;
+            }
+            Some(stop) if stop.offset < offset => {}
+            _ => {
+                if stops[stops.len() - 1].is_none() {
+                    stops[i..].rotate_right(1);
+                    stops[i] = Some(new);
+                }
+
+                break;
+            }
+        }
+    }
+}
+
+/// The position of a [`Linear`] gradient within some bounds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Position {
+    /// The gradient will be positioned with respect to two points.
+    Absolute {
+        /// The starting point of the gradient.
+        start: Point,
+        /// The ending point of the gradient.
+        end: Point,
+    },
+    /// The gradient will be positioned relative to the provided bounds.
+    Relative {
+        /// The top left position of the bounds.
+        top_left: Point,
+        /// The width & height of the bounds.
+        size: Size,
+        /// The start [Location] of the gradient.
+        start: Location,
+        /// The end [Location] of the gradient.
+        end: Location,
+    },
+}
+
+impl From<(Point, Point)> for Position {
+    fn from((start, end): (Point, Point)) -> Self {
+        Self::Absolute { start, end }
+    }
+}
+
+/// The location of a relatively-positioned gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    /// Top left.
+    TopLeft,
+    /// Top.
+    Top,
+    /// Top right.
+    TopRight,
+    /// Right.
+    Right,
+    /// Bottom right.
+    BottomRight,
+    /// Bottom.
+    Bottom,
+    /// Bottom left.
+    BottomLeft,
+    /// Left.
+    Left,
+}
+
+impl Location {
+    fn to_absolute(self, top_left: Point, size: Size) -> Point {
+        match self {
+            Location::TopLeft => top_left,
+            Location::Top => {
+                Point::new(top_left.x + size.width / 2.0, top_left.y)
+            }
+            Location::TopRight => {
+                Point::new(top_left.x + size.width, top_left.y)
+            }
+            Location::Right => Point::new(
+                top_left.x + size.width,
+                top_left.y + size.height / 2.0,
+            ),
+            Location::BottomRight => {
+                Point::new(top_left.x + size.width, top_left.y + size.height)
+            }
+            Location::Bottom => Point::new(
+                top_left.x + size.width / 2.0,
+                top_left.y + size.height,
+            ),
+            Location::BottomLeft => {
+                Point::new(top_left.x, top_left.y + size.height)
+            }
+            Location::Left => {
+                Point::new(top_left.x, top_left.y + size.height / 2.0)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stops_are_sorted_by_offset() {
+        let gradient = Linear::new(0.0)
+            .add_stop(1.0, Color::BLACK)
+            .add_stop(0.0, Color::WHITE)
+            .add_stop(0.5, Color::BLACK)
+            .add_stop(0.5, Color::WHITE)
+            .add_stop(2.0, Color::BLACK);
+
+        let offsets: Vec<f32> = gradient
+            .stops
+            .iter()
+            .flatten()
+            .map(|stop| stop.offset)
+            .collect();
+
+        assert_eq!(offsets, vec![0.0, 0.5, 1.0]);
+        assert_eq!(
+            gradient.stops[1].map(|stop| stop.color),
+            Some(Color::WHITE)
+        );
+    }
+
+    #[test]
+    fn points_are_relative_to_the_bounds() {
+        let bounds = Rectangle {
+            x: 10.0,
+            y: 20.0,
+            width: 100.0,
+            height: 50.0,
+        };
+
+        let between =
+            Linear::new((Point::new(0.0, 0.0), Point::new(100.0, 0.0)));
+
+        assert_eq!(
+            between.points(bounds),
+            (Point::new(10.0, 20.0), Point::new(110.0, 20.0))
+        );
+
+        let angle = Linear::new(0.0);
+
+        assert_eq!(
+            angle.points(bounds),
+            (Point::new(10.0, 45.0), Point::new(110.0, 45.0))
+        );
+    }
+}
@@ -25,6 +25,8 @@
 #![forbid(unsafe_code, rust_2018_idioms)]
 #![allow(clippy::inherent_to_string, clippy::type_complexity)]
 pub mod alignment;
+pub mod font;
+pub mod gradient;
 pub mod keyboard;
 pub mod mouse;
 pub mod time;
@@ -32,11 +34,11 @@ pub mod time;
 mod background;
 mod color;
 mod content_fit;
-mod font;
 mod length;
 mod padding;
 mod point;
 mod rectangle;
+mod shadow;
 mod size;
 mod vector;
 
@@ -45,9 +47,11 @@ pub use background::Background;
 pub use color::Color;
 pub use content_fit::ContentFit;
 pub use font::Font;
+pub use gradient::Gradient;
 pub use length::Length;
 pub use padding::Padding;
 pub use point::Point;
 pub use rectangle::Rectangle;
+pub use shadow::Shadow;
 pub use size::Size;
 pub use vector::Vector;
@@ -0,0 +1,211 @@
+//! Track mouse clicks.
+use crate::mouse::Button;
+use crate::time::{Duration, Instant};
+use crate::Point;
+
+/// A mouse click.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Click {
+    kind: Kind,
+    count: usize,
+    button: Button,
+    position: Point,
+    time: Instant,
+}
+
+/// The kind of mouse click.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// A single click
+    Single,
+
+    /// A double click
+    Double,
+
+    /// A triple click
+    Triple,
+}
+
+impl Kind {
+    fn next(&self) -> Kind {
+        match self {
+            Kind::Single => Kind::Double,
+            Kind::Double => Kind::Triple,
+            Kind::Triple => Kind::Double,
+        }
+    }
+}
+
+impl Click {
+    /// The maximum time between two consecutive clicks.
+    #[cfg(target_os = "macos")]
+    pub const INTERVAL: Duration = Duration::from_millis(500);
+
+    /// The maximum time between two consecutive clicks.
+    #[cfg(target_os = "windows")]
+    pub const INTERVAL: Duration = Duration::from_millis(500);
+
+    /// The maximum time between two consecutive clicks.
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    pub const INTERVAL: Duration = Duration::from_millis(400);
+
+    /// The maximum distance between the positions of two consecutive
+    /// clicks.
+    pub const DISTANCE: f32 = 4.0;
+
+    /// Creates a new [`Click`] of the given [`Button`] with the given position
+    /// and previous last [`Click`].
+    pub fn new(
+        position: Point,
+        button: Button,
+        previous: Option<Click>,
+    ) -> Click {
+        Self::at(position, button, previous, Instant::now())
+    }
+
+    fn at(
+        position: Point,
+        button: Button,
+        previous: Option<Click>,
+        time: Instant,
+    ) -> Click {
+        let (kind, count) = match previous {
+            Some(previous)
+                if previous.is_consecutive(position, button, time) =>
+            {
+                (previous.kind.next(), previous.count + 1)
+            }
+            _ => (Kind::Single, 1),
+        };
+
+        Click {
+            kind,
+            count,
+            button,
+            position,
+            time,
+        }
+    }
+
+    /// Returns the [`Kind`] of [`Click`].
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Returns the amount of consecutive clicks, including this one.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the [`Button`] of the [`Click`].
+    pub fn button(&self) -> Button {
+        self.button
+    }
+
+    /// Returns the position of the [`Click`].
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    fn is_consecutive(
+        &self,
+        new_position: Point,
+        button: Button,
+        time: Instant,
+    ) -> bool {
+        let duration = if time > self.time {
+            Some(time - self.time)
+        } else {
+            None
+        };
+
+        self.button == button
+            && self.position.distance(new_position) <= Self::DISTANCE
+            && duration
+                .map(|duration| duration <= Self::INTERVAL)
+                .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn click(previous: Option<Click>, after: Duration) -> Click {
+        let time = previous.map_or_else(Instant::now, |click| click.time);
+
+        Click::at(Point::ORIGIN, Button::Left, previous, time + after)
+    }
+
+    #[test]
+    fn consecutive_clicks_cycle_kinds() {
+        let first = click(None, Duration::ZERO);
+        let second = click(Some(first), Duration::from_millis(100));
+        let third = click(Some(second), Duration::from_millis(100));
+        let fourth = click(Some(third), Duration::from_millis(100));
+
+        assert_eq!(first.kind(), Kind::Single);
+        assert_eq!(second.kind(), Kind::Double);
+        assert_eq!(third.kind(), Kind::Triple);
+        assert_eq!(fourth.kind(), Kind::Double);
+        assert_eq!(fourth.count(), 4);
+    }
+
+    #[test]
+    fn slow_clicks_are_not_consecutive() {
+        let first = click(None, Duration::ZERO);
+
+        let at_interval = click(Some(first), Click::INTERVAL);
+        let after_interval =
+            click(Some(first), Click::INTERVAL + Duration::from_millis(1));
+
+        assert_eq!(at_interval.kind(), Kind::Double);
+        assert_eq!(after_interval.kind(), Kind::Single);
+        assert_eq!(after_interval.count(), 1);
+    }
+
+    #[test]
+    fn simultaneous_clicks_are_not_consecutive() {
+        let first = click(None, Duration::ZERO);
+
+        assert_eq!(click(Some(first), Duration::ZERO).kind(), Kind::Single);
+    }
+
+    #[test]
+    fn distant_clicks_are_not_consecutive() {
+        let first = click(None, Duration::ZERO);
+        let time = first.time + Duration::from_millis(100);
+
+        let near = Click::at(
+            Point::new(Click::DISTANCE, 0.0),
+            Button::Left,
+            Some(first),
+            time,
+        );
+
+        let far = Click::at(
+            Point::new(Click::DISTANCE + 1.0, 0.0),
+            Button::Left,
+            Some(first),
+            time,
+        );
+
+        assert_eq!(near.kind(), Kind::Double);
+        assert_eq!(far.kind(), Kind::Single);
+    }
+
+    #[test]
+    fn clicks_of_other_buttons_are_not_consecutive() {
+        let first = click(None, Duration::ZERO);
+
+        let right = Click::at(
+            Point::ORIGIN,
+            Button::Right,
+            Some(first),
+            first.time + Duration::from_millis(100),
+        );
+
+        assert_eq!(right.kind(), Kind::Single);
+        assert_eq!(right.button(), Button::Right);
+    }
+}
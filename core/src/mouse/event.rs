@@ -1,6 +1,7 @@
+use crate::keyboard;
 use crate::Point;
 
-use super::Button;
+use super::{Button, Click};
 
 /// A mouse event.
 ///
@@ -23,7 +24,20 @@ pub enum Event {
     },
 
     /// A mouse button was pressed.
-    ButtonPressed(Button),
+    ButtonPressed {
+        /// The pressed button.
+        button: Button,
+
+        /// The position of the mouse cursor.
+        position: Point,
+
+        /// The state of the modifier keys.
+        modifiers: keyboard::Modifiers,
+
+        /// The [`Click`] produced by the press, which tells whether it
+        /// follows previous ones.
+        click: Click,
+    },
 
     /// A mouse button was released.
     ButtonReleased(Button),
@@ -11,6 +11,25 @@ pub enum Interaction {
     Grabbing,
     ResizingHorizontally,
     ResizingVertically,
+    ResizingDiagonallyUp,
+    ResizingDiagonallyDown,
+    ResizingColumn,
+    ResizingRow,
+    Move,
+    AllScroll,
+    NotAllowed,
+    NoDrop,
+    Copy,
+    Alias,
+    Wait,
+    Help,
+    ContextMenu,
+    Cell,
+    VerticalText,
+    ZoomIn,
+    ZoomOut,
+    /// The cursor is not displayed.
+    Hidden,
 }
 
 impl Default for Interaction {
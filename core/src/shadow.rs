@@ -0,0 +1,26 @@
+use crate::{Color, Vector};
+
+/// A shadow cast by some element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shadow {
+    /// The [`Color`] of the [`Shadow`].
+    pub color: Color,
+
+    /// The offset of the [`Shadow`] relative to the element.
+    pub offset: Vector,
+
+    /// The radius of the blur of the [`Shadow`].
+    ///
+    /// A [`Shadow`] with a radius of `0.0` has sharp edges.
+    pub blur_radius: f32,
+}
+
+impl Default for Shadow {
+    fn default() -> Self {
+        Self {
+            color: Color::TRANSPARENT,
+            offset: Vector::new(0.0, 0.0),
+            blur_radius: 0.0,
+        }
+    }
+}
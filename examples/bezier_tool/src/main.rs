@@ -112,34 +112,35 @@ mod bezier {
             match event {
                 Event::Mouse(mouse_event) => {
                     let message = match mouse_event {
-                        mouse::Event::ButtonPressed(mouse::Button::Left) => {
-                            match *state {
-                                None => {
-                                    *state = Some(Pending::One {
-                                        from: cursor_position,
-                                    });
-
-                                    None
-                                }
-                                Some(Pending::One { from }) => {
-                                    *state = Some(Pending::Two {
-                                        from,
-                                        to: cursor_position,
-                                    });
-
-                                    None
-                                }
-                                Some(Pending::Two { from, to }) => {
-                                    *state = None;
-
-                                    Some(Curve {
-                                        from,
-                                        to,
-                                        control: cursor_position,
-                                    })
-                                }
+                        mouse::Event::ButtonPressed {
+                            button: mouse::Button::Left,
+                            ..
+                        } => match *state {
+                            None => {
+                                *state = Some(Pending::One {
+                                    from: cursor_position,
+                                });
+
+                                None
                             }
-                        }
+                            Some(Pending::One { from }) => {
+                                *state = Some(Pending::Two {
+                                    from,
+                                    to: cursor_position,
+                                });
+
+                                None
+                            }
+                            Some(Pending::Two { from, to }) => {
+                                *state = None;
+
+                                Some(Curve {
+                                    from,
+                                    to,
+                                    control: cursor_position,
+                                })
+                            }
+                        },
                         _ => None,
                     };
 
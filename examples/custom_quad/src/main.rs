@@ -57,6 +57,7 @@ mod quad {
                     border_radius: self.radius.into(),
                     border_width: self.border_width,
                     border_color: Color::from_rgb(1.0, 0.0, 0.0),
+                    ..renderer::Quad::default()
                 },
                 Color::BLACK,
             );
@@ -64,6 +64,7 @@ mod circle {
                     border_radius: self.radius.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    ..renderer::Quad::default()
                 },
                 Color::BLACK,
             );
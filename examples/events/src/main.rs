@@ -54,7 +54,7 @@ impl Application for Events {
                 Command::none()
             }
             Message::EventOccurred(event) => {
-                if let Event::Window(window::Event::CloseRequested) = event {
+                if let Event::Window(_, window::Event::CloseRequested) = event {
                     window::close()
                 } else {
                     Command::none()
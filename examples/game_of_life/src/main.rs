@@ -438,7 +438,7 @@ mod grid {
                     (event::Status::Captured, message)
                 }
                 Event::Mouse(mouse_event) => match mouse_event {
-                    mouse::Event::ButtonPressed(button) => {
+                    mouse::Event::ButtonPressed { button, .. } => {
                         let message = match button {
                             mouse::Button::Left => {
                                 *interaction = if is_populated {
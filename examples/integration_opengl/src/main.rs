@@ -58,6 +58,7 @@ pub fn main() {
 
     let mut cursor_position = PhysicalPosition::new(-1.0, -1.0);
     let mut modifiers = ModifiersState::default();
+    let mut last_click = None;
     let mut clipboard = Clipboard::connect(windowed_context.window());
 
     let mut renderer = Renderer::new(Backend::new(&gl, Settings::default()));
@@ -107,9 +108,15 @@ pub fn main() {
 
                 // Map window event to iced event
                 if let Some(event) = iced_winit::conversion::window_event(
+                    iced_winit::window::Id::MAIN,
                     &event,
                     windowed_context.window().scale_factor(),
                     modifiers,
+                    conversion::cursor_position(
+                        cursor_position,
+                        viewport.scale_factor(),
+                    ),
+                    &mut last_click,
                 ) {
                     state.queue_event(event);
                 }
@@ -73,16 +73,6 @@ impl Application for App {
                 Command::none()
             }
             Message::Event(event) => match event {
-                Event::Keyboard(keyboard::Event::KeyPressed {
-                    key_code: keyboard::KeyCode::Tab,
-                    modifiers,
-                }) => {
-                    if modifiers.shift() {
-                        widget::focus_previous()
-                    } else {
-                        widget::focus_next()
-                    }
-                }
                 Event::Keyboard(keyboard::Event::KeyPressed {
                     key_code: keyboard::KeyCode::Escape,
                     ..
@@ -381,9 +371,10 @@ mod modal {
             let content_bounds = layout.children().next().unwrap().bounds();
 
             if let Some(message) = self.on_blur.as_ref() {
-                if let Event::Mouse(mouse::Event::ButtonPressed(
-                    mouse::Button::Left,
-                )) = &event
+                if let Event::Mouse(mouse::Event::ButtonPressed {
+                    button: mouse::Button::Left,
+                    ..
+                }) = &event
                 {
                     if !content_bounds.contains(cursor_position) {
                         shell.publish(message.clone());
@@ -417,6 +408,7 @@ mod modal {
                     border_radius: renderer::BorderRadius::from(0.0),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    ..renderer::Quad::default()
                 },
                 Color {
                     a: 0.80,
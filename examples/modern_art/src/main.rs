@@ -105,21 +105,21 @@ fn generate_box(frame: &mut Frame, bounds: Size) -> bool {
     };
 
     let gradient = |top_left: Point, size: Size| -> Gradient {
-        let mut builder = Gradient::linear(Position::Relative {
+        let mut linear = Gradient::linear(Position::Relative {
             top_left,
             size,
             start: random_direction(),
             end: random_direction(),
         });
-        let stops = thread_rng().gen_range(1..15u32);
+        let stops = thread_rng().gen_range(1..8u32);
 
         let mut i = 0;
         while i <= stops {
-            builder = builder.add_stop(i as f32 / stops as f32, random_color());
+            linear = linear.add_stop(i as f32 / stops as f32, random_color());
             i += 1;
         }
 
-        builder.build().unwrap()
+        linear.into()
     };
 
     let top_left = Point::new(
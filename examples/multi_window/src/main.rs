@@ -0,0 +1,81 @@
+use iced::executor;
+use iced::multi_window::Application;
+use iced::widget::{button, column, container, text};
+use iced::window;
+use iced::{Alignment, Command, Element, Length, Settings, Theme};
+
+pub fn main() -> iced::Result {
+    Example::run(Settings::default())
+}
+
+#[derive(Default)]
+struct Example {
+    count: i32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Increment,
+    OpenWindow,
+    CloseWindow(window::Id),
+}
+
+impl Application for Example {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Theme = Theme;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (Self::default(), Command::none())
+    }
+
+    fn title(&self, window: window::Id) -> String {
+        if window == window::Id::MAIN {
+            String::from("Multi-window - Iced")
+        } else {
+            format!("Window {:?} - Iced", window)
+        }
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Increment => {
+                self.count += 1;
+
+                Command::none()
+            }
+            Message::OpenWindow => window::spawn(
+                window::Id::unique(),
+                window::Settings {
+                    size: (400, 300),
+                    ..window::Settings::default()
+                },
+            ),
+            Message::CloseWindow(id) => window::close_window(id),
+        }
+    }
+
+    fn view(&self, window: window::Id) -> Element<Message> {
+        let content = if window == window::Id::MAIN {
+            column![button("Open window").on_press(Message::OpenWindow)]
+        } else {
+            column![button("Close").on_press(Message::CloseWindow(window))]
+        };
+
+        let content = column![
+            text(format!("Shared count: {}", self.count)).size(40),
+            button("Increment").on_press(Message::Increment),
+            content,
+        ]
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -191,6 +191,11 @@ impl Application for Example {
                     is_maximized,
                 ))
                 .padding(10)
+                .on_double_click(if is_maximized {
+                    Message::Restore
+                } else {
+                    Message::Maximize(id)
+                })
                 .style(if is_focused {
                     style::title_bar_focused
                 } else {
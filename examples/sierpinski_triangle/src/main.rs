@@ -117,12 +117,14 @@ impl canvas::Program<Message> for SierpinskiGraph {
         match event {
             Event::Mouse(mouse_event) => {
                 let message = match mouse_event {
-                    iced::mouse::Event::ButtonPressed(
-                        iced::mouse::Button::Left,
-                    ) => Some(Message::PointAdded(cursor_position)),
-                    iced::mouse::Event::ButtonPressed(
-                        iced::mouse::Button::Right,
-                    ) => Some(Message::PointRemoved),
+                    iced::mouse::Event::ButtonPressed {
+                        button: iced::mouse::Button::Left,
+                        ..
+                    } => Some(Message::PointAdded(cursor_position)),
+                    iced::mouse::Event::ButtonPressed {
+                        button: iced::mouse::Button::Right,
+                        ..
+                    } => Some(Message::PointRemoved),
                     _ => None,
                 };
                 (event::Status::Captured, message)
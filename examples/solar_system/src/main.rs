@@ -212,11 +212,9 @@ impl<Message> canvas::Program<Message> for State {
                         end: Point::new(Self::EARTH_RADIUS, 0.0),
                     })
                     .add_stop(0.2, Color::from_rgb(0.15, 0.50, 1.0))
-                    .add_stop(0.8, Color::from_rgb(0.0, 0.20, 0.47))
-                    .build()
-                    .expect("Build Earth fill gradient");
+                    .add_stop(0.8, Color::from_rgb(0.0, 0.20, 0.47));
 
-                frame.fill(&earth, earth_fill);
+                frame.fill(&earth, Gradient::from(earth_fill));
 
                 frame.with_save(|frame| {
                     frame.rotate(rotation * 10.0);
@@ -1,15 +1,12 @@
 use iced::alignment::{self, Alignment};
-use iced::event::{self, Event};
-use iced::keyboard;
-use iced::subscription;
 use iced::theme::{self, Theme};
 use iced::widget::{
-    self, button, checkbox, column, container, row, scrollable, text,
-    text_input, Text,
+    button, checkbox, column, container, row, scrollable, text, text_input,
+    Text,
 };
 use iced::window;
 use iced::{Application, Element};
-use iced::{Color, Command, Font, Length, Settings, Subscription};
+use iced::{Color, Command, Font, Length, Settings};
 
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
@@ -49,7 +46,6 @@ enum Message {
     CreateTask,
     FilterChanged(Filter),
     TaskMessage(usize, TaskMessage),
-    TabPressed { shift: bool },
 }
 
 impl Application for Todos {
@@ -149,13 +145,6 @@ impl Application for Todos {
 
                         Command::none()
                     }
-                    Message::TabPressed { shift } => {
-                        if shift {
-                            widget::focus_previous()
-                        } else {
-                            widget::focus_next()
-                        }
-                    }
                     _ => Command::none(),
                 };
 
@@ -253,22 +242,6 @@ impl Application for Todos {
             }
         }
     }
-
-    fn subscription(&self) -> Subscription<Message> {
-        subscription::events_with(|event, status| match (event, status) {
-            (
-                Event::Keyboard(keyboard::Event::KeyPressed {
-                    key_code: keyboard::KeyCode::Tab,
-                    modifiers,
-                    ..
-                }),
-                event::Status::Ignored,
-            ) => Some(Message::TabPressed {
-                shift: modifiers.shift(),
-            }),
-            _ => None,
-        })
-    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -466,10 +439,8 @@ fn empty_message(message: &str) -> Element<'_, Message> {
 }
 
 // Fonts
-const ICONS: Font = Font::External {
-    name: "Icons",
-    bytes: include_bytes!("../../todos/fonts/icons.ttf"),
-};
+const ICONS: Font =
+    Font::external("Icons", include_bytes!("../../todos/fonts/icons.ttf"));
 
 fn icon(unicode: char) -> Text<'static> {
     text(unicode.to_string())
@@ -16,6 +16,7 @@ use iced_graphics::image::vector;
 use iced_graphics::layer;
 use iced_graphics::Rectangle;
 use iced_graphics::Size;
+use iced_native::image::FilterMethod;
 
 use glow::HasContext;
 
@@ -30,6 +31,7 @@ pub(crate) struct Pipeline {
     vertex_array: <glow::Context as HasContext>::VertexArray,
     vertex_buffer: <glow::Context as HasContext>::Buffer,
     transform_location: <glow::Context as HasContext>::UniformLocation,
+    opacity_location: <glow::Context as HasContext>::UniformLocation,
     storage: Storage,
     #[cfg(feature = "image")]
     raster_cache: RefCell<raster::Cache<Storage>>,
@@ -65,9 +67,15 @@ impl Pipeline {
             unsafe { gl.get_uniform_location(program, "u_Transform") }
                 .expect("Get transform location");
 
+        let opacity_location =
+            unsafe { gl.get_uniform_location(program, "u_Opacity") }
+                .expect("Get opacity location");
+
         unsafe {
             gl.use_program(Some(program));
 
+            gl.uniform_1_f32(Some(&opacity_location), 1.0);
+
             let transform: [f32; 16] = Transformation::identity().into();
             gl.uniform_matrix_4_f32_slice(
                 Some(&transform_location),
@@ -118,6 +126,7 @@ impl Pipeline {
             vertex_array,
             vertex_buffer,
             transform_location,
+            opacity_location,
             storage: Storage::default(),
             #[cfg(feature = "image")]
             raster_cache: RefCell::new(raster::Cache::default()),
@@ -131,6 +140,17 @@ impl Pipeline {
         self.raster_cache.borrow_mut().load(handle).dimensions()
     }
 
+    #[cfg(feature = "image")]
+    pub fn frame_delays(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> Option<Vec<iced_native::time::Duration>> {
+        self.raster_cache
+            .borrow_mut()
+            .frames(handle)
+            .map(|frames| frames.iter().map(|frame| frame.delay).collect())
+    }
+
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(
         &self,
@@ -168,14 +188,30 @@ impl Pipeline {
         let mut vector_cache = self.vector_cache.borrow_mut();
 
         for image in images {
-            let (entry, bounds) = match &image {
+            let (entry, bounds, filter_method, rotation, opacity) = match &image
+            {
                 #[cfg(feature = "image")]
-                layer::Image::Raster { handle, bounds } => (
+                layer::Image::Raster {
+                    handle,
+                    filter_method,
+                    bounds,
+                    rotation,
+                    opacity,
+                } => (
                     raster_cache.upload(handle, &mut gl, &mut self.storage),
                     bounds,
+                    *filter_method,
+                    *rotation,
+                    *opacity,
                 ),
                 #[cfg(not(feature = "image"))]
-                layer::Image::Raster { handle: _, bounds } => (None, bounds),
+                layer::Image::Raster {
+                    handle: _,
+                    filter_method,
+                    bounds,
+                    rotation,
+                    opacity,
+                } => (None, bounds, *filter_method, *rotation, *opacity),
 
                 #[cfg(feature = "svg")]
                 layer::Image::Vector {
@@ -194,11 +230,16 @@ impl Pipeline {
                             &mut self.storage,
                         ),
                         bounds,
+                        FilterMethod::Linear,
+                        0.0,
+                        1.0,
                     )
                 }
 
                 #[cfg(not(feature = "svg"))]
-                layer::Image::Vector { bounds, .. } => (None, bounds),
+                layer::Image::Vector { bounds, .. } => {
+                    (None, bounds, FilterMethod::Linear, 0.0, 1.0)
+                }
             };
 
             unsafe {
@@ -216,9 +257,34 @@ impl Pipeline {
                     continue;
                 }
 
-                let translate = Transformation::translate(bounds.x, bounds.y);
+                let filter = match filter_method {
+                    FilterMethod::Linear => glow::LINEAR,
+                    FilterMethod::Nearest => glow::NEAREST,
+                };
+
+                gl.tex_parameter_i32(
+                    glow::TEXTURE_2D,
+                    glow::TEXTURE_MIN_FILTER,
+                    filter as _,
+                );
+                gl.tex_parameter_i32(
+                    glow::TEXTURE_2D,
+                    glow::TEXTURE_MAG_FILTER,
+                    filter as _,
+                );
+
+                let center = bounds.center();
+
+                // Rotate the image around its center
+                let translate = Transformation::translate(center.x, center.y);
+                let rotate = Transformation::rotate(rotation);
+                let offset = Transformation::translate(
+                    -bounds.width / 2.0,
+                    -bounds.height / 2.0,
+                );
                 let scale = Transformation::scale(bounds.width, bounds.height);
-                let transformation = transformation * translate * scale;
+                let transformation =
+                    transformation * translate * rotate * offset * scale;
                 let matrix: [f32; 16] = transformation.into();
                 gl.uniform_matrix_4_f32_slice(
                     Some(&self.transform_location),
@@ -226,6 +292,8 @@ impl Pipeline {
                     &matrix,
                 );
 
+                gl.uniform_1_f32(Some(&self.opacity_location), opacity);
+
                 gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
 
                 gl.bind_texture(glow::TEXTURE_2D, None);
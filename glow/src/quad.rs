@@ -72,3 +72,94 @@ impl Pipeline {
         }
     }
 }
+
+/// The uniforms used to fill a quad with a gradient.
+///
+/// The stops of a gradient do not fit in the vertex attributes of older
+/// OpenGL versions, so quads with a gradient are drawn one at a time.
+#[derive(Debug)]
+struct Gradient {
+    stops: <glow::Context as HasContext>::UniformLocation,
+    direction: <glow::Context as HasContext>::UniformLocation,
+    colors: <glow::Context as HasContext>::UniformLocation,
+    offsets: <glow::Context as HasContext>::UniformLocation,
+}
+
+impl Gradient {
+    fn new(
+        gl: &glow::Context,
+        program: <glow::Context as HasContext>::Program,
+    ) -> Self {
+        let location = |name| {
+            unsafe { gl.get_uniform_location(program, name) }
+                .unwrap_or_else(|| panic!("Get {} location", name))
+        };
+
+        Self {
+            stops: location("u_GradientStops"),
+            direction: location("u_GradientDirection"),
+            colors: location("u_GradientColors"),
+            offsets: location("u_GradientOffsets"),
+        }
+    }
+
+    /// Fills the next quads with the gradient of the given [`layer::Quad`].
+    unsafe fn set(&self, gl: &glow::Context, quad: &layer::Quad, scale: f32) {
+        let mut colors = [0.0; 32];
+        let mut offsets = [0.0; 8];
+
+        for (i, (offset, color)) in quad.gradient().enumerate() {
+            colors[i * 4..(i + 1) * 4].copy_from_slice(&color);
+            offsets[i] = offset;
+        }
+
+        let [x1, y1, x2, y2] = quad.gradient_direction;
+
+        gl.uniform_4_f32(
+            Some(&self.direction),
+            x1 * scale,
+            y1 * scale,
+            x2 * scale,
+            y2 * scale,
+        );
+        gl.uniform_4_f32_slice(Some(&self.colors), &colors);
+        gl.uniform_1_f32_slice(Some(&self.offsets), &offsets);
+        gl.uniform_1_i32(Some(&self.stops), quad.gradient_stops as i32);
+    }
+
+    /// Fills the next quads with their solid color.
+    unsafe fn clear(&self, gl: &glow::Context) {
+        gl.uniform_1_i32(Some(&self.stops), 0);
+    }
+}
+
+/// Splits the quads in batches that can be drawn at once: either a run of
+/// solid quads or a single quad with a gradient.
+///
+/// Every batch is returned with the index of its first quad.
+fn batches(
+    instances: &[layer::Quad],
+) -> impl Iterator<Item = (usize, &[layer::Quad])> {
+    let mut start = 0;
+
+    std::iter::from_fn(move || {
+        if start >= instances.len() {
+            return None;
+        }
+
+        let end = if instances[start].gradient_stops > 0 {
+            start + 1
+        } else {
+            instances[start..]
+                .iter()
+                .position(|quad| quad.gradient_stops > 0)
+                .map(|length| start + length)
+                .unwrap_or(instances.len())
+        };
+
+        let batch = (start, &instances[start..end]);
+        start = end;
+
+        Some(batch)
+    })
+}
@@ -19,6 +19,7 @@ pub struct Pipeline {
     transform_location: <glow::Context as HasContext>::UniformLocation,
     scale_location: <glow::Context as HasContext>::UniformLocation,
     screen_height_location: <glow::Context as HasContext>::UniformLocation,
+    gradient: super::Gradient,
     current_transform: Transformation,
     current_scale: f32,
     current_target_height: u32,
@@ -51,6 +52,9 @@ impl Pipeline {
                     (3, "i_BorderColor"),
                     (4, "i_BorderRadius"),
                     (5, "i_BorderWidth"),
+                    (6, "q_Pos"),
+                    (7, "i_ShadowColor"),
+                    (8, "i_Shadow"),
                 ],
             )
         };
@@ -82,6 +86,14 @@ impl Pipeline {
             gl.use_program(None);
         }
 
+        let gradient = super::Gradient::new(gl, program);
+
+        unsafe {
+            gl.use_program(Some(program));
+            gradient.clear(gl);
+            gl.use_program(None);
+        }
+
         let (vertex_array, vertex_buffer, index_buffer) =
             unsafe { create_buffers(gl, MAX_VERTICES) };
 
@@ -93,6 +105,7 @@ impl Pipeline {
             transform_location,
             scale_location,
             screen_height_location,
+            gradient,
             current_transform: Transformation::identity(),
             current_scale: 1.0,
             current_target_height: 0,
@@ -169,9 +182,10 @@ impl Pipeline {
 
         let passes = vertices
             .chunks(MAX_VERTICES)
-            .zip(indices.chunks(MAX_INDICES));
+            .zip(indices.chunks(MAX_INDICES))
+            .zip(instances.chunks(MAX_QUADS));
 
-        for (vertices, indices) in passes {
+        for ((vertices, indices), instances) in passes {
             unsafe {
                 gl.buffer_sub_data_u8_slice(
                     glow::ARRAY_BUFFER,
@@ -184,13 +198,28 @@ impl Pipeline {
                     0,
                     bytemuck::cast_slice(indices),
                 );
+            }
 
-                gl.draw_elements(
-                    glow::TRIANGLES,
-                    indices.len() as i32,
-                    glow::UNSIGNED_INT,
-                    0,
-                );
+            for (start, batch) in super::batches(instances) {
+                let gradient =
+                    batch.first().filter(|quad| quad.gradient_stops > 0);
+
+                unsafe {
+                    if let Some(quad) = gradient {
+                        self.gradient.set(gl, quad, scale);
+                    }
+
+                    gl.draw_elements(
+                        glow::TRIANGLES,
+                        (batch.len() * 6) as i32,
+                        glow::UNSIGNED_INT,
+                        (start * 6 * std::mem::size_of::<i32>()) as i32,
+                    );
+
+                    if gradient.is_some() {
+                        self.gradient.clear(gl);
+                    }
+                }
             }
         }
 
@@ -281,6 +310,26 @@ unsafe fn create_buffers(
         4 * (2 + 2 + 4 + 4 + 4 + 1),
     );
 
+    gl.enable_vertex_attrib_array(7);
+    gl.vertex_attrib_pointer_f32(
+        7,
+        4,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 2),
+    );
+
+    gl.enable_vertex_attrib_array(8);
+    gl.vertex_attrib_pointer_f32(
+        8,
+        3,
+        glow::FLOAT,
+        false,
+        stride,
+        4 * (2 + 2 + 4 + 4 + 4 + 1 + 2 + 4),
+    );
+
     gl.bind_vertex_array(None);
     gl.bind_buffer(glow::ARRAY_BUFFER, None);
     gl.bind_buffer(glow::ELEMENT_ARRAY_BUFFER, None);
@@ -314,6 +363,11 @@ pub struct Vertex {
 
     /// The __quad__ position of the [`Vertex`].
     pub q_position: [f32; 2],
+    /// The shadow color of the [`Vertex`], in __linear RGB__.
+    pub shadow_color: [f32; 4],
+
+    /// The shadow offset and blur radius of the [`Vertex`].
+    pub shadow: [f32; 3],
 }
 
 impl Vertex {
@@ -328,6 +382,12 @@ impl Vertex {
             border_radius: quad.border_radius,
             border_width: quad.border_width,
             q_position: [0.0, 0.0],
+            shadow_color: quad.shadow_color,
+            shadow: [
+                quad.shadow_offset[0],
+                quad.shadow_offset[1],
+                quad.shadow_blur_radius,
+            ],
         };
 
         [
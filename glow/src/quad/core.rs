@@ -14,6 +14,7 @@ pub struct Pipeline {
     transform_location: <glow::Context as HasContext>::UniformLocation,
     scale_location: <glow::Context as HasContext>::UniformLocation,
     screen_height_location: <glow::Context as HasContext>::UniformLocation,
+    gradient: super::Gradient,
     current_transform: Transformation,
     current_scale: f32,
     current_target_height: u32,
@@ -46,6 +47,8 @@ impl Pipeline {
                     (3, "i_BorderColor"),
                     (4, "i_BorderRadius"),
                     (5, "i_BorderWidth"),
+                    (6, "i_ShadowColor"),
+                    (7, "i_Shadow"),
                 ],
             )
         };
@@ -77,6 +80,14 @@ impl Pipeline {
             gl.use_program(None);
         }
 
+        let gradient = super::Gradient::new(gl, program);
+
+        unsafe {
+            gl.use_program(Some(program));
+            gradient.clear(gl);
+            gl.use_program(None);
+        }
+
         let (vertex_array, instances) =
             unsafe { create_instance_buffer(gl, MAX_INSTANCES) };
 
@@ -87,6 +98,7 @@ impl Pipeline {
             transform_location,
             scale_location,
             screen_height_location,
+            gradient,
             current_transform: Transformation::identity(),
             current_scale: 1.0,
             current_target_height: 0,
@@ -147,20 +159,32 @@ impl Pipeline {
             self.current_target_height = target_height;
         }
 
-        for instances in instances.chunks(MAX_INSTANCES) {
-            unsafe {
-                gl.buffer_sub_data_u8_slice(
-                    glow::ARRAY_BUFFER,
-                    0,
-                    bytemuck::cast_slice(instances),
-                );
+        for (_, batch) in super::batches(instances) {
+            let gradient = batch.first().filter(|quad| quad.gradient_stops > 0);
 
-                gl.draw_arrays_instanced(
-                    glow::TRIANGLE_STRIP,
-                    0,
-                    4,
-                    instances.len() as i32,
-                );
+            if let Some(quad) = gradient {
+                unsafe { self.gradient.set(gl, quad, scale) };
+            }
+
+            for instances in batch.chunks(MAX_INSTANCES) {
+                unsafe {
+                    gl.buffer_sub_data_u8_slice(
+                        glow::ARRAY_BUFFER,
+                        0,
+                        bytemuck::cast_slice(instances),
+                    );
+
+                    gl.draw_arrays_instanced(
+                        glow::TRIANGLE_STRIP,
+                        0,
+                        4,
+                        instances.len() as i32,
+                    );
+                }
+            }
+
+            if gradient.is_some() {
+                unsafe { self.gradient.clear(gl) };
             }
         }
 
@@ -237,6 +261,32 @@ unsafe fn create_instance_buffer(
     );
     gl.vertex_attrib_divisor(5, 1);
 
+    // The gradient of a quad is set with uniforms, so the shadow
+    // attributes skip the gradient fields
+    let shadow_offset = 4 * (2 + 2 + 4 + 4 + 4 + 1 + 4 + 16 + 4 + 1);
+
+    gl.enable_vertex_attrib_array(6);
+    gl.vertex_attrib_pointer_f32(
+        6,
+        4,
+        glow::FLOAT,
+        false,
+        stride,
+        shadow_offset,
+    );
+    gl.vertex_attrib_divisor(6, 1);
+
+    gl.enable_vertex_attrib_array(7);
+    gl.vertex_attrib_pointer_f32(
+        7,
+        3,
+        glow::FLOAT,
+        false,
+        stride,
+        shadow_offset + 4 * 4,
+    );
+    gl.vertex_attrib_divisor(7, 1);
+
     gl.bind_vertex_array(None);
     gl.bind_buffer(glow::ARRAY_BUFFER, None);
 
@@ -13,7 +13,12 @@ layout (location = 0) out vec4 fragColor;
 
 in vec2 raw_position;
 
+// linear: xy = start, zw = end
+// radial: xy = center, z = radius
+// conic: xy = center, z = angle
 uniform vec4 gradient_direction;
+// 0 = linear, 1 = radial, 2 = conic
+uniform int gradient_kind;
 uniform int color_stops_size;
 // GLSL does not support dynamically sized arrays without SSBOs so this is capped to 16 stops
 //stored as color(vec4) -> offset(vec4) sequentially;
@@ -21,39 +26,55 @@ uniform vec4 color_stops[32];
 
 //TODO: rewrite without branching to make ALUs happy
 void main() {
-    vec2 start = gradient_direction.xy;
-    vec2 end = gradient_direction.zw;
-    vec2 gradient_vec = vec2(end - start);
-    vec2 current_vec = vec2(raw_position.xy - start);
-    vec2 unit = normalize(gradient_vec);
-    float coord_offset = dot(unit, current_vec) / length(gradient_vec);
-    //if a gradient has a start/end stop that is identical, the mesh will have a transparent fill
-    gl_FragColor = vec4(0.0, 0.0, 0.0, 0.0);
+    //degenerate gradients use their first stop, like in tiny-skia
+    float coord_offset = 0.0;
 
-    float min_offset = color_stops[1].x;
-    float max_offset = color_stops[color_stops_size - 1].x;
-
-    for (int i = 0; i < color_stops_size - 2; i += 2) {
-        float curr_offset = color_stops[i+1].x;
-        float next_offset = color_stops[i+3].x;
+    if (gradient_kind == 1) {
+        if (gradient_direction.z > 0.0) {
+            coord_offset = distance(raw_position.xy, gradient_direction.xy) / gradient_direction.z;
+        }
+    } else if (gradient_kind == 2) {
+        vec2 current_vec = vec2(raw_position.xy - gradient_direction.xy);
 
-        if (coord_offset <= min_offset) {
-            //current coordinate is before the first defined offset, set it to the start color
-            gl_FragColor = color_stops[0];
+        //the angle is undefined at the center itself
+        if (current_vec != vec2(0.0, 0.0)) {
+            coord_offset = fract((atan(current_vec.y, current_vec.x) - gradient_direction.z) / 6.28318530718);
         }
+    } else {
+        vec2 start = gradient_direction.xy;
+        vec2 end = gradient_direction.zw;
+        vec2 gradient_vec = vec2(end - start);
+        vec2 current_vec = vec2(raw_position.xy - start);
+        float length_squared = dot(gradient_vec, gradient_vec);
 
-        if (curr_offset <= coord_offset && coord_offset <= next_offset) {
-            //current fragment is between the current offset processing & the next one, interpolate colors
-            gl_FragColor = mix(color_stops[i], color_stops[i+2], smoothstep(
-                curr_offset,
-                next_offset,
-                coord_offset
-            ));
+        if (length_squared > 0.0) {
+            coord_offset = dot(gradient_vec, current_vec) / length_squared;
         }
+    }
+
+    float min_offset = color_stops[1].x;
+    float max_offset = color_stops[color_stops_size - 1].x;
+
+    if (coord_offset <= min_offset) {
+        //current coordinate is before the first defined offset, set it to the start color
+        //this also covers gradients with a single stop, like in wgpu
+        gl_FragColor = color_stops[0];
+    } else if (coord_offset >= max_offset) {
+        //current coordinate is after the last defined offset, set it to the last color
+        gl_FragColor = color_stops[color_stops_size - 2];
+    } else {
+        gl_FragColor = color_stops[0];
+
+        for (int i = 0; i < color_stops_size - 2; i += 2) {
+            float curr_offset = color_stops[i+1].x;
+            float next_offset = color_stops[i+3].x;
 
-        if (coord_offset >= max_offset) {
-            //current coordinate is before the last defined offset, set it to the last color
-            gl_FragColor = color_stops[color_stops_size - 2];
+            if (curr_offset <= coord_offset && coord_offset <= next_offset && curr_offset < next_offset) {
+                //current fragment is between the current offset processing & the next one, interpolate colors
+                gl_FragColor = mix(color_stops[i], color_stops[i+2],
+                    (coord_offset - curr_offset) / (next_offset - curr_offset)
+                );
+            }
         }
     }
 }
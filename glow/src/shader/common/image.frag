@@ -7,6 +7,7 @@ precision mediump float;
 #endif
 
 uniform sampler2D tex;
+uniform float u_Opacity;
 in vec2 tex_pos;
 
 #ifdef HIGHER_THAN_300
@@ -18,5 +19,7 @@ out vec4 fragColor;
 #endif
 
 void main() {
-    gl_FragColor = texture(tex, tex_pos);
+    vec4 color = texture(tex, tex_pos);
+
+    gl_FragColor = vec4(color.rgb, color.a * u_Opacity);
 }
@@ -7,6 +7,8 @@ attribute vec4 i_Color;
 attribute vec4 i_BorderColor;
 attribute vec4 i_BorderRadius;
 attribute float i_BorderWidth;
+attribute vec4 i_ShadowColor;
+attribute vec3 i_Shadow;
 attribute vec2 q_Pos;
 
 varying vec4 v_Color;
@@ -15,6 +17,8 @@ varying vec2 v_Pos;
 varying vec2 v_Scale;
 varying vec4 v_BorderRadius;
 varying float v_BorderWidth;
+varying vec4 v_ShadowColor;
+varying vec3 v_Shadow;
 
 
 void main() {
@@ -28,11 +32,23 @@ void main() {
         min(i_BorderRadius.w, min(i_Scale.x, i_Scale.y) / 2.0)
     );
 
+    // Grow the quad to cover its shadow
+    vec2 shadow_min = vec2(0.0);
+    vec2 shadow_max = vec2(0.0);
+
+    if (i_ShadowColor.a > 0.0) {
+        shadow_min = min(i_Shadow.xy - vec2(i_Shadow.z), vec2(0.0)) * u_Scale;
+        shadow_max = max(i_Shadow.xy + vec2(i_Shadow.z), vec2(0.0)) * u_Scale;
+    }
+
+    vec2 a_Pos = p_Pos + shadow_min;
+    vec2 a_Scale = p_Scale - shadow_min + shadow_max;
+
     mat4 i_Transform = mat4(
-        vec4(p_Scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4(0.0, p_Scale.y + 1.0, 0.0, 0.0),
+        vec4(a_Scale.x + 1.0, 0.0, 0.0, 0.0),
+        vec4(0.0, a_Scale.y + 1.0, 0.0, 0.0),
         vec4(0.0, 0.0, 1.0, 0.0),
-        vec4(p_Pos - vec2(0.5, 0.5), 0.0, 1.0)
+        vec4(a_Pos - vec2(0.5, 0.5), 0.0, 1.0)
     );
 
     v_Color = i_Color;
@@ -41,6 +57,8 @@ void main() {
     v_Scale = p_Scale;
     v_BorderRadius = i_BorderRadius * u_Scale;
     v_BorderWidth = i_BorderWidth * u_Scale;
+    v_ShadowColor = i_ShadowColor;
+    v_Shadow = i_Shadow * u_Scale;
 
     gl_Position = u_Transform * i_Transform * vec4(q_Pos, 0.0, 1.0);
 }
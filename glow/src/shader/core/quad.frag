@@ -13,12 +13,20 @@ out vec4 fragColor;
 
 uniform float u_ScreenHeight;
 
+// The gradient of the quad, if u_GradientStops is not zero
+uniform int u_GradientStops;
+uniform vec4 u_GradientDirection;
+uniform vec4 u_GradientColors[8];
+uniform float u_GradientOffsets[8];
+
 in vec4 v_Color;
 in vec4 v_BorderColor;
 in vec2 v_Pos;
 in vec2 v_Scale;
 in vec4 v_BorderRadius;
 in float v_BorderWidth;
+in vec4 v_ShadowColor;
+in vec3 v_Shadow;
 
 float fDistance(vec2 frag_coord, vec2 position, vec2 size, float radius)
 {
@@ -48,11 +56,38 @@ float selectBorderRadius(vec4 radi, vec2 position, vec2 center)
     return rx;
 }
 
+vec4 gradientColor(vec2 frag_coord)
+{
+    vec2 start = u_GradientDirection.xy;
+    vec2 direction = u_GradientDirection.zw - start;
+    float offset = dot(frag_coord - start, direction) / max(dot(direction, direction), 0.0001);
+
+    vec4 color = u_GradientColors[0];
+
+    for (int i = 1; i < 8; i++) {
+        if (i >= u_GradientStops) {
+            break;
+        }
+
+        float range = max(u_GradientOffsets[i] - u_GradientOffsets[i - 1], 0.0001);
+
+        color = mix(color, u_GradientColors[i], clamp(
+            (offset - u_GradientOffsets[i - 1]) / range,
+            0.0,
+            1.0
+        ));
+    }
+
+    return color;
+}
+
 void main() {
     vec4 mixed_color;
 
     vec2 fragCoord = vec2(gl_FragCoord.x, u_ScreenHeight - gl_FragCoord.y);
 
+    vec4 background = u_GradientStops > 0 ? gradientColor(fragCoord) : v_Color;
+
     float border_radius = selectBorderRadius(
         v_BorderRadius,
         fragCoord,
@@ -76,9 +111,9 @@ void main() {
             internal_distance
         );
 
-        mixed_color = mix(v_Color, v_BorderColor, border_mix);
+        mixed_color = mix(background, v_BorderColor, border_mix);
     } else {
-        mixed_color = v_Color;
+        mixed_color = background;
     }
 
     float d = fDistance(
@@ -91,5 +126,45 @@ void main() {
     float radius_alpha =
         1.0 - smoothstep(max(border_radius - 0.5, 0.0), border_radius + 0.5, d);
 
-    gl_FragColor = vec4(mixed_color.xyz, mixed_color.w * radius_alpha);
+    vec4 quad_color = vec4(mixed_color.xyz, mixed_color.w * radius_alpha);
+
+    if (v_ShadowColor.a > 0.0) {
+        vec2 shadow_offset = v_Shadow.xy;
+        float shadow_blur_radius = max(v_Shadow.z, 0.5);
+
+        float shadow_border_radius = selectBorderRadius(
+            v_BorderRadius,
+            fragCoord - shadow_offset,
+            (v_Pos + v_Scale * 0.5).xy
+        );
+
+        float shadow_distance = fDistance(
+            fragCoord,
+            v_Pos + shadow_offset,
+            v_Scale,
+            shadow_border_radius
+        ) - shadow_border_radius;
+
+        float shadow_alpha = v_ShadowColor.a * (1.0 - smoothstep(
+            -shadow_blur_radius,
+            shadow_blur_radius,
+            shadow_distance
+        ));
+
+        // Composite the quad over its shadow
+        float alpha = quad_color.a + shadow_alpha * (1.0 - quad_color.a);
+
+        if (alpha <= 0.0) {
+            discard;
+        }
+
+        vec3 color = (
+            quad_color.rgb * quad_color.a
+            + v_ShadowColor.rgb * shadow_alpha * (1.0 - quad_color.a)
+        ) / alpha;
+
+        gl_FragColor = vec4(color, alpha);
+    } else {
+        gl_FragColor = quad_color;
+    }
 }
@@ -7,6 +7,8 @@ in vec4 i_Color;
 in vec4 i_BorderColor;
 in vec4 i_BorderRadius;
 in float i_BorderWidth;
+in vec4 i_ShadowColor;
+in vec3 i_Shadow;
 
 out vec4 v_Color;
 out vec4 v_BorderColor;
@@ -14,6 +16,8 @@ out vec2 v_Pos;
 out vec2 v_Scale;
 out vec4 v_BorderRadius;
 out float v_BorderWidth;
+out vec4 v_ShadowColor;
+out vec3 v_Shadow;
 
 vec2 positions[4] = vec2[](
     vec2(0.0, 0.0),
@@ -34,11 +38,23 @@ void main() {
         min(i_BorderRadius.w, min(i_Scale.x, i_Scale.y) / 2.0)
     );
 
+    // Grow the quad to cover its shadow
+    vec2 shadow_min = vec2(0.0);
+    vec2 shadow_max = vec2(0.0);
+
+    if (i_ShadowColor.a > 0.0) {
+        shadow_min = min(i_Shadow.xy - vec2(i_Shadow.z), vec2(0.0)) * u_Scale;
+        shadow_max = max(i_Shadow.xy + vec2(i_Shadow.z), vec2(0.0)) * u_Scale;
+    }
+
+    vec2 a_Pos = p_Pos + shadow_min;
+    vec2 a_Scale = p_Scale - shadow_min + shadow_max;
+
     mat4 i_Transform = mat4(
-        vec4(p_Scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4(0.0, p_Scale.y + 1.0, 0.0, 0.0),
+        vec4(a_Scale.x + 1.0, 0.0, 0.0, 0.0),
+        vec4(0.0, a_Scale.y + 1.0, 0.0, 0.0),
         vec4(0.0, 0.0, 1.0, 0.0),
-        vec4(p_Pos - vec2(0.5, 0.5), 0.0, 1.0)
+        vec4(a_Pos - vec2(0.5, 0.5), 0.0, 1.0)
     );
 
     v_Color = i_Color;
@@ -47,6 +63,8 @@ void main() {
     v_Scale = p_Scale;
     v_BorderRadius = i_BorderRadius * u_Scale;
     v_BorderWidth = i_BorderWidth * u_Scale;
+    v_ShadowColor = i_ShadowColor;
+    v_Shadow = i_Shadow * u_Scale;
 
     gl_Position = u_Transform * i_Transform * vec4(q_Pos, 0.0, 1.0);
 }
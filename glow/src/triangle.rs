@@ -5,6 +5,7 @@ use crate::Transformation;
 use iced_graphics::gradient::Gradient;
 use iced_graphics::layer::mesh::{self, Mesh};
 use iced_graphics::triangle::{ColoredVertex2D, Vertex2D};
+use iced_graphics::Rectangle;
 
 use glow::HasContext;
 use std::marker::PhantomData;
@@ -181,7 +182,10 @@ impl Pipeline {
                     last_solid_vertex += buffers.vertices.len();
                 },
                 Mesh::Gradient {
-                    buffers, gradient, ..
+                    buffers,
+                    size,
+                    gradient,
+                    ..
                 } => unsafe {
                     gl.use_program(Some(self.gradient.program));
                     gl.bind_vertex_array(Some(self.gradient.vertex_array));
@@ -196,68 +200,76 @@ impl Pipeline {
                         self.gradient.uniforms.transform = transform;
                     }
 
-                    if &self.gradient.uniforms.gradient != *gradient {
-                        match gradient {
+                    let mut gradient = **gradient;
+
+                    if let Gradient::Linear(linear) = &mut gradient {
+                        linear.direction =
+                            linear.points(Rectangle::with_size(*size)).into();
+                    }
+
+                    if self.gradient.uniforms.gradient != gradient {
+                        let (direction, kind) = match gradient {
                             Gradient::Linear(linear) => {
-                                gl.uniform_4_f32(
-                                    Some(
-                                        &self
-                                            .gradient
-                                            .uniforms
-                                            .locations
-                                            .gradient_direction,
-                                    ),
-                                    linear.start.x,
-                                    linear.start.y,
-                                    linear.end.x,
-                                    linear.end.y,
-                                );
-
-                                gl.uniform_1_i32(
-                                    Some(
-                                        &self
-                                            .gradient
-                                            .uniforms
-                                            .locations
-                                            .color_stops_size,
-                                    ),
-                                    (linear.color_stops.len() * 2) as i32,
-                                );
-
-                                let mut stops = [0.0; 128];
-
-                                for (index, stop) in linear
-                                    .color_stops
-                                    .iter()
-                                    .enumerate()
-                                    .take(16)
-                                {
-                                    let [r, g, b, a] = stop.color.into_linear();
-
-                                    stops[index * 8] = r;
-                                    stops[(index * 8) + 1] = g;
-                                    stops[(index * 8) + 2] = b;
-                                    stops[(index * 8) + 3] = a;
-                                    stops[(index * 8) + 4] = stop.offset;
-                                    stops[(index * 8) + 5] = 0.;
-                                    stops[(index * 8) + 6] = 0.;
-                                    stops[(index * 8) + 7] = 0.;
-                                }
-
-                                gl.uniform_4_f32_slice(
-                                    Some(
-                                        &self
-                                            .gradient
-                                            .uniforms
-                                            .locations
-                                            .color_stops,
-                                    ),
-                                    &stops,
-                                );
+                                let (start, end) =
+                                    linear.points(Rectangle::default());
+
+                                ([start.x, start.y, end.x, end.y], 0)
                             }
+                            Gradient::Radial(radial) => (
+                                [
+                                    radial.center.x,
+                                    radial.center.y,
+                                    radial.radius,
+                                    0.0,
+                                ],
+                                1,
+                            ),
+                            Gradient::Conic(conic) => (
+                                [
+                                    conic.center.x,
+                                    conic.center.y,
+                                    conic.angle,
+                                    0.0,
+                                ],
+                                2,
+                            ),
+                        };
+
+                        let locations = &self.gradient.uniforms.locations;
+
+                        gl.uniform_4_f32_slice(
+                            Some(&locations.gradient_direction),
+                            &direction,
+                        );
+
+                        gl.uniform_1_i32(Some(&locations.gradient_kind), kind);
+
+                        gl.uniform_1_i32(
+                            Some(&locations.color_stops_size),
+                            (gradient.stops().count() * 2) as i32,
+                        );
+
+                        let mut stops = [0.0; 128];
+
+                        for (index, stop) in gradient.stops().enumerate() {
+                            let [r, g, b, a] = stop.color.into_linear();
+
+                            stops[index * 8] = r;
+                            stops[(index * 8) + 1] = g;
+                            stops[(index * 8) + 2] = b;
+                            stops[(index * 8) + 3] = a;
+                            stops[(index * 8) + 4] = stop.offset;
+                            stops[(index * 8) + 5] = 0.;
+                            stops[(index * 8) + 6] = 0.;
+                            stops[(index * 8) + 7] = 0.;
                         }
 
-                        self.gradient.uniforms.gradient = (*gradient).clone();
+                        gl.uniform_4_f32_slice(
+                            Some(&locations.color_stops),
+                            &stops,
+                        );
+
+                        self.gradient.uniforms.gradient = gradient;
                     }
 
                     gl.draw_elements_base_vertex(
@@ -455,7 +467,7 @@ mod gradient {
     use glow::{Context, HasContext, NativeProgram};
     use iced_graphics::gradient::{self, Gradient};
     use iced_graphics::triangle::Vertex2D;
-    use iced_graphics::Transformation;
+    use iced_graphics::{Point, Transformation};
 
     #[derive(Debug)]
     pub struct Program {
@@ -537,6 +549,7 @@ mod gradient {
     #[derive(Debug)]
     pub struct Locations {
         pub gradient_direction: <Context as HasContext>::UniformLocation,
+        pub gradient_kind: <Context as HasContext>::UniformLocation,
         pub color_stops_size: <Context as HasContext>::UniformLocation,
         //currently the maximum number of stops is 16 due to lack of SSBO in GL2.1
         pub color_stops: <Context as HasContext>::UniformLocation,
@@ -550,6 +563,10 @@ mod gradient {
             }
             .expect("Gradient - Get gradient_direction.");
 
+            let gradient_kind =
+                unsafe { gl.get_uniform_location(program, "gradient_kind") }
+                    .expect("Gradient - Get gradient_kind.");
+
             let color_stops_size =
                 unsafe { gl.get_uniform_location(program, "color_stops_size") }
                     .expect("Gradient - Get color_stops_size.");
@@ -577,14 +594,14 @@ mod gradient {
             }
 
             Self {
-                gradient: Gradient::Linear(gradient::Linear {
-                    start: Default::default(),
-                    end: Default::default(),
-                    color_stops: vec![],
-                }),
+                gradient: Gradient::Linear(gradient::Linear::new((
+                    Point::ORIGIN,
+                    Point::ORIGIN,
+                ))),
                 transform: Transformation::identity(),
                 locations: Locations {
                     gradient_direction,
+                    gradient_kind,
                     color_stops_size,
                     color_stops,
                     transform: transform_location,
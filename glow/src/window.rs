@@ -1,4 +1,6 @@
 //! Display rendering results on windows.
 mod compositor;
+mod surface;
 
 pub use compositor::Compositor;
+pub use surface::Surface;
@@ -1,6 +1,8 @@
+use crate::window::surface::{Offscreen, Surface};
 use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
 
 use glow::HasContext;
+use iced_graphics::window::GLCompositor;
 use iced_graphics::{compositor, Antialiasing, Size};
 
 use core::ffi::c_void;
@@ -10,12 +12,16 @@ use std::marker::PhantomData;
 #[allow(missing_debug_implementations)]
 pub struct Compositor<Theme> {
     gl: glow::Context,
+    viewport: Size<u32>,
+    samples: u32,
+    offscreen: Option<Offscreen>,
     theme: PhantomData<Theme>,
 }
 
-impl<Theme> iced_graphics::window::GLCompositor for Compositor<Theme> {
+impl<Theme> GLCompositor for Compositor<Theme> {
     type Settings = Settings;
     type Renderer = Renderer<Theme>;
+    type Surface = Surface;
 
     unsafe fn new(
         settings: Self::Settings,
@@ -50,11 +56,15 @@ impl<Theme> iced_graphics::window::GLCompositor for Compositor<Theme> {
         // Disable multisampling by default
         gl.disable(glow::MULTISAMPLE);
 
+        let samples = Self::sample_count(&settings);
         let renderer = Renderer::new(Backend::new(&gl, settings));
 
         Ok((
             Self {
                 gl,
+                viewport: Size::new(0, 0),
+                samples,
+                offscreen: None,
                 theme: PhantomData,
             },
             renderer,
@@ -69,6 +79,8 @@ impl<Theme> iced_graphics::window::GLCompositor for Compositor<Theme> {
     }
 
     fn resize_viewport(&mut self, physical_size: Size<u32>) {
+        self.viewport = physical_size;
+
         unsafe {
             self.gl.viewport(
                 0,
@@ -108,4 +120,160 @@ impl<Theme> iced_graphics::window::GLCompositor for Compositor<Theme> {
             backend.present(gl, primitive, viewport, overlay);
         });
     }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8> {
+        let Size { width, height } = viewport.physical_size();
+
+        self.present(renderer, viewport, background_color, overlay);
+
+        unsafe {
+            self.gl.bind_framebuffer(glow::READ_FRAMEBUFFER, None);
+
+            read_pixels(&self.gl, width, height)
+        }
+    }
+
+    unsafe fn create_surface(
+        &mut self,
+        loader_function: impl FnMut(&str) -> *const c_void,
+    ) -> Surface {
+        Surface::new(glow::Context::from_loader_function(loader_function))
+    }
+
+    fn present_surface<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        surface: &mut Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) {
+        self.present_offscreen(
+            renderer,
+            surface,
+            viewport,
+            background_color,
+            overlay,
+        );
+
+        self.finish_offscreen();
+    }
+
+    fn screenshot_surface<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        surface: &mut Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8> {
+        let Size { width, height } = viewport.physical_size();
+
+        self.present_offscreen(
+            renderer,
+            surface,
+            viewport,
+            background_color,
+            overlay,
+        );
+
+        let bytes = unsafe { read_pixels(&self.gl, width, height) };
+
+        self.finish_offscreen();
+
+        bytes
+    }
+
+    unsafe fn draw_surface(&mut self, surface: &Surface) {
+        surface.draw();
+    }
+
+    unsafe fn destroy_surface(&mut self, surface: Surface) {
+        surface.destroy();
+    }
+}
+
+impl<Theme> Compositor<Theme> {
+    /// Presents a frame onto the target of the given [`Surface`], leaving
+    /// its framebuffer bound.
+    fn present_offscreen<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Renderer<Theme>,
+        surface: &mut Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) {
+        let size = viewport.physical_size();
+
+        unsafe {
+            let texture = surface.target(&self.gl, size);
+
+            let offscreen = self
+                .offscreen
+                .get_or_insert_with(|| Offscreen::new(&self.gl, self.samples));
+
+            offscreen.bind(&self.gl, texture, size);
+        }
+
+        <Self as GLCompositor>::present(
+            self,
+            renderer,
+            viewport,
+            background_color,
+            overlay,
+        );
+
+        if let Some(offscreen) = &self.offscreen {
+            unsafe { offscreen.resolve(&self.gl, size) };
+        }
+    }
+
+    /// Makes a frame presented with [`present_offscreen`] visible to the
+    /// context of its window.
+    ///
+    /// [`present_offscreen`]: Self::present_offscreen
+    fn finish_offscreen(&mut self) {
+        let Size { width, height } = self.viewport;
+
+        unsafe {
+            self.gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+            self.gl.viewport(0, 0, width as i32, height as i32);
+
+            // Changes to shared objects are only guaranteed to be visible to
+            // other contexts once they are finished
+            self.gl.finish();
+        }
+    }
+}
+
+/// Reads back the pixels of the bound framebuffer.
+unsafe fn read_pixels(gl: &glow::Context, width: u32, height: u32) -> Vec<u8> {
+    let row_size = width as usize * 4;
+    let mut bytes = vec![0; row_size * height as usize];
+
+    gl.pixel_store_i32(glow::PACK_ALIGNMENT, 1);
+    gl.read_pixels(
+        0,
+        0,
+        width as i32,
+        height as i32,
+        glow::RGBA,
+        glow::UNSIGNED_BYTE,
+        glow::PixelPackData::Slice(&mut bytes),
+    );
+
+    // OpenGL stores rows from bottom to top
+    bytes
+        .chunks_exact(row_size)
+        .rev()
+        .flatten()
+        .copied()
+        .collect()
 }
@@ -0,0 +1,326 @@
+use crate::program::{self, Shader};
+use crate::Transformation;
+
+use glow::HasContext;
+use iced_graphics::Size;
+
+/// The surface of an additional window of a [`Compositor`].
+///
+/// Frames are presented onto a texture shared with the context of the
+/// window, which then draws it.
+///
+/// [`Compositor`]: super::Compositor
+#[allow(missing_debug_implementations)]
+pub struct Surface {
+    gl: glow::Context,
+    program: <glow::Context as HasContext>::Program,
+    vertex_array: <glow::Context as HasContext>::VertexArray,
+    vertex_buffer: <glow::Context as HasContext>::Buffer,
+    target: Option<Target>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Target {
+    texture: <glow::Context as HasContext>::Texture,
+    size: Size<u32>,
+}
+
+impl Surface {
+    /// Creates a new [`Surface`] with the context of its window.
+    pub(super) unsafe fn new(gl: glow::Context) -> Surface {
+        // Encode the linear colors of the texture, like the main window does
+        gl.enable(glow::FRAMEBUFFER_SRGB);
+
+        let shader_version = program::Version::new(&gl);
+
+        let program = {
+            let vertex_shader = Shader::vertex(
+                &gl,
+                &shader_version,
+                include_str!("../shader/common/image.vert"),
+            );
+            let fragment_shader = Shader::fragment(
+                &gl,
+                &shader_version,
+                include_str!("../shader/common/image.frag"),
+            );
+
+            program::create(
+                &gl,
+                &[vertex_shader, fragment_shader],
+                &[(0, "i_Position")],
+            )
+        };
+
+        let transform_location = gl
+            .get_uniform_location(program, "u_Transform")
+            .expect("Get transform location");
+
+        let opacity_location = gl
+            .get_uniform_location(program, "u_Opacity")
+            .expect("Get opacity location");
+
+        // Map the unit square to the whole window, keeping the bottom-up rows
+        // of the texture
+        let transform: [f32; 16] = (Transformation::translate(-1.0, -1.0)
+            * Transformation::scale(2.0, 2.0))
+        .into();
+
+        gl.use_program(Some(program));
+        gl.uniform_matrix_4_f32_slice(
+            Some(&transform_location),
+            false,
+            &transform,
+        );
+        gl.uniform_1_f32(Some(&opacity_location), 1.0);
+        gl.use_program(None);
+
+        let vertex_buffer = gl.create_buffer().expect("Create vertex buffer");
+        let vertex_array =
+            gl.create_vertex_array().expect("Create vertex array");
+
+        gl.bind_vertex_array(Some(vertex_array));
+        gl.bind_buffer(glow::ARRAY_BUFFER, Some(vertex_buffer));
+
+        let vertices = &[0u8, 0, 1, 0, 0, 1, 1, 1];
+        gl.buffer_data_u8_slice(
+            glow::ARRAY_BUFFER,
+            vertices,
+            glow::STATIC_DRAW,
+        );
+
+        gl.enable_vertex_attrib_array(0);
+        gl.vertex_attrib_pointer_f32(0, 2, glow::UNSIGNED_BYTE, false, 0, 0);
+
+        gl.bind_buffer(glow::ARRAY_BUFFER, None);
+        gl.bind_vertex_array(None);
+
+        Surface {
+            gl,
+            program,
+            vertex_array,
+            vertex_buffer,
+            target: None,
+        }
+    }
+
+    /// Returns the texture to present a frame of the given size onto,
+    /// allocating it with the context of the compositor if needed.
+    pub(super) unsafe fn target(
+        &mut self,
+        gl: &glow::Context,
+        size: Size<u32>,
+    ) -> <glow::Context as HasContext>::Texture {
+        if let Some(target) = self.target {
+            if target.size == size {
+                return target.texture;
+            }
+        }
+
+        let texture = match self.target {
+            Some(target) => target.texture,
+            None => gl.create_texture().expect("Create texture"),
+        };
+
+        gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+        gl.tex_image_2d(
+            glow::TEXTURE_2D,
+            0,
+            glow::SRGB8_ALPHA8 as i32,
+            size.width as i32,
+            size.height as i32,
+            0,
+            glow::RGBA,
+            glow::UNSIGNED_BYTE,
+            None,
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_WRAP_S,
+            glow::CLAMP_TO_EDGE as _,
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_WRAP_T,
+            glow::CLAMP_TO_EDGE as _,
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_MIN_FILTER,
+            glow::NEAREST as _,
+        );
+        gl.tex_parameter_i32(
+            glow::TEXTURE_2D,
+            glow::TEXTURE_MAG_FILTER,
+            glow::NEAREST as _,
+        );
+        gl.bind_texture(glow::TEXTURE_2D, None);
+
+        self.target = Some(Target { texture, size });
+
+        texture
+    }
+
+    /// Draws the texture of the [`Surface`] with the context of its window.
+    pub(super) unsafe fn draw(&self) {
+        let target = match self.target {
+            Some(target) => target,
+            None => return,
+        };
+
+        let gl = &self.gl;
+
+        gl.viewport(0, 0, target.size.width as i32, target.size.height as i32);
+
+        gl.use_program(Some(self.program));
+        gl.active_texture(glow::TEXTURE0);
+        gl.bind_texture(glow::TEXTURE_2D, Some(target.texture));
+        gl.bind_vertex_array(Some(self.vertex_array));
+
+        gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
+
+        gl.bind_vertex_array(None);
+        gl.bind_texture(glow::TEXTURE_2D, None);
+        gl.use_program(None);
+    }
+
+    /// Destroys the objects of the [`Surface`] with the context of its
+    /// window.
+    pub(super) unsafe fn destroy(self) {
+        let gl = &self.gl;
+
+        if let Some(target) = self.target {
+            gl.delete_texture(target.texture);
+        }
+
+        gl.delete_vertex_array(self.vertex_array);
+        gl.delete_buffer(self.vertex_buffer);
+        gl.delete_program(self.program);
+    }
+}
+
+/// The framebuffers a [`Compositor`] presents surfaces with.
+///
+/// Framebuffers are not shared between contexts, so they belong to the
+/// context of the compositor.
+///
+/// [`Compositor`]: super::Compositor
+#[derive(Debug)]
+pub(super) struct Offscreen {
+    framebuffer: <glow::Context as HasContext>::Framebuffer,
+    multisample: Option<Multisample>,
+}
+
+#[derive(Debug)]
+struct Multisample {
+    framebuffer: <glow::Context as HasContext>::Framebuffer,
+    renderbuffer: <glow::Context as HasContext>::Renderbuffer,
+    samples: i32,
+    size: Size<u32>,
+}
+
+impl Offscreen {
+    pub(super) unsafe fn new(gl: &glow::Context, samples: u32) -> Offscreen {
+        let framebuffer = gl.create_framebuffer().expect("Create framebuffer");
+
+        // Multisampled renderbuffers need OpenGL (ES) 3.0
+        let multisample = (samples > 0 && gl.version().major >= 3).then(|| {
+            let framebuffer =
+                gl.create_framebuffer().expect("Create framebuffer");
+            let renderbuffer =
+                gl.create_renderbuffer().expect("Create renderbuffer");
+
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
+            gl.framebuffer_renderbuffer(
+                glow::FRAMEBUFFER,
+                glow::COLOR_ATTACHMENT0,
+                glow::RENDERBUFFER,
+                Some(renderbuffer),
+            );
+            gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+
+            Multisample {
+                framebuffer,
+                renderbuffer,
+                samples: samples as i32,
+                size: Size::new(0, 0),
+            }
+        });
+
+        Offscreen {
+            framebuffer,
+            multisample,
+        }
+    }
+
+    /// Binds the framebuffer to draw a frame onto the given texture.
+    pub(super) unsafe fn bind(
+        &mut self,
+        gl: &glow::Context,
+        texture: <glow::Context as HasContext>::Texture,
+        size: Size<u32>,
+    ) {
+        gl.bind_framebuffer(glow::FRAMEBUFFER, Some(self.framebuffer));
+        gl.framebuffer_texture_2d(
+            glow::FRAMEBUFFER,
+            glow::COLOR_ATTACHMENT0,
+            glow::TEXTURE_2D,
+            Some(texture),
+            0,
+        );
+
+        if let Some(multisample) = &mut self.multisample {
+            if multisample.size != size {
+                gl.bind_renderbuffer(
+                    glow::RENDERBUFFER,
+                    Some(multisample.renderbuffer),
+                );
+                gl.renderbuffer_storage_multisample(
+                    glow::RENDERBUFFER,
+                    multisample.samples,
+                    glow::SRGB8_ALPHA8,
+                    size.width as i32,
+                    size.height as i32,
+                );
+                gl.bind_renderbuffer(glow::RENDERBUFFER, None);
+
+                multisample.size = size;
+            }
+
+            gl.bind_framebuffer(
+                glow::FRAMEBUFFER,
+                Some(multisample.framebuffer),
+            );
+        }
+
+        gl.viewport(0, 0, size.width as i32, size.height as i32);
+    }
+
+    /// Resolves the drawn frame into the texture bound last, leaving its
+    /// framebuffer bound.
+    pub(super) unsafe fn resolve(&self, gl: &glow::Context, size: Size<u32>) {
+        if let Some(multisample) = &self.multisample {
+            let (width, height) = (size.width as i32, size.height as i32);
+
+            gl.bind_framebuffer(
+                glow::READ_FRAMEBUFFER,
+                Some(multisample.framebuffer),
+            );
+            gl.bind_framebuffer(glow::DRAW_FRAMEBUFFER, Some(self.framebuffer));
+            gl.blit_framebuffer(
+                0,
+                0,
+                width,
+                height,
+                0,
+                0,
+                width,
+                height,
+                glow::COLOR_BUFFER_BIT,
+                glow::NEAREST,
+            );
+        }
+
+        gl.bind_framebuffer(glow::FRAMEBUFFER, Some(self.framebuffer));
+    }
+}
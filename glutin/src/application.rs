@@ -6,6 +6,7 @@ pub use iced_winit::application::StyleSheet;
 pub use iced_winit::Application;
 
 use iced_graphics::window;
+use iced_winit::accessibility;
 use iced_winit::application;
 use iced_winit::conversion;
 use iced_winit::futures;
@@ -15,8 +16,9 @@ use iced_winit::time::Instant;
 use iced_winit::user_interface;
 use iced_winit::{Clipboard, Command, Debug, Event, Proxy, Settings};
 
-use glutin::window::Window;
 use std::mem::ManuallyDrop;
+use std::sync::atomic;
+use std::sync::Arc;
 
 #[cfg(feature = "tracing")]
 use tracing::{info_span, instrument::Instrument};
@@ -37,7 +39,6 @@ where
     use futures::Future;
     use glutin::event_loop::EventLoopBuilder;
     use glutin::platform::run_return::EventLoopExtRunReturn;
-    use glutin::ContextBuilder;
 
     #[cfg(feature = "trace")]
     let _guard = iced_winit::Profiler::init();
@@ -73,56 +74,12 @@ where
 
         log::info!("Window builder: {:#?}", builder);
 
-        let opengl_builder = ContextBuilder::new()
-            .with_vsync(true)
-            .with_multisampling(C::sample_count(&compositor_settings) as u16);
-
-        let opengles_builder = opengl_builder.clone().with_gl(
-            glutin::GlRequest::Specific(glutin::Api::OpenGlEs, (2, 0)),
-        );
-
-        let (first_builder, second_builder) = if settings.try_opengles_first {
-            (opengles_builder, opengl_builder)
-        } else {
-            (opengl_builder, opengles_builder)
-        };
-
-        log::info!("Trying first builder: {:#?}", first_builder);
-
-        let context = first_builder
-            .build_windowed(builder.clone(), &event_loop)
-            .or_else(|_| {
-                log::info!("Trying second builder: {:#?}", second_builder);
-                second_builder.build_windowed(builder, &event_loop)
-            })
-            .map_err(|error| {
-                use glutin::CreationError;
-                use iced_graphics::Error as ContextError;
-
-                match error {
-                    CreationError::Window(error) => {
-                        Error::WindowCreationFailed(error)
-                    }
-                    CreationError::OpenGlVersionNotSupported => {
-                        Error::GraphicsCreationFailed(
-                            ContextError::VersionNotSupported,
-                        )
-                    }
-                    CreationError::NoAvailablePixelFormat => {
-                        Error::GraphicsCreationFailed(
-                            ContextError::NoAvailablePixelFormat,
-                        )
-                    }
-                    error => Error::GraphicsCreationFailed(
-                        ContextError::BackendError(error.to_string()),
-                    ),
-                }
-            })?;
-
-        #[allow(unsafe_code)]
-        unsafe {
-            context.make_current().expect("Make OpenGL context current")
-        }
+        create_context(
+            builder,
+            &event_loop,
+            C::sample_count(&compositor_settings) as u16,
+            settings.try_opengles_first,
+        )?
     };
 
     #[allow(unsafe_code)]
@@ -148,6 +105,7 @@ where
             context,
             init_command,
             settings.exit_on_close_request,
+            settings.focus_navigation,
         );
 
         #[cfg(feature = "tracing")]
@@ -202,6 +160,68 @@ where
     Ok(())
 }
 
+/// Creates a window with a current OpenGL context, trying OpenGL ES either
+/// first or as a fallback.
+pub(crate) fn create_context<T>(
+    builder: glutin::window::WindowBuilder,
+    event_loop: &glutin::event_loop::EventLoopWindowTarget<T>,
+    sample_count: u16,
+    try_opengles_first: bool,
+) -> Result<glutin::WindowedContext<glutin::PossiblyCurrent>, Error> {
+    use glutin::ContextBuilder;
+
+    let opengl_builder = ContextBuilder::new()
+        .with_vsync(true)
+        .with_multisampling(sample_count);
+
+    let opengles_builder = opengl_builder
+        .clone()
+        .with_gl(glutin::GlRequest::Specific(glutin::Api::OpenGlEs, (2, 0)));
+
+    let (first_builder, second_builder) = if try_opengles_first {
+        (opengles_builder, opengl_builder)
+    } else {
+        (opengl_builder, opengles_builder)
+    };
+
+    log::info!("Trying first builder: {:#?}", first_builder);
+
+    let context = first_builder
+        .build_windowed(builder.clone(), event_loop)
+        .or_else(|_| {
+            log::info!("Trying second builder: {:#?}", second_builder);
+            second_builder.build_windowed(builder, event_loop)
+        })
+        .map_err(|error| {
+            use glutin::CreationError;
+            use iced_graphics::Error as ContextError;
+
+            match error {
+                CreationError::Window(error) => {
+                    Error::WindowCreationFailed(error)
+                }
+                CreationError::OpenGlVersionNotSupported => {
+                    Error::GraphicsCreationFailed(
+                        ContextError::VersionNotSupported,
+                    )
+                }
+                CreationError::NoAvailablePixelFormat => {
+                    Error::GraphicsCreationFailed(
+                        ContextError::NoAvailablePixelFormat,
+                    )
+                }
+                error => Error::GraphicsCreationFailed(
+                    ContextError::BackendError(error.to_string()),
+                ),
+            }
+        })?;
+
+    #[allow(unsafe_code)]
+    unsafe {
+        Ok(context.make_current().expect("Make OpenGL context current"))
+    }
+}
+
 async fn run_instance<A, E, C>(
     mut application: A,
     mut compositor: C,
@@ -213,9 +233,10 @@ async fn run_instance<A, E, C>(
         glutin::event::Event<'_, A::Message>,
     >,
     mut control_sender: mpsc::UnboundedSender<glutin::event_loop::ControlFlow>,
-    mut context: glutin::ContextWrapper<glutin::PossiblyCurrent, Window>,
+    context: glutin::WindowedContext<glutin::PossiblyCurrent>,
     init_command: Command<A::Message>,
     exit_on_close_request: bool,
+    focus_navigation: bool,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
@@ -226,11 +247,20 @@ async fn run_instance<A, E, C>(
     use glutin::event_loop::ControlFlow;
     use iced_winit::futures::stream::StreamExt;
 
-    let mut clipboard = Clipboard::connect(context.window());
+    // The window is shared with the image decoder, so it can be woken up
+    #[allow(unsafe_code)]
+    let (mut context, window) = unsafe { context.split() };
+    let window = Arc::new(window);
+    let image_loaded = application::redraw_on_load(&window);
+
+    let mut clipboard = Clipboard::connect(&window);
     let mut cache = user_interface::Cache::default();
-    let mut state = application::State::new(&application, context.window());
+    let mut state = application::State::new(&application, &window);
     let mut viewport_version = state.viewport_version();
+    let mut accessibility =
+        accessibility::Connection::new(iced_winit::window::Id::MAIN, &window);
     let mut should_exit = false;
+    let mut screenshots = Vec::new();
 
     application::run_command(
         &application,
@@ -241,9 +271,10 @@ async fn run_instance<A, E, C>(
         &mut runtime,
         &mut clipboard,
         &mut should_exit,
+        &mut screenshots,
         &mut proxy,
         &mut debug,
-        context.window(),
+        &window,
         || compositor.fetch_information(),
     );
     runtime.track(application.subscription());
@@ -258,6 +289,7 @@ async fn run_instance<A, E, C>(
         ));
 
     let mut mouse_interaction = mouse::Interaction::default();
+    let mut last_click = None;
     let mut events = Vec::new();
     let mut messages = Vec::new();
     let mut redraw_pending = false;
@@ -272,7 +304,8 @@ async fn run_instance<A, E, C>(
                     event::StartCause::Init
                         | event::StartCause::Poll
                         | event::StartCause::ResumeTimeReached { .. }
-                );
+                ) || image_loaded
+                    .swap(false, atomic::Ordering::Relaxed);
             }
             event::Event::MainEventsCleared => {
                 if !redraw_pending && events.is_empty() && messages.is_empty() {
@@ -291,8 +324,19 @@ async fn run_instance<A, E, C>(
 
                 debug.event_processing_finished();
 
-                for event in events.drain(..).zip(statuses.into_iter()) {
-                    runtime.broadcast(event);
+                for (event, status) in
+                    events.drain(..).zip(statuses.into_iter())
+                {
+                    if focus_navigation {
+                        application::navigate_focus(
+                            &mut user_interface,
+                            &renderer,
+                            &event,
+                            status,
+                        );
+                    }
+
+                    runtime.broadcast((event, status));
                 }
 
                 if !messages.is_empty()
@@ -313,15 +357,16 @@ async fn run_instance<A, E, C>(
                         &mut runtime,
                         &mut clipboard,
                         &mut should_exit,
+                        &mut screenshots,
                         &mut proxy,
                         &mut debug,
                         &mut messages,
-                        context.window(),
+                        &window,
                         || compositor.fetch_information(),
                     );
 
                     // Update window
-                    state.synchronize(&application, context.window());
+                    state.synchronize(&application, &window);
 
                     user_interface =
                         ManuallyDrop::new(application::build_user_interface(
@@ -343,6 +388,7 @@ async fn run_instance<A, E, C>(
                 // Then, we can use the `interface_state` here to decide if a redraw
                 // is needed right away, or simply wait until a specific time.
                 let redraw_event = Event::Window(
+                    crate::window::Id::MAIN,
                     crate::window::Event::RedrawRequested(Instant::now()),
                 );
 
@@ -365,15 +411,20 @@ async fn run_instance<A, E, C>(
                 );
                 debug.draw_finished();
 
+                accessibility.update(&mut user_interface, &renderer);
+
                 if new_mouse_interaction != mouse_interaction {
-                    context.window().set_cursor_icon(
-                        conversion::mouse_interaction(new_mouse_interaction),
-                    );
+                    window.set_cursor_icon(conversion::mouse_interaction(
+                        new_mouse_interaction,
+                    ));
+                    window.set_cursor_visible(conversion::cursor_visible(
+                        new_mouse_interaction,
+                    ));
 
                     mouse_interaction = new_mouse_interaction;
                 }
 
-                context.window().request_redraw();
+                window.request_redraw();
                 runtime
                     .broadcast((redraw_event, crate::event::Status::Ignored));
 
@@ -445,12 +496,15 @@ async fn run_instance<A, E, C>(
                     );
                     debug.draw_finished();
 
+                    accessibility.update(&mut user_interface, &renderer);
+
                     if new_mouse_interaction != mouse_interaction {
-                        context.window().set_cursor_icon(
-                            conversion::mouse_interaction(
-                                new_mouse_interaction,
-                            ),
-                        );
+                        window.set_cursor_icon(conversion::mouse_interaction(
+                            new_mouse_interaction,
+                        ));
+                        window.set_cursor_visible(conversion::cursor_visible(
+                            new_mouse_interaction,
+                        ));
 
                         mouse_interaction = new_mouse_interaction;
                     }
@@ -465,12 +519,32 @@ async fn run_instance<A, E, C>(
                     viewport_version = current_viewport_version;
                 }
 
-                compositor.present(
-                    &mut renderer,
-                    state.viewport(),
-                    state.background_color(),
-                    &debug.overlay(),
-                );
+                if screenshots.is_empty() {
+                    compositor.present(
+                        &mut renderer,
+                        state.viewport(),
+                        state.background_color(),
+                        &debug.overlay(),
+                    );
+                } else {
+                    let bytes = compositor.screenshot(
+                        &mut renderer,
+                        state.viewport(),
+                        state.background_color(),
+                        &debug.overlay(),
+                    );
+
+                    let screenshot = crate::window::Screenshot::new(
+                        bytes,
+                        state.physical_size(),
+                    );
+
+                    for tag in screenshots.drain(..) {
+                        proxy
+                            .send_event(tag(screenshot.clone()))
+                            .expect("Send message to event loop");
+                    }
+                }
 
                 context.swap_buffers().expect("Swap buffers");
 
@@ -489,12 +563,15 @@ async fn run_instance<A, E, C>(
                     break;
                 }
 
-                state.update(context.window(), &window_event, &mut debug);
+                state.update(&window, &window_event, &mut debug);
 
                 if let Some(event) = conversion::window_event(
+                    crate::window::Id::MAIN,
                     &window_event,
                     state.scale_factor(),
                     state.modifiers(),
+                    state.cursor_position(),
+                    &mut last_click,
                 ) {
                     events.push(event);
                 }
@@ -505,4 +582,10 @@ async fn run_instance<A, E, C>(
 
     // Manually drop the user interface
     drop(ManuallyDrop::into_inner(user_interface));
+
+    // The context and the accessibility bridge must be destroyed before
+    // their window
+    drop(context);
+    drop(accessibility);
+    drop(window);
 }
@@ -28,6 +28,8 @@ pub use glutin;
 pub use iced_winit::*;
 
 pub mod application;
+#[cfg(feature = "multi-window")]
+pub mod multi_window;
 
 #[doc(no_inline)]
 pub use application::Application;
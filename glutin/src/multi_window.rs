@@ -0,0 +1,1109 @@
+//! Create interactive, native cross-platform applications with multiple
+//! windows.
+use crate::application::create_context;
+use crate::mouse;
+use crate::{Error, Executor, Runtime};
+
+pub use iced_winit::application::StyleSheet;
+pub use iced_winit::multi_window::{Application, State};
+
+use iced_graphics::window::GLCompositor;
+use iced_winit::accessibility;
+use iced_winit::application;
+use iced_winit::conversion;
+use iced_winit::futures;
+use iced_winit::futures::channel::mpsc;
+use iced_winit::multi_window::build_user_interface;
+use iced_winit::renderer;
+use iced_winit::settings;
+use iced_winit::time::Instant;
+use iced_winit::user_interface::{self, UserInterface};
+use iced_winit::widget::operation;
+use iced_winit::window;
+use iced_winit::{Clipboard, Command, Debug, Event, Proxy, Settings};
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::mem::ManuallyDrop;
+use std::rc::Rc;
+use std::sync::atomic;
+use std::sync::Arc;
+
+/// A request of the application instance to the event loop.
+enum Control {
+    /// Changes the [`ControlFlow`] of the event loop.
+    ///
+    /// [`ControlFlow`]: glutin::event_loop::ControlFlow
+    ChangeFlow(glutin::event_loop::ControlFlow),
+
+    /// Creates a new window.
+    CreateWindow {
+        id: window::Id,
+        title: String,
+        settings: settings::Window,
+    },
+}
+
+/// An event received by the application instance.
+enum Input<Message: 'static> {
+    /// An event of the event loop.
+    EventLoop(glutin::event::Event<'static, Message>),
+
+    /// A window has been created by the event loop, with an OpenGL context
+    /// sharing its objects with the main one.
+    WindowCreated {
+        id: window::Id,
+        context: glutin::WindowedContext<glutin::NotCurrent>,
+    },
+}
+
+/// An OpenGL context, made current on demand.
+struct Context {
+    raw: Option<glutin::RawContext<glutin::PossiblyCurrent>>,
+}
+
+impl Context {
+    fn new(raw: glutin::RawContext<glutin::PossiblyCurrent>) -> Self {
+        Self { raw: Some(raw) }
+    }
+
+    fn raw(&self) -> &glutin::RawContext<glutin::PossiblyCurrent> {
+        self.raw.as_ref().expect("OpenGL context")
+    }
+
+    fn make_current(&mut self) -> &glutin::RawContext<glutin::PossiblyCurrent> {
+        let raw = self.raw.take().expect("OpenGL context");
+
+        let raw = if raw.is_current() {
+            raw
+        } else {
+            #[allow(unsafe_code)]
+            unsafe {
+                raw.make_current()
+                    .map_err(|(_, error)| error)
+                    .expect("Make OpenGL context current")
+            }
+        };
+
+        self.raw.insert(raw)
+    }
+
+    fn destroy(&mut self) {
+        self.raw = None;
+    }
+}
+
+/// The OpenGL context and surface of an additional window.
+struct Secondary<S> {
+    surface: S,
+    context: Context,
+}
+
+/// A window of a running [`Application`].
+struct Window<A, C>
+where
+    A: Application,
+    C: GLCompositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    // Fields are dropped in declaration order, and neither the context nor
+    // the accessibility bridge must outlive the window they were created for.
+    // The main window draws with the context of the compositor instead.
+    secondary: Option<Secondary<C::Surface>>,
+    accessibility: accessibility::Connection,
+    raw: Arc<glutin::window::Window>,
+    state: State<A>,
+    viewport_version: usize,
+    mouse_interaction: mouse::Interaction,
+    last_click: Option<mouse::Click>,
+    screenshots: Vec<Box<dyn FnOnce(window::Screenshot) -> A::Message>>,
+}
+
+impl<A, C> Window<A, C>
+where
+    A: Application,
+    C: GLCompositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    fn new(
+        application: &A,
+        id: window::Id,
+        raw: glutin::window::Window,
+        secondary: Option<Secondary<C::Surface>>,
+    ) -> Self {
+        let state = State::new(application, id, &raw);
+        let accessibility = accessibility::Connection::new(id, &raw);
+
+        Self {
+            secondary,
+            raw: Arc::new(raw),
+            viewport_version: state.viewport_version(),
+            state,
+            mouse_interaction: mouse::Interaction::default(),
+            last_click: None,
+            screenshots: Vec::new(),
+            accessibility,
+        }
+    }
+
+    /// Closes the window, destroying the objects of its surface first.
+    fn close(self, compositor: &mut C) {
+        if let Some(Secondary {
+            surface,
+            mut context,
+        }) = self.secondary
+        {
+            let _ = context.make_current();
+
+            #[allow(unsafe_code)]
+            unsafe {
+                compositor.destroy_surface(surface);
+            }
+        }
+    }
+}
+
+/// Runs a multi-window [`Application`] with an executor, compositor, and the
+/// provided settings.
+///
+/// The [`Settings::window`] are used to create the [`window::Id::MAIN`].
+/// Additional windows get their own OpenGL context, which shares its objects
+/// with the main one.
+pub fn run<A, E, C>(
+    settings: Settings<A::Flags>,
+    compositor_settings: C::Settings,
+) -> Result<(), Error>
+where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: GLCompositor<Renderer = A::Renderer> + 'static,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    use futures::task;
+    use futures::Future;
+    use glutin::event_loop::EventLoopBuilder;
+    use glutin::platform::run_return::EventLoopExtRunReturn;
+    use glutin::ContextBuilder;
+
+    let mut debug = Debug::new();
+    debug.startup_started();
+
+    let mut event_loop = EventLoopBuilder::with_user_event().build();
+    let proxy = event_loop.create_proxy();
+
+    let runtime = {
+        let proxy = Proxy::new(event_loop.create_proxy());
+        let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
+
+        Runtime::new(executor, proxy)
+    };
+
+    let (application, init_command) = {
+        let flags = settings.flags;
+
+        runtime.enter(|| A::new(flags))
+    };
+
+    let context = {
+        let builder = settings.window.into_builder(
+            &application.title(window::Id::MAIN),
+            event_loop.primary_monitor(),
+            settings.id,
+        );
+
+        log::info!("Window builder: {:#?}", builder);
+
+        create_context(
+            builder,
+            &event_loop,
+            C::sample_count(&compositor_settings) as u16,
+            settings.try_opengles_first,
+        )?
+    };
+
+    #[allow(unsafe_code)]
+    let (compositor, renderer) = unsafe {
+        C::new(compositor_settings, |address| {
+            context.get_proc_address(address)
+        })?
+    };
+
+    // Additional contexts must use the same API to share objects
+    let gl_request = match context.get_api() {
+        glutin::Api::OpenGlEs => {
+            glutin::GlRequest::Specific(glutin::Api::OpenGlEs, (2, 0))
+        }
+        _ => glutin::GlRequest::Latest,
+    };
+
+    // The window is shared with the image decoder, so it can be woken up
+    #[allow(unsafe_code)]
+    let (context, window) = unsafe { context.split() };
+    let context = Rc::new(RefCell::new(Context::new(context)));
+
+    let (mut event_sender, event_receiver) = mpsc::unbounded();
+    let (control_sender, mut control_receiver) = mpsc::unbounded();
+
+    let mut instance = Box::pin(run_instance::<A, E, C>(
+        application,
+        compositor,
+        renderer,
+        runtime,
+        proxy,
+        debug,
+        event_receiver,
+        control_sender,
+        init_command,
+        context.clone(),
+        window,
+        settings.exit_on_close_request,
+        settings.focus_navigation,
+    ));
+
+    let mut task_context = task::Context::from_waker(task::noop_waker_ref());
+
+    let _ = event_loop.run_return(move |event, window_target, control_flow| {
+        use glutin::event_loop::ControlFlow;
+
+        if let ControlFlow::ExitWithCode(_) = control_flow {
+            return;
+        }
+
+        let event = match event {
+            glutin::event::Event::WindowEvent {
+                event:
+                    glutin::event::WindowEvent::ScaleFactorChanged {
+                        new_inner_size,
+                        ..
+                    },
+                window_id,
+            } => Some(glutin::event::Event::WindowEvent {
+                event: glutin::event::WindowEvent::Resized(*new_inner_size),
+                window_id,
+            }),
+            _ => event.to_static(),
+        };
+
+        if let Some(event) = event {
+            event_sender
+                .start_send(Input::EventLoop(event))
+                .expect("Send event");
+
+            loop {
+                let poll = instance.as_mut().poll(&mut task_context);
+
+                if let task::Poll::Ready(_) = poll {
+                    *control_flow = ControlFlow::Exit;
+                    break;
+                }
+
+                let mut windows_created = false;
+
+                while let Ok(Some(control)) = control_receiver.try_next() {
+                    match control {
+                        Control::ChangeFlow(flow) => {
+                            *control_flow = flow;
+                        }
+                        Control::CreateWindow {
+                            id,
+                            title,
+                            settings,
+                        } => {
+                            let builder = settings.into_builder(
+                                &title,
+                                window_target.primary_monitor(),
+                                None,
+                            );
+
+                            let main_context = context.borrow();
+                            let shared: &glutin::Context<
+                                glutin::PossiblyCurrent,
+                            > = main_context.raw();
+
+                            // Only the main window waits for vertical sync,
+                            // so windows do not slow down each other
+                            let result = ContextBuilder::new()
+                                .with_gl(gl_request)
+                                .with_vsync(false)
+                                .with_shared_lists(shared)
+                                .build_windowed(builder, window_target);
+
+                            match result {
+                                Ok(context) => {
+                                    event_sender
+                                        .start_send(Input::WindowCreated {
+                                            id,
+                                            context,
+                                        })
+                                        .expect("Send event");
+
+                                    windows_created = true;
+                                }
+                                Err(error) => {
+                                    log::error!(
+                                        "Failed to create window {:?}: {}",
+                                        id,
+                                        error
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if !windows_created {
+                    break;
+                }
+            }
+        }
+    });
+
+    Ok(())
+}
+
+async fn run_instance<A, E, C>(
+    mut application: A,
+    mut compositor: C,
+    mut renderer: A::Renderer,
+    mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
+    mut proxy: glutin::event_loop::EventLoopProxy<A::Message>,
+    mut debug: Debug,
+    mut event_receiver: mpsc::UnboundedReceiver<Input<A::Message>>,
+    mut control_sender: mpsc::UnboundedSender<Control>,
+    init_command: Command<A::Message>,
+    context: Rc<RefCell<Context>>,
+    window: glutin::window::Window,
+    exit_on_close_request: bool,
+    focus_navigation: bool,
+) where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: GLCompositor<Renderer = A::Renderer> + 'static,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    use glutin::event;
+    use glutin::event_loop::ControlFlow;
+    use iced_winit::futures::stream::StreamExt;
+
+    let mut clipboard = Clipboard::connect(&window);
+    let mut should_exit = false;
+
+    let mut window_ids = HashMap::from([(window.id(), window::Id::MAIN)]);
+    let mut windows = HashMap::from([(
+        window::Id::MAIN,
+        Window::<A, C>::new(&application, window::Id::MAIN, window, None),
+    )]);
+
+    let image_loaded =
+        application::redraw_on_load(&windows[&window::Id::MAIN].raw);
+
+    compositor
+        .resize_viewport(windows[&window::Id::MAIN].state.physical_size());
+
+    let mut caches =
+        HashMap::from([(window::Id::MAIN, user_interface::Cache::default())]);
+
+    run_command(
+        &application,
+        &mut caches,
+        &mut windows,
+        &mut compositor,
+        &mut renderer,
+        init_command,
+        &mut runtime,
+        &mut clipboard,
+        &mut should_exit,
+        &mut proxy,
+        &mut control_sender,
+        &mut debug,
+    );
+    runtime.track(application.subscription());
+
+    let mut user_interfaces = ManuallyDrop::new(build_user_interfaces(
+        &application,
+        caches,
+        &windows,
+        &mut renderer,
+        &mut debug,
+    ));
+
+    let mut events = Vec::new();
+    let mut messages = Vec::new();
+    let mut redraw_pending = false;
+
+    debug.startup_finished();
+
+    while let Some(input) = event_receiver.next().await {
+        let event = match input {
+            Input::EventLoop(event) => event,
+            Input::WindowCreated { id, context } => {
+                #[allow(unsafe_code)]
+                let (context, raw) = unsafe {
+                    context
+                        .make_current()
+                        .map_err(|(_, error)| error)
+                        .expect("Make OpenGL context current")
+                        .split()
+                };
+
+                #[allow(unsafe_code)]
+                let surface = unsafe {
+                    compositor.create_surface(|address| {
+                        context.get_proc_address(address)
+                    })
+                };
+
+                let _ = window_ids.insert(raw.id(), id);
+                let _ = windows.insert(
+                    id,
+                    Window::new(
+                        &application,
+                        id,
+                        raw,
+                        Some(Secondary {
+                            surface,
+                            context: Context::new(context),
+                        }),
+                    ),
+                );
+
+                let user_interface = build_user_interface(
+                    &application,
+                    user_interface::Cache::default(),
+                    &mut renderer,
+                    windows[&id].state.logical_size(),
+                    &mut debug,
+                    id,
+                );
+
+                let _ = user_interfaces.insert(id, user_interface);
+
+                continue;
+            }
+        };
+
+        match event {
+            event::Event::NewEvents(start_cause) => {
+                redraw_pending = matches!(
+                    start_cause,
+                    event::StartCause::Init
+                        | event::StartCause::Poll
+                        | event::StartCause::ResumeTimeReached { .. }
+                ) || image_loaded
+                    .swap(false, atomic::Ordering::Relaxed);
+            }
+            event::Event::MainEventsCleared => {
+                if !redraw_pending && events.is_empty() && messages.is_empty() {
+                    continue;
+                }
+
+                debug.event_processing_started();
+
+                let mut outdated = false;
+
+                for (id, user_interface) in user_interfaces.iter_mut() {
+                    let window = &windows[id];
+
+                    let window_events: Vec<Event> = events
+                        .iter()
+                        .filter(|(window_id, _)| window_id == id)
+                        .map(|(_, event)| Clone::clone(event))
+                        .collect();
+
+                    let (interface_state, statuses) = user_interface.update(
+                        &window_events,
+                        window.state.cursor_position(),
+                        &mut renderer,
+                        &mut clipboard,
+                        &mut messages,
+                    );
+
+                    for (event, status) in
+                        window_events.into_iter().zip(statuses)
+                    {
+                        if focus_navigation {
+                            application::navigate_focus(
+                                user_interface,
+                                &renderer,
+                                &event,
+                                status,
+                            );
+                        }
+
+                        runtime.broadcast((event, status));
+                    }
+
+                    outdated = outdated
+                        || matches!(
+                            interface_state,
+                            user_interface::State::Outdated
+                        );
+                }
+
+                events.clear();
+
+                debug.event_processing_finished();
+
+                if !messages.is_empty() || outdated {
+                    let mut caches: HashMap<_, _> =
+                        ManuallyDrop::into_inner(user_interfaces)
+                            .into_iter()
+                            .map(|(id, user_interface)| {
+                                (id, user_interface.into_cache())
+                            })
+                            .collect();
+
+                    // Update application
+                    update(
+                        &mut application,
+                        &mut caches,
+                        &mut windows,
+                        &mut compositor,
+                        &mut renderer,
+                        &mut runtime,
+                        &mut clipboard,
+                        &mut should_exit,
+                        &mut proxy,
+                        &mut control_sender,
+                        &mut debug,
+                        &mut messages,
+                    );
+
+                    // Update windows
+                    window_ids.retain(|_, id| windows.contains_key(id));
+
+                    for window in windows.values_mut() {
+                        window.state.synchronize(&application, &window.raw);
+                    }
+
+                    user_interfaces = ManuallyDrop::new(build_user_interfaces(
+                        &application,
+                        caches,
+                        &windows,
+                        &mut renderer,
+                        &mut debug,
+                    ));
+
+                    if should_exit {
+                        break;
+                    }
+                }
+
+                // TODO: Avoid redrawing all the time by forcing widgets to
+                // request redraws on state changes
+                //
+                // Then, we can use the `interface_state` here to decide if a redraw
+                // is needed right away, or simply wait until a specific time.
+                let now = Instant::now();
+                let mut control_flow = ControlFlow::Wait;
+
+                for (id, user_interface) in user_interfaces.iter_mut() {
+                    let window = &windows[id];
+
+                    let redraw_event =
+                        Event::Window(*id, window::Event::RedrawRequested(now));
+
+                    let (interface_state, _) = user_interface.update(
+                        &[redraw_event.clone()],
+                        window.state.cursor_position(),
+                        &mut renderer,
+                        &mut clipboard,
+                        &mut messages,
+                    );
+
+                    window.raw.request_redraw();
+                    runtime.broadcast((
+                        redraw_event,
+                        crate::event::Status::Ignored,
+                    ));
+
+                    if let user_interface::State::Updated {
+                        redraw_request: Some(redraw_request),
+                    } = interface_state
+                    {
+                        control_flow = match (control_flow, redraw_request) {
+                            (ControlFlow::Poll, _)
+                            | (_, window::RedrawRequest::NextFrame) => {
+                                ControlFlow::Poll
+                            }
+                            (
+                                ControlFlow::WaitUntil(current),
+                                window::RedrawRequest::At(at),
+                            ) => ControlFlow::WaitUntil(current.min(at)),
+                            (_, window::RedrawRequest::At(at)) => {
+                                ControlFlow::WaitUntil(at)
+                            }
+                        };
+                    }
+                }
+
+                let _ = control_sender
+                    .start_send(Control::ChangeFlow(control_flow));
+
+                redraw_pending = false;
+            }
+            event::Event::PlatformSpecific(event::PlatformSpecific::MacOS(
+                event::MacOS::ReceivedUrl(url),
+            )) => {
+                use iced_native::event;
+
+                events.push((
+                    window::Id::MAIN,
+                    iced_native::Event::PlatformSpecific(
+                        event::PlatformSpecific::MacOS(
+                            event::MacOS::ReceivedUrl(url),
+                        ),
+                    ),
+                ));
+            }
+            event::Event::UserEvent(message) => {
+                messages.push(message);
+            }
+            event::Event::RedrawRequested(window_id) => {
+                let id = match window_ids.get(&window_id) {
+                    Some(id) => *id,
+                    None => continue,
+                };
+
+                let window = match windows.get_mut(&id) {
+                    Some(window) => window,
+                    None => continue,
+                };
+
+                let physical_size = window.state.physical_size();
+
+                if physical_size.width == 0 || physical_size.height == 0 {
+                    continue;
+                }
+
+                debug.render_started();
+                let current_viewport_version = window.state.viewport_version();
+
+                if window.viewport_version != current_viewport_version {
+                    let logical_size = window.state.logical_size();
+
+                    debug.layout_started();
+                    if let Some(user_interface) = user_interfaces.remove(&id) {
+                        let _ = user_interfaces.insert(
+                            id,
+                            user_interface
+                                .relayout(logical_size, &mut renderer),
+                        );
+                    }
+                    debug.layout_finished();
+
+                    let size = glutin::dpi::PhysicalSize::new(
+                        physical_size.width,
+                        physical_size.height,
+                    );
+
+                    match &mut window.secondary {
+                        Some(secondary) => {
+                            secondary.context.make_current().resize(size);
+                        }
+                        None => {
+                            context.borrow_mut().make_current().resize(size);
+                            compositor.resize_viewport(physical_size);
+                        }
+                    }
+
+                    window.viewport_version = current_viewport_version;
+                }
+
+                let user_interface = match user_interfaces.get_mut(&id) {
+                    Some(user_interface) => user_interface,
+                    None => continue,
+                };
+
+                // All the windows share the same renderer, so the user
+                // interface of a window needs to be drawn right before
+                // presenting it.
+                debug.draw_started();
+                let new_mouse_interaction = user_interface.draw(
+                    &mut renderer,
+                    window.state.theme(),
+                    &renderer::Style {
+                        text_color: window.state.text_color(),
+                    },
+                    window.state.cursor_position(),
+                );
+                debug.draw_finished();
+
+                window.accessibility.update(user_interface, &renderer);
+
+                if new_mouse_interaction != window.mouse_interaction {
+                    window.raw.set_cursor_icon(conversion::mouse_interaction(
+                        new_mouse_interaction,
+                    ));
+                    window.raw.set_cursor_visible(conversion::cursor_visible(
+                        new_mouse_interaction,
+                    ));
+
+                    window.mouse_interaction = new_mouse_interaction;
+                }
+
+                // Every window is presented with the context of the
+                // compositor first
+                let mut main_context = context.borrow_mut();
+                let _ = main_context.make_current();
+
+                let screenshot = if window.screenshots.is_empty() {
+                    match &mut window.secondary {
+                        Some(secondary) => compositor.present_surface(
+                            &mut renderer,
+                            &mut secondary.surface,
+                            window.state.viewport(),
+                            window.state.background_color(),
+                            &debug.overlay(),
+                        ),
+                        None => compositor.present(
+                            &mut renderer,
+                            window.state.viewport(),
+                            window.state.background_color(),
+                            &debug.overlay(),
+                        ),
+                    }
+
+                    None
+                } else {
+                    Some(match &mut window.secondary {
+                        Some(secondary) => compositor.screenshot_surface(
+                            &mut renderer,
+                            &mut secondary.surface,
+                            window.state.viewport(),
+                            window.state.background_color(),
+                            &debug.overlay(),
+                        ),
+                        None => compositor.screenshot(
+                            &mut renderer,
+                            window.state.viewport(),
+                            window.state.background_color(),
+                            &debug.overlay(),
+                        ),
+                    })
+                };
+
+                match &mut window.secondary {
+                    Some(secondary) => {
+                        let context = secondary.context.make_current();
+
+                        #[allow(unsafe_code)]
+                        unsafe {
+                            compositor.draw_surface(&secondary.surface);
+                        }
+
+                        context.swap_buffers().expect("Swap buffers");
+                    }
+                    None => {
+                        main_context
+                            .raw()
+                            .swap_buffers()
+                            .expect("Swap buffers");
+                    }
+                }
+
+                if let Some(bytes) = screenshot {
+                    let screenshot =
+                        window::Screenshot::new(bytes, physical_size);
+
+                    for tag in window.screenshots.drain(..) {
+                        proxy
+                            .send_event(tag(screenshot.clone()))
+                            .expect("Send message to event loop");
+                    }
+                }
+
+                debug.render_finished();
+            }
+            event::Event::WindowEvent {
+                event: window_event,
+                window_id,
+            } => {
+                let id = match window_ids.get(&window_id) {
+                    Some(id) => *id,
+                    None => continue,
+                };
+
+                let is_closed =
+                    matches!(window_event, event::WindowEvent::Destroyed)
+                        || (exit_on_close_request
+                            && application::requests_exit(
+                                &window_event,
+                                windows[&id].state.modifiers(),
+                            ));
+
+                if is_closed {
+                    if id == window::Id::MAIN {
+                        break;
+                    }
+
+                    let _ = window_ids.remove(&window_id);
+                    let _ = user_interfaces.remove(&id);
+
+                    if let Some(window) = windows.remove(&id) {
+                        window.close(&mut compositor);
+                    }
+
+                    runtime.broadcast((
+                        Event::Window(id, window::Event::Closed),
+                        crate::event::Status::Ignored,
+                    ));
+
+                    continue;
+                }
+
+                let window = match windows.get_mut(&id) {
+                    Some(window) => window,
+                    None => continue,
+                };
+
+                window.state.update(&window.raw, &window_event, &mut debug);
+
+                if let Some(event) = conversion::window_event(
+                    id,
+                    &window_event,
+                    window.state.scale_factor(),
+                    window.state.modifiers(),
+                    window.state.cursor_position(),
+                    &mut window.last_click,
+                ) {
+                    events.push((id, event));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Manually drop the user interfaces
+    drop(ManuallyDrop::into_inner(user_interfaces));
+
+    // The main context must be destroyed before its window, but the event
+    // loop still holds it
+    context.borrow_mut().destroy();
+    drop(windows);
+}
+
+fn build_user_interfaces<'a, A, C>(
+    application: &'a A,
+    mut caches: HashMap<window::Id, user_interface::Cache>,
+    windows: &HashMap<window::Id, Window<A, C>>,
+    renderer: &mut A::Renderer,
+    debug: &mut Debug,
+) -> HashMap<window::Id, UserInterface<'a, A::Message, A::Renderer>>
+where
+    A: Application,
+    C: GLCompositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    windows
+        .iter()
+        .map(|(&id, window)| {
+            let user_interface = build_user_interface(
+                application,
+                caches.remove(&id).unwrap_or_default(),
+                renderer,
+                window.state.logical_size(),
+                debug,
+                id,
+            );
+
+            (id, user_interface)
+        })
+        .collect()
+}
+
+/// Updates an [`Application`] by feeding it the provided messages, spawning any
+/// resulting [`Command`], and tracking its [`Subscription`].
+///
+/// [`Subscription`]: crate::Subscription
+fn update<A, E, C>(
+    application: &mut A,
+    caches: &mut HashMap<window::Id, user_interface::Cache>,
+    windows: &mut HashMap<window::Id, Window<A, C>>,
+    compositor: &mut C,
+    renderer: &mut A::Renderer,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    clipboard: &mut Clipboard,
+    should_exit: &mut bool,
+    proxy: &mut glutin::event_loop::EventLoopProxy<A::Message>,
+    control_sender: &mut mpsc::UnboundedSender<Control>,
+    debug: &mut Debug,
+    messages: &mut Vec<A::Message>,
+) where
+    A: Application,
+    E: Executor,
+    C: GLCompositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    for message in messages.drain(..) {
+        debug.log_message(&message);
+
+        debug.update_started();
+        let command = runtime.enter(|| application.update(message));
+        debug.update_finished();
+
+        run_command(
+            application,
+            caches,
+            windows,
+            compositor,
+            renderer,
+            command,
+            runtime,
+            clipboard,
+            should_exit,
+            proxy,
+            control_sender,
+            debug,
+        );
+    }
+
+    let subscription = application.subscription();
+    runtime.track(subscription);
+}
+
+/// Runs the actions of a [`Command`].
+fn run_command<A, E, C>(
+    application: &A,
+    caches: &mut HashMap<window::Id, user_interface::Cache>,
+    windows: &mut HashMap<window::Id, Window<A, C>>,
+    compositor: &mut C,
+    renderer: &mut A::Renderer,
+    command: Command<A::Message>,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    clipboard: &mut Clipboard,
+    should_exit: &mut bool,
+    proxy: &mut glutin::event_loop::EventLoopProxy<A::Message>,
+    control_sender: &mut mpsc::UnboundedSender<Control>,
+    debug: &mut Debug,
+) where
+    A: Application,
+    E: Executor,
+    C: GLCompositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    use iced_native::clipboard;
+    use iced_native::command;
+    use iced_native::system;
+    use iced_native::window;
+
+    for action in command.actions() {
+        match action {
+            command::Action::Future(future) => {
+                runtime.spawn(future);
+            }
+            command::Action::Clipboard(action) => match action {
+                clipboard::Action::Read(tag) => {
+                    let message = tag(clipboard.read());
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+                clipboard::Action::Write(contents) => {
+                    clipboard.write(contents);
+                }
+            },
+            command::Action::Window(id, action) => match action {
+                window::Action::Spawn(settings) => {
+                    if windows.contains_key(&id) {
+                        log::warn!("Window {:?} already exists", id);
+                        continue;
+                    }
+
+                    let _ = control_sender.start_send(Control::CreateWindow {
+                        id,
+                        title: application.title(id),
+                        settings: settings.into(),
+                    });
+                }
+                window::Action::Close if id == window::Id::MAIN => {
+                    *should_exit = true;
+                }
+                window::Action::Close => {
+                    if let Some(window) = windows.remove(&id) {
+                        window.close(compositor);
+
+                        let _ = caches.remove(&id);
+
+                        runtime.broadcast((
+                            Event::Window(id, window::Event::Closed),
+                            crate::event::Status::Ignored,
+                        ));
+                    }
+                }
+                window::Action::Screenshot(tag) => match windows.get_mut(&id) {
+                    Some(window) => {
+                        window.screenshots.push(tag);
+                        window.raw.request_redraw();
+                    }
+                    None => log::warn!("Window {:?} does not exist", id),
+                },
+                action => match windows.get(&id) {
+                    Some(window) => {
+                        application::run_window_action(
+                            action,
+                            &window.raw,
+                            proxy,
+                        );
+                    }
+                    None => log::warn!("Window {:?} does not exist", id),
+                },
+            },
+            command::Action::System(action) => match action {
+                system::Action::QueryInformation(_tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let graphics_info = compositor.fetch_information();
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let information =
+                                crate::system::information(graphics_info);
+
+                            let message = _tag(information);
+
+                            proxy
+                                .send_event(message)
+                                .expect("Send message to event loop")
+                        });
+                    }
+                }
+            },
+            command::Action::Widget(action) => {
+                let mut current_operation = Some(action.into_operation());
+
+                while let Some(mut operation) = current_operation.take() {
+                    for (&id, window) in windows.iter() {
+                        let cache = caches.remove(&id).unwrap_or_default();
+
+                        let mut user_interface = build_user_interface(
+                            application,
+                            cache,
+                            renderer,
+                            window.state.logical_size(),
+                            debug,
+                            id,
+                        );
+
+                        user_interface.operate(renderer, operation.as_mut());
+
+                        let _ = caches.insert(id, user_interface.into_cache());
+                    }
+
+                    match operation.finish() {
+                        operation::Outcome::None => {}
+                        operation::Outcome::Some(message) => {
+                            proxy
+                                .send_event(message)
+                                .expect("Send message to event loop");
+                        }
+                        operation::Outcome::Chain(next) => {
+                            current_operation = Some(next);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
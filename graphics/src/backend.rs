@@ -2,7 +2,10 @@
 use iced_native::image;
 use iced_native::svg;
 use iced_native::text;
-use iced_native::{Font, Point, Size};
+use iced_native::time::Duration;
+use iced_native::{Font, Point, Rectangle, Size};
+
+use std::ops::Range;
 
 /// The graphics backend of a [`Renderer`].
 ///
@@ -34,39 +37,91 @@ pub trait Text {
     /// Returns the default size of text.
     fn default_size(&self) -> u16;
 
-    /// Measures the text contents with the given size and font,
+    /// Measures the text contents with the given size, line height and font,
     /// returning the size of a laid out paragraph that fits in the provided
     /// bounds.
     fn measure(
         &self,
         contents: &str,
         size: f32,
+        line_height: f32,
         font: Font,
         bounds: Size,
+        wrap: text::Wrap,
+        overflow: text::Overflow,
     ) -> (f32, f32);
 
-    /// Tests whether the provided point is within the boundaries of [`Text`]
-    /// laid out with the given parameters, returning information about
-    /// the nearest character.
+    /// Tests whether the provided point is within the boundaries of the given
+    /// [`Paragraph`], returning information about the nearest character.
     ///
     /// If nearest_only is true, the hit test does not consider whether the
     /// the point is interior to any glyph bounds, returning only the character
     /// with the nearest centeroid.
+    ///
+    /// [`Paragraph`]: text::Paragraph
     fn hit_test(
         &self,
-        contents: &str,
-        size: f32,
-        font: Font,
+        paragraph: text::Paragraph<'_, Font>,
+        point: Point,
+        nearest_only: bool,
+    ) -> Option<text::Hit>;
+
+    /// Measures the given spans laid out as a single paragraph, returning the
+    /// size of a laid out paragraph that fits in the provided bounds.
+    fn measure_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> (f32, f32);
+
+    /// Tests whether the provided point is within the boundaries of the given
+    /// spans laid out as a single paragraph, returning information about the
+    /// nearest character.
+    fn hit_test_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
         bounds: Size,
         point: Point,
         nearest_only: bool,
     ) -> Option<text::Hit>;
+
+    /// Returns the regions covered by the span at the given index when the
+    /// spans are laid out as a single paragraph.
+    fn span_bounds(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+        index: usize,
+    ) -> Vec<Rectangle>;
+
+    /// Returns the regions covered by the characters in the given selection
+    /// of the [`Paragraph`].
+    ///
+    /// [`Paragraph`]: text::Paragraph
+    fn selection_bounds(
+        &self,
+        paragraph: text::Paragraph<'_, Font>,
+        selection: Range<usize>,
+    ) -> Vec<Rectangle>;
+
+    /// Returns the regions covered by the characters in the given selection
+    /// when the spans are laid out as a single paragraph.
+    fn selection_bounds_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+        selection: Range<usize>,
+    ) -> Vec<Rectangle>;
 }
 
 /// A graphics backend that supports image rendering.
 pub trait Image {
     /// Returns the dimensions of the provided image.
     fn dimensions(&self, handle: &image::Handle) -> Size<u32>;
+
+    /// Returns the delays of the frames of the provided image, if it is
+    /// animated.
+    fn frame_delays(&self, handle: &image::Handle) -> Option<Vec<Duration>>;
 }
 
 /// A graphics backend that supports SVG rendering.
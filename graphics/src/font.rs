@@ -12,6 +12,29 @@ pub use font_kit::{
     error::SelectionError as LoadError, family_name::FamilyName as Family,
 };
 
+/// The system font families used, in order, to draw the characters missing
+/// in a font, like CJK ideographs or emoji.
+///
+/// A family is only loaded once a character is missing in every family
+/// before it.
+#[cfg(feature = "font-source")]
+#[cfg_attr(docsrs, doc(cfg(feature = "font-source")))]
+pub const FALLBACK_FAMILIES: &[&str] = &[
+    "Noto Sans",
+    "Noto Sans CJK SC",
+    "Noto Sans Symbols",
+    "Noto Sans Symbols 2",
+    "Noto Emoji",
+    "DejaVu Sans",
+    "Segoe UI",
+    "Segoe UI Symbol",
+    "Segoe UI Emoji",
+    "Microsoft YaHei",
+    "Helvetica Neue",
+    "PingFang SC",
+    "Apple Symbols",
+];
+
 /// A built-in fallback font, for convenience.
 #[cfg(feature = "font-fallback")]
 #[cfg_attr(docsrs, doc(cfg(feature = "font-fallback")))]
@@ -20,10 +43,10 @@ pub const FALLBACK: &[u8] = include_bytes!("../fonts/Lato-Regular.ttf");
 /// A built-in icon font, for convenience.
 #[cfg(feature = "font-icons")]
 #[cfg_attr(docsrs, doc(cfg(feature = "font-icons")))]
-pub const ICONS: iced_native::Font = iced_native::Font::External {
-    name: "iced_wgpu icons",
-    bytes: include_bytes!("../fonts/Icons.ttf"),
-};
+pub const ICONS: iced_native::Font = iced_native::Font::external(
+    "iced_wgpu icons",
+    include_bytes!("../fonts/Icons.ttf"),
+);
 
 /// The `char` representing a ✔ icon in the built-in [`ICONS`] font.
 #[cfg(feature = "font-icons")]
@@ -1,4 +1,7 @@
 use crate::font::{Family, LoadError};
+use crate::Font;
+
+use iced_native::font;
 
 /// A font source that can find and load system fonts.
 #[allow(missing_debug_implementations)]
@@ -16,10 +19,48 @@ impl Source {
 
     /// Finds and loads a font matching the set of provided family priorities.
     pub fn load(&self, families: &[Family]) -> Result<Vec<u8>, LoadError> {
-        let font = self.raw.select_best_match(
+        self.load_with_properties(
             families,
             &font_kit::properties::Properties::default(),
-        )?;
+        )
+    }
+
+    /// Finds and loads the system font that best matches the family, weight,
+    /// stretch and style of the given [`Font`].
+    ///
+    /// The [`font::Family::Default`] family matches the same fonts used as the
+    /// default font of a renderer. Fonts embedded in the application cannot
+    /// be loaded from the system.
+    pub fn load_font(&self, font: Font) -> Result<Vec<u8>, LoadError> {
+        let families = match font.family {
+            font::Family::Default => vec![Family::SansSerif, Family::Serif],
+            font::Family::External { .. } => {
+                return Err(LoadError::NotFound);
+            }
+            font::Family::Name(name) => vec![Family::Title(name.to_owned())],
+            font::Family::Serif => vec![Family::Serif],
+            font::Family::SansSerif => vec![Family::SansSerif],
+            font::Family::Cursive => vec![Family::Cursive],
+            font::Family::Fantasy => vec![Family::Fantasy],
+            font::Family::Monospace => vec![Family::Monospace],
+        };
+
+        self.load_with_properties(
+            &families,
+            &font_kit::properties::Properties {
+                weight: weight(font.weight),
+                stretch: stretch(font.stretch),
+                style: style(font.style),
+            },
+        )
+    }
+
+    fn load_with_properties(
+        &self,
+        families: &[Family],
+        properties: &font_kit::properties::Properties,
+    ) -> Result<Vec<u8>, LoadError> {
+        let font = self.raw.select_best_match(families, properties)?;
 
         match font {
             font_kit::handle::Handle::Path { path, .. } => {
@@ -43,3 +84,45 @@ impl Default for Source {
         Self::new()
     }
 }
+
+fn weight(weight: font::Weight) -> font_kit::properties::Weight {
+    use font_kit::properties::Weight;
+
+    match weight {
+        font::Weight::Thin => Weight::THIN,
+        font::Weight::ExtraLight => Weight::EXTRA_LIGHT,
+        font::Weight::Light => Weight::LIGHT,
+        font::Weight::Normal => Weight::NORMAL,
+        font::Weight::Medium => Weight::MEDIUM,
+        font::Weight::Semibold => Weight::SEMIBOLD,
+        font::Weight::Bold => Weight::BOLD,
+        font::Weight::ExtraBold => Weight::EXTRA_BOLD,
+        font::Weight::Black => Weight::BLACK,
+    }
+}
+
+fn stretch(stretch: font::Stretch) -> font_kit::properties::Stretch {
+    use font_kit::properties::Stretch;
+
+    match stretch {
+        font::Stretch::UltraCondensed => Stretch::ULTRA_CONDENSED,
+        font::Stretch::ExtraCondensed => Stretch::EXTRA_CONDENSED,
+        font::Stretch::Condensed => Stretch::CONDENSED,
+        font::Stretch::SemiCondensed => Stretch::SEMI_CONDENSED,
+        font::Stretch::Normal => Stretch::NORMAL,
+        font::Stretch::SemiExpanded => Stretch::SEMI_EXPANDED,
+        font::Stretch::Expanded => Stretch::EXPANDED,
+        font::Stretch::ExtraExpanded => Stretch::EXTRA_EXPANDED,
+        font::Stretch::UltraExpanded => Stretch::ULTRA_EXPANDED,
+    }
+}
+
+fn style(style: font::Style) -> font_kit::properties::Style {
+    use font_kit::properties::Style;
+
+    match style {
+        font::Style::Normal => Style::Normal,
+        font::Style::Italic => Style::Italic,
+        font::Style::Oblique => Style::Oblique,
+    }
+}
@@ -8,3 +8,28 @@ pub mod vector;
 pub mod storage;
 
 pub use storage::Storage;
+
+use std::sync::Mutex;
+
+static ON_LOAD: Mutex<Option<Box<dyn Fn() + Send>>> = Mutex::new(None);
+
+/// Sets the function called whenever an image finishes loading in the
+/// background.
+///
+/// Shells use it to wake up their event loop, so that images are drawn as
+/// soon as they are ready. It replaces any function set before.
+pub fn on_load(f: impl Fn() + Send + 'static) {
+    *ON_LOAD.lock().unwrap_or_else(|e| e.into_inner()) = Some(Box::new(f));
+}
+
+#[cfg(feature = "image_rs")]
+fn notify_load() {
+    if let Some(on_load) =
+        ON_LOAD.lock().unwrap_or_else(|e| e.into_inner()).as_ref()
+    {
+        on_load();
+    }
+}
+
+#[cfg(feature = "image_rs")]
+pub use raster::load;
@@ -3,17 +3,46 @@ use crate::image::Storage;
 use crate::Size;
 
 use iced_native::image;
+use iced_native::Command;
 
 use bitflags::bitflags;
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+/// Decoded image pixels in RGBA format.
+pub type Rgba = image_rs::ImageBuffer<image_rs::Rgba<u8>, Vec<u8>>;
+
+/// Loads the image of the given [`image::Handle`] in the background.
+///
+/// The returned [`Command`] produces the [`image::Handle`] once its pixels
+/// are ready to be drawn, or an [`image::Error`] if it could not be loaded.
+/// This can be used to preload images before they are displayed, in which
+/// case the decoded pixels are kept until the image is first drawn, or for a
+/// few seconds at most.
+pub fn load(
+    handle: image::Handle,
+) -> Command<Result<image::Handle, image::Error>> {
+    Command::perform(decoder::wait(&handle), move |result| {
+        result.map(|_| handle)
+    })
+}
+
+/// Returns true if the image of the given [`image::Handle`] is still being
+/// decoded in the background.
+pub fn is_loading(handle: &image::Handle) -> bool {
+    decoder::is_pending(handle)
+}
 
 /// Entry in cache corresponding to an image handle
 #[derive(Debug)]
 pub enum Memory<T: Storage> {
     /// Image data on host
-    Host(::image_rs::ImageBuffer<::image_rs::Rgba<u8>, Vec<u8>>),
+    Host(Rgba),
     /// Storage entry
     Device(T::Entry),
+    /// Image being decoded in the background, with its final dimensions
+    Loading(Size<u32>),
     /// Image not found
     NotFound,
     /// Invalid image data
@@ -32,6 +61,7 @@ impl<T: Storage> Memory<T> {
                 Size::new(width, height)
             }
             Memory::Device(entry) => entry.size(),
+            Memory::Loading(size) => *size,
             Memory::NotFound => Size::new(1, 1),
             Memory::Invalid => Size::new(1, 1),
         }
@@ -42,42 +72,44 @@ impl<T: Storage> Memory<T> {
 #[derive(Debug)]
 pub struct Cache<T: Storage> {
     map: HashMap<u64, Memory<T>>,
+    animations: HashMap<u64, Vec<image::Frame>>,
     hits: HashSet<u64>,
 }
 
 impl<T: Storage> Cache<T> {
     /// Load image
+    ///
+    /// Encoded images are decoded in the background. Until their pixels are
+    /// ready, they are kept as [`Memory::Loading`].
     pub fn load(&mut self, handle: &image::Handle) -> &mut Memory<T> {
+        if let Some(Memory::Loading(_)) = self.map.get(&handle.id()) {
+            if let Some(result) = decoder::take(handle) {
+                let memory = self.decoded(handle, result);
+
+                self.insert(handle, memory);
+            } else {
+                // The decoded pixels may have been evicted before this
+                // cache could take them
+                decoder::request(handle);
+            }
+        }
+
         if self.contains(handle) {
             return self.get(handle).unwrap();
         }
 
         let memory = match handle.data() {
-            image::Data::Path(path) => {
-                if let Ok(image) = image_rs::open(path) {
-                    let operation = std::fs::File::open(path)
-                        .ok()
-                        .map(std::io::BufReader::new)
-                        .and_then(|mut reader| {
-                            Operation::from_exif(&mut reader).ok()
-                        })
-                        .unwrap_or_else(Operation::empty);
-
-                    Memory::Host(operation.perform(image.to_rgba8()))
-                } else {
-                    Memory::NotFound
-                }
-            }
-            image::Data::Bytes(bytes) => {
-                if let Ok(image) = image_rs::load_from_memory(bytes) {
-                    let operation =
-                        Operation::from_exif(&mut std::io::Cursor::new(bytes))
-                            .ok()
-                            .unwrap_or_else(Operation::empty);
-
-                    Memory::Host(operation.perform(image.to_rgba8()))
-                } else {
-                    Memory::Invalid
+            image::Data::Path(_) | image::Data::Bytes(_) => {
+                match decoder::take(handle) {
+                    Some(result) => self.decoded(handle, result),
+                    None => match dimensions(handle.data()) {
+                        Ok(size) => {
+                            decoder::request(handle);
+
+                            Memory::Loading(size)
+                        }
+                        Err(error) => self.decoded(handle, Err(error)),
+                    },
                 }
             }
             image::Data::Rgba {
@@ -101,6 +133,16 @@ impl<T: Storage> Cache<T> {
         self.get(handle).unwrap()
     }
 
+    /// Load image and return its frames, if it is animated
+    pub fn frames(
+        &mut self,
+        handle: &image::Handle,
+    ) -> Option<&[image::Frame]> {
+        let _ = self.load(handle);
+
+        self.animations.get(&handle.id()).map(Vec::as_slice)
+    }
+
     /// Load image and upload raster data
     pub fn upload(
         &mut self,
@@ -127,6 +169,15 @@ impl<T: Storage> Cache<T> {
 
     /// Trim cache misses from cache
     pub fn trim(&mut self, storage: &mut T, state: &mut T::State<'_>) {
+        // The frames of an animation are kept for as long as it is in use,
+        // even if only some of them were drawn.
+        for (id, frames) in &self.animations {
+            if self.hits.contains(id) {
+                self.hits
+                    .extend(frames.iter().map(|frame| frame.handle.id()));
+            }
+        }
+
         let hits = &self.hits;
 
         self.map.retain(|k, memory| {
@@ -141,7 +192,40 @@ impl<T: Storage> Cache<T> {
             retain
         });
 
+        self.animations.retain(|k, _| hits.contains(k));
         self.hits.clear();
+
+        decoder::trim();
+    }
+
+    fn decoded(
+        &mut self,
+        handle: &image::Handle,
+        result: Result<Decoded, image::Error>,
+    ) -> Memory<T> {
+        match result {
+            Ok(Decoded { image, frames }) => {
+                if !frames.is_empty() {
+                    let frames = frames
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, (image, delay))| {
+                            let handle = handle.frame(index);
+
+                            self.insert(&handle, Memory::Host(image));
+
+                            image::Frame { handle, delay }
+                        })
+                        .collect();
+
+                    let _ = self.animations.insert(handle.id(), frames);
+                }
+
+                Memory::Host(image)
+            }
+            Err(image::Error::NotFound) => Memory::NotFound,
+            Err(image::Error::Invalid) => Memory::Invalid,
+        }
     }
 
     fn get(&mut self, handle: &image::Handle) -> Option<&mut Memory<T>> {
@@ -163,11 +247,443 @@ impl<T: Storage> Default for Cache<T> {
     fn default() -> Self {
         Self {
             map: HashMap::new(),
+            animations: HashMap::new(),
             hits: HashSet::new(),
         }
     }
 }
 
+/// Reads the dimensions of an encoded image without decoding its pixels.
+fn dimensions(data: &image::Data) -> Result<Size<u32>, image::Error> {
+    let ((width, height), operation) = match data {
+        image::Data::Path(path) => {
+            let dimensions = image_rs::io::Reader::open(path)
+                .map_err(|_| image::Error::NotFound)?
+                .with_guessed_format()
+                .map_err(|_| image::Error::NotFound)?
+                .into_dimensions()
+                .map_err(|_| image::Error::Invalid)?;
+
+            let operation = std::fs::File::open(path)
+                .ok()
+                .map(std::io::BufReader::new)
+                .and_then(|mut reader| Operation::from_exif(&mut reader).ok())
+                .unwrap_or_else(Operation::empty);
+
+            (dimensions, operation)
+        }
+        image::Data::Bytes(bytes) => {
+            let dimensions =
+                image_rs::io::Reader::new(std::io::Cursor::new(bytes))
+                    .with_guessed_format()
+                    .map_err(|_| image::Error::Invalid)?
+                    .into_dimensions()
+                    .map_err(|_| image::Error::Invalid)?;
+
+            let operation =
+                Operation::from_exif(&mut std::io::Cursor::new(bytes))
+                    .ok()
+                    .unwrap_or_else(Operation::empty);
+
+            (dimensions, operation)
+        }
+        image::Data::Rgba { width, height, .. } => {
+            ((*width, *height), Operation::empty())
+        }
+    };
+
+    if operation.contains(Operation::FLIP_DIAGONALLY) {
+        Ok(Size::new(height, width))
+    } else {
+        Ok(Size::new(width, height))
+    }
+}
+
+/// A decoded image.
+#[derive(Debug)]
+struct Decoded {
+    /// The pixels of the image, or of its first frame if it is animated.
+    image: Rgba,
+
+    /// The pixels and delay of every frame of the image, if it is animated.
+    frames: Vec<(Rgba, Duration)>,
+}
+
+/// Decodes an encoded image, applying its EXIF orientation.
+///
+/// Every frame of an animated image is decoded.
+fn decode(data: &image::Data) -> Result<Decoded, image::Error> {
+    let bytes = match data {
+        image::Data::Path(path) => {
+            Cow::Owned(std::fs::read(path).map_err(|_| image::Error::NotFound)?)
+        }
+        image::Data::Bytes(bytes) => Cow::Borrowed(bytes.as_ref()),
+        image::Data::Rgba {
+            width,
+            height,
+            pixels,
+        } => {
+            let image = image_rs::ImageBuffer::from_vec(
+                *width,
+                *height,
+                pixels.to_vec(),
+            )
+            .ok_or(image::Error::Invalid)?;
+
+            return Ok(Decoded {
+                image,
+                frames: Vec::new(),
+            });
+        }
+    };
+
+    if let Some(frames) = decode_animation(&bytes) {
+        let image = frames[0].buffer().clone();
+
+        let frames = frames
+            .into_iter()
+            .map(|frame| {
+                let (numerator, denominator) = frame.delay().numer_denom_ms();
+
+                (
+                    frame.into_buffer(),
+                    Duration::from_micros(
+                        u64::from(numerator) * 1000
+                            / u64::from(denominator.max(1)),
+                    ),
+                )
+            })
+            .collect();
+
+        return Ok(Decoded { image, frames });
+    }
+
+    let image = image_rs::load_from_memory(&bytes)
+        .map_err(|_| image::Error::Invalid)?;
+
+    let operation = Operation::from_exif(&mut std::io::Cursor::new(&bytes))
+        .ok()
+        .unwrap_or_else(Operation::empty);
+
+    Ok(Decoded {
+        image: operation.perform(image.to_rgba8()),
+        frames: Vec::new(),
+    })
+}
+
+/// Decodes all the frames of an animated GIF, APNG or WebP image.
+///
+/// Returns `None` if the image is not animated.
+#[cfg(any(feature = "gif", feature = "png", feature = "webp"))]
+fn decode_animation(bytes: &[u8]) -> Option<Vec<image_rs::Frame>> {
+    use image_rs::{AnimationDecoder, ImageFormat};
+
+    let frames: image_rs::Frames<'_> = match image_rs::guess_format(bytes)
+        .ok()?
+    {
+        #[cfg(feature = "gif")]
+        ImageFormat::Gif => image_rs::codecs::gif::GifDecoder::new(bytes)
+            .ok()?
+            .into_frames(),
+        #[cfg(feature = "png")]
+        ImageFormat::Png => {
+            let decoder = image_rs::codecs::png::PngDecoder::new(bytes).ok()?;
+
+            if !decoder.is_apng() {
+                return None;
+            }
+
+            decoder.apng().into_frames()
+        }
+        #[cfg(feature = "webp")]
+        ImageFormat::WebP => {
+            let decoder =
+                image_rs::codecs::webp::WebPDecoder::new(bytes).ok()?;
+
+            if !decoder.has_animation() {
+                return None;
+            }
+
+            decoder.into_frames()
+        }
+        _ => return None,
+    };
+
+    let frames = frames.collect_frames().ok()?;
+
+    if frames.len() > 1 {
+        Some(frames)
+    } else {
+        None
+    }
+}
+
+/// Decodes all the frames of an animated image.
+///
+/// None of the formats supporting animation are enabled, so this always
+/// returns `None`.
+#[cfg(not(any(feature = "gif", feature = "png", feature = "webp")))]
+fn decode_animation(_bytes: &[u8]) -> Option<Vec<image_rs::Frame>> {
+    None
+}
+
+/// A background worker that decodes images and shares the results with
+/// every [`Cache`].
+mod decoder {
+    use super::{decode, Decoded};
+
+    use iced_native::futures::channel::oneshot;
+    use iced_native::image;
+    use iced_native::time::{Duration, Instant};
+
+    use std::collections::HashMap;
+    use std::future::Future;
+    use std::sync::{mpsc, Mutex};
+
+    static DECODER: Mutex<Option<Decoder>> = Mutex::new(None);
+
+    /// The time the decoded pixels of an image are kept for if no cache
+    /// takes them.
+    const EVICTION_DELAY: Duration = Duration::from_secs(5);
+
+    #[derive(Default)]
+    struct Decoder {
+        sender: Option<mpsc::Sender<image::Handle>>,
+        entries: HashMap<u64, Entry>,
+    }
+
+    enum Entry {
+        Pending(Vec<oneshot::Sender<Result<(), image::Error>>>),
+        Done(Instant, Result<Decoded, image::Error>),
+    }
+
+    impl Decoder {
+        fn request(&mut self, handle: &image::Handle) {
+            if self.entries.contains_key(&handle.id()) {
+                return;
+            }
+
+            let _ =
+                self.entries.insert(handle.id(), Entry::Pending(Vec::new()));
+
+            let sender = self.sender.get_or_insert_with(spawn);
+
+            if let Err(mpsc::SendError(handle)) = sender.send(handle.clone()) {
+                // The worker is gone; decode in place instead
+                self.sender = None;
+                finish(self, &handle, decode(handle.data()));
+            }
+        }
+
+        fn trim(&mut self, now: Instant) {
+            self.entries.retain(|_, entry| match entry {
+                Entry::Pending(_) => true,
+                Entry::Done(done_at, _) => {
+                    now.duration_since(*done_at) < EVICTION_DELAY
+                }
+            });
+        }
+    }
+
+    fn with<O>(f: impl FnOnce(&mut Decoder) -> O) -> O {
+        let mut decoder = DECODER.lock().unwrap_or_else(|e| e.into_inner());
+
+        f(decoder.get_or_insert_with(Decoder::default))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn spawn() -> mpsc::Sender<image::Handle> {
+        let (sender, receiver) = mpsc::channel::<image::Handle>();
+
+        let _ = std::thread::Builder::new()
+            .name(String::from("iced_graphics image decoder"))
+            .spawn(move || {
+                for handle in receiver {
+                    let result = decode(handle.data());
+
+                    with(|decoder| finish(decoder, &handle, result));
+                }
+            });
+
+        sender
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn spawn() -> mpsc::Sender<image::Handle> {
+        // Threads are not available; the dropped receiver makes every
+        // request decode in place.
+        mpsc::channel().0
+    }
+
+    fn finish(
+        decoder: &mut Decoder,
+        handle: &image::Handle,
+        result: Result<Decoded, image::Error>,
+    ) {
+        let status = result.as_ref().map(|_| ()).map_err(|error| *error);
+
+        if let Some(Entry::Pending(waiting)) = decoder
+            .entries
+            .insert(handle.id(), Entry::Done(Instant::now(), result))
+        {
+            for sender in waiting {
+                let _ = sender.send(status);
+            }
+        }
+
+        crate::image::notify_load();
+    }
+
+    /// Starts decoding the image of the given handle, if it is not already.
+    pub fn request(handle: &image::Handle) {
+        with(|decoder| decoder.request(handle));
+    }
+
+    /// Takes the decoded image of the given handle, if it is ready.
+    pub fn take(
+        handle: &image::Handle,
+    ) -> Option<Result<Decoded, image::Error>> {
+        with(|decoder| match decoder.entries.remove(&handle.id()) {
+            Some(Entry::Done(_, result)) => Some(result),
+            Some(entry) => {
+                let _ = decoder.entries.insert(handle.id(), entry);
+
+                None
+            }
+            None => None,
+        })
+    }
+
+    /// Returns true if the image of the given handle is being decoded.
+    pub fn is_pending(handle: &image::Handle) -> bool {
+        with(|decoder| {
+            matches!(decoder.entries.get(&handle.id()), Some(Entry::Pending(_)))
+        })
+    }
+
+    /// Decodes the image of the given handle and waits until it is done.
+    pub fn wait(
+        handle: &image::Handle,
+    ) -> impl Future<Output = Result<(), image::Error>> {
+        let (sender, receiver) = oneshot::channel();
+
+        with(|decoder| {
+            decoder.request(handle);
+
+            match decoder.entries.get_mut(&handle.id()) {
+                Some(Entry::Pending(waiting)) => waiting.push(sender),
+                Some(Entry::Done(_, result)) => {
+                    let _ = sender
+                        .send(result.as_ref().map(|_| ()).map_err(|e| *e));
+                }
+                None => {}
+            }
+        });
+
+        async move { receiver.await.unwrap_or(Err(image::Error::Invalid)) }
+    }
+
+    /// Evicts the decoded images that no cache has taken for a while, like
+    /// preloaded images that are never drawn.
+    pub fn trim() {
+        with(|decoder| decoder.trim(Instant::now()));
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        use iced_native::futures::executor::block_on;
+
+        // The decoder is shared by all the tests, so every test uses
+        // different images.
+        fn pixels(red: u8) -> image::Handle {
+            image::Handle::from_pixels(1, 1, vec![red, 0, 0, 255])
+        }
+
+        #[test]
+        fn decoded_images_are_taken_once() {
+            let handle = pixels(1);
+
+            assert!(take(&handle).is_none());
+
+            request(&handle);
+
+            assert_eq!(block_on(wait(&handle)), Ok(()));
+            assert!(!is_pending(&handle));
+            assert!(matches!(take(&handle), Some(Ok(_))));
+            assert!(take(&handle).is_none());
+        }
+
+        #[test]
+        fn waiting_for_a_decoded_image_resolves_right_away() {
+            let handle = pixels(2);
+
+            assert_eq!(block_on(wait(&handle)), Ok(()));
+
+            let waiting = wait(&handle);
+
+            assert!(!is_pending(&handle));
+            assert_eq!(block_on(waiting), Ok(()));
+        }
+
+        #[test]
+        fn errors_are_reported() {
+            let missing = image::Handle::from_path("missing/image.png");
+            let invalid = image::Handle::from_memory(&b"not an image"[..]);
+            let truncated = image::Handle::from_pixels(2, 2, vec![3; 4]);
+
+            assert_eq!(block_on(wait(&missing)), Err(image::Error::NotFound));
+            assert_eq!(block_on(wait(&invalid)), Err(image::Error::Invalid));
+            assert_eq!(block_on(wait(&truncated)), Err(image::Error::Invalid));
+
+            assert!(matches!(
+                take(&missing),
+                Some(Err(image::Error::NotFound))
+            ));
+        }
+
+        #[test]
+        fn images_are_decoded_in_place_without_a_worker() {
+            // The receiver is dropped right away, like on Wasm
+            let mut decoder = Decoder {
+                sender: Some(mpsc::channel().0),
+                entries: HashMap::new(),
+            };
+
+            let handle = pixels(4);
+
+            decoder.request(&handle);
+
+            assert!(decoder.sender.is_none());
+            assert!(matches!(
+                decoder.entries.get(&handle.id()),
+                Some(Entry::Done(_, Ok(_)))
+            ));
+        }
+
+        #[test]
+        fn untaken_images_are_evicted_after_a_delay() {
+            let mut decoder = Decoder::default();
+            let done_at = Instant::now();
+
+            let _ = decoder.entries.insert(0, Entry::Pending(Vec::new()));
+            let _ = decoder
+                .entries
+                .insert(1, Entry::Done(done_at, Err(image::Error::Invalid)));
+
+            decoder.trim(done_at + EVICTION_DELAY - Duration::from_millis(1));
+
+            assert_eq!(decoder.entries.len(), 2);
+
+            decoder.trim(done_at + EVICTION_DELAY);
+
+            assert!(decoder.entries.contains_key(&0));
+            assert!(!decoder.entries.contains_key(&1));
+        }
+    }
+}
+
 bitflags! {
     struct Operation: u8 {
         const FLIP_HORIZONTALLY = 0b001;
@@ -8,7 +8,7 @@ pub mod mesh;
 pub use image::Image;
 pub use mesh::Mesh;
 pub use quad::Quad;
-pub use text::Text;
+pub use text::{RichText, Span, Text};
 
 use crate::alignment;
 use crate::{
@@ -30,6 +30,9 @@ pub struct Layer<'a> {
     /// The text of the [`Layer`].
     pub text: Vec<Text<'a>>,
 
+    /// The rich text of the [`Layer`].
+    pub rich_text: Vec<RichText<'a>>,
+
     /// The images of the [`Layer`].
     pub images: Vec<Image>,
 }
@@ -42,6 +45,7 @@ impl<'a> Layer<'a> {
             quads: Vec::new(),
             meshes: Vec::new(),
             text: Vec::new(),
+            rich_text: Vec::new(),
             images: Vec::new(),
         }
     }
@@ -62,9 +66,12 @@ impl<'a> Layer<'a> {
                 ),
                 color: [0.9, 0.9, 0.9, 1.0],
                 size: 20.0,
-                font: Font::Default,
+                font: Font::DEFAULT,
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Top,
+                line_height: 1.0,
+                wrap: iced_native::text::Wrap::default(),
+                overflow: iced_native::text::Overflow::default(),
             };
 
             overlay.text.push(text);
@@ -129,6 +136,9 @@ impl<'a> Layer<'a> {
                 font,
                 horizontal_alignment,
                 vertical_alignment,
+                line_height,
+                wrap,
+                overflow,
             } => {
                 let layer = &mut layers[current_layer];
 
@@ -140,6 +150,25 @@ impl<'a> Layer<'a> {
                     font: *font,
                     horizontal_alignment: *horizontal_alignment,
                     vertical_alignment: *vertical_alignment,
+                    line_height: *line_height,
+                    wrap: *wrap,
+                    overflow: *overflow,
+                });
+            }
+            Primitive::RichText { spans, bounds } => {
+                let layer = &mut layers[current_layer];
+
+                layer.rich_text.push(RichText {
+                    spans: spans
+                        .iter()
+                        .map(|span| Span {
+                            content: &span.content,
+                            color: span.color.into_linear(),
+                            size: span.size,
+                            font: span.font,
+                        })
+                        .collect(),
+                    bounds: *bounds + translation,
                 });
             }
             Primitive::Quad {
@@ -148,23 +177,36 @@ impl<'a> Layer<'a> {
                 border_radius,
                 border_width,
                 border_color,
+                shadow,
             } => {
                 let layer = &mut layers[current_layer];
 
                 // TODO: Move some of these computations to the GPU (?)
-                layer.quads.push(Quad {
+                let mut quad = Quad {
                     position: [
                         bounds.x + translation.x,
                         bounds.y + translation.y,
                     ],
                     size: [bounds.width, bounds.height],
-                    color: match background {
-                        Background::Color(color) => color.into_linear(),
-                    },
                     border_radius: *border_radius,
                     border_width: *border_width,
                     border_color: border_color.into_linear(),
-                });
+                    shadow_color: shadow.color.into_linear(),
+                    shadow_offset: [shadow.offset.x, shadow.offset.y],
+                    shadow_blur_radius: shadow.blur_radius,
+                    ..Quad::default()
+                };
+
+                match background {
+                    Background::Color(color) => {
+                        quad.color = color.into_linear();
+                    }
+                    Background::Gradient(gradient) => {
+                        quad.fill_gradient(gradient, *bounds + translation);
+                    }
+                }
+
+                layer.quads.push(quad);
             }
             Primitive::SolidMesh { buffers, size } => {
                 let layer = &mut layers[current_layer];
@@ -200,6 +242,7 @@ impl<'a> Layer<'a> {
                     layer.meshes.push(Mesh::Gradient {
                         origin: Point::new(translation.x, translation.y),
                         buffers,
+                        size: *size,
                         clip_bounds,
                         gradient,
                     });
@@ -243,12 +286,21 @@ impl<'a> Layer<'a> {
                     current_layer,
                 );
             }
-            Primitive::Image { handle, bounds } => {
+            Primitive::Image {
+                handle,
+                filter_method,
+                bounds,
+                rotation,
+                opacity,
+            } => {
                 let layer = &mut layers[current_layer];
 
                 layer.images.push(Image::Raster {
                     handle: handle.clone(),
+                    filter_method: *filter_method,
                     bounds: *bounds + translation,
+                    rotation: *rotation,
+                    opacity: *opacity,
                 });
             }
             Primitive::Svg {
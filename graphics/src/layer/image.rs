@@ -10,8 +10,17 @@ pub enum Image {
         /// The handle of a raster image.
         handle: image::Handle,
 
+        /// The filter method used to sample the image.
+        filter_method: image::FilterMethod,
+
         /// The bounds of the image.
         bounds: Rectangle,
+
+        /// The clockwise rotation of the image around its center, in radians.
+        rotation: f32,
+
+        /// The opacity of the image.
+        opacity: f32,
     },
     /// A vector image.
     Vector {
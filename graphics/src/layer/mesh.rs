@@ -1,6 +1,6 @@
 //! A collection of triangle primitives.
 use crate::triangle;
-use crate::{Gradient, Point, Rectangle};
+use crate::{Gradient, Point, Rectangle, Size};
 
 /// A mesh of triangles.
 #[derive(Debug, Clone, Copy)]
@@ -24,6 +24,11 @@ pub enum Mesh<'a> {
         /// The vertex and index buffers of the [`Mesh`].
         buffers: &'a triangle::Mesh2D<triangle::Vertex2D>,
 
+        /// The size of the drawable region of the [`Mesh`].
+        ///
+        /// The [`Gradient`] is positioned relative to it.
+        size: Size,
+
         /// The clipping bounds of the [`Mesh`].
         clip_bounds: Rectangle<f32>,
 
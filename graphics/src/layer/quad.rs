@@ -1,3 +1,6 @@
+use iced_native::gradient;
+use iced_native::Rectangle;
+
 /// A colored rectangle with a border.
 ///
 /// This type can be directly uploaded to GPU memory.
@@ -21,6 +24,108 @@ pub struct Quad {
 
     /// The border width of the [`Quad`].
     pub border_width: f32,
+
+    /// The start and end points of the gradient of the [`Quad`].
+    pub gradient_direction: [f32; 4],
+
+    /// The colors of the stops of the gradient of the [`Quad`], in
+    /// __linear RGB__.
+    ///
+    /// Every color is packed in two `u32` as 16-bit normalized RGBA.
+    pub gradient_colors: [u32; 16],
+
+    /// The offsets of the stops of the gradient of the [`Quad`].
+    ///
+    /// Every `u32` packs two offsets as 16-bit normalized numbers.
+    pub gradient_offsets: [u32; 4],
+
+    /// The amount of stops of the gradient of the [`Quad`].
+    ///
+    /// A [`Quad`] without gradient stops is filled with its `color`.
+    pub gradient_stops: u32,
+
+    /// The shadow color of the [`Quad`], in __linear RGB__.
+    pub shadow_color: [f32; 4],
+
+    /// The shadow offset of the [`Quad`].
+    pub shadow_offset: [f32; 2],
+
+    /// The shadow blur radius of the [`Quad`].
+    pub shadow_blur_radius: f32,
+}
+
+impl Quad {
+    /// Fills the [`Quad`] with the given linear gradient, positioned relative
+    /// to the given bounds.
+    pub fn fill_gradient(
+        &mut self,
+        gradient: &gradient::Linear,
+        bounds: Rectangle,
+    ) {
+        let (start, end) = gradient.points(bounds);
+
+        self.gradient_direction = [start.x, start.y, end.x, end.y];
+        self.gradient_stops = 0;
+
+        for (i, stop) in gradient.stops.iter().flatten().enumerate() {
+            let [r, g, b, a] = stop.color.into_linear();
+
+            self.gradient_colors[i * 2] = pack(r, g);
+            self.gradient_colors[i * 2 + 1] = pack(b, a);
+
+            let offsets = &mut self.gradient_offsets[i / 2];
+            *offsets |= u32::from(unorm16(stop.offset)) << (16 * (i % 2));
+
+            self.gradient_stops += 1;
+        }
+    }
+
+    /// Returns the offsets and colors of the stops of the gradient of the
+    /// [`Quad`], in __linear RGB__.
+    pub fn gradient(&self) -> impl Iterator<Item = (f32, [f32; 4])> + '_ {
+        (0..self.gradient_stops as usize).map(|i| {
+            let offset = self.gradient_offsets[i / 2] >> (16 * (i % 2));
+            let (r, g) = unpack(self.gradient_colors[i * 2]);
+            let (b, a) = unpack(self.gradient_colors[i * 2 + 1]);
+
+            (f32::from(offset as u16) / f32::from(u16::MAX), [r, g, b, a])
+        })
+    }
+}
+
+impl Default for Quad {
+    fn default() -> Self {
+        Self {
+            position: [0.0; 2],
+            size: [0.0; 2],
+            color: [0.0; 4],
+            border_color: [0.0; 4],
+            border_radius: [0.0; 4],
+            border_width: 0.0,
+            gradient_direction: [0.0; 4],
+            gradient_colors: [0; 16],
+            gradient_offsets: [0; 4],
+            gradient_stops: 0,
+            shadow_color: [0.0; 4],
+            shadow_offset: [0.0; 2],
+            shadow_blur_radius: 0.0,
+        }
+    }
+}
+
+fn unorm16(value: f32) -> u16 {
+    (value.clamp(0.0, 1.0) * f32::from(u16::MAX)).round() as u16
+}
+
+fn pack(low: f32, high: f32) -> u32 {
+    u32::from(unorm16(low)) | (u32::from(unorm16(high)) << 16)
+}
+
+fn unpack(packed: u32) -> (f32, f32) {
+    (
+        f32::from(packed as u16) / f32::from(u16::MAX),
+        f32::from((packed >> 16) as u16) / f32::from(u16::MAX),
+    )
 }
 
 #[allow(unsafe_code)]
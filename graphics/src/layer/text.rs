@@ -1,4 +1,5 @@
 use crate::{alignment, Font, Rectangle};
+use iced_native::text;
 
 /// A paragraph of text.
 #[derive(Debug, Clone, Copy)]
@@ -23,4 +24,41 @@ pub struct Text<'a> {
 
     /// The vertical alignment of the [`Text`].
     pub vertical_alignment: alignment::Vertical,
+
+    /// The line height of the [`Text`], relative to its font.
+    pub line_height: f32,
+
+    /// The wrapping strategy of the [`Text`].
+    pub wrap: text::Wrap,
+
+    /// The overflow strategy of the [`Text`].
+    pub overflow: text::Overflow,
+}
+
+/// A paragraph of text made of multiple [`Span`]s.
+///
+/// The paragraph is always aligned to the top left corner of its bounds.
+#[derive(Debug, Clone)]
+pub struct RichText<'a> {
+    /// The spans of the [`RichText`].
+    pub spans: Vec<Span<'a>>,
+
+    /// The layout bounds of the [`RichText`].
+    pub bounds: Rectangle,
+}
+
+/// A run of text with its own style, part of a [`RichText`].
+#[derive(Debug, Clone, Copy)]
+pub struct Span<'a> {
+    /// The content of the [`Span`].
+    pub content: &'a str,
+
+    /// The color of the [`Span`], in __linear RGB_.
+    pub color: [f32; 4],
+
+    /// The size of the [`Span`].
+    pub size: f32,
+
+    /// The font of the [`Span`].
+    pub font: Font,
 }
@@ -29,11 +29,13 @@ mod viewport;
 
 pub mod backend;
 pub mod font;
-pub mod gradient;
 pub mod image;
 pub mod layer;
 pub mod overlay;
 pub mod renderer;
+#[cfg(feature = "text-layout")]
+#[cfg_attr(docsrs, doc(cfg(feature = "text-layout")))]
+pub mod text;
 pub mod triangle;
 pub mod widget;
 pub mod window;
@@ -41,15 +43,15 @@ pub mod window;
 pub use antialiasing::Antialiasing;
 pub use backend::Backend;
 pub use error::Error;
-pub use gradient::Gradient;
 pub use layer::Layer;
-pub use primitive::Primitive;
+pub use primitive::{Primitive, Span};
 pub use renderer::Renderer;
 pub use transformation::Transformation;
 pub use viewport::Viewport;
 pub use window::compositor;
 
 pub use iced_native::alignment;
+pub use iced_native::gradient::{self, Gradient};
 pub use iced_native::{
-    Alignment, Background, Color, Font, Point, Rectangle, Size, Vector,
+    Alignment, Background, Color, Font, Point, Rectangle, Shadow, Size, Vector,
 };
@@ -1,6 +1,7 @@
 use iced_native::image;
 use iced_native::svg;
-use iced_native::{Background, Color, Font, Rectangle, Size, Vector};
+use iced_native::text;
+use iced_native::{Background, Color, Font, Rectangle, Shadow, Size, Vector};
 
 use crate::alignment;
 use crate::gradient::Gradient;
@@ -34,6 +35,19 @@ pub enum Primitive {
         horizontal_alignment: alignment::Horizontal,
         /// The vertical alignment of the text
         vertical_alignment: alignment::Vertical,
+        /// The line height of the text, relative to its font
+        line_height: f32,
+        /// The wrapping strategy of the text
+        wrap: text::Wrap,
+        /// The overflow strategy of the text
+        overflow: text::Overflow,
+    },
+    /// A paragraph of text made of multiple spans
+    RichText {
+        /// The spans of the text
+        spans: Vec<Span>,
+        /// The bounds of the text
+        bounds: Rectangle,
     },
     /// A quad primitive
     Quad {
@@ -47,13 +61,21 @@ pub enum Primitive {
         border_width: f32,
         /// The border color of the quad
         border_color: Color,
+        /// The shadow of the quad
+        shadow: Shadow,
     },
     /// An image primitive
     Image {
         /// The handle of the image
         handle: image::Handle,
+        /// The filter method used to sample the image
+        filter_method: image::FilterMethod,
         /// The bounds of the image
         bounds: Rectangle,
+        /// The clockwise rotation of the image around its center, in radians
+        rotation: f32,
+        /// The opacity of the image
+        opacity: f32,
     },
     /// An SVG primitive
     Svg {
@@ -118,6 +140,19 @@ pub enum Primitive {
     },
 }
 
+/// A run of text with its own style, part of a [`Primitive::RichText`].
+#[derive(Debug, Clone)]
+pub struct Span {
+    /// The contents of the span
+    pub content: String,
+    /// The color of the span
+    pub color: Color,
+    /// The size of the span
+    pub size: f32,
+    /// The font of the span
+    pub font: Font,
+}
+
 impl Default for Primitive {
     fn default() -> Primitive {
         Primitive::None
@@ -1,16 +1,20 @@
 //! Create a renderer from a [`Backend`].
 use crate::backend::{self, Backend};
-use crate::{Primitive, Vector};
+use crate::{Primitive, Span, Vector};
 use iced_native::image;
 use iced_native::layout;
 use iced_native::renderer;
 use iced_native::svg;
 use iced_native::text::{self, Text};
-use iced_native::{Background, Color, Element, Font, Point, Rectangle, Size};
+use iced_native::time::Duration;
+use iced_native::{
+    Background, Color, Element, Font, Point, Rectangle, Shadow, Size,
+};
 
 pub use iced_native::renderer::Style;
 
 use std::marker::PhantomData;
+use std::ops::Range;
 
 /// A backend-agnostic renderer that supports all the built-in widgets.
 #[derive(Debug)]
@@ -112,6 +116,7 @@ where
             border_radius: quad.border_radius.into(),
             border_width: quad.border_width,
             border_color: quad.border_color,
+            shadow: quad.shadow,
         });
     }
 
@@ -138,30 +143,76 @@ where
         &self,
         content: &str,
         size: u16,
+        line_height: f32,
         font: Font,
         bounds: Size,
+        wrap: text::Wrap,
+        overflow: text::Overflow,
     ) -> (f32, f32) {
-        self.backend()
-            .measure(content, f32::from(size), font, bounds)
+        self.backend().measure(
+            content,
+            f32::from(size),
+            line_height,
+            font,
+            bounds,
+            wrap,
+            overflow,
+        )
     }
 
     fn hit_test(
         &self,
-        content: &str,
-        size: f32,
-        font: Font,
+        paragraph: text::Paragraph<'_, Font>,
+        point: Point,
+        nearest_only: bool,
+    ) -> Option<text::Hit> {
+        self.backend().hit_test(paragraph, point, nearest_only)
+    }
+
+    fn measure_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.backend().measure_spans(spans, bounds)
+    }
+
+    fn hit_test_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
         bounds: Size,
         point: Point,
         nearest_only: bool,
     ) -> Option<text::Hit> {
-        self.backend().hit_test(
-            content,
-            size,
-            font,
-            bounds,
-            point,
-            nearest_only,
-        )
+        self.backend()
+            .hit_test_spans(spans, bounds, point, nearest_only)
+    }
+
+    fn span_bounds(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+        index: usize,
+    ) -> Vec<Rectangle> {
+        self.backend().span_bounds(spans, bounds, index)
+    }
+
+    fn selection_bounds(
+        &self,
+        paragraph: text::Paragraph<'_, Font>,
+        selection: Range<usize>,
+    ) -> Vec<Rectangle> {
+        self.backend().selection_bounds(paragraph, selection)
+    }
+
+    fn selection_bounds_spans(
+        &self,
+        spans: &[text::Span<'_, Font>],
+        bounds: Size,
+        selection: Range<usize>,
+    ) -> Vec<Rectangle> {
+        self.backend()
+            .selection_bounds_spans(spans, bounds, selection)
     }
 
     fn fill_text(&mut self, text: Text<'_, Self::Font>) {
@@ -173,8 +224,69 @@ where
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            line_height: text.line_height,
+            wrap: text.wrap,
+            overflow: text.overflow,
         });
     }
+
+    fn fill_rich_text(&mut self, text: text::RichText<'_, Self::Font>) {
+        self.primitives.push(Primitive::RichText {
+            spans: text
+                .spans
+                .iter()
+                .map(|span| Span {
+                    content: span.content.to_string(),
+                    color: span.color,
+                    size: span.size,
+                    font: span.font,
+                })
+                .collect(),
+            bounds: text.bounds,
+        });
+
+        // Decorations are drawn as quads over the regions covered by their
+        // spans, relative to the baseline of each line.
+        for (index, span) in text.spans.iter().enumerate() {
+            if !span.underline && !span.strikethrough {
+                continue;
+            }
+
+            let thickness = (span.size / 14.0).round().max(1.0);
+
+            let offsets: Vec<f32> =
+                [(span.underline, 0.85), (span.strikethrough, 0.55)]
+                    .into_iter()
+                    .filter_map(|(enabled, offset)| enabled.then_some(offset))
+                    .collect();
+
+            for region in self.backend().span_bounds(
+                text.spans,
+                text.bounds.size(),
+                index,
+            ) {
+                for offset in &offsets {
+                    self.primitives.push(Primitive::Quad {
+                        bounds: Rectangle {
+                            x: text.bounds.x + region.x,
+                            y: (text.bounds.y
+                                + region.y
+                                + region.height * offset
+                                - thickness / 2.0)
+                                .round(),
+                            width: region.width,
+                            height: thickness,
+                        },
+                        background: Background::Color(span.color),
+                        border_radius: [0.0; 4],
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                        shadow: Shadow::default(),
+                    });
+                }
+            }
+        }
+    }
 }
 
 impl<B, T> image::Renderer for Renderer<B, T>
@@ -187,8 +299,34 @@ where
         self.backend().dimensions(handle)
     }
 
-    fn draw(&mut self, handle: image::Handle, bounds: Rectangle) {
-        self.draw_primitive(Primitive::Image { handle, bounds })
+    fn frame_delays(&self, handle: &image::Handle) -> Option<Vec<Duration>> {
+        self.backend().frame_delays(handle)
+    }
+
+    fn frame(&self, handle: &image::Handle, index: usize) -> image::Handle {
+        handle.frame(index)
+    }
+
+    #[cfg(feature = "image_rs")]
+    fn is_loading(&self, handle: &image::Handle) -> bool {
+        crate::image::raster::is_loading(handle)
+    }
+
+    fn draw(
+        &mut self,
+        handle: image::Handle,
+        filter_method: image::FilterMethod,
+        bounds: Rectangle,
+        rotation: f32,
+        opacity: f32,
+    ) {
+        self.draw_primitive(Primitive::Image {
+            handle,
+            filter_method,
+            bounds,
+            rotation,
+            opacity,
+        })
     }
 }
 
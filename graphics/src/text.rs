@@ -0,0 +1,422 @@
+//! Lay out text with wrapping, line height and overflow strategies.
+mod measurer;
+
+pub use measurer::Measurer;
+
+use crate::alignment;
+
+use iced_native::text::{Overflow, Paragraph, Wrap};
+
+use glyph_brush::ab_glyph::{self, Font, ScaleFont};
+use glyph_brush::{
+    BuiltInLineBreaker, FontId, GlyphPositioner, HorizontalAlign,
+    SectionGeometry, SectionGlyph, ToSectionText, VerticalAlign,
+};
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+
+/// A [`GlyphPositioner`] that honors the alignment, [`Wrap`], line height and
+/// [`Overflow`] of some text.
+///
+/// The glyphs are first laid out from the top left corner of the section and
+/// then every line is moved and truncated as needed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Layout {
+    /// The horizontal alignment of the text.
+    pub horizontal_alignment: alignment::Horizontal,
+
+    /// The vertical alignment of the text.
+    pub vertical_alignment: alignment::Vertical,
+
+    /// The wrapping strategy of the text.
+    pub wrap: Wrap,
+
+    /// The distance between the baselines of consecutive lines, relative to
+    /// the default line height of their font.
+    pub line_height: f32,
+
+    /// The overflow strategy of the text.
+    pub overflow: Overflow,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            wrap: Wrap::default(),
+            line_height: 1.0,
+            overflow: Overflow::default(),
+        }
+    }
+}
+
+impl<Font> From<Paragraph<'_, Font>> for Layout {
+    fn from(paragraph: Paragraph<'_, Font>) -> Self {
+        Self {
+            horizontal_alignment: paragraph.horizontal_alignment,
+            vertical_alignment: paragraph.vertical_alignment,
+            wrap: paragraph.wrap,
+            line_height: paragraph.line_height,
+            overflow: paragraph.overflow,
+        }
+    }
+}
+
+impl Hash for Layout {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.horizontal_alignment.hash(state);
+        self.vertical_alignment.hash(state);
+        self.wrap.hash(state);
+        self.line_height.to_bits().hash(state);
+        self.overflow.hash(state);
+    }
+}
+
+impl GlyphPositioner for Layout {
+    fn calculate_glyphs<F, S>(
+        &self,
+        fonts: &[F],
+        geometry: &SectionGeometry,
+        sections: &[S],
+    ) -> Vec<SectionGlyph>
+    where
+        F: Font,
+        S: ToSectionText,
+    {
+        let (x, y) = geometry.screen_position;
+        let (max_width, max_height) = geometry.bounds;
+
+        let line_breaker = match self.wrap {
+            Wrap::Glyph => BuiltInLineBreaker::AnyCharLineBreaker,
+            Wrap::Word | Wrap::None => BuiltInLineBreaker::UnicodeLineBreaker,
+        };
+
+        let glyphs = glyph_brush::Layout::default_wrap()
+            .line_breaker(line_breaker)
+            .calculate_glyphs(
+                fonts,
+                &SectionGeometry {
+                    screen_position: (x, y),
+                    bounds: (
+                        if self.wrap == Wrap::None {
+                            f32::INFINITY
+                        } else {
+                            max_width
+                        },
+                        f32::INFINITY,
+                    ),
+                },
+                sections,
+            );
+
+        let mut lines = split_lines(fonts, glyphs);
+
+        // Lines are moved apart relative to their distance to the first line.
+        for line in &mut lines {
+            let shift = (self.line_height - 1.0) * (line.top - y);
+
+            line.top += shift;
+
+            for section_glyph in &mut line.glyphs {
+                section_glyph.glyph.position.y += shift;
+            }
+        }
+
+        if self.overflow == Overflow::Ellipsis {
+            let visible = lines
+                .iter()
+                .skip(1)
+                .take_while(|line| line.top + line.height <= y + max_height)
+                .count()
+                + 1;
+
+            let is_truncated = visible < lines.len();
+            lines.truncate(visible);
+
+            let last = lines.len().saturating_sub(1);
+
+            for (i, line) in lines.iter_mut().enumerate() {
+                if (is_truncated && i == last)
+                    || line.trimmed_width(fonts, sections, x) > max_width
+                {
+                    line.ellipsize(fonts, sections, x + max_width);
+                }
+            }
+        }
+
+        let height = lines
+            .last()
+            .map(|line| line.top + line.height - y)
+            .unwrap_or(0.0);
+
+        let vertical_shift = match self.vertical_alignment {
+            alignment::Vertical::Top => 0.0,
+            alignment::Vertical::Center => -height / 2.0,
+            alignment::Vertical::Bottom => -height,
+        };
+
+        lines
+            .into_iter()
+            .flat_map(|mut line| {
+                let width = line.trimmed_width(fonts, sections, x);
+
+                let horizontal_shift = match self.horizontal_alignment {
+                    alignment::Horizontal::Left => 0.0,
+                    alignment::Horizontal::Center => -width / 2.0,
+                    alignment::Horizontal::Right => -width,
+                };
+
+                for section_glyph in &mut line.glyphs {
+                    section_glyph.glyph.position.x += horizontal_shift;
+                    section_glyph.glyph.position.y += vertical_shift;
+                }
+
+                line.glyphs
+            })
+            .collect()
+    }
+
+    fn bounds_rect(&self, geometry: &SectionGeometry) -> ab_glyph::Rect {
+        glyph_brush::Layout::default_wrap()
+            .h_align(match self.horizontal_alignment {
+                alignment::Horizontal::Left => HorizontalAlign::Left,
+                alignment::Horizontal::Center => HorizontalAlign::Center,
+                alignment::Horizontal::Right => HorizontalAlign::Right,
+            })
+            .v_align(match self.vertical_alignment {
+                alignment::Vertical::Top => VerticalAlign::Top,
+                alignment::Vertical::Center => VerticalAlign::Center,
+                alignment::Vertical::Bottom => VerticalAlign::Bottom,
+            })
+            .bounds_rect(geometry)
+    }
+}
+
+/// A line of glyphs laid out from the left.
+#[derive(Debug)]
+struct Line {
+    glyphs: Vec<SectionGlyph>,
+    top: f32,
+    height: f32,
+}
+
+impl Line {
+    /// Returns the width of the [`Line`] measured from the given left edge,
+    /// ignoring any trailing whitespace.
+    fn trimmed_width<F: Font, S: ToSectionText>(
+        &self,
+        fonts: &[F],
+        sections: &[S],
+        left: f32,
+    ) -> f32 {
+        self.glyphs
+            .iter()
+            .rev()
+            .find(|section_glyph| !is_whitespace(sections, section_glyph))
+            .map(|section_glyph| right(fonts, section_glyph) - left)
+            .unwrap_or(0.0)
+    }
+
+    /// Drops the glyphs of the [`Line`] that do not fit before the given edge
+    /// and appends an ellipsis after the remaining ones.
+    fn ellipsize<F: Font, S: ToSectionText>(
+        &mut self,
+        fonts: &[F],
+        sections: &[S],
+        edge: f32,
+    ) {
+        let template = match self.glyphs.last() {
+            Some(section_glyph) => section_glyph.clone(),
+            None => return,
+        };
+
+        let left = self.glyphs[0].glyph.position.x;
+
+        let font = fonts[template.font_id.0].as_scaled(template.glyph.scale);
+
+        let (character, count) = if font.glyph_id('…').0 != 0 {
+            ('…', 1)
+        } else {
+            ('.', 3)
+        };
+
+        let id = font.glyph_id(character);
+        let advance = font.h_advance(id);
+
+        while let Some(section_glyph) = self.glyphs.last() {
+            if right(fonts, section_glyph) + advance * count as f32 <= edge
+                && !is_whitespace(sections, section_glyph)
+            {
+                break;
+            }
+
+            let _ = self.glyphs.pop();
+        }
+
+        let start = self
+            .glyphs
+            .last()
+            .map(|section_glyph| right(fonts, section_glyph))
+            .unwrap_or(left);
+
+        for i in 0..count {
+            self.glyphs.push(SectionGlyph {
+                glyph: ab_glyph::Glyph {
+                    id,
+                    scale: template.glyph.scale,
+                    position: ab_glyph::point(
+                        start + advance * i as f32,
+                        template.glyph.position.y,
+                    ),
+                },
+                ..template.clone()
+            });
+        }
+    }
+}
+
+/// Groups the glyphs laid out by [`glyph_brush`] into lines.
+fn split_lines<F: Font>(fonts: &[F], glyphs: Vec<SectionGlyph>) -> Vec<Line> {
+    let mut lines: Vec<(f32, Vec<SectionGlyph>)> = Vec::new();
+
+    for section_glyph in glyphs {
+        let baseline = section_glyph.glyph.position.y;
+
+        match lines.last_mut() {
+            Some((line_baseline, glyphs)) if *line_baseline == baseline => {
+                glyphs.push(section_glyph);
+            }
+            _ => {
+                lines.push((baseline, vec![section_glyph]));
+            }
+        }
+    }
+
+    lines
+        .into_iter()
+        .map(|(baseline, glyphs)| {
+            let (ascent, descent) = glyphs.iter().fold(
+                (0.0f32, 0.0f32),
+                |(ascent, descent), section_glyph| {
+                    let font = fonts[section_glyph.font_id.0]
+                        .as_scaled(section_glyph.glyph.scale);
+
+                    (ascent.max(font.ascent()), descent.min(font.descent()))
+                },
+            );
+
+            Line {
+                glyphs,
+                top: baseline - ascent,
+                height: ascent - descent,
+            }
+        })
+        .collect()
+}
+
+/// Returns the right edge of the advance of the given glyph.
+fn right<F: Font>(fonts: &[F], section_glyph: &SectionGlyph) -> f32 {
+    let font =
+        fonts[section_glyph.font_id.0].as_scaled(section_glyph.glyph.scale);
+
+    section_glyph.glyph.position.x + font.h_advance(section_glyph.glyph.id)
+}
+
+/// Returns whether the given glyph represents a whitespace character.
+fn is_whitespace<S: ToSectionText>(
+    sections: &[S],
+    section_glyph: &SectionGlyph,
+) -> bool {
+    sections[section_glyph.section_index]
+        .to_section_text()
+        .text
+        .get(section_glyph.byte_index..)
+        .and_then(|text| text.chars().next())
+        .map_or(false, char::is_whitespace)
+}
+
+/// Returns whether the given font has a glyph for every visible character of
+/// the text.
+pub fn is_supported<F: Font>(font: &F, text: &str) -> bool {
+    text.chars().all(|c| has_glyph(font, c))
+}
+
+/// Returns whether the given font has a glyph for the given character, or
+/// the character is invisible.
+pub fn has_glyph<F: Font>(font: &F, c: char) -> bool {
+    is_invisible(c) || font.glyph_id(c).0 != 0
+}
+
+/// Splits the text in runs of characters drawn with the same font.
+///
+/// Every visible character is drawn with the first font of the `chain` that
+/// has a glyph for it, or the first font of the `chain` if none of them do.
+/// Invisible characters, like whitespace, stay in the current run.
+///
+/// The fonts of the `chain` are indices into the given fonts.
+pub fn runs<F: Font>(
+    fonts: &[F],
+    chain: &[FontId],
+    text: &str,
+) -> Vec<(Range<usize>, FontId)> {
+    let primary = chain.first().copied().unwrap_or_default();
+
+    let mut runs: Vec<(Range<usize>, FontId)> = Vec::new();
+
+    for (index, c) in text.char_indices() {
+        let end = index + c.len_utf8();
+
+        let font_id = match runs.last() {
+            Some((_, current)) if is_invisible(c) => *current,
+            _ => chain
+                .iter()
+                .copied()
+                .find(|id| fonts[id.0].glyph_id(c).0 != 0)
+                .unwrap_or(primary),
+        };
+
+        match runs.last_mut() {
+            Some((range, current)) if *current == font_id => {
+                range.end = end;
+            }
+            _ => {
+                runs.push((index..end, font_id));
+            }
+        }
+    }
+
+    runs
+}
+
+fn is_invisible(c: char) -> bool {
+    c.is_whitespace() || c.is_control()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_fall_back_for_missing_glyphs() {
+        let fonts = [
+            ab_glyph::FontRef::try_from_slice(include_bytes!(
+                "../fonts/Lato-Regular.ttf"
+            ))
+            .unwrap(),
+            ab_glyph::FontRef::try_from_slice(include_bytes!(
+                "../fonts/Icons.ttf"
+            ))
+            .unwrap(),
+        ];
+
+        let text = "Done \u{F00C} ok";
+
+        assert!(!is_supported(&fonts[0], text));
+
+        assert_eq!(
+            runs(&fonts, &[FontId(0), FontId(1)], text),
+            vec![(0..5, FontId(0)), (5..9, FontId(1)), (9..11, FontId(0)),]
+        );
+    }
+}
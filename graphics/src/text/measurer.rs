@@ -0,0 +1,667 @@
+#[cfg(feature = "font-source")]
+use crate::font;
+use crate::text::{self, Layout};
+use crate::{Font, Point, Rectangle, Size};
+
+use iced_native::text::{Hit, Overflow, Paragraph, Span, Wrap};
+
+use glyph_brush::ab_glyph;
+use glyph_brush::{FontId, GlyphBrush, GlyphCruncher, Section, SectionGlyph};
+use std::cell::{Ref, RefCell, RefMut};
+use std::collections::HashMap;
+#[cfg(feature = "font-source")]
+use std::collections::HashSet;
+
+/// Resolves, measures and hit tests the text of a renderer.
+///
+/// A [`Measurer`] owns every font used by a renderer. The font ids it hands
+/// out are indices into [`Measurer::fonts`], so a renderer drawing with a
+/// brush of its own has to add any new fonts to it, in order, before drawing.
+///
+/// System fonts and the [`FALLBACK_FAMILIES`] are only loaded with the
+/// `font-source` feature. Without it, every [`Font`] that is not embedded in
+/// the application is drawn with the default font.
+///
+/// [`FALLBACK_FAMILIES`]: crate::font::FALLBACK_FAMILIES
+#[derive(Debug)]
+pub struct Measurer {
+    brush: RefCell<GlyphBrush<()>>,
+    font_map: RefCell<HashMap<Font, FontId>>,
+    #[cfg(feature = "font-source")]
+    fallbacks: RefCell<Fallbacks>,
+}
+
+impl Measurer {
+    /// Creates a new [`Measurer`] with the given default font.
+    pub fn new(default_font: ab_glyph::FontArc) -> Self {
+        Self {
+            brush: RefCell::new(
+                glyph_brush::GlyphBrushBuilder::using_font(default_font)
+                    .build(),
+            ),
+            font_map: RefCell::new(HashMap::new()),
+            #[cfg(feature = "font-source")]
+            fallbacks: RefCell::new(Fallbacks::default()),
+        }
+    }
+
+    /// Returns the fonts of the [`Measurer`], indexed by their [`FontId`].
+    pub fn fonts(&self) -> Ref<'_, [ab_glyph::FontArc]> {
+        Ref::map(self.brush.borrow(), |brush| brush.fonts())
+    }
+
+    /// Returns the [`GlyphBrush`] of the [`Measurer`], which can be used to
+    /// lay out glyphs by renderers that do not need a draw cache.
+    pub fn brush(&self) -> RefMut<'_, GlyphBrush<()>> {
+        self.brush.borrow_mut()
+    }
+
+    /// Measures the given content.
+    pub fn measure(
+        &self,
+        content: &str,
+        size: f32,
+        line_height: f32,
+        font: Font,
+        bounds: Size,
+        wrap: Wrap,
+        overflow: Overflow,
+    ) -> (f32, f32) {
+        self.measure_section(
+            &[span(content, size, font)],
+            bounds,
+            &Layout {
+                wrap,
+                line_height,
+                overflow,
+                ..Layout::default()
+            },
+        )
+    }
+
+    /// Measures the given spans.
+    pub fn measure_spans(
+        &self,
+        spans: &[Span<'_, Font>],
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.measure_section(spans, bounds, &Layout::default())
+    }
+
+    fn measure_section(
+        &self,
+        spans: &[Span<'_, Font>],
+        bounds: Size,
+        layout: &Layout,
+    ) -> (f32, f32) {
+        let (section, _) = self.section(spans, bounds);
+
+        if let Some(bounds) = self
+            .brush
+            .borrow_mut()
+            .glyph_bounds_custom_layout(section, layout)
+        {
+            (bounds.width().ceil(), bounds.height().ceil())
+        } else {
+            (0.0, 0.0)
+        }
+    }
+
+    /// Tests whether the given point is within the bounds of the given
+    /// [`Paragraph`].
+    pub fn hit_test(
+        &self,
+        paragraph: Paragraph<'_, Font>,
+        point: Point,
+        nearest_only: bool,
+    ) -> Option<Hit> {
+        self.hit_test_section(
+            &[span(paragraph.content, paragraph.size, paragraph.font)],
+            paragraph.bounds,
+            &Layout::from(paragraph),
+            point,
+            nearest_only,
+        )
+    }
+
+    /// Tests whether the given point is within the bounds of the given spans.
+    pub fn hit_test_spans(
+        &self,
+        spans: &[Span<'_, Font>],
+        bounds: Size,
+        point: Point,
+        nearest_only: bool,
+    ) -> Option<Hit> {
+        self.hit_test_section(
+            spans,
+            bounds,
+            &Layout::default(),
+            point,
+            nearest_only,
+        )
+    }
+
+    fn hit_test_section(
+        &self,
+        spans: &[Span<'_, Font>],
+        bounds: Size,
+        layout: &Layout,
+        point: Point,
+        nearest_only: bool,
+    ) -> Option<Hit> {
+        let (section, origins) = self.section(spans, bounds);
+
+        let mut brush = self.brush.borrow_mut();
+        let fonts = brush.fonts().to_vec();
+
+        let offsets = char_offsets(spans);
+
+        // Implements an iterator over the glyph bounding boxes.
+        let bounds =
+            brush
+                .glyphs_custom_layout(section, layout)
+                .map(|section_glyph| {
+                    (
+                        char_index(
+                            spans,
+                            &offsets,
+                            &origins,
+                            section_glyph.section_index,
+                            section_glyph.byte_index,
+                        ),
+                        glyph_bounds(&fonts, section_glyph),
+                    )
+                });
+
+        if !nearest_only {
+            for (index, bounds) in bounds.clone() {
+                if bounds.contains(point) {
+                    return Some(Hit::CharOffset(index));
+                }
+            }
+        }
+
+        let nearest = bounds
+            .map(|(index, bounds)| (index, bounds.center()))
+            .min_by(|(_, center_a), (_, center_b)| {
+                center_a
+                    .distance(point)
+                    .partial_cmp(&center_b.distance(point))
+                    .unwrap_or(std::cmp::Ordering::Greater)
+            });
+
+        nearest.map(|(index, center)| {
+            Hit::NearestCharOffset(index, point - center)
+        })
+    }
+
+    /// Returns the bounds of the span with the given index, one per line.
+    pub fn span_bounds(
+        &self,
+        spans: &[Span<'_, Font>],
+        bounds: Size,
+        index: usize,
+    ) -> Vec<Rectangle> {
+        self.regions(spans, bounds, &Layout::default(), |section_index, _| {
+            section_index == index
+        })
+    }
+
+    /// Returns the bounds of the given character range of a [`Paragraph`],
+    /// one per line.
+    pub fn selection_bounds(
+        &self,
+        paragraph: Paragraph<'_, Font>,
+        selection: std::ops::Range<usize>,
+    ) -> Vec<Rectangle> {
+        self.regions(
+            &[span(paragraph.content, paragraph.size, paragraph.font)],
+            paragraph.bounds,
+            &Layout::from(paragraph),
+            |_, index| selection.contains(&index),
+        )
+    }
+
+    /// Returns the bounds of the given character range of some spans, one
+    /// per line.
+    pub fn selection_bounds_spans(
+        &self,
+        spans: &[Span<'_, Font>],
+        bounds: Size,
+        selection: std::ops::Range<usize>,
+    ) -> Vec<Rectangle> {
+        self.regions(spans, bounds, &Layout::default(), |_, index| {
+            selection.contains(&index)
+        })
+    }
+
+    /// Returns the regions covered by the glyphs accepted by the given
+    /// predicate, merged into one region per line.
+    ///
+    /// The predicate receives the index of the span of every glyph and its
+    /// character index across all the spans.
+    fn regions(
+        &self,
+        spans: &[Span<'_, Font>],
+        bounds: Size,
+        layout: &Layout,
+        predicate: impl Fn(usize, usize) -> bool,
+    ) -> Vec<Rectangle> {
+        let (section, origins) = self.section(spans, bounds);
+
+        let mut brush = self.brush.borrow_mut();
+        let fonts = brush.fonts().to_vec();
+
+        let offsets = char_offsets(spans);
+
+        let mut regions: Vec<(f32, Rectangle)> = Vec::new();
+
+        for section_glyph in brush.glyphs_custom_layout(section, layout).filter(
+            |section_glyph| {
+                predicate(
+                    origins[section_glyph.section_index].0,
+                    char_index(
+                        spans,
+                        &offsets,
+                        &origins,
+                        section_glyph.section_index,
+                        section_glyph.byte_index,
+                    ),
+                )
+            },
+        ) {
+            let baseline = section_glyph.glyph.position.y;
+            let bounds = glyph_bounds(&fonts, section_glyph);
+
+            // Glyphs in the same line share their baseline.
+            match regions.last_mut() {
+                Some((line, region)) if *line == baseline => {
+                    *region = region.union(&bounds);
+                }
+                _ => {
+                    regions.push((baseline, bounds));
+                }
+            }
+        }
+
+        regions.into_iter().map(|(_, region)| region).collect()
+    }
+
+    /// Trims the glyphs cached while measuring.
+    pub fn trim_measurement_cache(&self) {
+        // TODO: We should probably use a `GlyphCalculator` for this. However,
+        // it uses a lifetimed `GlyphCalculatorGuard` with side-effects on drop.
+        // This makes stuff quite inconvenient. A manual method for trimming the
+        // cache would make our lives easier.
+        loop {
+            let action =
+                self.brush.borrow_mut().process_queued(|_, _| {}, |_| {});
+
+            match action {
+                Ok(_) => break,
+                Err(glyph_brush::BrushError::TextureTooSmall { suggested }) => {
+                    let (width, height) = suggested;
+
+                    self.brush.borrow_mut().resize_texture(width, height);
+                }
+            }
+        }
+    }
+
+    /// Returns the [`FontId`] of the given [`Font`], loading it if needed.
+    pub fn find_font(&self, font: Font) -> FontId {
+        if font.is_default() {
+            return FontId(0);
+        }
+
+        if let Some(font_id) = self.font_map.borrow().get(&font) {
+            return *font_id;
+        }
+
+        let font_id = match font.family {
+            iced_native::font::Family::External { bytes, .. } => {
+                self.load_external_font(bytes)
+            }
+            _ => self.load_system_font(font),
+        };
+
+        let _ = self.font_map.borrow_mut().insert(font, font_id);
+
+        font_id
+    }
+
+    /// Splits the given content in runs of text drawn with the same font,
+    /// using the fallback fonts for the characters missing in the given one.
+    ///
+    /// Every run is returned with its byte offset in the content.
+    pub fn runs<'a>(
+        &self,
+        content: &'a str,
+        font: Font,
+    ) -> Vec<(usize, &'a str, FontId)> {
+        let font_id = self.find_font(font);
+
+        let is_supported =
+            text::is_supported(&self.fonts()[font_id.0], content);
+
+        if is_supported {
+            return vec![(0, content, font_id)];
+        }
+
+        let chain = self.chain(font_id, content);
+
+        text::runs(&self.fonts(), &chain, content)
+            .into_iter()
+            .map(|(range, font_id)| (range.start, &content[range], font_id))
+            .collect()
+    }
+
+    /// Builds a section with the runs of the given spans.
+    ///
+    /// The index of the span and the byte offset of every text of the section
+    /// are returned as well.
+    pub fn section<'a>(
+        &self,
+        spans: &[Span<'a, Font>],
+        bounds: Size,
+    ) -> (Section<'a>, Vec<(usize, usize)>) {
+        let mut text = Vec::new();
+        let mut origins = Vec::new();
+
+        for (index, span) in spans.iter().enumerate() {
+            for (offset, run, font_id) in self.runs(span.content, span.font) {
+                text.push(glyph_brush::Text {
+                    text: run,
+                    scale: span.size.into(),
+                    font_id,
+                    extra: glyph_brush::Extra::default(),
+                });
+
+                origins.push((index, offset));
+            }
+        }
+
+        (
+            Section {
+                bounds: (bounds.width, bounds.height),
+                text,
+                ..Default::default()
+            },
+            origins,
+        )
+    }
+
+    fn add_font(&self, font: ab_glyph::FontArc) -> FontId {
+        self.brush.borrow_mut().add_font(font)
+    }
+
+    /// Loads the given bytes of a font embedded in the application.
+    ///
+    /// Every weight, stretch and style of an external font with the same
+    /// bytes shares a single [`FontId`].
+    fn load_external_font(&self, bytes: &'static [u8]) -> FontId {
+        let loaded = self.font_map.borrow().iter().find_map(|(font, id)| {
+            match font.family {
+                iced_native::font::Family::External {
+                    bytes: loaded, ..
+                } if std::ptr::eq(loaded, bytes) => Some(*id),
+                _ => None,
+            }
+        });
+
+        loaded.unwrap_or_else(|| {
+            self.add_font(
+                ab_glyph::FontArc::try_from_slice(bytes).expect("Load font"),
+            )
+        })
+    }
+
+    /// Loads the system font that best matches the given [`Font`], using the
+    /// default font if there is none.
+    #[cfg(feature = "font-source")]
+    fn load_system_font(&self, font: Font) -> FontId {
+        let loaded = font::Source::new()
+            .load_font(font)
+            .ok()
+            .and_then(|bytes| ab_glyph::FontArc::try_from_vec(bytes).ok());
+
+        if let Some(loaded) = loaded {
+            return self.add_font(loaded);
+        }
+
+        log::warn!(
+            "Font {:?} could not be loaded. Falling back to the default \
+            font...",
+            font
+        );
+
+        FontId(0)
+    }
+
+    #[cfg(not(feature = "font-source"))]
+    fn load_system_font(&self, font: Font) -> FontId {
+        log::warn!(
+            "Font {:?} is not embedded in the application and system fonts \
+            are disabled. Enable the `default_system_font` feature of your \
+            renderer to load it. Falling back to the default font...",
+            font
+        );
+
+        FontId(0)
+    }
+
+    /// Returns the fonts tried, in order, to draw the given content with the
+    /// font with the given id.
+    ///
+    /// A fallback font is only loaded when a character of the content is
+    /// missing in the fonts before it.
+    #[cfg(feature = "font-source")]
+    fn chain(&self, font_id: FontId, content: &str) -> Vec<FontId> {
+        let mut chain: Vec<FontId> = std::iter::once(font_id)
+            .chain((font_id.0 != 0).then_some(FontId(0)))
+            .collect();
+
+        for c in content.chars() {
+            let is_missing = {
+                let fonts = self.fonts();
+
+                !chain.iter().any(|id| text::has_glyph(&fonts[id.0], c))
+            };
+
+            if is_missing {
+                let _ = self.fallback(c);
+            }
+        }
+
+        chain.extend(self.fallbacks.borrow().loaded());
+        chain
+    }
+
+    #[cfg(not(feature = "font-source"))]
+    fn chain(&self, font_id: FontId, _content: &str) -> Vec<FontId> {
+        std::iter::once(font_id)
+            .chain((font_id.0 != 0).then_some(FontId(0)))
+            .collect()
+    }
+
+    /// Returns the first of the [`FALLBACK_FAMILIES`] with a glyph for the
+    /// given character, loading the families one at a time as needed.
+    ///
+    /// [`FALLBACK_FAMILIES`]: font::FALLBACK_FAMILIES
+    #[cfg(feature = "font-source")]
+    fn fallback(&self, c: char) -> Option<FontId> {
+        let mut fallbacks = self.fallbacks.borrow_mut();
+
+        if fallbacks.misses.contains(&c) {
+            return None;
+        }
+
+        for (name, fallback) in
+            font::FALLBACK_FAMILIES.iter().zip(&mut fallbacks.families)
+        {
+            let font_id = match *fallback {
+                Fallback::Loaded(font_id) => font_id,
+                Fallback::Missing => continue,
+                Fallback::Unloaded => {
+                    let loaded = font::Source::new()
+                        .load(&[font::Family::Title(String::from(*name))])
+                        .ok()
+                        .and_then(|bytes| {
+                            ab_glyph::FontArc::try_from_vec(bytes).ok()
+                        });
+
+                    match loaded {
+                        Some(loaded) => {
+                            let font_id = self.add_font(loaded);
+                            *fallback = Fallback::Loaded(font_id);
+
+                            font_id
+                        }
+                        None => {
+                            *fallback = Fallback::Missing;
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            if text::has_glyph(&self.fonts()[font_id.0], c) {
+                return Some(font_id);
+            }
+        }
+
+        let _ = fallbacks.misses.insert(c);
+
+        None
+    }
+}
+
+/// The state of the [`FALLBACK_FAMILIES`] of a [`Measurer`].
+///
+/// [`FALLBACK_FAMILIES`]: font::FALLBACK_FAMILIES
+#[cfg(feature = "font-source")]
+#[derive(Debug)]
+struct Fallbacks {
+    families: Vec<Fallback>,
+    misses: HashSet<char>,
+}
+
+#[cfg(feature = "font-source")]
+impl Fallbacks {
+    fn loaded(&self) -> impl Iterator<Item = FontId> + '_ {
+        self.families.iter().filter_map(|fallback| match fallback {
+            Fallback::Loaded(font_id) => Some(*font_id),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(feature = "font-source")]
+impl Default for Fallbacks {
+    fn default() -> Self {
+        Self {
+            families: vec![Fallback::Unloaded; font::FALLBACK_FAMILIES.len()],
+            misses: HashSet::new(),
+        }
+    }
+}
+
+#[cfg(feature = "font-source")]
+#[derive(Debug, Clone, Copy)]
+enum Fallback {
+    Unloaded,
+    Loaded(FontId),
+    Missing,
+}
+
+fn span(content: &str, size: f32, font: Font) -> Span<'_, Font> {
+    Span {
+        content,
+        size,
+        color: crate::Color::BLACK,
+        font,
+        underline: false,
+        strikethrough: false,
+    }
+}
+
+/// Returns the amount of characters preceding every span.
+fn char_offsets(spans: &[Span<'_, Font>]) -> Vec<usize> {
+    spans
+        .iter()
+        .scan(0, |count, span| {
+            let offset = *count;
+            *count += span.content.chars().count();
+
+            Some(offset)
+        })
+        .collect()
+}
+
+/// Computes the character index of a glyph across all the spans, based on
+/// the byte index within the input string of its section text.
+///
+/// The `origins` are the index of the span and the byte offset of every text
+/// of the section.
+fn char_index(
+    spans: &[Span<'_, Font>],
+    offsets: &[usize],
+    origins: &[(usize, usize)],
+    section_index: usize,
+    byte_index: usize,
+) -> usize {
+    let (index, offset) = origins[section_index];
+
+    offsets[index]
+        + spans[index]
+            .content
+            .char_indices()
+            .take_while(|(i, _)| *i < offset + byte_index)
+            .count()
+}
+
+/// Returns the bounds of the given glyph, spanning its whole advance and the
+/// full height of its font.
+fn glyph_bounds(
+    fonts: &[ab_glyph::FontArc],
+    section_glyph: &SectionGlyph,
+) -> Rectangle {
+    use ab_glyph::{Font, ScaleFont};
+
+    let glyph = &section_glyph.glyph;
+    let font = fonts[section_glyph.font_id.0].as_scaled(glyph.scale);
+
+    Rectangle::new(
+        Point::new(
+            glyph.position.x - font.h_side_bearing(glyph.id),
+            glyph.position.y - font.ascent(),
+        ),
+        Size::new(font.h_advance(glyph.id), font.ascent() - font.descent()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::font::Weight;
+
+    const LATO: &[u8] = include_bytes!("../../fonts/Lato-Regular.ttf");
+    const ICONS: &[u8] = include_bytes!("../../fonts/Icons.ttf");
+
+    #[test]
+    fn external_fonts_share_their_bytes() {
+        let measurer =
+            Measurer::new(ab_glyph::FontArc::try_from_slice(LATO).unwrap());
+
+        let icons = Font::external("Icons", ICONS);
+        let bold_icons = icons.weight(Weight::Bold);
+        let bold_lato = Font::external("Lato", LATO).weight(Weight::Bold);
+
+        let font_id = measurer.find_font(icons);
+
+        assert_ne!(font_id, FontId(0));
+        assert_eq!(measurer.find_font(bold_icons), font_id);
+        assert_ne!(measurer.find_font(bold_lato), font_id);
+        assert_eq!(measurer.fonts().len(), 3);
+    }
+}
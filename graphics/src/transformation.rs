@@ -30,6 +30,11 @@ impl Transformation {
     pub fn scale(x: f32, y: f32) -> Transformation {
         Transformation(Mat4::from_scale(Vec3::new(x, y, 1.0)))
     }
+
+    /// Creates a rotation transformation around the origin, in radians.
+    pub fn rotate(radians: f32) -> Transformation {
+        Transformation(Mat4::from_rotation_z(radians))
+    }
 }
 
 impl Mul for Transformation {
@@ -181,6 +181,9 @@ where
             iced_native::Event::Keyboard(keyboard_event) => {
                 Some(Event::Keyboard(keyboard_event))
             }
+            iced_native::Event::Gesture(gesture_event) => {
+                Some(Event::Gesture(gesture_event))
+            }
             _ => None,
         };
 
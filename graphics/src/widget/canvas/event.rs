@@ -1,4 +1,5 @@
 //! Handle events of a canvas.
+use iced_native::gesture;
 use iced_native::keyboard;
 use iced_native::mouse;
 use iced_native::touch;
@@ -18,4 +19,7 @@ pub enum Event {
 
     /// A keyboard event.
     Keyboard(keyboard::Event),
+
+    /// A gesture event.
+    Gesture(gesture::Event),
 }
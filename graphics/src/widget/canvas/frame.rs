@@ -26,7 +26,7 @@ enum Buffer {
     Solid(tessellation::VertexBuffers<triangle::ColoredVertex2D, u32>),
     Gradient(
         tessellation::VertexBuffers<triangle::Vertex2D, u32>,
-        Gradient,
+        Box<Gradient>,
     ),
 }
 
@@ -50,11 +50,11 @@ impl BufferStack {
                 }
             },
             Style::Gradient(gradient) => match self.stack.last() {
-                Some(Buffer::Gradient(_, last)) if gradient == last => {}
+                Some(Buffer::Gradient(_, last)) if gradient == &**last => {}
                 _ => {
                     self.stack.push(Buffer::Gradient(
                         tessellation::VertexBuffers::new(),
-                        gradient.clone(),
+                        Box::new(*gradient),
                     ));
                 }
             },
@@ -122,23 +122,65 @@ impl Transform {
         point.y = transformed.y;
     }
 
-    fn transform_style(&self, style: Style) -> Style {
+    /// Transforms the given [`Style`], positioning its gradient, if any,
+    /// relative to the given frame size.
+    fn transform_style(&self, style: Style, size: Size) -> Style {
         match style {
             Style::Solid(color) => Style::Solid(color),
             Style::Gradient(gradient) => {
-                Style::Gradient(self.transform_gradient(gradient))
+                Style::Gradient(self.transform_gradient(gradient, size))
             }
         }
     }
 
-    fn transform_gradient(&self, mut gradient: Gradient) -> Gradient {
-        let (start, end) = match &mut gradient {
-            Gradient::Linear(linear) => (&mut linear.start, &mut linear.end),
-        };
-        self.transform_point(start);
-        self.transform_point(end);
+    fn transform_gradient(
+        &self,
+        mut gradient: Gradient,
+        size: Size,
+    ) -> Gradient {
+        match &mut gradient {
+            Gradient::Linear(linear) => {
+                // Angles are relative to the whole frame, before any
+                // transformation
+                let (mut start, mut end) =
+                    linear.points(Rectangle::with_size(size));
+
+                self.transform_point(&mut start);
+                self.transform_point(&mut end);
+
+                linear.direction = (start, end).into();
+            }
+            Gradient::Radial(radial) => {
+                let radius =
+                    self.transform_vector(Vector::new(radial.radius, 0.0));
+
+                self.transform_point(&mut radial.center);
+                radial.radius =
+                    (radius.x * radius.x + radius.y * radius.y).sqrt();
+            }
+            Gradient::Conic(conic) => {
+                let direction = self.transform_vector(Vector::new(
+                    conic.angle.cos(),
+                    conic.angle.sin(),
+                ));
+
+                self.transform_point(&mut conic.center);
+                conic.angle = direction.y.atan2(direction.x);
+            }
+        }
+
         gradient
     }
+
+    /// Transforms the given [Vector] by the linear part of the
+    /// transformation matrix.
+    fn transform_vector(&self, vector: Vector) -> Vector {
+        let transformed = self
+            .raw
+            .transform_vector(euclid::Vector2D::new(vector.x, vector.y));
+
+        Vector::new(transformed.x, transformed.y)
+    }
 }
 
 impl Frame {
@@ -192,9 +234,9 @@ impl Frame {
     pub fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
         let Fill { style, rule } = fill.into();
 
-        let mut buffer = self
-            .buffers
-            .get_fill(&self.transforms.current.transform_style(style));
+        let mut buffer = self.buffers.get_fill(
+            &self.transforms.current.transform_style(style, self.size),
+        );
 
         let options =
             tessellation::FillOptions::default().with_fill_rule(rule.into());
@@ -227,9 +269,9 @@ impl Frame {
     ) {
         let Fill { style, rule } = fill.into();
 
-        let mut buffer = self
-            .buffers
-            .get_fill(&self.transforms.current.transform_style(style));
+        let mut buffer = self.buffers.get_fill(
+            &self.transforms.current.transform_style(style, self.size),
+        );
 
         let top_left =
             self.transforms.current.raw.transform_point(
@@ -258,9 +300,12 @@ impl Frame {
     pub fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>) {
         let stroke = stroke.into();
 
-        let mut buffer = self
-            .buffers
-            .get_stroke(&self.transforms.current.transform_style(stroke.style));
+        let mut buffer = self.buffers.get_stroke(
+            &self
+                .transforms
+                .current
+                .transform_style(stroke.style, self.size),
+        );
 
         let mut options = tessellation::StrokeOptions::default();
         options.line_width = stroke.width;
@@ -334,6 +379,9 @@ impl Frame {
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            line_height: 1.0,
+            wrap: iced_native::text::Wrap::default(),
+            overflow: iced_native::text::Overflow::default(),
         });
     }
 
@@ -452,7 +500,7 @@ impl Frame {
                                 indices: buffer.indices,
                             },
                             size: self.size,
-                            gradient,
+                            gradient: *gradient,
                         })
                     }
                 }
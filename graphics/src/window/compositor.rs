@@ -55,9 +55,28 @@ pub trait Compositor: Sized {
         background_color: Color,
         overlay: &[T],
     ) -> Result<(), SurfaceError>;
+
+    /// Presents the [`Renderer`] primitives like [`present`] does, and reads
+    /// back the resulting frame.
+    ///
+    /// The bytes are returned in RGBA order and the sRGB color space, with
+    /// rows stored from top to bottom. They describe an image with the
+    /// physical size of the given [`Viewport`].
+    ///
+    /// [`Renderer`]: Self::Renderer
+    /// [`present`]: Self::present
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Result<Vec<u8>, SurfaceError>;
 }
 
-/// Result of an unsuccessful call to [`Compositor::present`].
+/// Result of an unsuccessful call to [`Compositor::present`] or
+/// [`Compositor::screenshot`].
 #[derive(Clone, PartialEq, Eq, Debug, Error)]
 pub enum SurfaceError {
     /// A timeout was encountered while trying to acquire the next frame.
@@ -10,9 +10,11 @@ use core::ffi::c_void;
 /// A compositor is responsible for initializing a renderer and managing window
 /// surfaces.
 ///
-/// For now, this compositor only deals with a single global surface
-/// for drawing. However, the trait will most likely change in the near future
-/// to handle multiple surfaces at once.
+/// The compositor draws onto the window of the OpenGL context it was created
+/// with. Additional windows have their own context, sharing objects with the
+/// one of the compositor, and are drawn through a [`Surface`].
+///
+/// [`Surface`]: Self::Surface
 ///
 /// If you implement an OpenGL renderer, you can implement this trait to ease
 /// integration with existing windowing shells, like `iced_glutin`.
@@ -32,6 +34,12 @@ pub trait GLCompositor: Sized {
     /// It's up to you to decide the configuration supported by your renderer!
     type Settings: Default;
 
+    /// The surface of an additional window of the [`GLCompositor`].
+    ///
+    /// Frames are presented onto an offscreen target with the context of the
+    /// [`GLCompositor`], and then drawn onto the window with its own context.
+    type Surface;
+
     /// Creates a new [`GLCompositor`] and [`Renderer`] with the given
     /// [`Settings`] and an OpenGL address loader function.
     ///
@@ -68,4 +76,86 @@ pub trait GLCompositor: Sized {
         background_color: Color,
         overlay: &[T],
     );
+
+    /// Presents the primitives of the [`Renderer`] like [`present`] does, and
+    /// reads back the resulting frame.
+    ///
+    /// The bytes are returned in RGBA order and the sRGB color space, with rows
+    /// stored from top to bottom.
+    ///
+    /// [`Renderer`]: crate::Renderer
+    /// [`present`]: Self::present
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8>;
+
+    /// Creates a new [`Surface`] for an additional window.
+    ///
+    /// # Safety
+    /// The OpenGL context of the window must be current and share its objects
+    /// with the context of the [`GLCompositor`]. The `loader_function` should
+    /// resolve to valid OpenGL bindings for it.
+    ///
+    /// [`Surface`]: Self::Surface
+    #[allow(unsafe_code)]
+    unsafe fn create_surface(
+        &mut self,
+        loader_function: impl FnMut(&str) -> *const c_void,
+    ) -> Self::Surface;
+
+    /// Presents the primitives of the [`Renderer`] to the given [`Surface`].
+    ///
+    /// The OpenGL context of the [`GLCompositor`] must be current.
+    ///
+    /// [`Renderer`]: crate::Renderer
+    /// [`Surface`]: Self::Surface
+    fn present_surface<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    );
+
+    /// Presents the primitives of the [`Renderer`] to the given [`Surface`]
+    /// like [`present_surface`] does, and reads back the resulting frame.
+    ///
+    /// The bytes are returned like [`screenshot`] does.
+    ///
+    /// [`Renderer`]: crate::Renderer
+    /// [`Surface`]: Self::Surface
+    /// [`present_surface`]: Self::present_surface
+    /// [`screenshot`]: Self::screenshot
+    fn screenshot_surface<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8>;
+
+    /// Draws the last frame presented to the given [`Surface`] onto its
+    /// window.
+    ///
+    /// # Safety
+    /// The OpenGL context of the window of the [`Surface`] must be current.
+    ///
+    /// [`Surface`]: Self::Surface
+    #[allow(unsafe_code)]
+    unsafe fn draw_surface(&mut self, surface: &Self::Surface);
+
+    /// Destroys the OpenGL objects of the given [`Surface`].
+    ///
+    /// # Safety
+    /// The OpenGL context of the window of the [`Surface`] must be current.
+    ///
+    /// [`Surface`]: Self::Surface
+    #[allow(unsafe_code)]
+    unsafe fn destroy_surface(&mut self, surface: Self::Surface);
 }
@@ -1,4 +1,5 @@
 //! Build and reuse custom widgets using The Elm Architecture.
+use iced_native::accessibility;
 use iced_native::event;
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
@@ -304,6 +305,18 @@ where
             ) {
                 self.operation.text_input(state, id);
             }
+
+            fn accessible(
+                &mut self,
+                node: &accessibility::Node<'_>,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn widget::Operation<T>,
+                ),
+            ) {
+                self.operation.accessible(node, &mut |operation| {
+                    operate_on_children(&mut MapOperation { operation });
+                });
+            }
         }
 
         self.with_element(|element| {
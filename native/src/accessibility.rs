@@ -0,0 +1,390 @@
+//! Describe widgets to assistive technologies, like screen readers.
+//!
+//! Widgets describe themselves with a [`Node`] when they are traversed by an
+//! [`Operation`]. A [`Collector`] gathers all of these nodes in an
+//! accessibility [`Tree`].
+//!
+//! The windowing shells collect the [`Tree`] of every window after each frame
+//! and feed it to a platform bridge, like AccessKit, connected by the
+//! application. The [`Tree`] can also be requested with the [`tree`]
+//! command—or with [`UserInterface::accessibility`] when integrating a
+//! [`UserInterface`] manually.
+//!
+//! [`UserInterface`]: crate::UserInterface
+//! [`UserInterface::accessibility`]: crate::UserInterface::accessibility
+use crate::widget::operation::{Operation, Outcome};
+use crate::widget::Id;
+use crate::{Command, Rectangle};
+
+use std::borrow::Cow;
+
+/// The semantic role of a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// A widget that performs an action when pressed.
+    Button,
+
+    /// A widget that can be checked or unchecked.
+    CheckBox,
+
+    /// A widget that can be selected among a group of options.
+    RadioButton,
+
+    /// A widget that can be switched on or off.
+    Switch,
+
+    /// A widget that selects a value in a range.
+    Slider,
+
+    /// A widget that shows the progress of a task.
+    ProgressIndicator,
+
+    /// A widget that selects an option from a list.
+    ComboBox,
+
+    /// A widget that edits a single line of text.
+    TextInput,
+
+    /// A widget that edits multiple lines of text.
+    MultilineTextInput,
+
+    /// A widget that displays some text.
+    StaticText,
+
+    /// A widget that displays an image.
+    Image,
+
+    /// A widget that scrolls its contents.
+    ScrollView,
+}
+
+/// The value of a widget.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Some text.
+    Text(String),
+
+    /// A number in a range.
+    Numeric {
+        /// The current value.
+        value: f64,
+
+        /// The minimum value.
+        min: f64,
+
+        /// The maximum value.
+        max: f64,
+
+        /// The amount the value changes in a single step, if any.
+        step: Option<f64>,
+    },
+}
+
+/// The semantic description of a widget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node<'a> {
+    /// The [`Role`] of the widget.
+    pub role: Role,
+
+    /// The bounds of the widget.
+    pub bounds: Rectangle,
+
+    /// The [`Id`] of the widget, if any.
+    pub id: Option<Id>,
+
+    /// The label of the widget, if any.
+    pub label: Option<Cow<'a, str>>,
+
+    /// The [`Value`] of the widget, if any.
+    pub value: Option<Value>,
+
+    /// Whether the widget is checked, if it can be checked.
+    pub is_checked: Option<bool>,
+
+    /// Whether the widget is expanded, if it can be expanded.
+    pub is_expanded: Option<bool>,
+
+    /// Whether the widget is disabled.
+    pub is_disabled: bool,
+
+    /// Whether the widget is focused.
+    pub is_focused: bool,
+
+    /// The descendants of the widget that describe themselves.
+    pub children: Vec<Node<'a>>,
+}
+
+impl<'a> Node<'a> {
+    /// Creates a new [`Node`] with the given [`Role`] and bounds.
+    pub fn new(role: Role, bounds: Rectangle) -> Self {
+        Self {
+            role,
+            bounds,
+            id: None,
+            label: None,
+            value: None,
+            is_checked: None,
+            is_expanded: None,
+            is_disabled: false,
+            is_focused: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`Node`].
+    pub fn id(mut self, id: Option<&Id>) -> Self {
+        self.id = id.cloned();
+        self
+    }
+
+    /// Sets the label of the [`Node`].
+    pub fn label(mut self, label: impl Into<Cow<'a, str>>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the [`Value`] of the [`Node`].
+    pub fn value(mut self, value: Value) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Sets whether the [`Node`] is checked.
+    pub fn checked(mut self, is_checked: bool) -> Self {
+        self.is_checked = Some(is_checked);
+        self
+    }
+
+    /// Sets whether the [`Node`] is expanded.
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = Some(is_expanded);
+        self
+    }
+
+    /// Sets whether the [`Node`] is disabled.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets whether the [`Node`] is focused.
+    pub fn focused(mut self, is_focused: bool) -> Self {
+        self.is_focused = is_focused;
+        self
+    }
+
+    /// Returns the name of the [`Node`] that should be announced.
+    ///
+    /// This is the label of the [`Node`] or, if it has none, the text of its
+    /// descendants.
+    pub fn name(&self) -> Option<String> {
+        if let Some(label) = &self.label {
+            return Some(label.to_string());
+        }
+
+        let text: Vec<String> =
+            self.children.iter().filter_map(Node::name).collect();
+
+        if text.is_empty() {
+            None
+        } else {
+            Some(text.join(" "))
+        }
+    }
+
+    /// Turns the [`Node`] into one that owns all of its data.
+    pub fn into_owned(self) -> Node<'static> {
+        Node {
+            role: self.role,
+            bounds: self.bounds,
+            id: self.id,
+            label: self.label.map(|label| Cow::Owned(label.into_owned())),
+            value: self.value,
+            is_checked: self.is_checked,
+            is_expanded: self.is_expanded,
+            is_disabled: self.is_disabled,
+            is_focused: self.is_focused,
+            children: self.children.into_iter().map(Node::into_owned).collect(),
+        }
+    }
+}
+
+/// The accessibility tree of a user interface.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Tree {
+    /// The top-level nodes of the [`Tree`].
+    pub nodes: Vec<Node<'static>>,
+}
+
+impl Tree {
+    /// Returns true if the [`Tree`] has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the [`Node`] that is focused, if any.
+    pub fn focused(&self) -> Option<&Node<'static>> {
+        fn find<'a>(nodes: &'a [Node<'static>]) -> Option<&'a Node<'static>> {
+            nodes.iter().find_map(|node| {
+                if node.is_focused {
+                    Some(node)
+                } else {
+                    find(&node.children)
+                }
+            })
+        }
+
+        find(&self.nodes)
+    }
+}
+
+/// An [`Operation`] that collects the accessibility [`Tree`] of the widgets
+/// it traverses.
+#[derive(Debug, Clone)]
+pub struct Collector {
+    levels: Vec<Vec<Node<'static>>>,
+}
+
+impl Collector {
+    /// Creates a new empty [`Collector`].
+    pub fn new() -> Self {
+        Self {
+            levels: vec![Vec::new()],
+        }
+    }
+
+    /// Returns the [`Tree`] collected by the [`Collector`].
+    pub fn into_tree(mut self) -> Tree {
+        Tree {
+            nodes: self.levels.swap_remove(0),
+        }
+    }
+}
+
+impl Default for Collector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Operation<T> for Collector {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self)
+    }
+
+    fn accessible(
+        &mut self,
+        node: &Node<'_>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.levels.push(Vec::new());
+
+        operate_on_children(self);
+
+        let children = self.levels.pop().unwrap_or_default();
+
+        if let Some(level) = self.levels.last_mut() {
+            level.push(Node {
+                children,
+                ..node.clone().into_owned()
+            });
+        }
+    }
+}
+
+/// Produces an [`Operation`] that collects the accessibility [`Tree`] of the
+/// widgets it traverses and turns it into an output with the given function.
+pub fn collect<T>(f: impl Fn(Tree) -> T + 'static) -> impl Operation<T> {
+    struct Collect<F> {
+        collector: Collector,
+        f: F,
+    }
+
+    impl<T, F> Operation<T> for Collect<F>
+    where
+        F: Fn(Tree) -> T,
+    {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            self.collector.container(id, operate_on_children);
+        }
+
+        fn accessible(
+            &mut self,
+            node: &Node<'_>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            self.collector.accessible(node, operate_on_children);
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            Outcome::Some((self.f)(self.collector.clone().into_tree()))
+        }
+    }
+
+    Collect {
+        collector: Collector::new(),
+        f,
+    }
+}
+
+/// Produces a [`Command`] that collects the accessibility [`Tree`] of the
+/// current user interface and produces a message with it.
+pub fn tree<Message: 'static>(
+    f: impl Fn(Tree) -> Message + 'static,
+) -> Command<Message> {
+    Command::widget(collect(f))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nodes_are_nested() {
+        let mut collector = Collector::new();
+
+        let button = Node::new(Role::Button, Rectangle::default());
+        let text =
+            Node::new(Role::StaticText, Rectangle::default()).label("Submit");
+
+        Operation::<()>::container(&mut collector, None, &mut |operation| {
+            operation.accessible(&button, &mut |operation| {
+                operation.container(None, &mut |operation| {
+                    operation.accessible(&text, &mut |_| {});
+                });
+            });
+        });
+
+        let tree = collector.into_tree();
+
+        assert_eq!(tree.nodes.len(), 1);
+        assert_eq!(tree.nodes[0].role, Role::Button);
+        assert_eq!(tree.nodes[0].children, vec![text]);
+        assert_eq!(tree.nodes[0].name(), Some(String::from("Submit")));
+    }
+
+    #[test]
+    fn collect_finishes_with_the_tree() {
+        let mut operation = collect(|tree| tree);
+
+        let text =
+            Node::new(Role::StaticText, Rectangle::default()).label("Hello");
+
+        operation.container(None, &mut |operation| {
+            operation.accessible(&text, &mut |_| {});
+        });
+
+        match operation.finish() {
+            Outcome::Some(tree) => assert_eq!(tree.nodes, vec![text]),
+            _ => panic!("collect should produce a tree"),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Drag and drop data between widgets of an application.
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+/// The data carried by a drag and drop session.
+///
+/// A [`Payload`] can hold any value. Drop targets recover it by downcasting
+/// to the type they expect.
+#[derive(Clone)]
+pub struct Payload(Arc<dyn Any + Send + Sync>);
+
+impl Payload {
+    /// Creates a new [`Payload`] carrying the given value.
+    pub fn new<T>(value: T) -> Self
+    where
+        T: Any + Send + Sync,
+    {
+        Self(Arc::new(value))
+    }
+
+    /// Returns a reference to the value of the [`Payload`], if it has the
+    /// given type.
+    pub fn downcast_ref<T>(&self) -> Option<&T>
+    where
+        T: Any,
+    {
+        self.0.downcast_ref()
+    }
+
+    /// Returns true if the value of the [`Payload`] has the given type.
+    pub fn is<T>(&self) -> bool
+    where
+        T: Any,
+    {
+        self.0.is::<T>()
+    }
+}
+
+impl fmt::Debug for Payload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Payload").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for Payload {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// A drag and drop event.
+///
+/// These events are produced by a [`UserInterface`] while a drag and drop
+/// session, started with [`Shell::start_drag`], is in progress. The position
+/// of the cursor is the one given to the widgets alongside the event.
+///
+/// [`UserInterface`]: crate::UserInterface
+/// [`Shell::start_drag`]: crate::Shell::start_drag
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// The [`Payload`] has been moved.
+    Moved(Payload),
+
+    /// The [`Payload`] has been dropped.
+    Dropped(Payload),
+
+    /// The drag and drop session has been cancelled.
+    ///
+    /// This happens when `Escape` is pressed, when the cursor leaves the
+    /// window, when the window loses focus or when a touch is lost.
+    Cancelled(Payload),
+}
+
+impl Event {
+    /// Returns the [`Payload`] of the [`Event`].
+    pub fn payload(&self) -> &Payload {
+        match self {
+            Self::Moved(payload)
+            | Self::Dropped(payload)
+            | Self::Cancelled(payload) => payload,
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::accessibility;
 use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
@@ -338,6 +339,18 @@ where
             fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
                 self.operation.custom(state, id);
             }
+
+            fn accessible(
+                &mut self,
+                node: &accessibility::Node<'_>,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn widget::Operation<T>,
+                ),
+            ) {
+                self.operation.accessible(node, &mut |operation| {
+                    operate_on_children(&mut MapOperation { operation });
+                });
+            }
         }
 
         self.widget.operate(
@@ -533,6 +546,7 @@ where
                     border_color: color,
                     border_width: 1.0,
                     border_radius: 0.0.into(),
+                    ..renderer::Quad::default()
                 },
                 Color::TRANSPARENT,
             );
@@ -1,4 +1,6 @@
 //! Handle events of a user interface.
+use crate::drag;
+use crate::gesture;
 use crate::keyboard;
 use crate::mouse;
 use crate::touch;
@@ -18,14 +20,20 @@ pub enum Event {
     /// A mouse event
     Mouse(mouse::Event),
 
-    /// A window event
-    Window(window::Event),
+    /// A window event, together with the [`window::Id`] of its window
+    Window(window::Id, window::Event),
 
     /// A touch event
     Touch(touch::Event),
 
     /// A platform specific event
     PlatformSpecific(PlatformSpecific),
+
+    /// A drag and drop event
+    Drag(drag::Event),
+
+    /// A gesture event
+    Gesture(gesture::Event),
 }
 
 /// A platform specific event
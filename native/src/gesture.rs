@@ -0,0 +1,354 @@
+//! Recognize gestures performed with touch input.
+use crate::time::{Duration, Instant};
+use crate::touch;
+use crate::{Point, Vector};
+
+/// A gesture performed by the user.
+///
+/// Positions are given in the same coordinates as the touch events the
+/// gesture was recognized from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// A finger was pressed and lifted quickly without moving.
+    Tap {
+        /// The position of the tap.
+        position: Point,
+    },
+
+    /// A finger tapped twice in quick succession at the same place.
+    DoubleTap {
+        /// The position of the second tap.
+        position: Point,
+    },
+
+    /// A finger was pressed for a while without moving.
+    LongPress {
+        /// The position of the finger.
+        position: Point,
+    },
+
+    /// One or more fingers were moved.
+    Pan {
+        /// The position of the finger, or the center of the fingers.
+        position: Point,
+
+        /// The translation since the previous [`Event::Pan`].
+        translation: Vector,
+    },
+
+    /// Two fingers were moved closer or apart.
+    Pinch {
+        /// The center of the fingers.
+        center: Point,
+
+        /// The scale factor since the previous [`Event::Pinch`].
+        scale: f32,
+    },
+
+    /// Two fingers were rotated around each other.
+    Rotate {
+        /// The center of the fingers.
+        center: Point,
+
+        /// The clockwise angle, in radians, since the previous
+        /// [`Event::Rotate`].
+        angle: f32,
+    },
+}
+
+/// Recognizes gestures from a stream of touch events.
+#[derive(Debug, Clone, Default)]
+pub struct Recognizer {
+    fingers: Vec<(touch::Finger, Point)>,
+    press: Option<Press>,
+    last_tap: Option<(Point, Instant)>,
+}
+
+/// A finger that may become a tap or a long press.
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    origin: Point,
+    time: Instant,
+    is_long: bool,
+}
+
+impl Recognizer {
+    /// The distance a finger can move before it starts panning.
+    pub const SLOP: f32 = 10.0;
+
+    /// The time a finger needs to be pressed to produce an
+    /// [`Event::LongPress`].
+    pub const LONG_PRESS: Duration = Duration::from_millis(500);
+
+    /// The maximum time between the taps of an [`Event::DoubleTap`].
+    pub const DOUBLE_TAP: Duration = Duration::from_millis(300);
+
+    /// Creates a new [`Recognizer`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Processes the given [`touch::Event`] that happened at the given time
+    /// and returns the recognized gestures.
+    pub fn recognize(
+        &mut self,
+        event: &touch::Event,
+        now: Instant,
+    ) -> Vec<Event> {
+        let mut gestures: Vec<Event> = self.poll(now).into_iter().collect();
+
+        match *event {
+            touch::Event::FingerPressed { id, position } => {
+                self.fingers.retain(|(finger, _)| *finger != id);
+                self.fingers.push((id, position));
+
+                self.press = (self.fingers.len() == 1).then_some(Press {
+                    origin: position,
+                    time: now,
+                    is_long: false,
+                });
+            }
+            touch::Event::FingerMoved { id, position } => {
+                let previous = self.fingers.clone();
+
+                match self.fingers.iter_mut().find(|(finger, _)| *finger == id)
+                {
+                    Some((_, current)) => *current = position,
+                    None => return gestures,
+                }
+
+                if let Some(press) = self.press {
+                    if press.origin.distance(position) <= Self::SLOP {
+                        return gestures;
+                    }
+
+                    self.press = None;
+                }
+
+                gestures.extend(movement(&previous, &self.fingers));
+            }
+            touch::Event::FingerLifted { id, position } => {
+                self.fingers.retain(|(finger, _)| *finger != id);
+
+                if let Some(press) = self.press.take() {
+                    if !press.is_long && now - press.time < Self::LONG_PRESS {
+                        gestures.push(self.tap(position, now));
+                    }
+                }
+            }
+            touch::Event::FingerLost { id, .. } => {
+                self.fingers.retain(|(finger, _)| *finger != id);
+                self.press = None;
+            }
+        }
+
+        gestures
+    }
+
+    /// Produces the gestures that are recognized by the passage of time, like
+    /// an [`Event::LongPress`].
+    pub fn poll(&mut self, now: Instant) -> Option<Event> {
+        let press = self.press.as_mut()?;
+
+        if press.is_long || now - press.time < Self::LONG_PRESS {
+            return None;
+        }
+
+        press.is_long = true;
+
+        Some(Event::LongPress {
+            position: press.origin,
+        })
+    }
+
+    /// Returns the time when [`Recognizer::poll`] should be called next, if
+    /// any.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.press
+            .filter(|press| !press.is_long)
+            .map(|press| press.time + Self::LONG_PRESS)
+    }
+
+    fn tap(&mut self, position: Point, now: Instant) -> Event {
+        match self.last_tap.take() {
+            Some((last_position, time))
+                if now - time <= Self::DOUBLE_TAP
+                    && last_position.distance(position) <= Self::SLOP =>
+            {
+                Event::DoubleTap { position }
+            }
+            _ => {
+                self.last_tap = Some((position, now));
+
+                Event::Tap { position }
+            }
+        }
+    }
+}
+
+/// Returns the gestures performed by moving the given fingers from their
+/// previous positions.
+fn movement(
+    previous: &[(touch::Finger, Point)],
+    current: &[(touch::Finger, Point)],
+) -> Vec<Event> {
+    match (previous, current) {
+        ([(_, from)], [(_, to)]) => vec![Event::Pan {
+            position: *to,
+            translation: *to - *from,
+        }],
+        ([(_, a), (_, b), ..], [(_, a2), (_, b2), ..]) => {
+            let center = midpoint(*a, *b);
+            let new_center = midpoint(*a2, *b2);
+
+            let mut gestures = vec![Event::Pan {
+                position: new_center,
+                translation: new_center - center,
+            }];
+
+            let distance = a.distance(*b);
+
+            if distance > 0.0 {
+                gestures.push(Event::Pinch {
+                    center: new_center,
+                    scale: a2.distance(*b2) / distance,
+                });
+            }
+
+            let angle = angle(*a2, *b2) - angle(*a, *b);
+
+            // The angle is wrapped to the shortest rotation.
+            let angle = (angle + std::f32::consts::PI)
+                .rem_euclid(std::f32::consts::TAU)
+                - std::f32::consts::PI;
+
+            gestures.push(Event::Rotate {
+                center: new_center,
+                angle,
+            });
+
+            gestures
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn midpoint(a: Point, b: Point) -> Point {
+    Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0)
+}
+
+fn angle(a: Point, b: Point) -> f32 {
+    (b.y - a.y).atan2(b.x - a.x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pressed(id: u64, x: f32, y: f32) -> touch::Event {
+        touch::Event::FingerPressed {
+            id: touch::Finger(id),
+            position: Point::new(x, y),
+        }
+    }
+
+    fn moved(id: u64, x: f32, y: f32) -> touch::Event {
+        touch::Event::FingerMoved {
+            id: touch::Finger(id),
+            position: Point::new(x, y),
+        }
+    }
+
+    fn lifted(id: u64, x: f32, y: f32) -> touch::Event {
+        touch::Event::FingerLifted {
+            id: touch::Finger(id),
+            position: Point::new(x, y),
+        }
+    }
+
+    #[test]
+    fn taps_and_long_presses() {
+        let mut recognizer = Recognizer::new();
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+
+        assert!(recognizer
+            .recognize(&pressed(0, 10.0, 10.0), at(0))
+            .is_empty());
+        assert!(recognizer
+            .recognize(&moved(0, 12.0, 10.0), at(50))
+            .is_empty());
+        assert_eq!(
+            recognizer.recognize(&lifted(0, 12.0, 10.0), at(100)),
+            vec![Event::Tap {
+                position: Point::new(12.0, 10.0)
+            }]
+        );
+
+        let _ = recognizer.recognize(&pressed(0, 12.0, 10.0), at(200));
+        assert_eq!(
+            recognizer.recognize(&lifted(0, 12.0, 10.0), at(250)),
+            vec![Event::DoubleTap {
+                position: Point::new(12.0, 10.0)
+            }]
+        );
+
+        let _ = recognizer.recognize(&pressed(0, 50.0, 50.0), at(1000));
+        assert_eq!(recognizer.deadline(), Some(at(1500)));
+        assert_eq!(recognizer.poll(at(1200)), None);
+        assert_eq!(
+            recognizer.poll(at(1500)),
+            Some(Event::LongPress {
+                position: Point::new(50.0, 50.0)
+            })
+        );
+        assert_eq!(recognizer.deadline(), None);
+        assert!(recognizer
+            .recognize(&lifted(0, 50.0, 50.0), at(1600))
+            .is_empty());
+    }
+
+    #[test]
+    fn pans_pinches_and_rotations() {
+        let mut recognizer = Recognizer::new();
+        let now = Instant::now();
+
+        let _ = recognizer.recognize(&pressed(0, 0.0, 0.0), now);
+        assert_eq!(
+            recognizer.recognize(&moved(0, 20.0, 0.0), now),
+            vec![Event::Pan {
+                position: Point::new(20.0, 0.0),
+                translation: Vector::new(20.0, 0.0),
+            }]
+        );
+
+        let _ = recognizer.recognize(&pressed(1, 20.0, 20.0), now);
+        let gestures = recognizer.recognize(&moved(1, 20.0, 40.0), now);
+
+        assert_eq!(
+            gestures[..2],
+            [
+                Event::Pan {
+                    position: Point::new(20.0, 20.0),
+                    translation: Vector::new(0.0, 10.0),
+                },
+                Event::Pinch {
+                    center: Point::new(20.0, 20.0),
+                    scale: 2.0,
+                },
+            ]
+        );
+        assert!(matches!(
+            gestures[2],
+            Event::Rotate { angle, .. } if angle.abs() < f32::EPSILON
+        ));
+
+        let gestures = recognizer.recognize(&moved(1, -20.0, 40.0), now);
+
+        assert!(matches!(
+            gestures[2],
+            Event::Rotate { angle, .. }
+                if (angle - std::f32::consts::FRAC_PI_4).abs() < 1e-5
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 //! Load and draw raster graphics.
+use crate::time::Duration;
 use crate::{Hasher, Rectangle, Size};
 
 use std::borrow::Cow;
@@ -58,6 +59,22 @@ impl Handle {
         }
     }
 
+    /// Returns a [`Handle`] for the frame at the given index of the animated
+    /// image of this [`Handle`].
+    ///
+    /// The returned [`Handle`] shares the data of this one. Renderers use it
+    /// to identify the decoded pixels of the frame.
+    pub fn frame(&self, index: usize) -> Handle {
+        let mut hasher = Hasher::default();
+        self.id.hash(&mut hasher);
+        index.hash(&mut hasher);
+
+        Handle {
+            id: hasher.finish(),
+            data: self.data.clone(),
+        }
+    }
+
     /// Returns the unique identifier of the [`Handle`].
     pub fn id(&self) -> u64 {
         self.id
@@ -116,6 +133,40 @@ impl std::fmt::Debug for Data {
     }
 }
 
+/// A frame of an animated image.
+#[derive(Debug, Clone)]
+pub struct Frame<H = Handle> {
+    /// The handle of the still image of the [`Frame`].
+    pub handle: H,
+
+    /// How long the [`Frame`] is displayed before the next one.
+    pub delay: Duration,
+}
+
+/// The filtering method used to sample an image when it is scaled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FilterMethod {
+    /// Bilinear interpolation, which results in smooth edges.
+    #[default]
+    Linear,
+
+    /// Nearest neighbor sampling, which keeps edges sharp.
+    ///
+    /// This is useful for pixel art or to inspect the pixels of an image
+    /// when zoomed in.
+    Nearest,
+}
+
+/// An error that occurred while loading an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The image could not be found.
+    NotFound,
+
+    /// The image data is invalid or its format is not supported.
+    Invalid,
+}
+
 /// A [`Renderer`] that can render raster graphics.
 ///
 /// [renderer]: crate::renderer
@@ -128,7 +179,45 @@ pub trait Renderer: crate::Renderer {
     /// Returns the dimensions of an image for the given [`Handle`].
     fn dimensions(&self, handle: &Self::Handle) -> Size<u32>;
 
+    /// Returns true if the image of the given [`Handle`] is still being
+    /// loaded in the background.
+    ///
+    /// Shells redraw as soon as the image is ready, so widgets only need
+    /// this to draw something else in the meantime.
+    fn is_loading(&self, _handle: &Self::Handle) -> bool {
+        false
+    }
+
+    /// Returns the delays of the frames of the image of the given
+    /// [`Handle`], if it is animated and has been loaded.
+    ///
+    /// [`Handle`]: Self::Handle
+    fn frame_delays(&self, _handle: &Self::Handle) -> Option<Vec<Duration>> {
+        None
+    }
+
+    /// Returns the [`Handle`] of the frame at the given index of the
+    /// animated image of the given [`Handle`].
+    ///
+    /// [`Handle`]: Self::Handle
+    fn frame(&self, handle: &Self::Handle, _index: usize) -> Self::Handle {
+        handle.clone()
+    }
+
     /// Draws an image with the given [`Handle`] and inside the provided
     /// `bounds`.
-    fn draw(&mut self, handle: Self::Handle, bounds: Rectangle);
+    ///
+    /// The image is sampled using the given [`FilterMethod`], rotated
+    /// clockwise around the center of the `bounds` by `rotation` radians,
+    /// and drawn with the given `opacity`.
+    ///
+    /// [`Handle`]: Self::Handle
+    fn draw(
+        &mut self,
+        handle: Self::Handle,
+        filter_method: FilterMethod,
+        bounds: Rectangle,
+        rotation: f32,
+        opacity: f32,
+    );
 }
@@ -21,6 +21,8 @@ use crate::Element;
 use crate::layout::{Limits, Node};
 use crate::{Alignment, Padding, Point, Size};
 
+use std::ops::Range;
+
 /// The main axis of a flex layout.
 #[derive(Debug)]
 pub enum Axis {
@@ -230,3 +232,324 @@ where
 
     Node::with_children(size.pad(padding), nodes)
 }
+
+/// Computes the flex layout with the given axis and limits, breaking the
+/// items onto new lines when they do not fit in the main axis.
+///
+/// The items of every line are separated by `spacing` and aligned in the
+/// cross axis of the line using `align_items`. The lines themselves are
+/// separated by `line_spacing`.
+///
+/// It returns a new layout [`Node`].
+pub fn resolve_wrapping<Message, Renderer>(
+    axis: Axis,
+    renderer: &Renderer,
+    limits: &Limits,
+    padding: Padding,
+    spacing: f32,
+    line_spacing: f32,
+    align_items: Alignment,
+    items: &[Element<'_, Message, Renderer>],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let limits = limits.pad(padding);
+    let max_main = axis.main(limits.max());
+
+    let child_limits = Limits::new(Size::ZERO, limits.max());
+
+    let mut nodes: Vec<Node> = items
+        .iter()
+        .map(|child| child.as_widget().layout(renderer, &child_limits))
+        .collect();
+
+    let mut lines: Vec<(Range<usize>, f32)> = Vec::new();
+    let mut line_start = 0;
+    let mut line_main = 0.0;
+    let mut line_cross: f32 = 0.0;
+    let mut main: f32 = 0.0;
+
+    for (i, node) in nodes.iter().enumerate() {
+        let size = node.size();
+
+        if i > line_start && line_main + spacing + axis.main(size) > max_main {
+            lines.push((line_start..i, line_cross));
+
+            line_start = i;
+            line_main = 0.0;
+            line_cross = 0.0;
+        }
+
+        if i > line_start {
+            line_main += spacing;
+        }
+
+        line_main += axis.main(size);
+        line_cross = line_cross.max(axis.cross(size));
+        main = main.max(line_main);
+    }
+
+    if line_start < nodes.len() {
+        lines.push((line_start..nodes.len(), line_cross));
+    }
+
+    let pad = axis.pack(padding.left as f32, padding.top as f32);
+    let mut cross = pad.1;
+
+    for (i, (range, line_cross)) in lines.into_iter().enumerate() {
+        if i > 0 {
+            cross += line_spacing;
+        }
+
+        let mut line_main = pad.0;
+
+        for node in &mut nodes[range] {
+            let (x, y) = axis.pack(line_main, cross);
+
+            node.move_to(Point::new(x, y));
+
+            match axis {
+                Axis::Horizontal => {
+                    node.align(
+                        Alignment::Start,
+                        align_items,
+                        Size::new(0.0, line_cross),
+                    );
+                }
+                Axis::Vertical => {
+                    node.align(
+                        align_items,
+                        Alignment::Start,
+                        Size::new(line_cross, 0.0),
+                    );
+                }
+            }
+
+            line_main += axis.main(node.size()) + spacing;
+        }
+
+        cross += line_cross;
+    }
+
+    let (width, height) = axis.pack(main, cross - pad.1);
+    let size = limits.resolve(Size::new(width, height));
+
+    Node::with_children(size.pad(padding), nodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::{self, Null};
+    use crate::widget::Tree;
+    use crate::{Layout, Length, Rectangle, Widget};
+
+    /// A widget of a fixed size, regardless of its limits.
+    struct Fixed(Size);
+
+    impl Widget<(), Null> for Fixed {
+        fn width(&self) -> Length {
+            Length::Shrink
+        }
+
+        fn height(&self) -> Length {
+            Length::Shrink
+        }
+
+        fn layout(&self, _renderer: &Null, _limits: &Limits) -> Node {
+            Node::new(self.0)
+        }
+
+        fn draw(
+            &self,
+            _state: &Tree,
+            _renderer: &mut Null,
+            _theme: &<Null as crate::Renderer>::Theme,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    fn items(sizes: &[(f32, f32)]) -> Vec<Element<'static, (), Null>> {
+        sizes
+            .iter()
+            .map(|&(width, height)| {
+                Element::new(Fixed(Size::new(width, height)))
+            })
+            .collect()
+    }
+
+    fn wrap(
+        axis: Axis,
+        max: Size,
+        padding: Padding,
+        spacing: f32,
+        line_spacing: f32,
+        align_items: Alignment,
+        sizes: &[(f32, f32)],
+    ) -> Node {
+        let limits = Limits::new(Size::ZERO, max)
+            .width(Length::Shrink)
+            .height(Length::Shrink);
+
+        resolve_wrapping(
+            axis,
+            &Null::new(),
+            &limits,
+            padding,
+            spacing,
+            line_spacing,
+            align_items,
+            &items(sizes),
+        )
+    }
+
+    fn positions(node: &Node) -> Vec<(f32, f32)> {
+        node.children()
+            .iter()
+            .map(|child| (child.bounds().x, child.bounds().y))
+            .collect()
+    }
+
+    #[test]
+    fn lines_break_at_max_main() {
+        let node = wrap(
+            Axis::Horizontal,
+            Size::new(100.0, 1000.0),
+            Padding::ZERO,
+            10.0,
+            0.0,
+            Alignment::Start,
+            &[(45.0, 10.0), (45.0, 10.0), (45.0, 10.0), (20.0, 10.0)],
+        );
+
+        // Items filling the line exactly still fit in it
+        assert_eq!(
+            positions(&node),
+            vec![(0.0, 0.0), (55.0, 0.0), (0.0, 10.0), (55.0, 10.0)]
+        );
+        assert_eq!(node.size(), Size::new(100.0, 20.0));
+
+        let node = wrap(
+            Axis::Vertical,
+            Size::new(1000.0, 100.0),
+            Padding::ZERO,
+            10.0,
+            0.0,
+            Alignment::Start,
+            &[(10.0, 60.0), (20.0, 30.0), (10.0, 20.0)],
+        );
+
+        assert_eq!(
+            positions(&node),
+            vec![(0.0, 0.0), (0.0, 70.0), (20.0, 0.0)]
+        );
+        assert_eq!(node.size(), Size::new(30.0, 100.0));
+    }
+
+    #[test]
+    fn items_wider_than_the_line_get_their_own() {
+        let node = wrap(
+            Axis::Horizontal,
+            Size::new(100.0, 1000.0),
+            Padding::ZERO,
+            0.0,
+            0.0,
+            Alignment::Start,
+            &[(150.0, 10.0), (30.0, 10.0), (150.0, 20.0), (30.0, 10.0)],
+        );
+
+        // No empty line is created before a leading wide item
+        assert_eq!(
+            positions(&node),
+            vec![(0.0, 0.0), (0.0, 10.0), (0.0, 20.0), (0.0, 40.0)]
+        );
+
+        // The wide items overflow, but the node stays within its limits
+        assert_eq!(node.size(), Size::new(100.0, 50.0));
+    }
+
+    #[test]
+    fn lines_are_separated_by_line_spacing() {
+        let node = wrap(
+            Axis::Horizontal,
+            Size::new(100.0, 1000.0),
+            Padding::ZERO,
+            10.0,
+            5.0,
+            Alignment::Start,
+            &[(60.0, 10.0), (60.0, 20.0), (30.0, 10.0), (60.0, 10.0)],
+        );
+
+        assert_eq!(
+            positions(&node),
+            vec![(0.0, 0.0), (0.0, 15.0), (70.0, 15.0), (0.0, 40.0)]
+        );
+        assert_eq!(node.size(), Size::new(100.0, 50.0));
+    }
+
+    #[test]
+    fn items_are_aligned_within_their_line() {
+        let sizes = [(30.0, 10.0), (30.0, 30.0), (30.0, 20.0), (30.0, 10.0)];
+
+        let aligned = |axis: Axis, alignment: Alignment| {
+            let max = match axis {
+                Axis::Horizontal => Size::new(100.0, 1000.0),
+                Axis::Vertical => Size::new(1000.0, 100.0),
+            };
+
+            let sizes: Vec<_> = match axis {
+                Axis::Horizontal => sizes.to_vec(),
+                Axis::Vertical => sizes.iter().map(|&(w, h)| (h, w)).collect(),
+            };
+
+            wrap(axis, max, Padding::ZERO, 5.0, 0.0, alignment, &sizes)
+        };
+
+        // The last item is alone in its line, so it is aligned to itself
+        assert_eq!(
+            positions(&aligned(Axis::Horizontal, Alignment::Center)),
+            vec![(0.0, 10.0), (35.0, 0.0), (70.0, 5.0), (0.0, 30.0)]
+        );
+        assert_eq!(
+            positions(&aligned(Axis::Horizontal, Alignment::End)),
+            vec![(0.0, 20.0), (35.0, 0.0), (70.0, 10.0), (0.0, 30.0)]
+        );
+        assert_eq!(
+            positions(&aligned(Axis::Vertical, Alignment::End)),
+            vec![(20.0, 0.0), (0.0, 35.0), (10.0, 70.0), (30.0, 0.0)]
+        );
+
+        let filled = aligned(Axis::Horizontal, Alignment::Fill);
+
+        assert!(filled.children()[..3]
+            .iter()
+            .all(|child| child.size().height == 30.0));
+        assert_eq!(filled.children()[3].size().height, 10.0);
+    }
+
+    #[test]
+    fn padding_surrounds_the_lines() {
+        let node = wrap(
+            Axis::Horizontal,
+            Size::new(120.0, 1000.0),
+            Padding::from([5, 10]),
+            10.0,
+            5.0,
+            Alignment::Start,
+            &[(45.0, 10.0), (45.0, 10.0), (45.0, 10.0)],
+        );
+
+        // The padding is taken from the space available for the lines
+        assert_eq!(
+            positions(&node),
+            vec![(10.0, 5.0), (65.0, 5.0), (10.0, 20.0)]
+        );
+        assert_eq!(node.size(), Size::new(120.0, 35.0));
+    }
+}
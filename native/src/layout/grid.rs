@@ -0,0 +1,306 @@
+//! Distribute elements in a grid of rows and columns.
+use crate::alignment;
+use crate::layout::{Limits, Node};
+use crate::{Element, Length, Padding, Point, Size};
+
+/// The placement of an element in a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    /// The row of the [`Cell`].
+    pub row: usize,
+
+    /// The column of the [`Cell`].
+    pub column: usize,
+
+    /// The amount of rows the [`Cell`] spans.
+    pub row_span: usize,
+
+    /// The amount of columns the [`Cell`] spans.
+    pub column_span: usize,
+
+    /// The horizontal alignment of the contents of the [`Cell`], if it
+    /// overrides the one of the grid.
+    pub horizontal_alignment: Option<alignment::Horizontal>,
+
+    /// The vertical alignment of the contents of the [`Cell`], if it
+    /// overrides the one of the grid.
+    pub vertical_alignment: Option<alignment::Vertical>,
+}
+
+impl Cell {
+    /// Creates a new [`Cell`] at the given row and column.
+    pub fn new(row: usize, column: usize) -> Self {
+        Self {
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+            horizontal_alignment: None,
+            vertical_alignment: None,
+        }
+    }
+
+    /// Sets the amount of rows and columns the [`Cell`] spans.
+    pub fn span(mut self, rows: usize, columns: usize) -> Self {
+        self.row_span = rows.max(1);
+        self.column_span = columns.max(1);
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents of the [`Cell`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = Some(alignment);
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of the [`Cell`].
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = Some(alignment);
+        self
+    }
+}
+
+/// Computes the grid layout of the given items with the provided column and
+/// row tracks.
+///
+/// Every item is placed in its [`Cell`]. A track can have a fixed size, shrink
+/// to fit the items that only span that track, or fill a portion of the
+/// remaining space. Items that fall outside the given tracks create new
+/// tracks that shrink to fit.
+///
+/// It returns a new layout [`Node`].
+pub fn resolve<Message, Renderer>(
+    renderer: &Renderer,
+    limits: &Limits,
+    padding: Padding,
+    column_spacing: f32,
+    row_spacing: f32,
+    columns: &[Length],
+    rows: &[Length],
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    cells: &[Cell],
+    items: &[Element<'_, Message, Renderer>],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let limits = limits.pad(padding);
+    let max = limits.max();
+
+    let columns = implicit_tracks(
+        columns,
+        cells.iter().map(|cell| cell.column + cell.column_span),
+    );
+    let rows = implicit_tracks(
+        rows,
+        cells.iter().map(|cell| cell.row + cell.row_span),
+    );
+
+    // First, the columns are sized using the intrinsic width of the items...
+    let loose = Limits::new(Size::ZERO, max);
+    let mut contents: Vec<f32> = vec![0.0; columns.len()];
+
+    for (cell, item) in cells.iter().zip(items) {
+        if cell.column_span == 1
+            && !matches!(columns[cell.column], Length::Units(_))
+        {
+            let width = item.as_widget().layout(renderer, &loose).size().width;
+
+            contents[cell.column] = contents[cell.column].max(width);
+        }
+    }
+
+    let column_widths =
+        resolve_tracks(&columns, &contents, max.width, column_spacing);
+    let column_offsets = offsets(&column_widths, column_spacing);
+
+    // ...then, the rows are sized using the height of the items once they are
+    // constrained by the width of their columns...
+    let mut contents: Vec<f32> = vec![0.0; rows.len()];
+
+    for (cell, item) in cells.iter().zip(items) {
+        if cell.row_span == 1 && !matches!(rows[cell.row], Length::Units(_)) {
+            let width = span(
+                &column_widths,
+                column_spacing,
+                cell.column,
+                cell.column_span,
+            );
+
+            let limits = Limits::new(Size::ZERO, Size::new(width, max.height));
+
+            let height =
+                item.as_widget().layout(renderer, &limits).size().height;
+
+            contents[cell.row] = contents[cell.row].max(height);
+        }
+    }
+
+    let row_heights = resolve_tracks(&rows, &contents, max.height, row_spacing);
+    let row_offsets = offsets(&row_heights, row_spacing);
+
+    // ...and, finally, every item is laid out and aligned inside of its cell.
+    let nodes = cells
+        .iter()
+        .zip(items)
+        .map(|(cell, item)| {
+            let space = Size::new(
+                span(
+                    &column_widths,
+                    column_spacing,
+                    cell.column,
+                    cell.column_span,
+                ),
+                span(&row_heights, row_spacing, cell.row, cell.row_span),
+            );
+
+            let mut node = item
+                .as_widget()
+                .layout(renderer, &Limits::new(Size::ZERO, space));
+
+            node.move_to(Point::new(
+                padding.left as f32 + column_offsets[cell.column],
+                padding.top as f32 + row_offsets[cell.row],
+            ));
+
+            node.align(
+                cell.horizontal_alignment
+                    .unwrap_or(horizontal_alignment)
+                    .into(),
+                cell.vertical_alignment.unwrap_or(vertical_alignment).into(),
+                space,
+            );
+
+            node
+        })
+        .collect();
+
+    let size = limits.resolve(Size::new(
+        span(&column_widths, column_spacing, 0, column_widths.len()),
+        span(&row_heights, row_spacing, 0, row_heights.len()),
+    ));
+
+    Node::with_children(size.pad(padding), nodes)
+}
+
+/// Extends the given tracks with shrinking tracks until there are enough of
+/// them to hold every cell.
+fn implicit_tracks(
+    tracks: &[Length],
+    ends: impl Iterator<Item = usize>,
+) -> Vec<Length> {
+    let count = ends.fold(tracks.len(), usize::max);
+
+    let mut tracks = tracks.to_vec();
+    tracks.resize(count, Length::Shrink);
+
+    tracks
+}
+
+/// Computes the size of every track, given the size of their contents and the
+/// available space.
+///
+/// Filling tracks share the space left by the other tracks. If that space is
+/// unbounded, they shrink to fit their contents instead.
+fn resolve_tracks(
+    tracks: &[Length],
+    contents: &[f32],
+    available: f32,
+    spacing: f32,
+) -> Vec<f32> {
+    let total_spacing = spacing * tracks.len().saturating_sub(1) as f32;
+
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .zip(contents)
+        .map(|(track, content)| match track {
+            Length::Units(units) => f32::from(*units),
+            Length::Shrink => *content,
+            Length::Fill | Length::FillPortion(_) => 0.0,
+        })
+        .collect();
+
+    let fill_sum: u16 = tracks.iter().map(Length::fill_factor).sum();
+
+    if fill_sum > 0 {
+        let remaining = available - total_spacing - sizes.iter().sum::<f32>();
+
+        for ((size, track), content) in
+            sizes.iter_mut().zip(tracks).zip(contents)
+        {
+            let factor = track.fill_factor();
+
+            if factor == 0 {
+                continue;
+            }
+
+            *size = if remaining.is_finite() {
+                (remaining * f32::from(factor) / f32::from(fill_sum)).max(0.0)
+            } else {
+                *content
+            };
+        }
+    }
+
+    sizes
+}
+
+/// Returns the offset of every track from the start of the grid.
+fn offsets(sizes: &[f32], spacing: f32) -> Vec<f32> {
+    sizes
+        .iter()
+        .scan(0.0, |offset, size| {
+            let current = *offset;
+            *offset += size + spacing;
+
+            Some(current)
+        })
+        .collect()
+}
+
+/// Returns the total size of `count` tracks starting at `start`, including
+/// the spacing between them.
+fn span(sizes: &[f32], spacing: f32, start: usize, count: usize) -> f32 {
+    let end = (start + count).min(sizes.len());
+
+    if start >= end {
+        return 0.0;
+    }
+
+    sizes[start..end].iter().sum::<f32>() + spacing * (end - start - 1) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_share_remaining_space() {
+        let tracks = [
+            Length::Units(100),
+            Length::Shrink,
+            Length::Fill,
+            Length::FillPortion(3),
+        ];
+
+        let sizes =
+            resolve_tracks(&tracks, &[0.0, 50.0, 0.0, 0.0], 600.0, 10.0);
+
+        assert_eq!(sizes, vec![100.0, 50.0, 105.0, 315.0]);
+        assert_eq!(offsets(&sizes, 10.0), vec![0.0, 110.0, 170.0, 285.0]);
+        assert_eq!(span(&sizes, 10.0, 1, 2), 165.0);
+    }
+
+    #[test]
+    fn fill_tracks_shrink_when_unbounded() {
+        let sizes = resolve_tracks(
+            &[Length::Fill, Length::Fill],
+            &[20.0, 40.0],
+            f32::INFINITY,
+            0.0,
+        );
+
+        assert_eq!(sizes, vec![20.0, 40.0]);
+    }
+}
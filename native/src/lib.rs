@@ -44,9 +44,12 @@
 #![forbid(unsafe_code, rust_2018_idioms)]
 #![allow(clippy::inherent_to_string, clippy::type_complexity)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+pub mod accessibility;
 pub mod clipboard;
 pub mod command;
+pub mod drag;
 pub mod event;
+pub mod gesture;
 pub mod image;
 pub mod keyboard;
 pub mod layout;
@@ -78,10 +81,12 @@ mod debug;
 mod debug;
 
 pub use iced_core::alignment;
+pub use iced_core::font;
+pub use iced_core::gradient;
 pub use iced_core::time;
 pub use iced_core::{
-    color, Alignment, Background, Color, ContentFit, Font, Length, Padding,
-    Point, Rectangle, Size, Vector,
+    color, Alignment, Background, Color, ContentFit, Font, Gradient, Length,
+    Padding, Point, Rectangle, Shadow, Size, Vector,
 };
 pub use iced_futures::{executor, futures};
 pub use iced_style::application;
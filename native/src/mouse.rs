@@ -1,6 +1,2 @@
 //! Track mouse events.
-
-pub mod click;
-
-pub use click::Click;
 pub use iced_core::mouse::*;
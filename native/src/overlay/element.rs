@@ -1,5 +1,6 @@
 pub use crate::Overlay;
 
+use crate::accessibility;
 use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
@@ -194,6 +195,18 @@ where
             fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
                 self.operation.custom(state, id);
             }
+
+            fn accessible(
+                &mut self,
+                node: &accessibility::Node<'_>,
+                operate_on_children: &mut dyn FnMut(
+                    &mut dyn widget::Operation<T>,
+                ),
+            ) {
+                self.operation.accessible(node, &mut |operation| {
+                    operate_on_children(&mut MapOperation { operation });
+                });
+            }
         }
 
         self.content
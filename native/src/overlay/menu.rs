@@ -288,6 +288,7 @@ where
                 border_color: appearance.border_color,
                 border_width: appearance.border_width,
                 border_radius: appearance.border_radius.into(),
+                ..renderer::Quad::default()
             },
             appearance.background,
         );
@@ -368,7 +369,10 @@ where
         _shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+            Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Left,
+                ..
+            }) => {
                 let bounds = layout.bounds();
 
                 if bounds.contains(cursor_position) {
@@ -480,6 +484,7 @@ where
                         border_color: Color::TRANSPARENT,
                         border_width: 0.0,
                         border_radius: appearance.border_radius.into(),
+                        ..renderer::Quad::default()
                     },
                     appearance.selected_background,
                 );
@@ -502,6 +507,9 @@ where
                 },
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Center,
+                line_height: 1.0,
+                wrap: text::Wrap::default(),
+                overflow: text::Overflow::default(),
             });
         }
     }
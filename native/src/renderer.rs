@@ -5,7 +5,7 @@ mod null;
 pub use null::Null;
 
 use crate::layout;
-use crate::{Background, Color, Element, Rectangle, Vector};
+use crate::{Background, Color, Element, Rectangle, Shadow, Vector};
 
 /// A component that can be used by widgets to draw themselves on a screen.
 pub trait Renderer: Sized {
@@ -57,6 +57,21 @@ pub struct Quad {
 
     /// The border color of the [`Quad`].
     pub border_color: Color,
+
+    /// The [`Shadow`] of the [`Quad`].
+    pub shadow: Shadow,
+}
+
+impl Default for Quad {
+    fn default() -> Self {
+        Self {
+            bounds: Rectangle::default(),
+            border_radius: BorderRadius::default(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
+        }
+    }
 }
 
 /// The border radi for the corners of a graphics primitive in the order:
@@ -2,6 +2,8 @@ use crate::renderer::{self, Renderer};
 use crate::text::{self, Text};
 use crate::{Background, Font, Point, Rectangle, Size, Theme, Vector};
 
+use std::ops::Range;
+
 /// A renderer that does nothing.
 ///
 /// It can be useful if you are writing tests!
@@ -40,7 +42,7 @@ impl Renderer for Null {
 impl text::Renderer for Null {
     type Font = Font;
 
-    const ICON_FONT: Font = Font::Default;
+    const ICON_FONT: Font = Font::DEFAULT;
     const CHECKMARK_ICON: char = '0';
     const ARROW_DOWN_ICON: char = '0';
 
@@ -52,17 +54,35 @@ impl text::Renderer for Null {
         &self,
         _content: &str,
         _size: u16,
+        _line_height: f32,
         _font: Font,
         _bounds: Size,
+        _wrap: text::Wrap,
+        _overflow: text::Overflow,
     ) -> (f32, f32) {
         (0.0, 20.0)
     }
 
     fn hit_test(
         &self,
-        _contents: &str,
-        _size: f32,
-        _font: Self::Font,
+        _paragraph: text::Paragraph<'_, Self::Font>,
+        _point: Point,
+        _nearest_only: bool,
+    ) -> Option<text::Hit> {
+        None
+    }
+
+    fn measure_spans(
+        &self,
+        _spans: &[text::Span<'_, Self::Font>],
+        _bounds: Size,
+    ) -> (f32, f32) {
+        (0.0, 20.0)
+    }
+
+    fn hit_test_spans(
+        &self,
+        _spans: &[text::Span<'_, Self::Font>],
         _bounds: Size,
         _point: Point,
         _nearest_only: bool,
@@ -70,5 +90,33 @@ impl text::Renderer for Null {
         None
     }
 
+    fn span_bounds(
+        &self,
+        _spans: &[text::Span<'_, Self::Font>],
+        _bounds: Size,
+        _index: usize,
+    ) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
+    fn selection_bounds(
+        &self,
+        _paragraph: text::Paragraph<'_, Self::Font>,
+        _selection: Range<usize>,
+    ) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
+    fn selection_bounds_spans(
+        &self,
+        _spans: &[text::Span<'_, Self::Font>],
+        _bounds: Size,
+        _selection: Range<usize>,
+    ) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
     fn fill_text(&mut self, _text: Text<'_, Self::Font>) {}
+
+    fn fill_rich_text(&mut self, _text: text::RichText<'_, Self::Font>) {}
 }
@@ -1,3 +1,4 @@
+use crate::drag;
 use crate::window;
 
 /// A connection to the state of a shell.
@@ -12,6 +13,7 @@ pub struct Shell<'a, Message> {
     redraw_request: Option<window::RedrawRequest>,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
+    drag: Option<drag::Payload>,
 }
 
 impl<'a, Message> Shell<'a, Message> {
@@ -22,6 +24,7 @@ impl<'a, Message> Shell<'a, Message> {
             redraw_request: None,
             is_layout_invalid: false,
             are_widgets_invalid: false,
+            drag: None,
         }
     }
 
@@ -83,6 +86,20 @@ impl<'a, Message> Shell<'a, Message> {
         self.are_widgets_invalid = true;
     }
 
+    /// Starts a drag and drop session carrying the given [`drag::Payload`].
+    ///
+    /// Every widget will receive [`drag::Event`]s until the payload is
+    /// dropped or the session is cancelled.
+    pub fn start_drag(&mut self, payload: drag::Payload) {
+        self.drag = Some(payload);
+    }
+
+    /// Returns the [`drag::Payload`] of the drag and drop session started
+    /// with this [`Shell`], if any.
+    pub fn drag(&self) -> Option<&drag::Payload> {
+        self.drag.as_ref()
+    }
+
     /// Merges the current [`Shell`] with another one by applying the given
     /// function to the messages of the latter.
     ///
@@ -99,5 +116,9 @@ impl<'a, Message> Shell<'a, Message> {
 
         self.are_widgets_invalid =
             self.are_widgets_invalid || other.are_widgets_invalid;
+
+        if other.drag.is_some() {
+            self.drag = other.drag;
+        }
     }
 }
@@ -2,6 +2,8 @@
 use crate::alignment;
 use crate::{Color, Point, Rectangle, Size, Vector};
 
+use std::ops::Range;
+
 /// A paragraph.
 #[derive(Debug, Clone, Copy)]
 pub struct Text<'a, Font> {
@@ -25,6 +27,110 @@ pub struct Text<'a, Font> {
 
     /// The vertical alignment of the [`Text`].
     pub vertical_alignment: alignment::Vertical,
+
+    /// The distance between the baselines of consecutive lines of the
+    /// [`Text`], relative to the default line height of its font.
+    pub line_height: f32,
+
+    /// The [`Wrap`] strategy of the [`Text`].
+    pub wrap: Wrap,
+
+    /// The [`Overflow`] strategy of the [`Text`].
+    pub overflow: Overflow,
+}
+
+/// A paragraph laid out in some bounds, used to find the position of its
+/// characters without drawing it.
+///
+/// It is laid out like a [`Text`] with the same parameters would be drawn.
+#[derive(Debug, Clone, Copy)]
+pub struct Paragraph<'a, Font> {
+    /// The content of the [`Paragraph`].
+    pub content: &'a str,
+
+    /// The size of the bounds of the [`Paragraph`].
+    pub bounds: Size,
+
+    /// The size of the [`Paragraph`].
+    pub size: f32,
+
+    /// The font of the [`Paragraph`].
+    pub font: Font,
+
+    /// The horizontal alignment of the [`Paragraph`].
+    pub horizontal_alignment: alignment::Horizontal,
+
+    /// The vertical alignment of the [`Paragraph`].
+    pub vertical_alignment: alignment::Vertical,
+
+    /// The distance between the baselines of consecutive lines of the
+    /// [`Paragraph`], relative to the default line height of its font.
+    pub line_height: f32,
+
+    /// The [`Wrap`] strategy of the [`Paragraph`].
+    pub wrap: Wrap,
+
+    /// The [`Overflow`] strategy of the [`Paragraph`].
+    pub overflow: Overflow,
+}
+
+/// The wrapping strategy of some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Wrap {
+    /// Lines are broken between words.
+    #[default]
+    Word,
+
+    /// Lines are broken between any two glyphs.
+    Glyph,
+
+    /// Lines are only broken by explicit line breaks.
+    None,
+}
+
+/// The strategy used when some text does not fit in its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Overflow {
+    /// The text is clipped by its bounds.
+    #[default]
+    Clip,
+
+    /// The lines that do not fit are truncated and end with an ellipsis.
+    Ellipsis,
+}
+
+/// A run of text with its own style, part of a [`RichText`] paragraph.
+#[derive(Debug, Clone, Copy)]
+pub struct Span<'a, Font> {
+    /// The content of the [`Span`].
+    pub content: &'a str,
+
+    /// The size of the [`Span`].
+    pub size: f32,
+
+    /// The color of the [`Span`].
+    pub color: Color,
+
+    /// The font of the [`Span`].
+    pub font: Font,
+
+    /// Whether the [`Span`] is underlined.
+    pub underline: bool,
+
+    /// Whether the [`Span`] is struck through.
+    pub strikethrough: bool,
+}
+
+/// A paragraph made of multiple [`Span`]s laid out one after the other.
+///
+/// The paragraph is always aligned to the top left corner of its bounds.
+#[derive(Debug, Clone, Copy)]
+pub struct RichText<'a, Font> {
+    /// The spans of the paragraph.
+    pub spans: &'a [Span<'a, Font>],
+
+    /// The bounds of the paragraph.
+    pub bounds: Rectangle,
 }
 
 /// The result of hit testing on text.
@@ -76,39 +182,110 @@ pub trait Renderer: crate::Renderer {
     fn default_size(&self) -> u16;
 
     /// Measures the text in the given bounds and returns the minimum boundaries
-    /// that can fit the contents.
+    /// that can fit the contents, honoring the given line height, [`Wrap`]
+    /// and [`Overflow`] strategies.
     fn measure(
         &self,
         content: &str,
         size: u16,
+        line_height: f32,
         font: Self::Font,
         bounds: Size,
+        wrap: Wrap,
+        overflow: Overflow,
     ) -> (f32, f32);
 
     /// Measures the width of the text as if it were laid out in a single line.
     fn measure_width(&self, content: &str, size: u16, font: Self::Font) -> f32 {
-        let (width, _) = self.measure(content, size, font, Size::INFINITY);
+        let (width, _) = self.measure(
+            content,
+            size,
+            1.0,
+            font,
+            Size::INFINITY,
+            Wrap::None,
+            Overflow::Clip,
+        );
 
         width
     }
 
-    /// Tests whether the provided point is within the boundaries of text
-    /// laid out with the given parameters, returning information about
-    /// the nearest character.
+    /// Tests whether the provided point is within the boundaries of the given
+    /// [`Paragraph`], returning information about the nearest character.
+    ///
+    /// The point is relative to the position the [`Paragraph`] would be drawn
+    /// at, which depends on its alignment like the bounds of a [`Text`].
     ///
     /// If `nearest_only` is true, the hit test does not consider whether the
     /// the point is interior to any glyph bounds, returning only the character
     /// with the nearest centeroid.
     fn hit_test(
         &self,
-        contents: &str,
-        size: f32,
-        font: Self::Font,
+        paragraph: Paragraph<'_, Self::Font>,
+        point: Point,
+        nearest_only: bool,
+    ) -> Option<Hit>;
+
+    /// Measures the given [`Span`]s laid out as a single paragraph in the
+    /// given bounds and returns the minimum boundaries that can fit them.
+    fn measure_spans(
+        &self,
+        spans: &[Span<'_, Self::Font>],
+        bounds: Size,
+    ) -> (f32, f32);
+
+    /// Tests whether the provided point is within the boundaries of the given
+    /// [`Span`]s laid out as a single paragraph.
+    ///
+    /// The character offset of the returned [`Hit`] counts the characters of
+    /// all the spans, in order.
+    fn hit_test_spans(
+        &self,
+        spans: &[Span<'_, Self::Font>],
         bounds: Size,
         point: Point,
         nearest_only: bool,
     ) -> Option<Hit>;
 
+    /// Returns the regions covered by the [`Span`] at the given index when the
+    /// spans are laid out as a single paragraph, one per line it spans.
+    ///
+    /// The regions are relative to the top left corner of the paragraph.
+    fn span_bounds(
+        &self,
+        spans: &[Span<'_, Self::Font>],
+        bounds: Size,
+        index: usize,
+    ) -> Vec<Rectangle>;
+
+    /// Returns the regions covered by the characters in the given `selection`
+    /// of the [`Paragraph`], one per line.
+    ///
+    /// The regions are relative to the position the [`Paragraph`] would be
+    /// drawn at, like the point of [`hit_test`].
+    ///
+    /// [`hit_test`]: Self::hit_test
+    fn selection_bounds(
+        &self,
+        paragraph: Paragraph<'_, Self::Font>,
+        selection: Range<usize>,
+    ) -> Vec<Rectangle>;
+
+    /// Returns the regions covered by the characters in the given `selection`
+    /// when the [`Span`]s are laid out as a single paragraph, one per line.
+    ///
+    /// The characters of all the spans are counted in order and the regions
+    /// are relative to the top left corner of the paragraph.
+    fn selection_bounds_spans(
+        &self,
+        spans: &[Span<'_, Self::Font>],
+        bounds: Size,
+        selection: Range<usize>,
+    ) -> Vec<Rectangle>;
+
     /// Draws the given [`Text`].
     fn fill_text(&mut self, text: Text<'_, Self::Font>);
+
+    /// Draws the given [`RichText`].
+    fn fill_rich_text(&mut self, text: RichText<'_, Self::Font>);
 }
@@ -1,9 +1,15 @@
 //! Implement your own event loop to drive a user interface.
+use crate::accessibility;
 use crate::application;
+use crate::drag;
 use crate::event::{self, Event};
+use crate::gesture;
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
+use crate::time::Instant;
+use crate::touch;
 use crate::widget;
 use crate::window;
 use crate::{Clipboard, Element, Layout, Point, Rectangle, Shell, Size};
@@ -28,6 +34,8 @@ pub struct UserInterface<'a, Message, Renderer> {
     state: widget::Tree,
     overlay: Option<layout::Node>,
     bounds: Size,
+    drag: Option<drag::Payload>,
+    gestures: gesture::Recognizer,
 }
 
 impl<'a, Message, Renderer> UserInterface<'a, Message, Renderer>
@@ -97,7 +105,11 @@ where
     ) -> Self {
         let root = root.into();
 
-        let Cache { mut state } = cache;
+        let Cache {
+            mut state,
+            drag,
+            gestures,
+        } = cache;
         state.diff(root.as_widget());
 
         let base =
@@ -109,6 +121,8 @@ where
             state,
             overlay: None,
             bounds,
+            drag,
+            gestures,
         }
     }
 
@@ -191,6 +205,7 @@ where
 
         let mut outdated = false;
         let mut redraw_request = None;
+        let now = Instant::now();
 
         let mut manual_overlay =
             ManuallyDrop::new(self.root.as_widget_mut().overlay(
@@ -220,6 +235,10 @@ where
 
                 event_statuses.push(event_status);
 
+                if let Some(payload) = shell.drag() {
+                    self.drag = Some(payload.clone());
+                }
+
                 match (redraw_request, shell.redraw_request()) {
                     (None, Some(at)) => {
                         redraw_request = Some(at);
@@ -261,7 +280,11 @@ where
                 }
             }
 
-            let base_cursor = if layout.bounds().contains(cursor_position) {
+            // While dragging, the cursor stays available to the widgets below
+            // so they can act as drop targets.
+            let base_cursor = if self.drag.is_none()
+                && layout.bounds().contains(cursor_position)
+            {
                 // TODO: Type-safe cursor availability
                 Point::new(-1.0, -1.0)
             } else {
@@ -282,6 +305,13 @@ where
             .cloned()
             .zip(overlay_statuses.into_iter())
             .map(|(event, overlay_status)| {
+                let gestures = match &event {
+                    Event::Touch(touch_event) => {
+                        self.gestures.recognize(touch_event, now)
+                    }
+                    _ => self.gestures.poll(now).into_iter().collect(),
+                };
+
                 if matches!(overlay_status, event::Status::Captured) {
                     return overlay_status;
                 }
@@ -290,7 +320,7 @@ where
 
                 let event_status = self.root.as_widget_mut().on_event(
                     &mut self.state,
-                    event,
+                    event.clone(),
                     Layout::new(&self.base),
                     base_cursor,
                     renderer,
@@ -302,6 +332,50 @@ where
                     self.overlay = None;
                 }
 
+                if let Some(payload) = shell.drag() {
+                    self.drag = Some(payload.clone());
+                }
+
+                if let Some(drag_event) = self
+                    .drag
+                    .as_ref()
+                    .and_then(|payload| drag_event(&event, payload))
+                {
+                    if !matches!(drag_event, drag::Event::Moved(_)) {
+                        self.drag = None;
+                    }
+
+                    let _ = self.root.as_widget_mut().on_event(
+                        &mut self.state,
+                        Event::Drag(drag_event),
+                        Layout::new(&self.base),
+                        cursor_position,
+                        renderer,
+                        clipboard,
+                        &mut shell,
+                    );
+
+                    // The drag preview follows the cursor.
+                    self.overlay = None;
+                }
+
+                for gesture in gestures {
+                    let _ = self.root.as_widget_mut().on_event(
+                        &mut self.state,
+                        Event::Gesture(gesture),
+                        Layout::new(&self.base),
+                        base_cursor,
+                        renderer,
+                        clipboard,
+                        &mut shell,
+                    );
+                }
+
+                // A long press is recognized once its deadline is reached.
+                if let Some(deadline) = self.gestures.deadline() {
+                    shell.request_redraw(window::RedrawRequest::At(deadline));
+                }
+
                 match (redraw_request, shell.redraw_request()) {
                     (None, Some(at)) => {
                         redraw_request = Some(at);
@@ -435,12 +509,13 @@ where
                 .take()
                 .unwrap_or_else(|| overlay.layout(renderer, self.bounds));
 
-            let new_cursor_position =
-                if overlay_layout.bounds().contains(cursor_position) {
-                    Point::new(-1.0, -1.0)
-                } else {
-                    cursor_position
-                };
+            let new_cursor_position = if self.drag.is_none()
+                && overlay_layout.bounds().contains(cursor_position)
+            {
+                Point::new(-1.0, -1.0)
+            } else {
+                cursor_position
+            };
 
             self.overlay = Some(overlay_layout);
 
@@ -544,16 +619,75 @@ where
         }
     }
 
+    /// Collects the accessibility [`Tree`] of the [`UserInterface`], which
+    /// describes its widgets to assistive technologies.
+    ///
+    /// [`Tree`]: accessibility::Tree
+    pub fn accessibility(
+        &mut self,
+        renderer: &Renderer,
+    ) -> accessibility::Tree {
+        let mut collector = accessibility::Collector::new();
+
+        self.operate(renderer, &mut collector);
+
+        collector.into_tree()
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
-        Self::build(self.root, bounds, Cache { state: self.state }, renderer)
+        Self::build(
+            self.root,
+            bounds,
+            Cache {
+                state: self.state,
+                drag: self.drag,
+                gestures: self.gestures,
+            },
+            renderer,
+        )
+    }
+
+    /// Returns the [`drag::Payload`] of the drag and drop session in
+    /// progress, if any.
+    pub fn drag(&self) -> Option<&drag::Payload> {
+        self.drag.as_ref()
     }
 
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
     /// process.
     pub fn into_cache(self) -> Cache {
-        Cache { state: self.state }
+        Cache {
+            state: self.state,
+            drag: self.drag,
+            gestures: self.gestures,
+        }
+    }
+}
+
+/// Produces the [`drag::Event`] caused by the given [`Event`] while the given
+/// [`drag::Payload`] is being dragged, if any.
+fn drag_event(event: &Event, payload: &drag::Payload) -> Option<drag::Event> {
+    match event {
+        Event::Mouse(mouse::Event::CursorMoved { .. })
+        | Event::Touch(touch::Event::FingerMoved { .. }) => {
+            Some(drag::Event::Moved(payload.clone()))
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. }) => {
+            Some(drag::Event::Dropped(payload.clone()))
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Escape,
+            ..
+        })
+        | Event::Mouse(mouse::Event::CursorLeft)
+        | Event::Window(_, window::Event::Unfocused)
+        | Event::Touch(touch::Event::FingerLost { .. }) => {
+            Some(drag::Event::Cancelled(payload.clone()))
+        }
+        _ => None,
     }
 }
 
@@ -561,6 +695,8 @@ where
 #[derive(Debug)]
 pub struct Cache {
     state: widget::Tree,
+    drag: Option<drag::Payload>,
+    gestures: gesture::Recognizer,
 }
 
 impl Cache {
@@ -571,6 +707,8 @@ impl Cache {
     pub fn new() -> Cache {
         Cache {
             state: widget::Tree::empty(),
+            drag: None,
+            gestures: gesture::Recognizer::new(),
         }
     }
 }
@@ -594,3 +732,202 @@ pub enum State {
         redraw_request: Option<window::RedrawRequest>,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::clipboard;
+    use crate::renderer::Null;
+    use crate::{Length, Widget};
+
+    /// A widget that starts dragging when pressed and publishes the drag
+    /// events it receives.
+    struct Source;
+
+    impl Widget<drag::Event, Null> for Source {
+        fn width(&self) -> Length {
+            Length::Fill
+        }
+
+        fn height(&self) -> Length {
+            Length::Fill
+        }
+
+        fn layout(
+            &self,
+            _renderer: &Null,
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(limits.max())
+        }
+
+        fn on_event(
+            &mut self,
+            _state: &mut widget::Tree,
+            event: Event,
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            _renderer: &Null,
+            _clipboard: &mut dyn Clipboard,
+            shell: &mut Shell<'_, drag::Event>,
+        ) -> event::Status {
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed {
+                    button: mouse::Button::Left,
+                    ..
+                }) => {
+                    shell.start_drag(drag::Payload::new("payload"));
+
+                    event::Status::Captured
+                }
+                Event::Drag(event) => {
+                    shell.publish(event);
+
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            }
+        }
+
+        fn draw(
+            &self,
+            _state: &widget::Tree,
+            _renderer: &mut Null,
+            _theme: &crate::Theme,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    const SIZE: Size = Size::new(100.0, 100.0);
+
+    fn build(cache: Cache) -> UserInterface<'static, drag::Event, Null> {
+        UserInterface::build(Element::new(Source), SIZE, cache, &mut Null)
+    }
+
+    fn update(
+        user_interface: &mut UserInterface<'_, drag::Event, Null>,
+        event: Event,
+    ) -> Vec<drag::Event> {
+        let mut messages = Vec::new();
+
+        let _ = user_interface.update(
+            &[event],
+            Point::new(50.0, 50.0),
+            &mut Null,
+            &mut clipboard::Null,
+            &mut messages,
+        );
+
+        messages
+    }
+
+    fn press() -> Event {
+        let position = Point::new(50.0, 50.0);
+
+        Event::Mouse(mouse::Event::ButtonPressed {
+            button: mouse::Button::Left,
+            position,
+            modifiers: keyboard::Modifiers::default(),
+            click: mouse::Click::new(position, mouse::Button::Left, None),
+        })
+    }
+
+    fn move_cursor() -> Event {
+        Event::Mouse(mouse::Event::CursorMoved {
+            position: Point::new(60.0, 60.0),
+        })
+    }
+
+    #[test]
+    fn dragging_starts_from_the_shell() {
+        let mut user_interface = build(Cache::new());
+
+        assert!(user_interface.drag().is_none());
+        assert!(update(&mut user_interface, press()).is_empty());
+
+        let payload = user_interface.drag().expect("Drag session");
+
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"payload"));
+    }
+
+    #[test]
+    fn moving_keeps_the_session_alive() {
+        let mut user_interface = build(Cache::new());
+        let _ = update(&mut user_interface, press());
+
+        let payload = user_interface.drag().cloned().unwrap();
+
+        for _ in 0..2 {
+            assert_eq!(
+                update(&mut user_interface, move_cursor()),
+                vec![drag::Event::Moved(payload.clone())]
+            );
+            assert_eq!(user_interface.drag(), Some(&payload));
+        }
+    }
+
+    #[test]
+    fn releasing_or_cancelling_ends_the_session() {
+        let events = [
+            (
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                true,
+            ),
+            (
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: keyboard::KeyCode::Escape,
+                    modifiers: keyboard::Modifiers::default(),
+                }),
+                false,
+            ),
+            (Event::Mouse(mouse::Event::CursorLeft), false),
+            (
+                Event::Window(window::Id::MAIN, window::Event::Unfocused),
+                false,
+            ),
+        ];
+
+        for (event, is_drop) in events {
+            let mut user_interface = build(Cache::new());
+            let _ = update(&mut user_interface, press());
+
+            let payload = user_interface.drag().cloned().unwrap();
+
+            let expected = if is_drop {
+                drag::Event::Dropped(payload)
+            } else {
+                drag::Event::Cancelled(payload)
+            };
+
+            assert_eq!(update(&mut user_interface, event), vec![expected]);
+            assert!(user_interface.drag().is_none());
+            assert!(update(&mut user_interface, move_cursor()).is_empty());
+        }
+    }
+
+    #[test]
+    fn the_session_survives_rebuilds() {
+        let mut user_interface = build(Cache::new());
+        let _ = update(&mut user_interface, press());
+
+        let payload = user_interface.drag().cloned().unwrap();
+
+        let user_interface = build(user_interface.into_cache());
+
+        assert_eq!(user_interface.drag(), Some(&payload));
+
+        let mut user_interface =
+            user_interface.relayout(Size::new(200.0, 200.0), &mut Null);
+
+        assert_eq!(user_interface.drag(), Some(&payload));
+        assert_eq!(
+            update(&mut user_interface, move_cursor()),
+            vec![drag::Event::Moved(payload)]
+        );
+    }
+}
@@ -15,6 +15,11 @@ pub mod button;
 pub mod checkbox;
 pub mod column;
 pub mod container;
+pub mod context_menu;
+pub mod drag_source;
+pub mod drop_target;
+pub mod focus_ring;
+pub mod grid;
 pub mod helpers;
 pub mod image;
 pub mod operation;
@@ -22,18 +27,23 @@ pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod rich_text;
 pub mod row;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod space;
+pub mod stack;
 pub mod svg;
+pub mod table;
 pub mod text;
+pub mod text_editor;
 pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
 pub mod tree;
 pub mod vertical_slider;
+pub mod wrap;
 
 mod action;
 mod id;
@@ -47,6 +57,14 @@ pub use column::Column;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use drag_source::DragSource;
+#[doc(no_inline)]
+pub use drop_target::DropTarget;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
 pub use helpers::*;
 #[doc(no_inline)]
 pub use image::Image;
@@ -59,6 +77,8 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
 pub use rule::Rule;
@@ -69,10 +89,16 @@ pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use stack::Stack;
+#[doc(no_inline)]
 pub use svg::Svg;
 #[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
+pub use text_editor::TextEditor;
+#[doc(no_inline)]
 pub use text_input::TextInput;
 #[doc(no_inline)]
 pub use toggler::Toggler;
@@ -82,6 +108,8 @@ pub use tooltip::Tooltip;
 pub use tree::Tree;
 #[doc(no_inline)]
 pub use vertical_slider::VerticalSlider;
+#[doc(no_inline)]
+pub use wrap::Wrap;
 
 pub use action::Action;
 pub use id::Id;
@@ -1,3 +1,4 @@
+use crate::accessibility;
 use crate::widget::operation::{self, Focusable, Operation, Scrollable};
 use crate::widget::Id;
 
@@ -53,44 +54,6 @@ where
         id: Option<&Id>,
         operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
     ) {
-        struct MapRef<'a, A> {
-            operation: &'a mut dyn Operation<A>,
-        }
-
-        impl<'a, A, B> Operation<B> for MapRef<'a, A> {
-            fn container(
-                &mut self,
-                id: Option<&Id>,
-                operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
-            ) {
-                let Self { operation, .. } = self;
-
-                operation.container(id, &mut |operation| {
-                    operate_on_children(&mut MapRef { operation });
-                });
-            }
-
-            fn scrollable(
-                &mut self,
-                state: &mut dyn Scrollable,
-                id: Option<&Id>,
-            ) {
-                self.operation.scrollable(state, id);
-            }
-
-            fn focusable(
-                &mut self,
-                state: &mut dyn Focusable,
-                id: Option<&Id>,
-            ) {
-                self.operation.focusable(state, id);
-            }
-
-            fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
-                self.operation.custom(state, id);
-            }
-        }
-
         let Self { operation, .. } = self;
 
         MapRef {
@@ -127,6 +90,19 @@ where
         self.operation.custom(state, id);
     }
 
+    fn accessible(
+        &mut self,
+        node: &accessibility::Node<'_>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
+        let Self { operation, .. } = self;
+
+        MapRef {
+            operation: operation.as_mut(),
+        }
+        .accessible(node, operate_on_children);
+    }
+
     fn finish(&self) -> operation::Outcome<B> {
         match self.operation.finish() {
             operation::Outcome::None => operation::Outcome::None,
@@ -142,3 +118,54 @@ where
         }
     }
 }
+
+#[allow(missing_debug_implementations)]
+struct MapRef<'a, A> {
+    operation: &'a mut dyn Operation<A>,
+}
+
+impl<'a, A, B> Operation<B> for MapRef<'a, A> {
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
+        let Self { operation, .. } = self;
+
+        operation.container(id, &mut |operation| {
+            operate_on_children(&mut MapRef { operation });
+        });
+    }
+
+    fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+        self.operation.scrollable(state, id);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        self.operation.focusable(state, id);
+    }
+
+    fn text_input(
+        &mut self,
+        state: &mut dyn operation::TextInput,
+        id: Option<&Id>,
+    ) {
+        self.operation.text_input(state, id);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        self.operation.custom(state, id);
+    }
+
+    fn accessible(
+        &mut self,
+        node: &accessibility::Node<'_>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+    ) {
+        let Self { operation, .. } = self;
+
+        operation.accessible(node, &mut |operation| {
+            operate_on_children(&mut MapRef { operation });
+        });
+    }
+}
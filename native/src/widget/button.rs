@@ -1,17 +1,20 @@
 //! Allow your users to perform actions by pressing a button.
 //!
 //! A [`Button`] has some local [`State`].
+use crate::accessibility;
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
 use crate::touch;
+use crate::widget::focus_ring;
+use crate::widget::operation::{self, Operation};
 use crate::widget::tree::{self, Tree};
-use crate::widget::Operation;
 use crate::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
-    Rectangle, Shell, Vector, Widget,
+    Rectangle, Shadow, Shell, Vector, Widget,
 };
 
 pub use iced_style::button::{Appearance, StyleSheet};
@@ -58,6 +61,8 @@ where
 {
     content: Element<'a, Message, Renderer>,
     on_press: Option<Message>,
+    on_double_press: Option<Message>,
+    on_right_press: Option<Message>,
     width: Length,
     height: Length,
     padding: Padding,
@@ -74,6 +79,8 @@ where
         Button {
             content: content.into(),
             on_press: None,
+            on_double_press: None,
+            on_right_press: None,
             width: Length::Shrink,
             height: Length::Shrink,
             padding: Padding::new(5),
@@ -107,6 +114,20 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the [`Button`] is double
+    /// clicked.
+    pub fn on_double_press(mut self, msg: Message) -> Self {
+        self.on_double_press = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] is pressed
+    /// with the right mouse button.
+    pub fn on_right_press(mut self, msg: Message) -> Self {
+        self.on_right_press = Some(msg);
+        self
+    }
+
     /// Sets the style variant of this [`Button`].
     pub fn style(
         mut self,
@@ -172,7 +193,20 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
-        operation.container(None, &mut |operation| {
+        let state = tree.state.downcast_mut::<State>();
+
+        let node = accessibility::Node::new(
+            accessibility::Role::Button,
+            layout.bounds(),
+        )
+        .disabled(self.on_press.is_none())
+        .focused(state.is_focused);
+
+        if self.on_press.is_some() {
+            operation.focusable(state, None);
+        }
+
+        operation.accessible(&node, &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
                 layout.children().next().unwrap(),
@@ -210,6 +244,8 @@ where
             cursor_position,
             shell,
             &self.on_press,
+            &self.on_double_press,
+            &self.on_right_press,
             || tree.state.downcast_mut::<State>(),
         )
     }
@@ -291,6 +327,7 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_pressed: bool,
+    is_focused: bool,
 }
 
 impl State {
@@ -298,6 +335,25 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`Button`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 /// Processes the given [`Event`] and updates the [`State`] of a [`Button`]
@@ -308,21 +364,54 @@ pub fn update<'a, Message: Clone>(
     cursor_position: Point,
     shell: &mut Shell<'_, Message>,
     on_press: &Option<Message>,
+    on_double_press: &Option<Message>,
+    on_right_press: &Option<Message>,
     state: impl FnOnce() -> &'a mut State,
 ) -> event::Status {
     match event {
-        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        Event::Mouse(mouse::Event::ButtonPressed {
+            button: mouse::Button::Right,
+            ..
+        }) if layout.bounds().contains(cursor_position) => {
+            if let Some(on_right_press) = on_right_press.clone() {
+                shell.publish(on_right_press);
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonPressed {
+            button: mouse::Button::Left,
+            ..
+        })
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
-            if on_press.is_some() {
-                let bounds = layout.bounds();
+            let state = state();
 
-                if bounds.contains(cursor_position) {
-                    let state = state();
+            // Keyboard focus is dropped as soon as the pointer is used
+            state.is_focused = false;
 
+            if layout.bounds().contains(cursor_position) {
+                let mut status = event::Status::Ignored;
+
+                if let Event::Mouse(mouse::Event::ButtonPressed {
+                    click, ..
+                }) = event
+                {
+                    if click.kind() == mouse::click::Kind::Double {
+                        if let Some(on_double_press) = on_double_press.clone() {
+                            shell.publish(on_double_press);
+
+                            status = event::Status::Captured;
+                        }
+                    }
+                }
+
+                if on_press.is_some() {
                     state.is_pressed = true;
 
-                    return event::Status::Captured;
+                    status = event::Status::Captured;
                 }
+
+                return status;
             }
         }
         Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
@@ -348,6 +437,20 @@ pub fn update<'a, Message: Clone>(
 
             state.is_pressed = false;
         }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Enter | keyboard::KeyCode::Space,
+            ..
+        }) => {
+            if let Some(on_press) = on_press.clone() {
+                let state = state();
+
+                if state.is_focused {
+                    shell.publish(on_press);
+
+                    return event::Status::Captured;
+                }
+            }
+        }
         _ => {}
     }
 
@@ -370,12 +473,11 @@ where
     Renderer::Theme: StyleSheet,
 {
     let is_mouse_over = bounds.contains(cursor_position);
+    let state = state();
 
     let styling = if !is_enabled {
         style_sheet.disabled(style)
     } else if is_mouse_over {
-        let state = state();
-
         if state.is_pressed {
             style_sheet.pressed(style)
         } else {
@@ -385,30 +487,31 @@ where
         style_sheet.active(style)
     };
 
-    if styling.background.is_some() || styling.border_width > 0.0 {
-        if styling.shadow_offset != Vector::default() {
-            // TODO: Implement proper shadow support
-            renderer.fill_quad(
-                renderer::Quad {
-                    bounds: Rectangle {
-                        x: bounds.x + styling.shadow_offset.x,
-                        y: bounds.y + styling.shadow_offset.y,
-                        ..bounds
-                    },
-                    border_radius: styling.border_radius.into(),
-                    border_width: 0.0,
-                    border_color: Color::TRANSPARENT,
-                },
-                Background::Color([0.0, 0.0, 0.0, 0.5].into()),
-            );
-        }
+    let has_body = styling.background.is_some() || styling.border_width > 0.0;
+
+    let shadow = Shadow {
+        offset: styling.shadow.offset + styling.shadow_offset,
+        color: if has_body
+            && styling.shadow_offset != Vector::default()
+            && styling.shadow.color.a == 0.0
+        {
+            // Styles that only set a `shadow_offset` keep their old
+            // translucent black shadow
+            Color::from_rgba(0.0, 0.0, 0.0, 0.5)
+        } else {
+            styling.shadow.color
+        },
+        ..styling.shadow
+    };
 
+    if has_body || shadow.color.a > 0.0 {
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
                 border_radius: styling.border_radius.into(),
                 border_width: styling.border_width,
                 border_color: styling.border_color,
+                shadow,
             },
             styling
                 .background
@@ -416,6 +519,10 @@ where
         );
     }
 
+    if state.is_focused && is_enabled {
+        focus_ring::draw(renderer, bounds, style_sheet.focus_ring(style));
+    }
+
     styling
 }
 
@@ -1,12 +1,17 @@
 //! Show toggle controls using checkboxes.
+use crate::accessibility;
 use crate::alignment;
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
 use crate::touch;
-use crate::widget::{self, Row, Text, Tree};
+use crate::widget::focus_ring;
+use crate::widget::operation::{self, Operation};
+use crate::widget::tree::{self, Tree};
+use crate::widget::{self, Row, Text};
 use crate::{
     Alignment, Clipboard, Element, Layout, Length, Point, Rectangle, Shell,
     Widget,
@@ -132,6 +137,14 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet + widget::text::StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -166,9 +179,32 @@ where
             .layout(renderer, limits)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.accessible(
+            &accessibility::Node::new(
+                accessibility::Role::CheckBox,
+                layout.bounds(),
+            )
+            .label(self.label.as_str())
+            .checked(self.is_checked)
+            .focused(state.is_focused),
+            &mut |_| {},
+        );
+
+        operation.focusable(state, None);
+    }
+
     fn on_event(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
@@ -177,8 +213,13 @@ where
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Left,
+                ..
+            })
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                tree.state.downcast_mut::<State>().is_focused = false;
+
                 let mouse_over = layout.bounds().contains(cursor_position);
 
                 if mouse_over {
@@ -187,6 +228,14 @@ where
                     return event::Status::Captured;
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space,
+                ..
+            }) if tree.state.downcast_ref::<State>().is_focused => {
+                shell.publish((self.on_toggle)(!self.is_checked));
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
@@ -210,7 +259,7 @@ where
 
     fn draw(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         style: &renderer::Style,
@@ -239,10 +288,19 @@ where
                     border_radius: custom_style.border_radius.into(),
                     border_width: custom_style.border_width,
                     border_color: custom_style.border_color,
+                    ..renderer::Quad::default()
                 },
                 custom_style.background,
             );
 
+            if tree.state.downcast_ref::<State>().is_focused {
+                focus_ring::draw(
+                    renderer,
+                    bounds,
+                    theme.focus_ring(&self.style),
+                );
+            }
+
             if self.is_checked {
                 renderer.fill_text(text::Text {
                     content: &Renderer::CHECKMARK_ICON.to_string(),
@@ -256,6 +314,9 @@ where
                     color: custom_style.checkmark_color,
                     horizontal_alignment: alignment::Horizontal::Center,
                     vertical_alignment: alignment::Vertical::Center,
+                    line_height: 1.0,
+                    wrap: text::Wrap::default(),
+                    overflow: text::Overflow::default(),
                 });
             }
         }
@@ -275,11 +336,33 @@ where
                 },
                 alignment::Horizontal::Left,
                 alignment::Vertical::Center,
+                1.0,
+                crate::text::Wrap::default(),
+                crate::text::Overflow::default(),
             );
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_focused: bool,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 impl<'a, Message, Renderer> From<Checkbox<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
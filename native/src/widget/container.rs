@@ -319,13 +319,17 @@ pub fn draw_background<Renderer>(
 ) where
     Renderer: crate::Renderer,
 {
-    if appearance.background.is_some() || appearance.border_width > 0.0 {
+    if appearance.background.is_some()
+        || appearance.border_width > 0.0
+        || appearance.shadow.color.a > 0.0
+    {
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
                 border_radius: appearance.border_radius.into(),
                 border_width: appearance.border_width,
                 border_color: appearance.border_color,
+                shadow: appearance.shadow,
             },
             appearance
                 .background
@@ -0,0 +1,1028 @@
+//! Open a menu of actions on some content with a right click.
+//!
+//! A [`ContextMenu`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::gesture;
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Padding, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+pub use iced_style::menu::{Appearance, StyleSheet};
+
+/// The hint displayed on the [`Item`]s that open a submenu.
+const SUBMENU_HINT: &str = "›";
+
+/// A widget that opens a menu of [`Item`]s at the cursor when its content is
+/// right clicked or long pressed.
+///
+/// The menu can be navigated with the arrow keys. It closes when one of its
+/// items is selected, when the user clicks outside of it or presses Escape.
+#[allow(missing_debug_implementations)]
+pub struct ContextMenu<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    items: Vec<Item<Message>>,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> ContextMenu<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default padding of the items of a [`ContextMenu`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4,
+        right: 10,
+        bottom: 4,
+        left: 10,
+    };
+
+    /// Creates a new [`ContextMenu`] with the given content and menu items.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        items: Vec<Item<Message>>,
+    ) -> Self {
+        ContextMenu {
+            content: content.into(),
+            items,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Padding`] of the items of the [`ContextMenu`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`ContextMenu`].
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the [`ContextMenu`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`ContextMenu`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// An entry in the menu of a [`ContextMenu`].
+#[derive(Debug, Clone)]
+pub struct Item<Message> {
+    label: String,
+    shortcut: Option<String>,
+    kind: Kind<Message>,
+}
+
+#[derive(Debug, Clone)]
+enum Kind<Message> {
+    Action(Option<Message>),
+    Submenu(Vec<Item<Message>>),
+    Separator,
+}
+
+impl<Message> Item<Message> {
+    /// Creates a new [`Item`] with the given label.
+    ///
+    /// The [`Item`] is disabled until [`Item::on_select`] is set.
+    pub fn new(label: impl Into<String>) -> Self {
+        Item {
+            label: label.into(),
+            shortcut: None,
+            kind: Kind::Action(None),
+        }
+    }
+
+    /// Creates a new [`Item`] that opens a submenu with the given items.
+    ///
+    /// The [`Item`] is disabled if there are no items.
+    pub fn submenu(
+        label: impl Into<String>,
+        items: Vec<Item<Message>>,
+    ) -> Self {
+        Item {
+            label: label.into(),
+            shortcut: None,
+            kind: Kind::Submenu(items),
+        }
+    }
+
+    /// Creates a new [`Item`] that separates groups of items.
+    pub fn separator() -> Self {
+        Item {
+            label: String::new(),
+            shortcut: None,
+            kind: Kind::Separator,
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Item`] is selected.
+    ///
+    /// It has no effect on submenus and separators.
+    pub fn on_select(mut self, message: Message) -> Self {
+        if let Kind::Action(on_select) = &mut self.kind {
+            *on_select = Some(message);
+        }
+
+        self
+    }
+
+    /// Sets the keyboard shortcut displayed next to the label of the
+    /// [`Item`].
+    ///
+    /// The shortcut is only a hint; the application still needs to handle
+    /// the key presses. Submenus display an arrow in its place, so their
+    /// shortcut is never shown.
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Returns whether the [`Item`] can be selected or not.
+    pub fn is_enabled(&self) -> bool {
+        match &self.kind {
+            Kind::Action(on_select) => on_select.is_some(),
+            Kind::Submenu(items) => !items.is_empty(),
+            Kind::Separator => false,
+        }
+    }
+
+    fn submenu_items(&self) -> Option<&[Item<Message>]> {
+        match &self.kind {
+            Kind::Submenu(items) if !items.is_empty() => Some(items),
+            _ => None,
+        }
+    }
+
+    fn hint(&self) -> Option<&str> {
+        match &self.kind {
+            Kind::Action(_) => self.shortcut.as_deref(),
+            Kind::Submenu(_) => Some(SUBMENU_HINT),
+            Kind::Separator => None,
+        }
+    }
+}
+
+/// The local state of a [`ContextMenu`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    menu: Option<Menu>,
+}
+
+/// An open menu.
+#[derive(Debug, Clone)]
+struct Menu {
+    position: Point,
+
+    /// The index of the highlighted item of each open menu, from the
+    /// outermost to the innermost.
+    path: Vec<usize>,
+
+    /// The level and index of the item under the last press, if any.
+    pressed: Option<(usize, usize)>,
+}
+
+impl Menu {
+    /// Highlights the item at the given level and index, opening its submenu
+    /// if it has one, and remembers it as pressed.
+    fn press<Message>(
+        &mut self,
+        menus: &[&[Item<Message>]],
+        level: usize,
+        index: usize,
+    ) {
+        if matches!(item_at(menus, level, index), Some(item) if item.is_enabled())
+        {
+            self.path.truncate(level);
+            self.path.push(index);
+        }
+
+        self.pressed = Some((level, index));
+    }
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the menu of the [`ContextMenu`] is open or not.
+    pub fn is_open(&self) -> bool {
+        self.menu.is_some()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ContextMenu<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Right,
+                ..
+            })
+            | Event::Gesture(gesture::Event::LongPress { .. })
+                if layout.bounds().contains(cursor_position) =>
+            {
+                let state = tree.state.downcast_mut::<State>();
+
+                state.menu = Some(Menu {
+                    position: cursor_position,
+                    path: Vec::new(),
+                    pressed: None,
+                });
+
+                shell.invalidate_layout();
+
+                event::Status::Captured
+            }
+            _ => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(menu) = &state.menu {
+            return Some(overlay::Element::new(
+                menu.position,
+                Box::new(Overlay {
+                    state,
+                    items: &self.items,
+                    padding: self.padding,
+                    text_size: self.text_size,
+                    font: self.font.clone(),
+                    style: &self.style,
+                }),
+            ));
+        }
+
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<ContextMenu<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        context_menu: ContextMenu<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(context_menu)
+    }
+}
+
+/// The open menus of a [`ContextMenu`].
+///
+/// Its layout covers the whole window, so that clicks outside of the menus
+/// can close them.
+struct Overlay<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    state: &'a mut State,
+    items: &'a [Item<Message>],
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: &'a <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Overlay<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout_menu(
+        &self,
+        renderer: &Renderer,
+        items: &[Item<Message>],
+        text_size: u16,
+    ) -> layout::Node {
+        let measure = |content: &str| {
+            renderer.measure_width(content, text_size, self.font.clone())
+        };
+
+        let label_width = items
+            .iter()
+            .map(|item| measure(&item.label))
+            .fold(0.0, f32::max);
+
+        let hint_width = items
+            .iter()
+            .filter_map(Item::hint)
+            .map(measure)
+            .fold(0.0, f32::max);
+
+        let width = f32::from(self.padding.horizontal())
+            + label_width
+            + if hint_width > 0.0 {
+                f32::from(text_size) + hint_width
+            } else {
+                0.0
+            };
+
+        let mut height = 0.0;
+
+        let children = items
+            .iter()
+            .map(|item| {
+                let item_height = match item.kind {
+                    Kind::Separator => f32::from(self.padding.vertical()) + 1.0,
+                    _ => f32::from(text_size + self.padding.vertical()),
+                };
+
+                let mut node = layout::Node::new(Size::new(width, item_height));
+
+                node.move_to(Point::new(0.0, height));
+                height += item_height;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(Size::new(width, height), children)
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let path = match &self.state.menu {
+            Some(menu) => &menu.path[..],
+            None => &[],
+        };
+
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let mut parent: Option<(Rectangle, Rectangle)> = None;
+
+        let menus = open_menus(self.items, path)
+            .into_iter()
+            .enumerate()
+            .map(|(level, items)| {
+                let mut node = self.layout_menu(renderer, items, text_size);
+                let size = node.size();
+
+                // Menus open to the right and below of their origin, unless
+                // they do not fit in the window.
+                let (x, y) = match parent {
+                    None => (
+                        if position.x + size.width > bounds.width {
+                            position.x - size.width
+                        } else {
+                            position.x
+                        },
+                        if position.y + size.height > bounds.height {
+                            position.y - size.height
+                        } else {
+                            position.y
+                        },
+                    ),
+                    Some((menu, item)) => (
+                        if menu.x + menu.width + size.width > bounds.width {
+                            menu.x - size.width
+                        } else {
+                            menu.x + menu.width
+                        },
+                        item.y.min(bounds.height - size.height),
+                    ),
+                };
+
+                node.move_to(Point::new(x.max(0.0), y.max(0.0)));
+
+                let menu = node.bounds();
+
+                parent = path
+                    .get(level)
+                    .and_then(|index| node.children().get(*index))
+                    .map(|item| {
+                        let item = item.bounds();
+
+                        (
+                            menu,
+                            Rectangle {
+                                x: menu.x + item.x,
+                                y: menu.y + item.y,
+                                ..item
+                            },
+                        )
+                    });
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(bounds, menus)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let menu = match &mut self.state.menu {
+            Some(menu) => menu,
+            None => return event::Status::Ignored,
+        };
+
+        let menus = open_menus(self.items, &menu.path);
+
+        // The items may have changed since the menu was opened.
+        menu.path.truncate(menus.len());
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some((level, index)) = hovered(layout, cursor_position) {
+                    let mut path =
+                        menu.path[..level.min(menu.path.len())].to_vec();
+
+                    if let Some(index) = index.filter(|index| {
+                        matches!(
+                            item_at(&menus, level, *index),
+                            Some(item) if item.is_enabled()
+                        )
+                    }) {
+                        path.push(index);
+                    }
+
+                    if path != menu.path {
+                        menu.path = path;
+
+                        shell.invalidate_layout();
+                    }
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed { .. })
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let is_primary = !matches!(
+                    event,
+                    Event::Mouse(mouse::Event::ButtonPressed { button, .. })
+                        if button != mouse::Button::Left
+                );
+
+                match hovered(layout, cursor_position) {
+                    Some((level, Some(index))) if is_primary => {
+                        menu.press(&menus, level, index);
+                    }
+                    Some(_) => return event::Status::Captured,
+                    None => {
+                        self.state.menu = None;
+                    }
+                }
+
+                shell.invalidate_layout();
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                let pressed = menu.pressed.take();
+
+                // Items are only selected when released over the item that
+                // was pressed.
+                let (level, index) = match hovered(layout, cursor_position) {
+                    Some((level, Some(index)))
+                        if pressed == Some((level, index)) =>
+                    {
+                        (level, index)
+                    }
+                    Some(_) => return event::Status::Captured,
+                    None => return event::Status::Ignored,
+                };
+
+                if let Some(Kind::Action(Some(on_select))) =
+                    item_at(&menus, level, index).map(|item| &item.kind)
+                {
+                    shell.publish(on_select.clone());
+
+                    self.state.menu = None;
+
+                    shell.invalidate_layout();
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => {
+                let level = menu.path.len().saturating_sub(1);
+                let highlighted = menu
+                    .path
+                    .last()
+                    .and_then(|index| item_at(&menus, level, *index));
+
+                match key_code {
+                    keyboard::KeyCode::Escape => {
+                        self.state.menu = None;
+                    }
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                        if let Some(index) = step(
+                            menus[level],
+                            menu.path.last().copied(),
+                            key_code == keyboard::KeyCode::Down,
+                        ) {
+                            menu.path.truncate(level);
+                            menu.path.push(index);
+                        }
+                    }
+                    keyboard::KeyCode::Left => {
+                        if menu.path.len() > 1 {
+                            let _ = menu.path.pop();
+                        }
+                    }
+                    keyboard::KeyCode::Right
+                    | keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::Space => {
+                        match highlighted.map(|item| &item.kind) {
+                            Some(Kind::Submenu(items)) => {
+                                menu.path.extend(step(items, None, true));
+                            }
+                            Some(Kind::Action(Some(on_select)))
+                                if key_code != keyboard::KeyCode::Right =>
+                            {
+                                shell.publish(on_select.clone());
+
+                                self.state.menu = None;
+                            }
+                            _ => {}
+                        }
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                shell.invalidate_layout();
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let path = match &self.state.menu {
+            Some(menu) => &menu.path[..],
+            None => return mouse::Interaction::default(),
+        };
+
+        let menus = open_menus(self.items, path);
+
+        match hovered(layout, cursor_position) {
+            Some((level, Some(index)))
+                if matches!(
+                    item_at(&menus, level, index),
+                    Some(item) if item.is_enabled()
+                ) =>
+            {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+        let path = match &self.state.menu {
+            Some(menu) => &menu.path[..],
+            None => return,
+        };
+
+        let appearance = theme.appearance(self.style);
+        let text_size = f32::from(
+            self.text_size.unwrap_or_else(|| renderer.default_size()),
+        );
+
+        let disabled_text_color = Color {
+            a: appearance.text_color.a * 0.5,
+            ..appearance.text_color
+        };
+
+        for ((level, items), layout) in open_menus(self.items, path)
+            .into_iter()
+            .enumerate()
+            .zip(layout.children())
+        {
+            let bounds = layout.bounds();
+
+            // Every menu gets its own layer, so submenus are drawn on top of
+            // the text of their parents.
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_color: appearance.border_color,
+                        border_width: appearance.border_width,
+                        border_radius: appearance.border_radius.into(),
+                        ..renderer::Quad::default()
+                    },
+                    appearance.background,
+                );
+
+                for ((index, item), layout) in
+                    items.iter().enumerate().zip(layout.children())
+                {
+                    let bounds = layout.bounds();
+
+                    if let Kind::Separator = item.kind {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: bounds.x + f32::from(self.padding.left),
+                                    y: bounds.center_y().floor(),
+                                    width: bounds.width
+                                        - f32::from(self.padding.horizontal()),
+                                    height: 1.0,
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            appearance.border_color,
+                        );
+
+                        continue;
+                    }
+
+                    let is_highlighted = path.get(level) == Some(&index);
+
+                    if is_highlighted {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds,
+                                border_color: Color::TRANSPARENT,
+                                border_width: 0.0,
+                                border_radius: appearance.border_radius.into(),
+                                ..renderer::Quad::default()
+                            },
+                            appearance.selected_background,
+                        );
+                    }
+
+                    let color = if is_highlighted {
+                        appearance.selected_text_color
+                    } else if item.is_enabled() {
+                        appearance.text_color
+                    } else {
+                        disabled_text_color
+                    };
+
+                    let text = |content, x, horizontal_alignment| Text {
+                        content,
+                        bounds: Rectangle {
+                            x,
+                            y: bounds.center_y(),
+                            width: f32::INFINITY,
+                            ..bounds
+                        },
+                        size: text_size,
+                        font: self.font.clone(),
+                        color,
+                        horizontal_alignment,
+                        vertical_alignment: alignment::Vertical::Center,
+                        line_height: 1.0,
+                        wrap: text::Wrap::None,
+                        overflow: text::Overflow::default(),
+                    };
+
+                    renderer.fill_text(text(
+                        &item.label,
+                        bounds.x + f32::from(self.padding.left),
+                        alignment::Horizontal::Left,
+                    ));
+
+                    if let Some(hint) = item.hint() {
+                        renderer.fill_text(text(
+                            hint,
+                            bounds.x + bounds.width
+                                - f32::from(self.padding.right),
+                            alignment::Horizontal::Right,
+                        ));
+                    }
+                }
+            });
+        }
+    }
+}
+
+/// Returns the items of the open menus for the given path, from the
+/// outermost to the innermost.
+fn open_menus<'a, Message>(
+    items: &'a [Item<Message>],
+    path: &[usize],
+) -> Vec<&'a [Item<Message>]> {
+    let mut menus = vec![items];
+
+    for index in path {
+        match menus[menus.len() - 1]
+            .get(*index)
+            .and_then(Item::submenu_items)
+        {
+            Some(items) => menus.push(items),
+            None => break,
+        }
+    }
+
+    menus
+}
+
+/// Returns the item at the given index of the open menu at the given level.
+fn item_at<'a, Message>(
+    menus: &[&'a [Item<Message>]],
+    level: usize,
+    index: usize,
+) -> Option<&'a Item<Message>> {
+    menus.get(level).and_then(|items| items.get(index))
+}
+
+/// Returns the level of the innermost menu under the cursor and the index of
+/// its item under the cursor, if any.
+fn hovered(
+    layout: Layout<'_>,
+    cursor_position: Point,
+) -> Option<(usize, Option<usize>)> {
+    let menus: Vec<_> = layout.children().collect();
+
+    menus
+        .into_iter()
+        .enumerate()
+        .rev()
+        .find(|(_, menu)| menu.bounds().contains(cursor_position))
+        .map(|(level, menu)| {
+            (
+                level,
+                menu.children()
+                    .position(|item| item.bounds().contains(cursor_position)),
+            )
+        })
+}
+
+/// Returns the index of the next enabled item after the given one, wrapping
+/// around the ends of the menu.
+fn step<Message>(
+    items: &[Item<Message>],
+    current: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let len = items.len();
+
+    (1..=len)
+        .map(|offset| match current {
+            Some(current) if forward => (current + offset) % len,
+            Some(current) => (current + len - offset) % len,
+            None if forward => offset - 1,
+            None => len - offset,
+        })
+        .find(|index| items[*index].is_enabled())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> Vec<Item<()>> {
+        vec![
+            Item::new("Cut").on_select(()).shortcut("Ctrl+X"),
+            Item::new("Copy"),
+            Item::separator(),
+            Item::submenu(
+                "Share",
+                vec![Item::new("Mail"), Item::new("Link").on_select(())],
+            ),
+            Item::submenu("Empty", vec![]),
+        ]
+    }
+
+    #[test]
+    fn steps_over_disabled_items() {
+        let items = items();
+
+        assert_eq!(step(&items, None, true), Some(0));
+        assert_eq!(step(&items, Some(0), true), Some(3));
+        assert_eq!(step(&items, Some(3), true), Some(0));
+        assert_eq!(step(&items, None, false), Some(3));
+        assert_eq!(step(&items, Some(0), false), Some(3));
+        assert_eq!(step(&items[1..3], None, true), None);
+    }
+
+    #[test]
+    fn pressing_highlights_enabled_items() {
+        let items = items();
+        let menus = open_menus(&items, &[]);
+
+        let mut menu = Menu {
+            position: Point::ORIGIN,
+            path: Vec::new(),
+            pressed: None,
+        };
+
+        menu.press(&menus, 0, 1);
+
+        assert_eq!(menu.path, Vec::<usize>::new());
+        assert_eq!(menu.pressed, Some((0, 1)));
+
+        menu.press(&menus, 0, 3);
+
+        assert_eq!(menu.path, vec![3]);
+        assert_eq!(menu.pressed, Some((0, 3)));
+    }
+
+    #[test]
+    fn opens_submenus_along_the_path() {
+        let items = items();
+
+        assert_eq!(open_menus(&items, &[]).len(), 1);
+        assert_eq!(open_menus(&items, &[0]).len(), 1);
+        assert_eq!(open_menus(&items, &[4]).len(), 1);
+
+        let menus = open_menus(&items, &[3, 1]);
+
+        assert_eq!(menus.len(), 2);
+        assert_eq!(menus[1][1].label, "Link");
+    }
+}
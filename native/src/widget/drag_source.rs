@@ -0,0 +1,397 @@
+//! Drag some content around to carry data with it.
+//!
+//! A [`DragSource`] has some local [`State`].
+use crate::drag;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+/// A widget that starts a drag and drop session carrying some payload when
+/// its content is dragged.
+///
+/// While dragging, a copy of the content follows the cursor on top of the
+/// rest of the application.
+#[allow(missing_debug_implementations)]
+pub struct DragSource<'a, T, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    payload: T,
+    on_drag: Option<Message>,
+    on_cancel: Option<Message>,
+    threshold: f32,
+}
+
+impl<'a, T, Message, Renderer> DragSource<'a, T, Message, Renderer> {
+    /// The default distance the cursor needs to travel before a drag starts.
+    pub const DEFAULT_THRESHOLD: f32 = 5.0;
+
+    /// Creates a new [`DragSource`] with the given content, carrying the
+    /// given payload.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        payload: T,
+    ) -> Self {
+        DragSource {
+            content: content.into(),
+            payload,
+            on_drag: None,
+            on_cancel: None,
+            threshold: Self::DEFAULT_THRESHOLD,
+        }
+    }
+
+    /// Sets the message that will be produced when the [`DragSource`] starts
+    /// being dragged.
+    pub fn on_drag(mut self, message: Message) -> Self {
+        self.on_drag = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the drag of the
+    /// [`DragSource`] is cancelled.
+    pub fn on_cancel(mut self, message: Message) -> Self {
+        self.on_cancel = Some(message);
+        self
+    }
+
+    /// Sets the distance the cursor needs to travel while pressed before the
+    /// [`DragSource`] starts being dragged.
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+/// The local state of a [`DragSource`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    pressed_at: Option<Point>,
+    grab: Vector,
+    cursor: Option<Point>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the [`DragSource`] is being dragged or not.
+    pub fn is_dragging(&self) -> bool {
+        self.cursor.is_some()
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for DragSource<'a, T, Message, Renderer>
+where
+    T: Clone + Send + Sync + 'static,
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if state.is_dragging() {
+            match event {
+                Event::Drag(drag::Event::Moved(_)) => {
+                    state.cursor = Some(cursor_position);
+                }
+                Event::Drag(drag::Event::Dropped(_)) => {
+                    *state = State::new();
+                }
+                Event::Drag(drag::Event::Cancelled(_)) => {
+                    *state = State::new();
+
+                    if let Some(on_cancel) = self.on_cancel.clone() {
+                        shell.publish(on_cancel);
+                    }
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                ))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+                | Event::Touch(touch::Event::FingerLost { .. }) => {
+                    // The content saw the press that started the drag, so it
+                    // needs the release to reset its own state. The cursor is
+                    // unavailable to avoid triggering the content on drop.
+                    let _ = self.content.as_widget_mut().on_event(
+                        &mut tree.children[0],
+                        event,
+                        layout,
+                        Point::new(-1.0, -1.0),
+                        renderer,
+                        clipboard,
+                        shell,
+                    );
+                }
+                _ => {}
+            }
+
+            return event::Status::Ignored;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Left,
+                ..
+            })
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let bounds = layout.bounds();
+
+                if bounds.contains(cursor_position) {
+                    state.pressed_at = Some(cursor_position);
+                    state.grab = cursor_position - bounds.position();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(pressed_at) = state.pressed_at {
+                    if pressed_at.distance(cursor_position) > self.threshold {
+                        state.pressed_at = None;
+                        state.cursor = Some(cursor_position);
+
+                        shell.start_drag(drag::Payload::new(
+                            self.payload.clone(),
+                        ));
+
+                        if let Some(on_drag) = self.on_drag.clone() {
+                            shell.publish(on_drag);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed_at = None;
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.is_dragging() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::Idle
+            && layout.bounds().contains(cursor_position)
+        {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(cursor) = state.cursor {
+            return Some(overlay::Element::new(
+                cursor - state.grab,
+                Box::new(Preview {
+                    content: &self.content,
+                    tree: &tree.children[0],
+                    size: layout.bounds().size(),
+                }),
+            ));
+        }
+
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, T, Message, Renderer> From<DragSource<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'static + Clone + Send + Sync,
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        drag_source: DragSource<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(drag_source)
+    }
+}
+
+/// The copy of the content of a [`DragSource`] that follows the cursor.
+struct Preview<'a, 'b, Message, Renderer> {
+    content: &'b Element<'a, Message, Renderer>,
+    tree: &'b Tree,
+    size: Size,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Preview<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        _bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let mut node = self
+            .content
+            .as_widget()
+            .layout(renderer, &layout::Limits::new(self.size, self.size));
+
+        node.move_to(position);
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        self.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            &layout.bounds(),
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse::Interaction::Grabbing
+    }
+}
@@ -0,0 +1,281 @@
+//! Receive the data dropped on some content.
+//!
+//! A [`DropTarget`] has some local [`State`].
+use crate::drag;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Vector, Widget,
+};
+
+/// A widget that produces messages when a payload of some type is dragged
+/// over its content and dropped on it.
+///
+/// Positions are relative to the top left corner of the [`DropTarget`]. When
+/// drop targets are nested, the innermost one receives the drop.
+#[allow(missing_debug_implementations)]
+pub struct DropTarget<'a, T, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    on_enter: Option<Box<dyn Fn(&T) -> Message + 'a>>,
+    on_hover: Option<Box<dyn Fn(&T, Point) -> Message + 'a>>,
+    on_leave: Option<Message>,
+    on_drop: Option<Box<dyn Fn(T, Point) -> Message + 'a>>,
+}
+
+impl<'a, T, Message, Renderer> DropTarget<'a, T, Message, Renderer> {
+    /// Creates a new [`DropTarget`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        DropTarget {
+            content: content.into(),
+            on_enter: None,
+            on_hover: None,
+            on_leave: None,
+            on_drop: None,
+        }
+    }
+
+    /// Sets the message that will be produced when a payload is dragged into
+    /// the [`DropTarget`].
+    pub fn on_enter(mut self, f: impl Fn(&T) -> Message + 'a) -> Self {
+        self.on_enter = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when a payload is dragged over
+    /// the [`DropTarget`].
+    pub fn on_hover(mut self, f: impl Fn(&T, Point) -> Message + 'a) -> Self {
+        self.on_hover = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when a payload is dragged out
+    /// of the [`DropTarget`], dropped on it or its drag is cancelled.
+    pub fn on_leave(mut self, message: Message) -> Self {
+        self.on_leave = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when a payload is dropped on
+    /// the [`DropTarget`].
+    pub fn on_drop(mut self, f: impl Fn(T, Point) -> Message + 'a) -> Self {
+        self.on_drop = Some(Box::new(f));
+        self
+    }
+}
+
+/// The local state of a [`DropTarget`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_hovered: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether a payload is being dragged over the [`DropTarget`].
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for DropTarget<'a, T, Message, Renderer>
+where
+    T: Clone + Send + Sync + 'static,
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        let drag_event = match event {
+            Event::Drag(drag_event) => drag_event,
+            _ => return status,
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        let value = match drag_event.payload().downcast_ref::<T>() {
+            Some(value) => value,
+            None => return status,
+        };
+
+        let bounds = layout.bounds();
+        let is_over = bounds.contains(cursor_position);
+        let position = cursor_position - Vector::new(bounds.x, bounds.y);
+
+        if let drag::Event::Moved(_) = drag_event {
+            if is_over {
+                if !state.is_hovered {
+                    state.is_hovered = true;
+
+                    if let Some(on_enter) = &self.on_enter {
+                        shell.publish(on_enter(value));
+                    }
+                }
+
+                if let Some(on_hover) = &self.on_hover {
+                    shell.publish(on_hover(value, position));
+                }
+
+                return status;
+            }
+        }
+
+        if state.is_hovered {
+            state.is_hovered = false;
+
+            if let Some(on_leave) = self.on_leave.clone() {
+                shell.publish(on_leave);
+            }
+        }
+
+        match (&drag_event, &self.on_drop) {
+            (drag::Event::Dropped(_), Some(on_drop))
+                if is_over && status == event::Status::Ignored =>
+            {
+                shell.publish(on_drop(value.clone(), position));
+
+                event::Status::Captured
+            }
+            _ => status,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, T, Message, Renderer> From<DropTarget<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'static + Clone + Send + Sync,
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        drop_target: DropTarget<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(drop_target)
+    }
+}
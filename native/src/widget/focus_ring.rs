@@ -0,0 +1,29 @@
+//! Draw a ring around focused widgets.
+use crate::renderer;
+use crate::{Color, Rectangle};
+
+pub use iced_style::focus::Ring;
+
+/// Draws the given focus [`Ring`] around the given bounds.
+pub fn draw<Renderer>(renderer: &mut Renderer, bounds: Rectangle, ring: Ring)
+where
+    Renderer: crate::Renderer,
+{
+    let spread = ring.offset + ring.width;
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: bounds.x - spread,
+                y: bounds.y - spread,
+                width: bounds.width + spread * 2.0,
+                height: bounds.height + spread * 2.0,
+            },
+            border_radius: ring.border_radius.into(),
+            border_width: ring.width,
+            border_color: ring.color,
+            ..renderer::Quad::default()
+        },
+        Color::TRANSPARENT,
+    );
+}
@@ -0,0 +1,331 @@
+//! Distribute content in a grid of rows and columns.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout::{self, Layout};
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::{Operation, Tree};
+use crate::{
+    Clipboard, Element, Length, Padding, Point, Rectangle, Shell, Widget,
+};
+
+pub use layout::grid::Cell;
+
+/// A container that distributes its contents in a grid of rows and columns.
+///
+/// The size of every column and row is given by a track [`Length`]:
+///
+/// - [`Length::Units`] makes a track of a fixed size.
+/// - [`Length::Shrink`] makes a track fit the contents of its cells.
+/// - [`Length::Fill`] and [`Length::FillPortion`] make a track fill a
+///   portion of the remaining space.
+///
+/// Rows that are not specified shrink to fit their contents.
+///
+/// # Example
+/// ```
+/// # type Grid<'a, Message> =
+/// #     iced_native::widget::Grid<'a, Message, iced_native::renderer::Null>;
+/// # type Text<'a> = iced_native::widget::Text<'a, iced_native::renderer::Null>;
+/// #
+/// use iced_native::widget::grid::Cell;
+/// use iced_native::Length;
+///
+/// # #[derive(Debug, Clone)]
+/// # enum Message {}
+/// #
+/// let form: Grid<'_, Message> = Grid::new([Length::Shrink, Length::Fill])
+///     .spacing(10)
+///     .push(Text::new("Name"))
+///     .push(Text::new("Ferris"))
+///     .push(Text::new("Species"))
+///     .push(Text::new("Crab"))
+///     .push_cell(Cell::new(2, 0).span(1, 2), Text::new("Likes Rust"));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Renderer> {
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    column_spacing: u16,
+    row_spacing: u16,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    cells: Vec<Cell>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Grid<'a, Message, Renderer> {
+    /// Creates an empty [`Grid`] with the given column tracks.
+    pub fn new(columns: impl IntoIterator<Item = Length>) -> Self {
+        Grid {
+            columns: columns.into_iter().collect(),
+            rows: Vec::new(),
+            column_spacing: 0,
+            row_spacing: 0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            cells: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the row tracks of the [`Grid`].
+    pub fn rows(mut self, rows: impl IntoIterator<Item = Length>) -> Self {
+        self.rows = rows.into_iter().collect();
+        self
+    }
+
+    /// Sets the spacing _between_ both the columns and rows of the [`Grid`].
+    pub fn spacing(self, units: u16) -> Self {
+        self.column_spacing(units).row_spacing(units)
+    }
+
+    /// Sets the horizontal spacing _between_ the columns of the [`Grid`].
+    pub fn column_spacing(mut self, units: u16) -> Self {
+        self.column_spacing = units;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the rows of the [`Grid`].
+    pub fn row_spacing(mut self, units: u16) -> Self {
+        self.row_spacing = units;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Grid`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the default horizontal alignment of the contents of the cells of
+    /// the [`Grid`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the default vertical alignment of the contents of the cells of
+    /// the [`Grid`].
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Adds an element to the [`Grid`], in the cell that follows the last
+    /// one added.
+    ///
+    /// Cells are filled row by row, moving to the next row once every
+    /// column is filled.
+    pub fn push(
+        self,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        let columns = self.columns.len().max(1);
+
+        let (row, column) = match self.cells.last() {
+            Some(last) if last.column + last.column_span >= columns => {
+                (last.row + 1, 0)
+            }
+            Some(last) => (last.row, last.column + last.column_span),
+            None => (0, 0),
+        };
+
+        self.push_cell(Cell::new(row, column), child)
+    }
+
+    /// Adds an element to the [`Grid`] in the given [`Cell`].
+    pub fn push_cell(
+        mut self,
+        cell: Cell,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.cells.push(cell);
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Grid<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children)
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::grid::resolve(
+            renderer,
+            &limits,
+            self.padding,
+            self.column_spacing as f32,
+            self.row_spacing as f32,
+            &self.columns,
+            &self.rows,
+            self.horizontal_alignment,
+            self.vertical_alignment,
+            &self.cells,
+            &self.children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child.as_widget().draw(
+                state,
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor_position,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+{
+    fn from(grid: Grid<'a, Message, Renderer>) -> Self {
+        Self::new(grid)
+    }
+}
@@ -32,6 +32,19 @@ macro_rules! row {
     );
 }
 
+/// Creates a [`Stack`] with the given children.
+///
+/// [`Stack`]: widget::Stack
+#[macro_export]
+macro_rules! stack {
+    () => (
+        $crate::widget::Stack::new()
+    );
+    ($($x:expr),+ $(,)?) => (
+        $crate::widget::Stack::with_children(vec![$($crate::Element::from($x)),+])
+    );
+}
+
 /// Creates a new [`Container`] with the provided content.
 ///
 /// [`Container`]: widget::Container
@@ -54,6 +67,15 @@ pub fn column<Message, Renderer>(
     widget::Column::with_children(children)
 }
 
+/// Creates a new [`Stack`] with the given children.
+///
+/// [`Stack`]: widget::Stack
+pub fn stack<Message, Renderer>(
+    children: Vec<Element<'_, Message, Renderer>>,
+) -> widget::Stack<'_, Message, Renderer> {
+    widget::Stack::with_children(children)
+}
+
 /// Creates a new [`Row`] with the given children.
 ///
 /// [`Row`]: widget::Row
@@ -63,6 +85,15 @@ pub fn row<Message, Renderer>(
     widget::Row::with_children(children)
 }
 
+/// Creates a new [`Grid`] with the given column tracks.
+///
+/// [`Grid`]: widget::Grid
+pub fn grid<'a, Message, Renderer>(
+    columns: impl IntoIterator<Item = Length>,
+) -> widget::Grid<'a, Message, Renderer> {
+    widget::Grid::new(columns)
+}
+
 /// Creates a new [`Scrollable`] with the provided content.
 ///
 /// [`Scrollable`]: widget::Scrollable
@@ -90,6 +121,48 @@ where
     widget::Button::new(content)
 }
 
+/// Creates a new [`ContextMenu`] with the provided content and menu items.
+///
+/// [`ContextMenu`]: widget::ContextMenu
+pub fn context_menu<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    items: Vec<widget::context_menu::Item<Message>>,
+) -> widget::ContextMenu<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::context_menu::StyleSheet,
+{
+    widget::ContextMenu::new(content, items)
+}
+
+/// Creates a new [`DragSource`] with the provided content, carrying the
+/// given payload.
+///
+/// [`DragSource`]: widget::DragSource
+pub fn drag_source<'a, T, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    payload: T,
+) -> widget::DragSource<'a, T, Message, Renderer>
+where
+    T: Clone + Send + Sync + 'static,
+    Renderer: crate::Renderer,
+{
+    widget::DragSource::new(content, payload)
+}
+
+/// Creates a new [`DropTarget`] with the provided content.
+///
+/// [`DropTarget`]: widget::DropTarget
+pub fn drop_target<'a, T, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> widget::DropTarget<'a, T, Message, Renderer>
+where
+    T: Clone + Send + Sync + 'static,
+    Renderer: crate::Renderer,
+{
+    widget::DropTarget::new(content)
+}
+
 /// Creates a new [`Tooltip`] with the provided content, tooltip text, and [`tooltip::Position`].
 ///
 /// [`Tooltip`]: widget::Tooltip
@@ -117,6 +190,29 @@ where
     widget::Text::new(text.to_string())
 }
 
+/// Creates a new [`RichText`] widget with the provided spans.
+///
+/// [`RichText`]: widget::RichText
+pub fn rich_text<'a, Message, Renderer>(
+    spans: Vec<widget::rich_text::Span<'a, Message, Renderer::Font>>,
+) -> widget::RichText<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::text::StyleSheet,
+{
+    widget::RichText::new(spans)
+}
+
+/// Creates a new [`Span`] of a [`RichText`] widget with the provided content.
+///
+/// [`Span`]: widget::rich_text::Span
+/// [`RichText`]: widget::RichText
+pub fn span<'a, Message, Font>(
+    content: impl Into<Cow<'a, str>>,
+) -> widget::rich_text::Span<'a, Message, Font> {
+    widget::rich_text::Span::new(content)
+}
+
 /// Creates a new [`Checkbox`].
 ///
 /// [`Checkbox`]: widget::Checkbox
@@ -165,6 +261,35 @@ where
     widget::Toggler::new(label, is_checked, f)
 }
 
+/// Creates a new [`Table`] with the given [`Column`]s and rows.
+///
+/// [`Table`]: widget::Table
+/// [`Column`]: widget::table::Column
+pub fn table<'a, T, Message, Renderer>(
+    columns: Vec<widget::table::Column<'a, T>>,
+    rows: &'a [T],
+) -> widget::Table<'a, T, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::table::StyleSheet,
+{
+    widget::Table::new(columns, rows)
+}
+
+/// Creates a new [`TextEditor`] displaying the given [`Content`].
+///
+/// [`TextEditor`]: widget::TextEditor
+/// [`Content`]: widget::text_editor::Content
+pub fn text_editor<'a, Message, Renderer>(
+    content: &'a widget::text_editor::Content,
+) -> widget::TextEditor<'a, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::text_editor::StyleSheet,
+{
+    widget::TextEditor::new(content)
+}
+
 /// Creates a new [`TextInput`].
 ///
 /// [`TextInput`]: widget::TextInput
@@ -2,15 +2,25 @@
 pub mod viewer;
 pub use viewer::Viewer;
 
+use crate::accessibility;
+use crate::event::{self, Event};
 use crate::image;
 use crate::layout;
 use crate::renderer;
-use crate::widget::Tree;
+use crate::time::{Duration, Instant};
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::window;
 use crate::{
-    ContentFit, Element, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+    Clipboard, ContentFit, Element, Hasher, Layout, Length, Point, Rectangle,
+    Shell, Size, Vector, Widget,
 };
 
-use std::hash::Hash;
+use std::hash::{Hash, Hasher as _};
+
+/// The delay used for animation frames with a delay too short to be
+/// meaningful, like most browsers do.
+const DEFAULT_FRAME_DELAY: Duration = Duration::from_millis(100);
 
 /// Creates a new [`Viewer`] with the given image `Handle`.
 pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
@@ -28,13 +38,38 @@ pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
 /// let image = Image::<image::Handle>::new("resources/ferris.png");
 /// ```
 ///
+/// If the image is animated, its frames are played in a loop. Use
+/// [`Image::playing`] to pause it.
+///
 /// <img src="https://github.com/iced-rs/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
-#[derive(Debug, Hash)]
+#[derive(Debug)]
 pub struct Image<Handle> {
     handle: Handle,
+    placeholder: Option<Handle>,
     width: Length,
     height: Length,
     content_fit: ContentFit,
+    filter_method: image::FilterMethod,
+    rotation: f32,
+    opacity: f32,
+    is_playing: bool,
+}
+
+impl<Handle> Hash for Image<Handle>
+where
+    Handle: Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.handle.hash(state);
+        self.placeholder.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.content_fit.hash(state);
+        self.filter_method.hash(state);
+        self.rotation.to_bits().hash(state);
+        self.opacity.to_bits().hash(state);
+        self.is_playing.hash(state);
+    }
 }
 
 impl<Handle> Image<Handle> {
@@ -42,12 +77,60 @@ impl<Handle> Image<Handle> {
     pub fn new<T: Into<Handle>>(handle: T) -> Self {
         Image {
             handle: handle.into(),
+            placeholder: None,
             width: Length::Shrink,
             height: Length::Shrink,
             content_fit: ContentFit::Contain,
+            filter_method: image::FilterMethod::default(),
+            rotation: 0.0,
+            opacity: 1.0,
+            is_playing: true,
         }
     }
 
+    /// Sets the image drawn in place of the [`Image`] while it is still
+    /// loading in the background.
+    ///
+    /// The placeholder is fit to the bounds of the final image. It should be
+    /// ready to be drawn right away, like an image created from pixels.
+    pub fn placeholder<T: Into<Handle>>(mut self, placeholder: T) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`Image`].
+    ///
+    /// [`FilterMethod`]: image::FilterMethod
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the rotation of the [`Image`], in radians.
+    ///
+    /// The image is rotated clockwise around its center. The rotation does
+    /// not affect the layout of the [`Image`].
+    pub fn rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Sets the opacity of the [`Image`], between `0.0` and `1.0`.
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets whether the animation of the [`Image`] is playing, if it is
+    /// animated.
+    ///
+    /// Animations play by default. A paused animation keeps displaying its
+    /// current frame.
+    pub fn playing(mut self, is_playing: bool) -> Self {
+        self.is_playing = is_playing;
+        self
+    }
+
     /// Sets the width of the [`Image`] boundaries.
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -111,11 +194,103 @@ where
     layout::Node::new(final_size)
 }
 
+/// The local state of an [`Image`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    handle: Option<u64>,
+    delays: Vec<Duration>,
+    frame: usize,
+    next_frame_at: Option<Instant>,
+}
+
+impl State {
+    /// Returns true if the [`State`] belongs to the given image `Handle`.
+    fn is_of<Handle: Hash>(&self, handle: &Handle) -> bool {
+        self.handle == Some(hash(handle))
+    }
+
+    /// Restarts the animation if the image `Handle` changed, and fetches the
+    /// delays of its frames once they are loaded.
+    fn synchronize<Renderer>(
+        &mut self,
+        renderer: &Renderer,
+        handle: &Renderer::Handle,
+    ) where
+        Renderer: image::Renderer,
+    {
+        if !self.is_of(handle) {
+            *self = State {
+                handle: Some(hash(handle)),
+                ..State::default()
+            };
+        }
+
+        if self.delays.is_empty() {
+            if let Some(delays) = renderer.frame_delays(handle) {
+                self.delays = delays;
+            }
+        }
+    }
+
+    /// Advances the animation up to `now`, returning when the next frame is
+    /// due.
+    fn advance(&mut self, now: Instant) -> Option<Instant> {
+        if self.delays.len() < 2 {
+            return None;
+        }
+
+        let delay = |frame: usize| {
+            let delay = self.delays[frame % self.delays.len()];
+
+            if delay <= Duration::from_millis(10) {
+                DEFAULT_FRAME_DELAY
+            } else {
+                delay
+            }
+        };
+
+        let mut next_frame_at = self
+            .next_frame_at
+            .unwrap_or_else(|| now + delay(self.frame));
+
+        // Avoid catching up frame by frame after a long pause
+        let total = (0..self.delays.len()).map(delay).sum::<Duration>();
+
+        if next_frame_at + total < now {
+            next_frame_at = now;
+        }
+
+        while next_frame_at <= now {
+            self.frame = (self.frame + 1) % self.delays.len();
+            next_frame_at += delay(self.frame);
+        }
+
+        self.next_frame_at = Some(next_frame_at);
+
+        Some(next_frame_at)
+    }
+}
+
+fn hash<Handle: Hash>(handle: &Handle) -> u64 {
+    let mut hasher = Hasher::default();
+    handle.hash(&mut hasher);
+
+    hasher.finish()
+}
+
 impl<Message, Renderer, Handle> Widget<Message, Renderer> for Image<Handle>
 where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone + Hash,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -139,9 +314,50 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.accessible(
+            &accessibility::Node::new(
+                accessibility::Role::Image,
+                layout.bounds(),
+            ),
+            &mut |_| {},
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Window(_, window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            state.synchronize(renderer, &self.handle);
+
+            if !self.is_playing {
+                state.next_frame_at = None;
+            } else if let Some(next_frame_at) = state.advance(now) {
+                shell.request_redraw(window::RedrawRequest::At(next_frame_at));
+            }
+        }
+
+        event::Status::Ignored
+    }
+
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         _theme: &Renderer::Theme,
         _style: &renderer::Style,
@@ -152,6 +368,19 @@ where
         let Size { width, height } = renderer.dimensions(&self.handle);
         let image_size = Size::new(width as f32, height as f32);
 
+        let state = tree.state.downcast_ref::<State>();
+
+        let handle = match &self.placeholder {
+            Some(placeholder) if renderer.is_loading(&self.handle) => {
+                placeholder.clone()
+            }
+            // The state may belong to the previous image until the next redraw
+            _ if state.frame > 0 && state.is_of(&self.handle) => {
+                renderer.frame(&self.handle, state.frame)
+            }
+            _ => self.handle.clone(),
+        };
+
         let bounds = layout.bounds();
         let adjusted_fit = self.content_fit.fit(image_size, bounds.size());
 
@@ -167,7 +396,13 @@ where
                 ..bounds
             };
 
-            renderer.draw(self.handle.clone(), drawing_bounds + offset)
+            renderer.draw(
+                handle.clone(),
+                self.filter_method,
+                drawing_bounds + offset,
+                self.rotation,
+                self.opacity,
+            )
         };
 
         if adjusted_fit.width > bounds.width
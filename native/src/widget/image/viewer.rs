@@ -1,5 +1,6 @@
 //! Zoom and pan on an image.
 use crate::event::{self, Event};
+use crate::gesture;
 use crate::image;
 use crate::layout;
 use crate::mouse;
@@ -21,6 +22,7 @@ pub struct Viewer<Handle> {
     min_scale: f32,
     max_scale: f32,
     scale_step: f32,
+    filter_method: image::FilterMethod,
     handle: Handle,
 }
 
@@ -34,6 +36,7 @@ impl<Handle> Viewer<Handle> {
             min_scale: 0.25,
             max_scale: 10.0,
             scale_step: 0.10,
+            filter_method: image::FilterMethod::default(),
             handle,
         }
     }
@@ -44,6 +47,18 @@ impl<Handle> Viewer<Handle> {
         self
     }
 
+    /// Sets the [`FilterMethod`] used to sample the image of the [`Viewer`].
+    ///
+    /// [`FilterMethod::Nearest`] is useful to inspect individual pixels when
+    /// zooming in.
+    ///
+    /// [`FilterMethod`]: image::FilterMethod
+    /// [`FilterMethod::Nearest`]: image::FilterMethod::Nearest
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
     /// Sets the width of the [`Viewer`].
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -165,39 +180,18 @@ where
                         if y < 0.0 && previous_scale > self.min_scale
                             || y > 0.0 && previous_scale < self.max_scale
                         {
-                            state.scale = (if y > 0.0 {
+                            let scale = if y > 0.0 {
                                 state.scale * (1.0 + self.scale_step)
                             } else {
                                 state.scale / (1.0 + self.scale_step)
-                            })
-                            .clamp(self.min_scale, self.max_scale);
+                            };
 
-                            let image_size = image_size(
+                            self.zoom(
                                 renderer,
-                                &self.handle,
                                 state,
-                                bounds.size(),
-                            );
-
-                            let factor = state.scale / previous_scale - 1.0;
-
-                            let cursor_to_center =
-                                cursor_position - bounds.center();
-
-                            let adjustment = cursor_to_center * factor
-                                + state.current_offset * factor;
-
-                            state.current_offset = Vector::new(
-                                if image_size.width > bounds.width {
-                                    state.current_offset.x + adjustment.x
-                                } else {
-                                    0.0
-                                },
-                                if image_size.height > bounds.height {
-                                    state.current_offset.y + adjustment.y
-                                } else {
-                                    0.0
-                                },
+                                bounds,
+                                scale,
+                                cursor_position,
                             );
                         }
                     }
@@ -205,10 +199,38 @@ where
 
                 event::Status::Captured
             }
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                if is_mouse_over =>
+            Event::Gesture(gesture::Event::Pinch { center, scale })
+                if bounds.contains(center) =>
             {
                 let state = tree.state.downcast_mut::<State>();
+                let scale = state.scale * scale;
+
+                self.zoom(renderer, state, bounds, scale, center);
+
+                event::Status::Captured
+            }
+            Event::Gesture(gesture::Event::Pan {
+                position,
+                translation,
+            }) if bounds.contains(position) => {
+                let state = tree.state.downcast_mut::<State>();
+
+                let image_size =
+                    image_size(renderer, &self.handle, state, bounds.size());
+
+                state.current_offset = clamp_offset(
+                    state.offset(bounds, image_size) - translation,
+                    bounds,
+                    image_size,
+                );
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Left,
+                ..
+            }) if is_mouse_over => {
+                let state = tree.state.downcast_mut::<State>();
 
                 state.cursor_grabbed_at = Some(cursor_position);
                 state.starting_offset = state.current_offset;
@@ -237,32 +259,13 @@ where
                         bounds.size(),
                     );
 
-                    let hidden_width = (image_size.width - bounds.width / 2.0)
-                        .max(0.0)
-                        .round();
-
-                    let hidden_height = (image_size.height
-                        - bounds.height / 2.0)
-                        .max(0.0)
-                        .round();
-
                     let delta = position - origin;
 
-                    let x = if bounds.width < image_size.width {
-                        (state.starting_offset.x - delta.x)
-                            .clamp(-hidden_width, hidden_width)
-                    } else {
-                        0.0
-                    };
-
-                    let y = if bounds.height < image_size.height {
-                        (state.starting_offset.y - delta.y)
-                            .clamp(-hidden_height, hidden_height)
-                    } else {
-                        0.0
-                    };
-
-                    state.current_offset = Vector::new(x, y);
+                    state.current_offset = clamp_offset(
+                        state.starting_offset - delta,
+                        bounds,
+                        image_size,
+                    );
 
                     event::Status::Captured
                 } else {
@@ -324,17 +327,84 @@ where
                 image::Renderer::draw(
                     renderer,
                     self.handle.clone(),
+                    self.filter_method,
                     Rectangle {
                         x: bounds.x,
                         y: bounds.y,
                         ..Rectangle::with_size(image_size)
                     },
+                    0.0,
+                    1.0,
                 )
             });
         });
     }
 }
 
+impl<Handle> Viewer<Handle> {
+    /// Sets the scale of the image, keeping the point of the image at the
+    /// given position in place.
+    fn zoom<Renderer>(
+        &self,
+        renderer: &Renderer,
+        state: &mut State,
+        bounds: Rectangle,
+        scale: f32,
+        position: Point,
+    ) where
+        Renderer: image::Renderer<Handle = Handle>,
+    {
+        let previous_scale = state.scale;
+
+        state.scale = scale.clamp(self.min_scale, self.max_scale);
+
+        let image_size =
+            image_size(renderer, &self.handle, state, bounds.size());
+
+        let factor = state.scale / previous_scale - 1.0;
+
+        let cursor_to_center = position - bounds.center();
+
+        let adjustment =
+            cursor_to_center * factor + state.current_offset * factor;
+
+        state.current_offset = Vector::new(
+            if image_size.width > bounds.width {
+                state.current_offset.x + adjustment.x
+            } else {
+                0.0
+            },
+            if image_size.height > bounds.height {
+                state.current_offset.y + adjustment.y
+            } else {
+                0.0
+            },
+        );
+    }
+}
+
+/// Returns the offset closest to the given one that keeps an image of the
+/// given size visible in the bounds of a [`Viewer`].
+fn clamp_offset(offset: Vector, bounds: Rectangle, image_size: Size) -> Vector {
+    let hidden_width = (image_size.width - bounds.width / 2.0).max(0.0).round();
+
+    let hidden_height =
+        (image_size.height - bounds.height / 2.0).max(0.0).round();
+
+    Vector::new(
+        if bounds.width < image_size.width {
+            offset.x.clamp(-hidden_width, hidden_width)
+        } else {
+            0.0
+        },
+        if bounds.height < image_size.height {
+            offset.y.clamp(-hidden_height, hidden_height)
+        } else {
+            0.0
+        },
+    )
+}
+
 /// The local state of a [`Viewer`].
 #[derive(Debug, Clone, Copy)]
 pub struct State {
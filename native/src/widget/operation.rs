@@ -7,6 +7,7 @@ pub use focusable::Focusable;
 pub use scrollable::Scrollable;
 pub use text_input::TextInput;
 
+use crate::accessibility;
 use crate::widget::Id;
 
 use std::any::Any;
@@ -34,6 +35,19 @@ pub trait Operation<T> {
     /// Operates on a widget that has text input.
     fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {}
 
+    /// Operates on a widget that describes itself to assistive technologies
+    /// with an [`accessibility::Node`].
+    ///
+    /// The `operate_on_children` function can be called to return control to
+    /// the widget tree and keep traversing the descendants of the widget.
+    fn accessible(
+        &mut self,
+        _node: &accessibility::Node<'_>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        self.container(None, operate_on_children);
+    }
+
     /// Operates on a custom widget with some state.
     fn custom(&mut self, _state: &mut dyn Any, _id: Option<&Id>) {}
 
@@ -528,7 +528,10 @@ pub fn update<'a, Message, T: Draggable>(
     let mut event_status = event::Status::Ignored;
 
     match event {
-        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        Event::Mouse(mouse::Event::ButtonPressed {
+            button: mouse::Button::Left,
+            ..
+        })
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
             let bounds = layout.bounds();
 
@@ -881,6 +884,7 @@ pub fn draw<Renderer, T>(
                     border_radius: 0.0.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    ..renderer::Quad::default()
                 },
                 highlight.color,
             );
@@ -22,6 +22,7 @@ where
     controls: Option<Element<'a, Message, Renderer>>,
     padding: Padding,
     always_show_controls: bool,
+    on_double_click: Option<Box<dyn Fn() -> Message + 'a>>,
     style: <Renderer::Theme as container::StyleSheet>::Style,
 }
 
@@ -40,6 +41,7 @@ where
             controls: None,
             padding: Padding::ZERO,
             always_show_controls: false,
+            on_double_click: None,
             style: Default::default(),
         }
     }
@@ -80,6 +82,20 @@ where
         self.always_show_controls = true;
         self
     }
+
+    /// Sets the message that will be produced when the pick area of the
+    /// [`TitleBar`] is double clicked.
+    ///
+    /// This is commonly used to maximize or restore its [`Pane`].
+    ///
+    /// [`Pane`]: crate::widget::pane_grid::Pane
+    pub fn on_double_click(mut self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_double_click = Some(Box::new(move || message.clone()));
+        self
+    }
 }
 
 impl<'a, Message, Renderer> TitleBar<'a, Message, Renderer>
@@ -339,7 +355,7 @@ where
         let title_status = if show_title {
             self.content.as_widget_mut().on_event(
                 &mut tree.children[0],
-                event,
+                event.clone(),
                 title_layout,
                 cursor_position,
                 renderer,
@@ -350,6 +366,23 @@ where
             event::Status::Ignored
         };
 
+        if let Event::Mouse(mouse::Event::ButtonPressed {
+            button: mouse::Button::Left,
+            click,
+            ..
+        }) = event
+        {
+            if let Some(on_double_click) = &self.on_double_click {
+                if click.kind() == mouse::click::Kind::Double
+                    && self.is_over_pick_area(layout, cursor_position)
+                {
+                    shell.publish(on_double_click());
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
         control_status.merge(title_status)
     }
 
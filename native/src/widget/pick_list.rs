@@ -1,4 +1,5 @@
 //! Display a dropdown list of selectable values.
+use crate::accessibility;
 use crate::alignment;
 use crate::event::{self, Event};
 use crate::keyboard;
@@ -10,6 +11,8 @@ use crate::renderer;
 use crate::text::{self, Text};
 use crate::touch;
 use crate::widget::container;
+use crate::widget::focus_ring;
+use crate::widget::operation::{self, Operation};
 use crate::widget::scrollable;
 use crate::widget::tree::{self, Tree};
 use crate::{
@@ -223,6 +226,34 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        let mut node = accessibility::Node::new(
+            accessibility::Role::ComboBox,
+            layout.bounds(),
+        )
+        .expanded(state.is_open)
+        .focused(state.is_focused);
+
+        if let Some(placeholder) = &self.placeholder {
+            node = node.label(placeholder.as_str());
+        }
+
+        if let Some(selected) = &self.selected {
+            node = node.value(accessibility::Value::Text(selected.to_string()));
+        }
+
+        operation.accessible(&node, &mut |_| {});
+        operation.focusable(state, None);
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -258,7 +289,7 @@ where
 
     fn draw(
         &self,
-        _tree: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         _style: &renderer::Style,
@@ -271,6 +302,7 @@ where
             theme,
             layout,
             cursor_position,
+            tree.state.downcast_ref::<State<T>>(),
             self.padding,
             self.text_size,
             &self.font,
@@ -326,6 +358,7 @@ pub struct State<T> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
+    is_focused: bool,
     hovered_option: Option<usize>,
     last_selection: Option<T>,
 }
@@ -337,10 +370,31 @@ impl<T> State<T> {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
             is_open: bool::default(),
+            is_focused: bool::default(),
             hovered_option: Option::default(),
             last_selection: Option::default(),
         }
     }
+
+    /// Returns whether the [`PickList`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+impl<T> operation::Focusable for State<T> {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_open = false;
+    }
 }
 
 impl<T> Default for State<T> {
@@ -373,12 +427,8 @@ where
     let max_width = match width {
         Length::Shrink => {
             let measure = |label: &str| -> u32 {
-                let (width, _) = renderer.measure(
-                    label,
-                    text_size,
-                    font.clone(),
-                    Size::new(f32::INFINITY, f32::INFINITY),
-                );
+                let width =
+                    renderer.measure_width(label, text_size, font.clone());
 
                 width.round() as u32
             };
@@ -423,10 +473,15 @@ where
     T: PartialEq + Clone + 'a,
 {
     match event {
-        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        Event::Mouse(mouse::Event::ButtonPressed {
+            button: mouse::Button::Left,
+            ..
+        })
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
             let state = state();
 
+            state.is_focused = false;
+
             let event_status = if state.is_open {
                 // Event wasn't processed by overlay, so cursor was clicked either outside it's
                 // bounds or on the drop-down, either way we close the overlay.
@@ -496,6 +551,71 @@ where
                 event::Status::Ignored
             }
         }
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+            let state = state();
+
+            if !state.is_focused {
+                return event::Status::Ignored;
+            }
+
+            match key_code {
+                keyboard::KeyCode::Space | keyboard::KeyCode::Enter => {
+                    if state.is_open {
+                        if let Some(option) = state
+                            .hovered_option
+                            .and_then(|index| options.get(index))
+                        {
+                            shell.publish((on_selected)(option.clone()));
+                        }
+
+                        state.is_open = false;
+                    } else {
+                        state.is_open = true;
+                        state.hovered_option = options
+                            .iter()
+                            .position(|option| Some(option) == selected);
+                    }
+
+                    event::Status::Captured
+                }
+                keyboard::KeyCode::Escape if state.is_open => {
+                    state.is_open = false;
+
+                    event::Status::Captured
+                }
+                keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                    let is_next = key_code == keyboard::KeyCode::Down;
+
+                    let current = if state.is_open {
+                        state.hovered_option
+                    } else {
+                        options
+                            .iter()
+                            .position(|option| Some(option) == selected)
+                    };
+
+                    let next = match current {
+                        Some(index) if is_next => {
+                            (index + 1).min(options.len().saturating_sub(1))
+                        }
+                        Some(index) => index.saturating_sub(1),
+                        None if is_next => 0,
+                        None => options.len().saturating_sub(1),
+                    };
+
+                    if state.is_open {
+                        state.hovered_option = Some(next);
+                    } else if let Some(option) = options.get(next) {
+                        if Some(option) != selected {
+                            shell.publish((on_selected)(option.clone()));
+                        }
+                    }
+
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            }
+        }
         Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
             let state = state();
 
@@ -573,6 +693,7 @@ pub fn draw<T, Renderer>(
     theme: &Renderer::Theme,
     layout: Layout<'_>,
     cursor_position: Point,
+    state: &State<T>,
     padding: Padding,
     text_size: Option<u16>,
     font: &Renderer::Font,
@@ -588,6 +709,7 @@ pub fn draw<T, Renderer>(
     let bounds = layout.bounds();
     let is_mouse_over = bounds.contains(cursor_position);
     let is_selected = selected.is_some();
+    let focus_ring = theme.focus_ring(style);
 
     let style = if is_mouse_over {
         theme.hovered(style)
@@ -601,10 +723,24 @@ pub fn draw<T, Renderer>(
             border_color: style.border_color,
             border_width: style.border_width,
             border_radius: style.border_radius.into(),
+            ..renderer::Quad::default()
         },
         style.background,
     );
 
+    if state.is_focused {
+        focus_ring::draw(
+            renderer,
+            bounds,
+            focus_ring::Ring {
+                border_radius: style.border_radius
+                    + focus_ring.offset
+                    + focus_ring.width,
+                ..focus_ring
+            },
+        );
+    }
+
     if let Some((font, text, size)) = handle.content() {
         let size = f32::from(size.unwrap_or_else(|| renderer.default_size()));
 
@@ -621,6 +757,9 @@ pub fn draw<T, Renderer>(
             },
             horizontal_alignment: alignment::Horizontal::Right,
             vertical_alignment: alignment::Vertical::Top,
+            line_height: 1.0,
+            wrap: text::Wrap::default(),
+            overflow: text::Overflow::default(),
         });
     }
 
@@ -647,6 +786,9 @@ pub fn draw<T, Renderer>(
             },
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
+            line_height: 1.0,
+            wrap: text::Wrap::default(),
+            overflow: text::Overflow::default(),
         });
     }
 }
@@ -1,7 +1,8 @@
 //! Provide progress feedback to your users.
+use crate::accessibility;
 use crate::layout;
 use crate::renderer;
-use crate::widget::Tree;
+use crate::widget::{Operation, Tree};
 use crate::{Color, Element, Layout, Length, Point, Rectangle, Size, Widget};
 
 use std::ops::RangeInclusive;
@@ -104,6 +105,28 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.accessible(
+            &accessibility::Node::new(
+                accessibility::Role::ProgressIndicator,
+                layout.bounds(),
+            )
+            .value(accessibility::Value::Numeric {
+                value: f64::from(self.value),
+                min: f64::from(*self.range.start()),
+                max: f64::from(*self.range.end()),
+                step: None,
+            }),
+            &mut |_| {},
+        );
+    }
+
     fn draw(
         &self,
         _state: &Tree,
@@ -132,6 +155,7 @@ where
                 border_radius: style.border_radius.into(),
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                ..renderer::Quad::default()
             },
             style.background,
         );
@@ -146,6 +170,7 @@ where
                     border_radius: style.border_radius.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    ..renderer::Quad::default()
                 },
                 style.bar,
             );
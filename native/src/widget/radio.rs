@@ -1,12 +1,17 @@
 //! Create choices using radio buttons.
+use crate::accessibility;
 use crate::alignment;
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
 use crate::touch;
-use crate::widget::{self, Row, Text, Tree};
+use crate::widget::focus_ring;
+use crate::widget::operation::{self, Operation};
+use crate::widget::tree::{self, Tree};
+use crate::widget::{self, Row, Text};
 use crate::{
     Alignment, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
     Shell, Widget,
@@ -146,6 +151,14 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet + widget::text::StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -174,9 +187,32 @@ where
             .layout(renderer, limits)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.accessible(
+            &accessibility::Node::new(
+                accessibility::Role::RadioButton,
+                layout.bounds(),
+            )
+            .label(self.label.as_str())
+            .checked(self.is_selected)
+            .focused(state.is_focused),
+            &mut |_| {},
+        );
+
+        operation.focusable(state, None);
+    }
+
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
@@ -185,14 +221,27 @@ where
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Left,
+                ..
+            })
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                tree.state.downcast_mut::<State>().is_focused = false;
+
                 if layout.bounds().contains(cursor_position) {
                     shell.publish(self.on_click.clone());
 
                     return event::Status::Captured;
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space | keyboard::KeyCode::Enter,
+                ..
+            }) if tree.state.downcast_ref::<State>().is_focused => {
+                shell.publish(self.on_click.clone());
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
@@ -216,7 +265,7 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         style: &renderer::Style,
@@ -248,10 +297,24 @@ where
                     border_radius: (size / 2.0).into(),
                     border_width: custom_style.border_width,
                     border_color: custom_style.border_color,
+                    ..renderer::Quad::default()
                 },
                 custom_style.background,
             );
 
+            if tree.state.downcast_ref::<State>().is_focused {
+                let ring = theme.focus_ring(&self.style);
+
+                focus_ring::draw(
+                    renderer,
+                    bounds,
+                    focus_ring::Ring {
+                        border_radius: size / 2.0 + ring.offset + ring.width,
+                        ..ring
+                    },
+                );
+            }
+
             if self.is_selected {
                 renderer.fill_quad(
                     renderer::Quad {
@@ -264,6 +327,7 @@ where
                         border_radius: (dot_size / 2.0).into(),
                         border_width: 0.0,
                         border_color: Color::TRANSPARENT,
+                        ..renderer::Quad::default()
                     },
                     custom_style.dot_color,
                 );
@@ -285,11 +349,33 @@ where
                 },
                 alignment::Horizontal::Left,
                 alignment::Vertical::Center,
+                1.0,
+                crate::text::Wrap::default(),
+                crate::text::Overflow::default(),
             );
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_focused: bool,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 impl<'a, Message, Renderer> From<Radio<Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
@@ -0,0 +1,489 @@
+//! Write paragraphs of text made of spans with their own style.
+use crate::accessibility;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::widget::text::{draw_selection, update};
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+use std::borrow::Cow;
+
+pub use iced_style::text::{Appearance, StyleSheet};
+
+/// A paragraph of text made of [`Span`]s, each with its own font, size,
+/// color and decorations.
+///
+/// The spans are measured and laid out as a single paragraph. A [`Span`] can
+/// also be a link that produces a message when clicked.
+///
+/// Like [`Text`], a [`RichText`] can be made [`selectable`].
+///
+/// [`Text`]: crate::widget::Text
+/// [`selectable`]: RichText::selectable
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::Color;
+/// # use iced_native::widget::rich_text::Span;
+/// #
+/// # type RichText<'a, Message> =
+/// #     iced_native::widget::RichText<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     OpenDocs,
+/// }
+///
+/// let text = RichText::new(vec![
+///     Span::new("iced ").size(30),
+///     Span::new("is a cross-platform GUI library. "),
+///     Span::new("Read the docs!")
+///         .color(Color::from_rgb(0.0, 0.0, 1.0))
+///         .underline(true)
+///         .link(Message::OpenDocs),
+/// ]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RichText<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    spans: Vec<Span<'a, Message, Renderer::Font>>,
+    size: Option<u16>,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    selectable: bool,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> RichText<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`RichText`] with the given [`Span`]s.
+    pub fn new(spans: Vec<Span<'a, Message, Renderer::Font>>) -> Self {
+        RichText {
+            spans,
+            size: None,
+            font: Default::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            selectable: false,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a [`Span`] to the [`RichText`].
+    pub fn push(mut self, span: Span<'a, Message, Renderer::Font>) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    /// Sets the default size of the spans of the [`RichText`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the default [`Font`] of the spans of the [`RichText`].
+    ///
+    /// [`Font`]: crate::text::Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`RichText`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RichText`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`RichText`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets whether the contents of the [`RichText`] can be selected and
+    /// copied, like a selectable [`Text`].
+    ///
+    /// Pressing a link never starts a selection.
+    ///
+    /// [`Text`]: crate::widget::Text
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    fn content(&self) -> String {
+        self.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    fn text_spans(
+        &self,
+        renderer: &Renderer,
+        color: Color,
+    ) -> Vec<text::Span<'_, Renderer::Font>> {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+
+        self.spans
+            .iter()
+            .map(|span| text::Span {
+                content: &span.content,
+                size: f32::from(span.size.unwrap_or(size)),
+                color: span.color.unwrap_or(color),
+                font: span.font.clone().unwrap_or_else(|| self.font.clone()),
+                underline: span.underline,
+                strikethrough: span.strikethrough,
+            })
+            .collect()
+    }
+
+    /// Returns the index of the link [`Span`] under the cursor, if any.
+    fn link_at(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        if !bounds.contains(cursor_position)
+            || self.spans.iter().all(|span| span.link.is_none())
+        {
+            return None;
+        }
+
+        let spans = self.text_spans(renderer, Color::BLACK);
+
+        let hit = renderer.hit_test_spans(
+            &spans,
+            bounds.size(),
+            Point::new(
+                cursor_position.x - bounds.x,
+                cursor_position.y - bounds.y,
+            ),
+            false,
+        )?;
+
+        let offset = match hit {
+            text::Hit::CharOffset(offset) => offset,
+            text::Hit::NearestCharOffset(_, _) => return None,
+        };
+
+        let mut start = 0;
+
+        self.spans.iter().position(|span| {
+            let end = start + span.content.chars().count();
+            let is_hit = (start..end).contains(&offset);
+
+            start = end;
+
+            is_hit && span.link.is_some()
+        })
+    }
+}
+
+/// A run of text with its own style, part of a [`RichText`].
+#[allow(missing_debug_implementations)]
+pub struct Span<'a, Message, Font> {
+    content: Cow<'a, str>,
+    size: Option<u16>,
+    font: Option<Font>,
+    color: Option<Color>,
+    underline: bool,
+    strikethrough: bool,
+    link: Option<Message>,
+}
+
+impl<'a, Message, Font> Span<'a, Message, Font> {
+    /// Creates a new [`Span`] with the given contents.
+    pub fn new(content: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            content: content.into(),
+            size: None,
+            font: None,
+            color: None,
+            underline: false,
+            strikethrough: false,
+            link: None,
+        }
+    }
+
+    /// Sets the size of the [`Span`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`Span`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets whether the [`Span`] is underlined.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets whether the [`Span`] is struck through.
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.strikethrough = strikethrough;
+        self
+    }
+
+    /// Turns the [`Span`] into a link that produces the given message when
+    /// clicked.
+    pub fn link(mut self, message: Message) -> Self {
+        self.link = Some(message);
+        self
+    }
+}
+
+/// The local state of a [`RichText`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    pressed_link: Option<usize>,
+    selection: crate::widget::text::State,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for RichText<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let spans = self.text_spans(renderer, Color::BLACK);
+
+        let (width, height) = renderer.measure_spans(&spans, limits.max());
+
+        let size = limits.resolve(Size::new(width, height));
+
+        layout::Node::new(size)
+    }
+
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let label = self.content();
+
+        operation.accessible(
+            &accessibility::Node::new(
+                accessibility::Role::StaticText,
+                layout.bounds(),
+            )
+            .label(label),
+            &mut |_| {},
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Left,
+                ..
+            })
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) =
+                    self.link_at(renderer, layout.bounds(), cursor_position)
+                {
+                    state.pressed_link = Some(index);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if let Some(pressed) = state.pressed_link.take() {
+                    let index = self.link_at(
+                        renderer,
+                        layout.bounds(),
+                        cursor_position,
+                    );
+
+                    if index == Some(pressed) {
+                        if let Some(message) = self.spans[pressed].link.clone()
+                        {
+                            shell.publish(message);
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed_link = None;
+            }
+            _ => {}
+        }
+
+        if !self.selectable {
+            return event::Status::Ignored;
+        }
+
+        let bounds = layout.bounds();
+        let spans = self.text_spans(renderer, Color::BLACK);
+
+        update(
+            event,
+            layout,
+            cursor_position,
+            clipboard,
+            &self.content(),
+            |point| {
+                renderer.hit_test_spans(
+                    &spans,
+                    bounds.size(),
+                    Point::new(point.x - bounds.x, point.y - bounds.y),
+                    true,
+                )
+            },
+            &mut state.selection,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let appearance = theme.appearance(self.style);
+        let color = appearance.color.unwrap_or(style.text_color);
+
+        let spans = self.text_spans(renderer, color);
+        let bounds = layout.bounds();
+
+        if self.selectable {
+            let state = tree.state.downcast_ref::<State>();
+
+            if let Some(selection) = state.selection.selection() {
+                let regions = renderer.selection_bounds_spans(
+                    &spans,
+                    bounds.size(),
+                    selection,
+                );
+
+                draw_selection(
+                    renderer,
+                    bounds.position(),
+                    &regions,
+                    theme.selection_color(self.style),
+                );
+            }
+        }
+
+        renderer.fill_rich_text(text::RichText {
+            spans: &spans,
+            bounds,
+        });
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self
+            .link_at(renderer, layout.bounds(), cursor_position)
+            .is_some()
+        {
+            mouse::Interaction::Pointer
+        } else if self.selectable && layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<RichText<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        rich_text: RichText<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(rich_text)
+    }
+}
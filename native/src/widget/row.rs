@@ -4,7 +4,7 @@ use crate::layout::{self, Layout};
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
-use crate::widget::{Operation, Tree};
+use crate::widget::{Operation, Tree, Wrap};
 use crate::{
     Alignment, Clipboard, Element, Length, Padding, Point, Rectangle, Shell,
     Widget,
@@ -83,6 +83,17 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
         self.children.push(child.into());
         self
     }
+
+    /// Turns the [`Row`] into a [`Wrap`], which breaks its contents onto
+    /// new lines when they do not fit.
+    pub fn wrap(self) -> Wrap<'a, Message, Renderer> {
+        Wrap::with_children(self.children)
+            .spacing(self.spacing)
+            .padding(self.padding)
+            .width(self.width)
+            .height(self.height)
+            .align_items(self.align_items)
+    }
 }
 
 impl<'a, Message, Renderer> Default for Row<'a, Message, Renderer> {
@@ -126,6 +126,7 @@ where
                 border_radius: style.radius.into(),
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                ..renderer::Quad::default()
             },
             style.color,
         );
@@ -1,4 +1,5 @@
 //! Navigate an endless amount of content with a scrollbar.
+use crate::accessibility;
 use crate::event::{self, Event};
 use crate::keyboard;
 use crate::layout;
@@ -7,6 +8,7 @@ use crate::overlay;
 use crate::renderer;
 use crate::touch;
 use crate::widget;
+use crate::widget::focus_ring;
 use crate::widget::operation::{self, Operation};
 use crate::widget::tree::{self, Tree};
 use crate::{
@@ -208,7 +210,22 @@ where
 
         operation.scrollable(state, self.id.as_ref().map(|id| &id.0));
 
-        operation.container(None, &mut |operation| {
+        let bounds = layout.bounds();
+        let content_bounds = layout.children().next().unwrap().bounds();
+
+        let node =
+            accessibility::Node::new(accessibility::Role::ScrollView, bounds)
+                .id(self.id.as_ref().map(|id| &id.0))
+                .focused(state.is_focused);
+
+        // Only scrollables with some overflow can be scrolled with the keyboard
+        if content_bounds.width > bounds.width
+            || content_bounds.height > bounds.height
+        {
+            operation.focusable(state, self.id.as_ref().map(|id| &id.0));
+        }
+
+        operation.accessible(&node, &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
                 layout.children().next().unwrap(),
@@ -487,6 +504,53 @@ pub fn update<Message>(
         return event::Status::Ignored;
     }
 
+    if matches!(
+        event,
+        Event::Mouse(mouse::Event::ButtonPressed { .. })
+            | Event::Touch(touch::Event::FingerPressed { .. })
+    ) {
+        state.is_focused = false;
+    }
+
+    if let Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) = event
+    {
+        if state.is_focused {
+            let delta = match key_code {
+                keyboard::KeyCode::Up => Some(Vector::new(0.0, 60.0)),
+                keyboard::KeyCode::Down => Some(Vector::new(0.0, -60.0)),
+                keyboard::KeyCode::Left => Some(Vector::new(60.0, 0.0)),
+                keyboard::KeyCode::Right => Some(Vector::new(-60.0, 0.0)),
+                keyboard::KeyCode::PageUp => {
+                    Some(Vector::new(0.0, bounds.height))
+                }
+                keyboard::KeyCode::PageDown => {
+                    Some(Vector::new(0.0, -bounds.height))
+                }
+                keyboard::KeyCode::Home => {
+                    Some(Vector::new(0.0, content_bounds.height))
+                }
+                keyboard::KeyCode::End => {
+                    Some(Vector::new(0.0, -content_bounds.height))
+                }
+                _ => None,
+            };
+
+            if let Some(delta) = delta {
+                state.scroll(delta, bounds, content_bounds);
+
+                notify_on_scroll(
+                    state,
+                    on_scroll,
+                    bounds,
+                    content_bounds,
+                    shell,
+                );
+
+                return event::Status::Captured;
+            }
+        }
+    }
+
     if mouse_over_scrollable {
         match event {
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
@@ -595,7 +659,10 @@ pub fn update<Message>(
         }
     } else if mouse_over_y_scrollbar {
         match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Left,
+                ..
+            })
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 if let (Some(scroller_grabbed_at), Some(scrollbar)) =
                     (scrollbars.grab_y_scroller(cursor_position), scrollbars.y)
@@ -662,7 +729,10 @@ pub fn update<Message>(
         }
     } else if mouse_over_x_scrollbar {
         match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Left,
+                ..
+            })
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 if let (Some(scroller_grabbed_at), Some(scrollbar)) =
                     (scrollbars.grab_x_scroller(cursor_position), scrollbars.x)
@@ -819,6 +889,7 @@ pub fn draw<Renderer>(
                             border_radius: style.border_radius.into(),
                             border_width: style.border_width,
                             border_color: style.border_color,
+                            ..renderer::Quad::default()
                         },
                         style
                             .background
@@ -837,6 +908,7 @@ pub fn draw<Renderer>(
                             border_radius: style.scroller.border_radius.into(),
                             border_width: style.scroller.border_width,
                             border_color: style.scroller.border_color,
+                            ..renderer::Quad::default()
                         },
                         style.scroller.color,
                     );
@@ -889,6 +961,10 @@ pub fn draw<Renderer>(
             },
         );
     }
+
+    if state.is_focused {
+        focus_ring::draw(renderer, bounds, theme.focus_ring(style));
+    }
 }
 
 fn notify_on_scroll<Message>(
@@ -926,6 +1002,7 @@ pub struct State {
     offset_x: Offset,
     x_scroller_grabbed_at: Option<f32>,
     keyboard_modifiers: keyboard::Modifiers,
+    is_focused: bool,
 }
 
 impl Default for State {
@@ -937,6 +1014,7 @@ impl Default for State {
             offset_x: Offset::Absolute(0.0),
             x_scroller_grabbed_at: None,
             keyboard_modifiers: keyboard::Modifiers::default(),
+            is_focused: false,
         }
     }
 }
@@ -947,6 +1025,20 @@ impl operation::Scrollable for State {
     }
 }
 
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Offset {
     Absolute(f32),
@@ -1060,6 +1152,11 @@ impl State {
         self.x_scroller_grabbed_at.is_some()
             || self.y_scroller_grabbed_at.is_some()
     }
+
+    /// Returns whether the [`Scrollable`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
 }
 
 #[derive(Debug)]
@@ -0,0 +1,337 @@
+//! Display content on top of other content.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::{Operation, Tree};
+use crate::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+/// A container that displays its children on top of each other.
+///
+/// Every child of a [`Stack`] is laid out in the same bounds and aligned
+/// inside of them. The first child is drawn at the bottom and the last one at
+/// the top, which also gets to handle events first.
+#[allow(missing_debug_implementations)]
+pub struct Stack<'a, Message, Renderer> {
+    width: Length,
+    height: Length,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    alignments: Vec<Option<(alignment::Horizontal, alignment::Vertical)>>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Stack<'a, Message, Renderer> {
+    /// Creates an empty [`Stack`].
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Stack`] with the given elements.
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Stack {
+            width: Length::Shrink,
+            height: Length::Shrink,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            alignments: vec![None; children.len()],
+            children,
+        }
+    }
+
+    /// Sets the width of the [`Stack`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Stack`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the default horizontal alignment of the children of the
+    /// [`Stack`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the default vertical alignment of the children of the [`Stack`].
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Adds an element on top of the [`Stack`].
+    pub fn push(
+        mut self,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.children.push(child.into());
+        self.alignments.push(None);
+        self
+    }
+
+    /// Adds an element on top of the [`Stack`] with its own alignment.
+    pub fn push_aligned(
+        mut self,
+        child: impl Into<Element<'a, Message, Renderer>>,
+        horizontal_alignment: alignment::Horizontal,
+        vertical_alignment: alignment::Vertical,
+    ) -> Self {
+        self.children.push(child.into());
+        self.alignments
+            .push(Some((horizontal_alignment, vertical_alignment)));
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Stack<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Stack<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        // The intrinsic size of the children that do not fill the stack
+        // determines the size of a shrinking stack...
+        let intrinsic = self.children.iter().fold(Size::ZERO, |size, child| {
+            let widget = child.as_widget();
+
+            let fills_width = widget.width().fill_factor() != 0;
+            let fills_height = widget.height().fill_factor() != 0;
+
+            if fills_width && fills_height {
+                return size;
+            }
+
+            let child_size = widget.layout(renderer, &limits.loose()).size();
+
+            Size::new(
+                if fills_width {
+                    size.width
+                } else {
+                    size.width.max(child_size.width)
+                },
+                if fills_height {
+                    size.height
+                } else {
+                    size.height.max(child_size.height)
+                },
+            )
+        });
+
+        let size = limits.resolve(intrinsic);
+
+        // ...and then every child is laid out again inside of it.
+        let child_limits = layout::Limits::new(Size::ZERO, size);
+
+        let children = self
+            .children
+            .iter()
+            .zip(&self.alignments)
+            .map(|(child, alignment)| {
+                let (horizontal_alignment, vertical_alignment) = alignment
+                    .unwrap_or((
+                        self.horizontal_alignment,
+                        self.vertical_alignment,
+                    ));
+
+                let mut node =
+                    child.as_widget().layout(renderer, &child_limits);
+
+                node.align(
+                    horizontal_alignment.into(),
+                    vertical_alignment.into(),
+                    size,
+                );
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                })
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let layouts: Vec<_> = layout.children().collect();
+
+        // The topmost child handles the event first
+        for ((child, state), layout) in self
+            .children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layouts)
+            .rev()
+        {
+            let status = child.as_widget_mut().on_event(
+                state,
+                event.clone(),
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            );
+
+            if let event::Status::Captured = status {
+                return event::Status::Captured;
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let layouts: Vec<_> = layout.children().collect();
+
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layouts)
+            .rev()
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state,
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .find(|interaction| *interaction != mouse::Interaction::default())
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        for (i, ((child, state), layout)) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            let draw = |renderer: &mut Renderer| {
+                child.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor_position,
+                    viewport,
+                );
+            };
+
+            // Every child above the first one gets its own layer, so that it
+            // is drawn entirely on top of the children below.
+            if i == 0 {
+                draw(renderer);
+            } else {
+                renderer.with_layer(bounds, draw);
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Stack<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+{
+    fn from(stack: Stack<'a, Message, Renderer>) -> Self {
+        Self::new(stack)
+    }
+}
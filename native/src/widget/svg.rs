@@ -1,8 +1,9 @@
 //! Display vector graphics in your application.
+use crate::accessibility;
 use crate::layout;
 use crate::renderer;
 use crate::svg;
-use crate::widget::Tree;
+use crate::widget::{Operation, Tree};
 use crate::{
     ContentFit, Element, Layout, Length, Point, Rectangle, Size, Vector, Widget,
 };
@@ -136,6 +137,22 @@ where
         layout::Node::new(final_size)
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.accessible(
+            &accessibility::Node::new(
+                accessibility::Role::Image,
+                layout.bounds(),
+            ),
+            &mut |_| {},
+        );
+    }
+
     fn draw(
         &self,
         _state: &Tree,
@@ -0,0 +1,953 @@
+//! Display large amounts of data in rows and columns.
+//!
+//! A [`Table`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::scrollable;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+
+use std::ops::Range;
+
+pub use iced_style::table::{Appearance, StyleSheet};
+
+/// The icon drawn next to the header of a column sorted in ascending order.
+const ASCENDING_ICON: char = '▲';
+
+/// The icon drawn next to the header of a column sorted in descending order.
+const DESCENDING_ICON: char = '▼';
+
+/// The distance from a column divider at which it can be grabbed.
+const DIVIDER_LEEWAY: f32 = 4.0;
+
+/// A widget that displays a slice of rows in typed columns.
+///
+/// Only the rows inside of the viewport of a [`Table`] are laid out and drawn,
+/// which keeps it responsive no matter how many rows it has.
+///
+/// # Example
+/// ```
+/// # type Table<'a, T, Message> =
+/// #     iced_native::widget::Table<'a, T, Message, iced_native::renderer::Null>;
+/// #
+/// use iced_native::widget::table;
+///
+/// struct User {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Sort(usize, table::Order),
+///     Select(usize),
+/// }
+///
+/// let users = vec![User { name: String::from("Ferris"), age: 13 }];
+///
+/// let table = Table::new(
+///     vec![
+///         table::Column::new("Name", |user: &User| user.name.clone()),
+///         table::Column::new("Age", |user: &User| user.age.to_string())
+///             .width(60.0),
+///     ],
+///     &users,
+/// )
+/// .on_sort(Message::Sort)
+/// .on_select(Message::Select);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, T, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    columns: Vec<Column<'a, T>>,
+    rows: &'a [T],
+    width: Length,
+    height: Length,
+    row_height: u16,
+    padding: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    sort: Option<(usize, Order)>,
+    selected: Option<usize>,
+    on_sort: Option<Box<dyn Fn(usize, Order) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_resize: Option<Box<dyn Fn(ResizeEvent) -> Message + 'a>>,
+    scrollbar: scrollable::Properties,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> Table<'a, T, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default height of the rows of a [`Table`].
+    pub const DEFAULT_ROW_HEIGHT: u16 = 30;
+
+    /// The default horizontal padding of the cells of a [`Table`].
+    pub const DEFAULT_PADDING: u16 = 8;
+
+    /// Creates a new [`Table`] with the given [`Column`]s and rows.
+    pub fn new(columns: Vec<Column<'a, T>>, rows: &'a [T]) -> Self {
+        Table {
+            columns,
+            rows,
+            width: Length::Fill,
+            height: Length::Fill,
+            row_height: Self::DEFAULT_ROW_HEIGHT,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            sort: None,
+            selected: None,
+            on_sort: None,
+            on_select: None,
+            on_resize: None,
+            scrollbar: scrollable::Properties::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`Table`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the height of the header and the rows of the [`Table`].
+    pub fn row_height(mut self, row_height: u16) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the horizontal padding of the cells of the [`Table`].
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the [`Table`].
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`Table`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the index of the [`Column`] the rows of the [`Table`] are
+    /// currently sorted by, and its [`Order`].
+    ///
+    /// The rows are not sorted by the [`Table`]; this only shows an
+    /// indicator next to the header of the [`Column`].
+    pub fn sort(mut self, sort: Option<(usize, Order)>) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Sets the index of the row of the [`Table`] that is selected, if any.
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the message that will be produced when the header of a sortable
+    /// [`Column`] is clicked.
+    ///
+    /// The closure receives the index of the [`Column`] and the [`Order`]
+    /// the rows should be sorted in.
+    pub fn on_sort(
+        mut self,
+        on_sort: impl Fn(usize, Order) -> Message + 'a,
+    ) -> Self {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Sets the message that will be produced when a row of the [`Table`]
+    /// is clicked.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(usize) -> Message + 'a,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Enables the resizing of the columns of the [`Table`] by dragging the
+    /// dividers of its header.
+    ///
+    /// The widths of the columns are not changed by the [`Table`]; you need
+    /// to store the width of the [`ResizeEvent`] and apply it to its
+    /// [`Column`].
+    pub fn on_column_resize(
+        mut self,
+        on_resize: impl Fn(ResizeEvent) -> Message + 'a,
+    ) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the [`scrollable::Properties`] of the scrollbars of the [`Table`].
+    pub fn scrollbar(mut self, scrollbar: scrollable::Properties) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn total_width(&self) -> f32 {
+        self.columns.iter().map(|column| column.width).sum()
+    }
+
+    fn divider_at(&self, header: Rectangle, x: f32) -> Option<usize> {
+        let mut right = header.x;
+
+        self.columns.iter().enumerate().find_map(|(index, column)| {
+            right += column.width;
+
+            ((x - right).abs() <= DIVIDER_LEEWAY).then_some(index)
+        })
+    }
+
+    fn column_at(&self, header: Rectangle, x: f32) -> Option<usize> {
+        let mut left = header.x;
+
+        self.columns.iter().position(|column| {
+            let is_inside = x >= left && x < left + column.width;
+
+            left += column.width;
+
+            is_inside
+        })
+    }
+}
+
+/// The definition of a column of a [`Table`].
+#[allow(missing_debug_implementations)]
+pub struct Column<'a, T> {
+    header: String,
+    width: f32,
+    horizontal_alignment: alignment::Horizontal,
+    is_sortable: bool,
+    cell: Box<dyn Fn(&T) -> String + 'a>,
+}
+
+impl<'a, T> Column<'a, T> {
+    /// The default width of a [`Column`].
+    pub const DEFAULT_WIDTH: f32 = 150.0;
+
+    /// The minimum width of a [`Column`].
+    pub const MIN_WIDTH: f32 = 20.0;
+
+    /// Creates a new [`Column`] with the given header and a closure that
+    /// produces the contents of its cell for every row.
+    pub fn new(
+        header: impl Into<String>,
+        cell: impl Fn(&T) -> String + 'a,
+    ) -> Self {
+        Column {
+            header: header.into(),
+            width: Self::DEFAULT_WIDTH,
+            horizontal_alignment: alignment::Horizontal::Left,
+            is_sortable: true,
+            cell: Box::new(cell),
+        }
+    }
+
+    /// Sets the width of the [`Column`].
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width.max(Self::MIN_WIDTH);
+        self
+    }
+
+    /// Sets the horizontal alignment of the cells of the [`Column`].
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: alignment::Horizontal,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets whether the [`Table`] can be sorted by the [`Column`].
+    ///
+    /// By default, it can.
+    pub fn sortable(mut self, is_sortable: bool) -> Self {
+        self.is_sortable = is_sortable;
+        self
+    }
+}
+
+/// The order the rows of a [`Table`] are sorted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// From the smallest value to the largest.
+    Ascending,
+
+    /// From the largest value to the smallest.
+    Descending,
+}
+
+impl Order {
+    /// Returns the opposite [`Order`].
+    pub fn reverse(self) -> Self {
+        match self {
+            Order::Ascending => Order::Descending,
+            Order::Descending => Order::Ascending,
+        }
+    }
+}
+
+/// A [`Column`] of a [`Table`] was resized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResizeEvent {
+    /// The index of the [`Column`].
+    pub column: usize,
+
+    /// The new width of the [`Column`].
+    pub width: f32,
+}
+
+/// The local state of a [`Table`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    scrollable: scrollable::State,
+    resizing: Option<Resizing>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resizing {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for Table<'a, T, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet + scrollable::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let row_height = f32::from(self.row_height);
+        let total_width = self.total_width();
+
+        let size = limits.resolve(Size::new(
+            total_width,
+            row_height * (self.rows.len() + 1) as f32,
+        ));
+
+        let header = layout::Node::new(Size::new(size.width, row_height));
+
+        // The rows are never laid out; their bounds are computed from the
+        // viewport when needed.
+        let content = layout::Node::new(Size::new(
+            total_width.max(size.width),
+            row_height * self.rows.len() as f32,
+        ));
+
+        let mut body = layout::Node::with_children(
+            Size::new(size.width, (size.height - row_height).max(0.0)),
+            vec![content],
+        );
+
+        body.move_to(Point::new(0.0, row_height));
+
+        layout::Node::with_children(size, vec![header, body])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let body = children.next().unwrap();
+
+        let offset = state
+            .scrollable
+            .offset(body.bounds(), body.children().next().unwrap().bounds());
+
+        let header_bounds = header.bounds();
+        let header_cursor = cursor_position + Vector::new(offset.x, 0.0);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Left,
+                ..
+            })
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if header_bounds.contains(cursor_position) =>
+            {
+                if let Some(column) = self.on_resize.as_ref().and_then(|_| {
+                    self.divider_at(header_bounds, header_cursor.x)
+                }) {
+                    state.resizing = Some(Resizing {
+                        column,
+                        origin: cursor_position.x,
+                        width: self.columns[column].width,
+                    });
+
+                    return event::Status::Captured;
+                }
+
+                if let Some(on_sort) = &self.on_sort {
+                    if let Some(column) =
+                        self.column_at(header_bounds, header_cursor.x)
+                    {
+                        if self.columns[column].is_sortable {
+                            let order = match self.sort {
+                                Some((sorted, order)) if sorted == column => {
+                                    order.reverse()
+                                }
+                                _ => Order::Ascending,
+                            };
+
+                            shell.publish(on_sort(column, order));
+                        }
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let (Some(resizing), Some(on_resize)) =
+                    (state.resizing, &self.on_resize)
+                {
+                    let width = (resizing.width + cursor_position.x
+                        - resizing.origin)
+                        .max(Column::<T>::MIN_WIDTH);
+
+                    shell.publish(on_resize(ResizeEvent {
+                        column: resizing.column,
+                        width,
+                    }));
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.resizing.is_some() =>
+            {
+                state.resizing = None;
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let row_height = f32::from(self.row_height);
+        let rows = self.rows.len();
+        let on_select = &self.on_select;
+
+        scrollable::update(
+            &mut state.scrollable,
+            event,
+            body,
+            cursor_position,
+            clipboard,
+            shell,
+            &self.scrollbar,
+            Some(&self.scrollbar),
+            &None,
+            |event, layout, cursor_position, _clipboard, shell| {
+                let bounds = layout.bounds();
+
+                match event {
+                    Event::Mouse(mouse::Event::ButtonPressed {
+                        button: mouse::Button::Left,
+                        ..
+                    })
+                    | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                        if let Some(on_select) = on_select {
+                            if let Some(row) = row_at(
+                                bounds,
+                                row_height,
+                                rows,
+                                cursor_position,
+                            ) {
+                                shell.publish(on_select(row));
+
+                                return event::Status::Captured;
+                            }
+                        }
+
+                        event::Status::Ignored
+                    }
+                    _ => event::Status::Ignored,
+                }
+            },
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.resizing.is_some() {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let body = children.next().unwrap();
+
+        let header_bounds = header.bounds();
+
+        if header_bounds.contains(cursor_position) {
+            let offset = state.scrollable.offset(
+                body.bounds(),
+                body.children().next().unwrap().bounds(),
+            );
+
+            let x = cursor_position.x + offset.x;
+
+            if self.on_resize.is_some()
+                && self.divider_at(header_bounds, x).is_some()
+            {
+                return mouse::Interaction::ResizingHorizontally;
+            }
+
+            return match self.column_at(header_bounds, x) {
+                Some(column)
+                    if self.on_sort.is_some()
+                        && self.columns[column].is_sortable =>
+                {
+                    mouse::Interaction::Pointer
+                }
+                _ => mouse::Interaction::default(),
+            };
+        }
+
+        let row_height = f32::from(self.row_height);
+
+        scrollable::mouse_interaction(
+            &state.scrollable,
+            body,
+            cursor_position,
+            &self.scrollbar,
+            Some(&self.scrollbar),
+            |layout, cursor_position, _viewport| {
+                if self.on_select.is_some()
+                    && row_at(
+                        layout.bounds(),
+                        row_height,
+                        self.rows.len(),
+                        cursor_position,
+                    )
+                    .is_some()
+                {
+                    mouse::Interaction::Pointer
+                } else {
+                    mouse::Interaction::default()
+                }
+            },
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+        let body = children.next().unwrap();
+
+        let offset = state
+            .scrollable
+            .offset(body.bounds(), body.children().next().unwrap().bounds());
+
+        let text_size = f32::from(
+            self.text_size.unwrap_or_else(|| renderer.default_size()),
+        );
+        let row_height = f32::from(self.row_height);
+        let padding = f32::from(self.padding);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+                ..renderer::Quad::default()
+            },
+            appearance.background,
+        );
+
+        // Header
+        let header_bounds = header.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: header_bounds,
+                border_radius: appearance.border_radius.into(),
+                ..renderer::Quad::default()
+            },
+            appearance.header_background,
+        );
+
+        renderer.with_layer(header_bounds, |renderer| {
+            renderer.with_translation(
+                Vector::new(-offset.x, 0.0),
+                |renderer| {
+                    let mut x = header_bounds.x;
+
+                    for (index, column) in self.columns.iter().enumerate() {
+                        let cell = Rectangle {
+                            x,
+                            width: column.width,
+                            ..header_bounds
+                        };
+
+                        let indicator = match self.sort {
+                            Some((sorted, Order::Ascending))
+                                if sorted == index =>
+                            {
+                                Some(ASCENDING_ICON)
+                            }
+                            Some((sorted, Order::Descending))
+                                if sorted == index =>
+                            {
+                                Some(DESCENDING_ICON)
+                            }
+                            _ => None,
+                        };
+
+                        let content = match indicator {
+                            Some(icon) => format!("{} {}", column.header, icon),
+                            None => column.header.clone(),
+                        };
+
+                        draw_cell(
+                            renderer,
+                            &content,
+                            cell,
+                            padding,
+                            text_size,
+                            self.font.clone(),
+                            appearance.header_text_color,
+                            column.horizontal_alignment,
+                        );
+
+                        x += column.width;
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: x - 0.5,
+                                    width: 1.0,
+                                    ..header_bounds
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            Background::Color(appearance.divider_color),
+                        );
+                    }
+                },
+            );
+        });
+
+        // Rows
+        scrollable::draw(
+            &state.scrollable,
+            renderer,
+            theme,
+            body,
+            cursor_position,
+            &self.scrollbar,
+            Some(&self.scrollbar),
+            &Default::default(),
+            |renderer, layout, cursor_position, viewport| {
+                let content = layout.bounds();
+
+                let visible = visible_rows(
+                    content,
+                    *viewport,
+                    row_height,
+                    self.rows.len(),
+                );
+
+                let hovered = row_at(
+                    content,
+                    row_height,
+                    self.rows.len(),
+                    cursor_position,
+                );
+
+                for index in visible.clone() {
+                    let row = Rectangle {
+                        y: content.y + row_height * index as f32,
+                        height: row_height,
+                        ..content
+                    };
+
+                    let background = if Some(index) == self.selected {
+                        Some(appearance.selected_row_background)
+                    } else if Some(index) == hovered && self.on_select.is_some()
+                    {
+                        appearance.hovered_row_background
+                    } else if index % 2 == 1 {
+                        appearance.alternate_row_background
+                    } else {
+                        None
+                    };
+
+                    if let Some(background) = background {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: row,
+                                ..renderer::Quad::default()
+                            },
+                            background,
+                        );
+                    }
+                }
+
+                let mut x = content.x;
+
+                for column in &self.columns {
+                    let clip = Rectangle {
+                        x,
+                        width: column.width,
+                        ..*viewport
+                    };
+
+                    renderer.with_layer(clip, |renderer| {
+                        for index in visible.clone() {
+                            let cell = Rectangle {
+                                x,
+                                y: content.y + row_height * index as f32,
+                                width: column.width,
+                                height: row_height,
+                            };
+
+                            let color = if Some(index) == self.selected {
+                                appearance.selected_text_color
+                            } else {
+                                appearance.text_color
+                            };
+
+                            draw_cell(
+                                renderer,
+                                &(column.cell)(&self.rows[index]),
+                                cell,
+                                padding,
+                                text_size,
+                                self.font.clone(),
+                                color,
+                                column.horizontal_alignment,
+                            );
+                        }
+                    });
+
+                    x += column.width;
+                }
+            },
+        );
+    }
+}
+
+impl<'a, T, Message, Renderer> From<Table<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a,
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet + scrollable::StyleSheet,
+{
+    fn from(
+        table: Table<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(table)
+    }
+}
+
+fn draw_cell<Renderer>(
+    renderer: &mut Renderer,
+    content: &str,
+    bounds: Rectangle,
+    padding: f32,
+    size: f32,
+    font: Renderer::Font,
+    color: Color,
+    horizontal_alignment: alignment::Horizontal,
+) where
+    Renderer: text::Renderer,
+{
+    let x = match horizontal_alignment {
+        alignment::Horizontal::Left => bounds.x + padding,
+        alignment::Horizontal::Center => bounds.center_x(),
+        alignment::Horizontal::Right => bounds.x + bounds.width - padding,
+    };
+
+    renderer.fill_text(Text {
+        content,
+        size,
+        font,
+        color,
+        bounds: Rectangle {
+            x,
+            y: bounds.center_y(),
+            width: (bounds.width - padding * 2.0).max(0.0),
+            ..bounds
+        },
+        horizontal_alignment,
+        vertical_alignment: alignment::Vertical::Center,
+        line_height: 1.0,
+        wrap: text::Wrap::None,
+        overflow: text::Overflow::Ellipsis,
+    });
+}
+
+/// Returns the index of the row under the given position, if any.
+fn row_at(
+    content: Rectangle,
+    row_height: f32,
+    rows: usize,
+    position: Point,
+) -> Option<usize> {
+    if !content.contains(position) || row_height <= 0.0 {
+        return None;
+    }
+
+    let row = ((position.y - content.y) / row_height) as usize;
+
+    (row < rows).then_some(row)
+}
+
+/// Returns the range of rows that intersect the given viewport.
+fn visible_rows(
+    content: Rectangle,
+    viewport: Rectangle,
+    row_height: f32,
+    rows: usize,
+) -> Range<usize> {
+    if row_height <= 0.0 {
+        return 0..0;
+    }
+
+    let start = ((viewport.y - content.y) / row_height).floor().max(0.0);
+    let end = ((viewport.y + viewport.height - content.y) / row_height)
+        .ceil()
+        .max(0.0);
+
+    (start as usize).min(rows)..(end as usize).min(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_visible_rows_are_drawn() {
+        let content = Rectangle {
+            x: 0.0,
+            y: 30.0,
+            width: 200.0,
+            height: 30.0 * 100_000.0,
+        };
+
+        let viewport = Rectangle {
+            x: 0.0,
+            y: 30.0 + 30.0 * 500.0 + 15.0,
+            width: 200.0,
+            height: 300.0,
+        };
+
+        assert_eq!(visible_rows(content, viewport, 30.0, 100_000), 500..511);
+        assert_eq!(visible_rows(content, viewport, 30.0, 505), 500..505);
+
+        assert_eq!(
+            row_at(content, 30.0, 100_000, Point::new(10.0, 30.0 + 65.0)),
+            Some(2)
+        );
+        assert_eq!(
+            row_at(content, 30.0, 2, Point::new(10.0, 30.0 + 65.0)),
+            None
+        );
+    }
+}
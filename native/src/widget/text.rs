@@ -1,12 +1,26 @@
 //! Write some text for your users to read.
+//!
+//! A selectable [`Text`] has some local [`State`].
+use crate::accessibility;
 use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
+use crate::mouse::{self, click};
 use crate::renderer;
 use crate::text;
-use crate::widget::Tree;
-use crate::{Element, Layout, Length, Point, Rectangle, Size, Widget};
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Point, Rectangle, Shell, Size,
+    Widget,
+};
 
 use std::borrow::Cow;
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
 
 pub use iced_style::text::{Appearance, StyleSheet};
 
@@ -24,6 +38,11 @@ pub use iced_style::text::{Appearance, StyleSheet};
 ///     .style(Color::from([0.0, 0.0, 1.0]));
 /// ```
 ///
+/// A [`Text`] can also be made [`selectable`], letting users select its
+/// contents with the mouse and copy them to the [`Clipboard`].
+///
+/// [`selectable`]: Text::selectable
+///
 /// ![Text drawn by `iced_wgpu`](https://github.com/iced-rs/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/text.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct Text<'a, Renderer>
@@ -37,6 +56,10 @@ where
     height: Length,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    line_height: f32,
+    wrap: text::Wrap,
+    overflow: text::Overflow,
+    selectable: bool,
     font: Renderer::Font,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
@@ -56,6 +79,10 @@ where
             height: Length::Shrink,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
+            line_height: 1.0,
+            wrap: text::Wrap::default(),
+            overflow: text::Overflow::default(),
+            selectable: false,
             style: Default::default(),
         }
     }
@@ -112,6 +139,115 @@ where
         self.vertical_alignment = alignment;
         self
     }
+
+    /// Sets the distance between the baselines of consecutive lines of the
+    /// [`Text`], relative to the default line height of its font.
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.line_height = line_height;
+        self
+    }
+
+    /// Sets the [`text::Wrap`] strategy of the [`Text`].
+    pub fn wrap(mut self, wrap: text::Wrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets the [`text::Overflow`] strategy of the [`Text`].
+    ///
+    /// With [`text::Overflow::Ellipsis`], the lines that do not fit in the
+    /// bounds of the [`Text`] are truncated and end with an ellipsis.
+    pub fn overflow(mut self, overflow: text::Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets whether the contents of the [`Text`] can be selected and copied.
+    ///
+    /// A selectable [`Text`] supports selecting with a mouse drag, double
+    /// clicking to select a word and triple clicking to select everything.
+    /// Once clicked, it also handles the usual shortcuts to select all and
+    /// copy the selection.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    fn paragraph(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+    ) -> text::Paragraph<'_, Renderer::Font> {
+        text::Paragraph {
+            content: &self.content,
+            bounds,
+            size: f32::from(
+                self.size.unwrap_or_else(|| renderer.default_size()),
+            ),
+            font: self.font.clone(),
+            horizontal_alignment: self.horizontal_alignment,
+            vertical_alignment: self.vertical_alignment,
+            line_height: self.line_height,
+            wrap: self.wrap,
+            overflow: self.overflow,
+        }
+    }
+
+    fn hit_test(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        point: Point,
+    ) -> Option<text::Hit> {
+        let position = position(
+            bounds,
+            self.horizontal_alignment,
+            self.vertical_alignment,
+        );
+
+        renderer.hit_test(
+            self.paragraph(renderer, bounds.size()),
+            Point::new(point.x - position.x, point.y - position.y),
+            true,
+        )
+    }
+}
+
+/// The local state of a selectable [`Text`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    anchor: usize,
+    head: usize,
+    is_focused: bool,
+    is_dragging: bool,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    /// Creates a new [`State`], with nothing selected.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the range of characters currently selected, if any.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        if self.anchor == self.head {
+            None
+        } else {
+            Some(self.anchor.min(self.head)..self.anchor.max(self.head))
+        }
+    }
+
+    /// Returns whether the selectable text was the last one clicked, which
+    /// makes it handle keyboard shortcuts.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn select(&mut self, range: Range<usize>) {
+        self.anchor = range.start;
+        self.head = range.end;
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Text<'a, Renderer>
@@ -119,6 +255,22 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        if self.selectable {
+            tree::Tag::of::<State>()
+        } else {
+            tree::Tag::stateless()
+        }
+    }
+
+    fn state(&self) -> tree::State {
+        if self.selectable {
+            tree::State::new(State::new())
+        } else {
+            tree::State::None
+        }
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -138,17 +290,66 @@ where
 
         let bounds = limits.max();
 
-        let (width, height) =
-            renderer.measure(&self.content, size, self.font.clone(), bounds);
+        let (width, height) = renderer.measure(
+            &self.content,
+            size,
+            self.line_height,
+            self.font.clone(),
+            bounds,
+            self.wrap,
+            self.overflow,
+        );
 
         let size = limits.resolve(Size::new(width, height));
 
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.accessible(
+            &accessibility::Node::new(
+                accessibility::Role::StaticText,
+                layout.bounds(),
+            )
+            .label(self.content.as_ref()),
+            &mut |_| {},
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if !self.selectable {
+            return event::Status::Ignored;
+        }
+
+        update(
+            event,
+            layout,
+            cursor_position,
+            clipboard,
+            &self.content,
+            |point| self.hit_test(renderer, layout.bounds(), point),
+            tree.state.downcast_mut::<State>(),
+        )
+    }
+
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         style: &renderer::Style,
@@ -156,6 +357,32 @@ where
         _cursor_position: Point,
         _viewport: &Rectangle,
     ) {
+        if self.selectable {
+            let state = tree.state.downcast_ref::<State>();
+
+            if let Some(selection) = state.selection() {
+                let bounds = layout.bounds();
+
+                let position = position(
+                    bounds,
+                    self.horizontal_alignment,
+                    self.vertical_alignment,
+                );
+
+                let regions = renderer.selection_bounds(
+                    self.paragraph(renderer, bounds.size()),
+                    selection,
+                );
+
+                draw_selection(
+                    renderer,
+                    position,
+                    &regions,
+                    theme.selection_color(self.style),
+                );
+            }
+        }
+
         draw(
             renderer,
             style,
@@ -166,10 +393,214 @@ where
             theme.appearance(self.style),
             self.horizontal_alignment,
             self.vertical_alignment,
+            self.line_height,
+            self.wrap,
+            self.overflow,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.selectable && layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+/// Processes an [`Event`] and updates the [`State`] of a selectable [`Text`]
+/// accordingly.
+///
+/// The `hit_test` function must return the [`text::Hit`] of the given
+/// absolute position on the `content`, if any.
+pub fn update(
+    event: Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    clipboard: &mut dyn Clipboard,
+    content: &str,
+    hit_test: impl Fn(Point) -> Option<text::Hit>,
+    state: &mut State,
+) -> event::Status {
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed {
+            button: mouse::Button::Left,
+            ..
+        })
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if !layout.bounds().contains(cursor_position) {
+                state.is_focused = false;
+                state.is_dragging = false;
+                state.select(0..0);
+
+                return event::Status::Ignored;
+            }
+
+            let kind = match event {
+                Event::Mouse(mouse::Event::ButtonPressed { click, .. }) => {
+                    click.kind()
+                }
+                _ => click::Kind::Single,
+            };
+
+            let hit = hit_test(cursor_position);
+
+            match kind {
+                click::Kind::Single => {
+                    let position = hit.map(text::Hit::cursor).unwrap_or(0);
+
+                    if state.keyboard_modifiers.shift() {
+                        state.head = position;
+                    } else {
+                        state.select(position..position);
+                    }
+
+                    state.is_dragging = true;
+                }
+                click::Kind::Double => {
+                    let index = match hit {
+                        Some(text::Hit::CharOffset(index))
+                        | Some(text::Hit::NearestCharOffset(index, _)) => index,
+                        None => 0,
+                    };
+
+                    state.select(word_at(content, index));
+                    state.is_dragging = false;
+                }
+                click::Kind::Triple => {
+                    state.select(0..content.chars().count());
+                    state.is_dragging = false;
+                }
+            }
+
+            state.is_focused = true;
+
+            return event::Status::Captured;
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. })
+        | Event::Touch(touch::Event::FingerLost { .. }) => {
+            state.is_dragging = false;
+        }
+        Event::Mouse(mouse::Event::CursorMoved { position })
+        | Event::Touch(touch::Event::FingerMoved { position, .. })
+            if state.is_dragging =>
+        {
+            if let Some(hit) = hit_test(position) {
+                state.head = hit.cursor();
+            }
+
+            return event::Status::Captured;
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+            if state.is_focused && state.keyboard_modifiers.command() =>
+        {
+            match key_code {
+                keyboard::KeyCode::A => {
+                    state.select(0..content.chars().count());
+
+                    return event::Status::Captured;
+                }
+                keyboard::KeyCode::C => {
+                    if let Some(selection) = state.selection() {
+                        clipboard.write(
+                            content
+                                .chars()
+                                .skip(selection.start)
+                                .take(selection.len())
+                                .collect(),
+                        );
+                    }
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+        Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+            state.keyboard_modifiers = modifiers;
+        }
+        _ => {}
+    }
+
+    event::Status::Ignored
+}
+
+/// Draws the selection of some selectable text, given the regions it covers
+/// relative to the provided position.
+pub fn draw_selection<Renderer>(
+    renderer: &mut Renderer,
+    position: Point,
+    regions: &[Rectangle],
+    color: Color,
+) where
+    Renderer: crate::Renderer,
+{
+    for region in regions {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: position.x + region.x,
+                    y: position.y + region.y,
+                    ..*region
+                },
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                ..renderer::Quad::default()
+            },
+            color,
         );
     }
 }
 
+/// Returns the range of characters of the word at the given character index,
+/// or of the whitespace around it.
+fn word_at(content: &str, index: usize) -> Range<usize> {
+    let mut start = 0;
+
+    for word in content.split_word_bounds() {
+        let end = start + word.chars().count();
+
+        if index < end {
+            return start..end;
+        }
+
+        start = end;
+    }
+
+    start..start
+}
+
+/// Returns the position text is drawn at inside of the given bounds, based
+/// on its alignment.
+fn position(
+    bounds: Rectangle,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+) -> Point {
+    let x = match horizontal_alignment {
+        alignment::Horizontal::Left => bounds.x,
+        alignment::Horizontal::Center => bounds.center_x(),
+        alignment::Horizontal::Right => bounds.x + bounds.width,
+    };
+
+    let y = match vertical_alignment {
+        alignment::Vertical::Top => bounds.y,
+        alignment::Vertical::Center => bounds.center_y(),
+        alignment::Vertical::Bottom => bounds.y + bounds.height,
+    };
+
+    Point::new(x, y)
+}
+
 /// Draws text using the same logic as the [`Text`] widget.
 ///
 /// Specifically:
@@ -190,22 +621,15 @@ pub fn draw<Renderer>(
     appearance: Appearance,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    line_height: f32,
+    wrap: text::Wrap,
+    overflow: text::Overflow,
 ) where
     Renderer: text::Renderer,
 {
     let bounds = layout.bounds();
-
-    let x = match horizontal_alignment {
-        alignment::Horizontal::Left => bounds.x,
-        alignment::Horizontal::Center => bounds.center_x(),
-        alignment::Horizontal::Right => bounds.x + bounds.width,
-    };
-
-    let y = match vertical_alignment {
-        alignment::Vertical::Top => bounds.y,
-        alignment::Vertical::Center => bounds.center_y(),
-        alignment::Vertical::Bottom => bounds.y + bounds.height,
-    };
+    let Point { x, y } =
+        position(bounds, horizontal_alignment, vertical_alignment);
 
     renderer.fill_text(crate::text::Text {
         content,
@@ -215,6 +639,9 @@ pub fn draw<Renderer>(
         font,
         horizontal_alignment,
         vertical_alignment,
+        line_height,
+        wrap,
+        overflow,
     });
 }
 
@@ -242,6 +669,10 @@ where
             height: self.height,
             horizontal_alignment: self.horizontal_alignment,
             vertical_alignment: self.vertical_alignment,
+            line_height: self.line_height,
+            wrap: self.wrap,
+            overflow: self.overflow,
+            selectable: self.selectable,
             font: self.font.clone(),
             style: self.style,
         }
@@ -257,3 +688,20 @@ where
         Text::new(contents).into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_at_selects_words_and_whitespace() {
+        let content = "héllo, wörld";
+
+        assert_eq!(word_at(content, 0), 0..5);
+        assert_eq!(word_at(content, 4), 0..5);
+        assert_eq!(word_at(content, 5), 5..6);
+        assert_eq!(word_at(content, 6), 6..7);
+        assert_eq!(word_at(content, 9), 7..12);
+        assert_eq!(word_at(content, 20), 12..12);
+    }
+}
@@ -0,0 +1,1041 @@
+//! Display a multi-line text editor.
+//!
+//! A [`TextEditor`] has some local [`State`], but its text, cursor and
+//! history live in a [`Content`] owned by the application.
+mod content;
+mod wrap;
+
+pub use content::{Action, Content, Edit, Motion, Position};
+
+use wrap::Rows;
+
+use crate::accessibility;
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse::{self, click};
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::time::{Duration, Instant};
+use crate::touch;
+use crate::widget;
+use crate::widget::operation::{self, Operation};
+use crate::widget::tree::{self, Tree};
+use crate::window;
+use crate::{
+    Clipboard, Color, Command, Element, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+pub use iced_style::text_input::{Appearance, StyleSheet};
+
+/// A multi-line text editor.
+///
+/// # Example
+/// ```
+/// # pub type TextEditor<'a, Message> = iced_native::widget::TextEditor<'a, Message, iced_native::renderer::Null>;
+/// use iced_native::widget::text_editor;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Edit(text_editor::Action),
+/// }
+///
+/// let content = text_editor::Content::with_text("Some\nlines");
+///
+/// let editor = TextEditor::new(&content)
+///     .on_action(Message::Edit)
+///     .padding(10);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TextEditor<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Option<Id>,
+    content: &'a Content,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    size: Option<u16>,
+    on_action: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> TextEditor<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`TextEditor`] displaying the given [`Content`].
+    ///
+    /// The [`TextEditor`] is read-only until [`on_action`] is set.
+    ///
+    /// [`on_action`]: Self::on_action
+    pub fn new(content: &'a Content) -> Self {
+        TextEditor {
+            id: None,
+            content,
+            font: Default::default(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: Padding::new(5),
+            size: None,
+            on_action: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`TextEditor`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the function that produces a message when the user interacts
+    /// with the [`TextEditor`].
+    ///
+    /// The resulting [`Action`] should be applied to the [`Content`] with
+    /// [`Content::perform`].
+    pub fn on_action(
+        mut self,
+        on_action: impl Fn(Action) -> Message + 'a,
+    ) -> Self {
+        self.on_action = Some(Box::new(on_action));
+        self
+    }
+
+    /// Sets the [`Font`] of the [`TextEditor`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the [`TextEditor`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`TextEditor`].
+    ///
+    /// A [`TextEditor`] with [`Length::Shrink`] height grows to fit all of
+    /// its lines and never scrolls.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`TextEditor`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`TextEditor`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`TextEditor`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TextEditor<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let padding = self.padding.fit(Size::ZERO, limits.max());
+
+        let limits = limits.width(self.width).height(self.height).pad(padding);
+        let text_width = limits.max().width;
+
+        let text_height = if self.height == Length::Shrink {
+            let mut rows = Rows::default();
+            rows.update(renderer, self.content, text_width, size, &self.font);
+
+            rows.height()
+        } else {
+            limits.max().height
+        };
+
+        let text_size = limits.resolve(Size::new(text_width, text_height));
+
+        let mut text = layout::Node::new(text_size);
+        text.move_to(Point::new(padding.left.into(), padding.top.into()));
+
+        layout::Node::with_children(text_size.pad(padding), vec![text])
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        let node = accessibility::Node::new(
+            accessibility::Role::MultilineTextInput,
+            layout.bounds(),
+        )
+        .id(self.id.as_ref().map(|id| &id.0))
+        .value(accessibility::Value::Text(self.content.text()))
+        .disabled(self.on_action.is_none())
+        .focused(state.is_focused());
+
+        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
+        operation.accessible(&node, &mut |_| {});
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        update(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+            self.content,
+            self.size,
+            &self.font,
+            self.on_action.as_deref(),
+            tree.state.downcast_mut::<State>(),
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        draw(
+            renderer,
+            theme,
+            layout,
+            cursor_position,
+            tree.state.downcast_ref::<State>(),
+            self.content,
+            self.size,
+            &self.font,
+            &self.style,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse_interaction(layout, cursor_position, self.on_action.is_some())
+    }
+}
+
+impl<'a, Message, Renderer> From<TextEditor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        text_editor: TextEditor<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(text_editor)
+    }
+}
+
+/// The identifier of a [`TextEditor`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Command`] that focuses the [`TextEditor`] with the given [`Id`].
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id.0))
+}
+
+/// Processes an [`Event`] and updates the [`State`] of a [`TextEditor`]
+/// accordingly.
+pub fn update<Message, Renderer>(
+    event: Event,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+    content: &Content,
+    size: Option<u16>,
+    font: &Renderer::Font,
+    on_action: Option<&dyn Fn(Action) -> Message>,
+    state: &mut State,
+) -> event::Status
+where
+    Renderer: text::Renderer,
+{
+    let bounds = layout.bounds();
+    let text_bounds = layout.children().next().unwrap().bounds();
+    let size = size.unwrap_or_else(|| renderer.default_size());
+
+    state.rows.borrow_mut().update(
+        renderer,
+        content,
+        text_bounds.width,
+        size,
+        font,
+    );
+
+    let on_action = match on_action {
+        Some(on_action) => on_action,
+        None => {
+            if let Event::Mouse(mouse::Event::WheelScrolled { delta }) = event {
+                if bounds.contains(cursor_position) {
+                    return state.scroll(delta, text_bounds);
+                }
+            }
+
+            state.is_focused = None;
+
+            return event::Status::Ignored;
+        }
+    };
+
+    let mut publish = |action: Action| shell.publish(on_action(action));
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed {
+            button: mouse::Button::Left,
+            ..
+        })
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            let is_clicked = bounds.contains(cursor_position);
+
+            state.is_focused = if is_clicked {
+                state.is_focused.or_else(|| {
+                    let now = Instant::now();
+
+                    Some(Focus {
+                        updated_at: now,
+                        now,
+                    })
+                })
+            } else {
+                None
+            };
+
+            if is_clicked {
+                let position = state.hit_test(
+                    renderer,
+                    content,
+                    text_bounds,
+                    cursor_position,
+                    size,
+                    font,
+                );
+
+                let kind = match event {
+                    Event::Mouse(mouse::Event::ButtonPressed {
+                        click, ..
+                    }) => click.kind(),
+                    _ => click::Kind::Single,
+                };
+
+                match kind {
+                    click::Kind::Single => {
+                        if state.keyboard_modifiers.shift() {
+                            publish(Action::Select(Motion::To(position)));
+                        } else {
+                            publish(Action::Move(Motion::To(position)));
+                        }
+
+                        state.is_dragging = true;
+                    }
+                    click::Kind::Double => {
+                        publish(Action::Move(Motion::To(position)));
+                        publish(Action::SelectWord);
+
+                        state.is_dragging = false;
+                    }
+                    click::Kind::Triple => {
+                        publish(Action::Move(Motion::To(position)));
+                        publish(Action::SelectLine);
+
+                        state.is_dragging = false;
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. })
+        | Event::Touch(touch::Event::FingerLost { .. }) => {
+            state.is_dragging = false;
+        }
+        Event::Mouse(mouse::Event::CursorMoved { position })
+        | Event::Touch(touch::Event::FingerMoved { position, .. })
+            if state.is_dragging =>
+        {
+            let position = state.hit_test(
+                renderer,
+                content,
+                text_bounds,
+                position,
+                size,
+                font,
+            );
+
+            publish(Action::Select(Motion::To(position)));
+
+            return event::Status::Captured;
+        }
+        Event::Mouse(mouse::Event::WheelScrolled { delta })
+            if bounds.contains(cursor_position) =>
+        {
+            return state.scroll(delta, text_bounds);
+        }
+        Event::Keyboard(keyboard::Event::CharacterReceived(c)) => {
+            if let Some(focus) = &mut state.is_focused {
+                if !state.keyboard_modifiers.command() && !c.is_control() {
+                    publish(Action::Edit(Edit::Insert(c)));
+
+                    focus.updated_at = Instant::now();
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+            if let Some(focus) = &mut state.is_focused {
+                let modifiers = state.keyboard_modifiers;
+                focus.updated_at = Instant::now();
+
+                let motion = |motion| {
+                    if modifiers.shift() {
+                        Action::Select(motion)
+                    } else {
+                        Action::Move(motion)
+                    }
+                };
+
+                let is_jump = platform::is_jump_modifier_pressed(modifiers);
+
+                match key_code {
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        publish(Action::Edit(Edit::Enter));
+                    }
+                    keyboard::KeyCode::Backspace => {
+                        if is_jump && content.selection().is_none() {
+                            publish(Action::Select(Motion::WordLeft));
+                        }
+
+                        publish(Action::Edit(Edit::Backspace));
+                    }
+                    keyboard::KeyCode::Delete => {
+                        if is_jump && content.selection().is_none() {
+                            publish(Action::Select(Motion::WordRight));
+                        }
+
+                        publish(Action::Edit(Edit::Delete));
+                    }
+                    keyboard::KeyCode::Left => {
+                        publish(motion(if is_jump {
+                            Motion::WordLeft
+                        } else {
+                            Motion::Left
+                        }));
+                    }
+                    keyboard::KeyCode::Right => {
+                        publish(motion(if is_jump {
+                            Motion::WordRight
+                        } else {
+                            Motion::Right
+                        }));
+                    }
+                    keyboard::KeyCode::Up => {
+                        let target = state
+                            .vertical_motion(renderer, content, -1, size, font);
+
+                        publish(motion(target));
+                    }
+                    keyboard::KeyCode::Down => {
+                        let target = state
+                            .vertical_motion(renderer, content, 1, size, font);
+
+                        publish(motion(target));
+                    }
+                    keyboard::KeyCode::PageUp | keyboard::KeyCode::PageDown => {
+                        let line_height = state.rows.borrow().line_height();
+                        let page =
+                            (text_bounds.height / line_height).floor().max(1.0)
+                                as isize;
+
+                        let target = state.vertical_motion(
+                            renderer,
+                            content,
+                            if key_code == keyboard::KeyCode::PageUp {
+                                -page
+                            } else {
+                                page
+                            },
+                            size,
+                            font,
+                        );
+
+                        publish(motion(target));
+                    }
+                    keyboard::KeyCode::Home => {
+                        publish(motion(if modifiers.command() {
+                            Motion::DocumentStart
+                        } else {
+                            Motion::Home
+                        }));
+                    }
+                    keyboard::KeyCode::End => {
+                        publish(motion(if modifiers.command() {
+                            Motion::DocumentEnd
+                        } else {
+                            Motion::End
+                        }));
+                    }
+                    keyboard::KeyCode::C if modifiers.command() => {
+                        if let Some(selection) = content.selected_text() {
+                            clipboard.write(selection);
+                        }
+                    }
+                    keyboard::KeyCode::X if modifiers.command() => {
+                        if let Some(selection) = content.selected_text() {
+                            clipboard.write(selection);
+
+                            publish(Action::Edit(Edit::Delete));
+                        }
+                    }
+                    keyboard::KeyCode::V if modifiers.command() => {
+                        if let Some(contents) = clipboard.read() {
+                            publish(Action::Edit(Edit::Paste(Arc::new(
+                                contents,
+                            ))));
+                        }
+                    }
+                    keyboard::KeyCode::A if modifiers.command() => {
+                        publish(Action::SelectAll);
+                    }
+                    keyboard::KeyCode::Z if modifiers.command() => {
+                        if modifiers.shift() {
+                            publish(Action::Redo);
+                        } else {
+                            publish(Action::Undo);
+                        }
+                    }
+                    keyboard::KeyCode::Y if modifiers.command() => {
+                        publish(Action::Redo);
+                    }
+                    keyboard::KeyCode::Escape => {
+                        state.is_focused = None;
+                        state.is_dragging = false;
+
+                        state.keyboard_modifiers =
+                            keyboard::Modifiers::default();
+                    }
+                    keyboard::KeyCode::Tab => {
+                        return event::Status::Ignored;
+                    }
+                    _ => {}
+                }
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Keyboard(keyboard::Event::KeyReleased { key_code, .. })
+            if state.is_focused.is_some() =>
+        {
+            if key_code == keyboard::KeyCode::Tab {
+                return event::Status::Ignored;
+            }
+
+            return event::Status::Captured;
+        }
+        Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+            state.keyboard_modifiers = modifiers;
+        }
+        Event::Window(_, window::Event::RedrawRequested(now)) => {
+            if let Some(focus) = &mut state.is_focused {
+                focus.now = now;
+
+                let millis_until_redraw = CURSOR_BLINK_INTERVAL_MILLIS
+                    - (now - focus.updated_at).as_millis()
+                        % CURSOR_BLINK_INTERVAL_MILLIS;
+
+                shell.request_redraw(window::RedrawRequest::At(
+                    now + Duration::from_millis(millis_until_redraw as u64),
+                ));
+            }
+
+            state.scroll_to_cursor(renderer, content, text_bounds, size, font);
+        }
+        _ => {}
+    }
+
+    event::Status::Ignored
+}
+
+/// Draws the [`TextEditor`] with the given [`Renderer`].
+///
+/// [`Renderer`]: text::Renderer
+pub fn draw<Renderer>(
+    renderer: &mut Renderer,
+    theme: &Renderer::Theme,
+    layout: Layout<'_>,
+    cursor_position: Point,
+    state: &State,
+    content: &Content,
+    size: Option<u16>,
+    font: &Renderer::Font,
+    style: &<Renderer::Theme as StyleSheet>::Style,
+) where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    let bounds = layout.bounds();
+    let text_bounds = layout.children().next().unwrap().bounds();
+    let size = size.unwrap_or_else(|| renderer.default_size());
+
+    let is_mouse_over = bounds.contains(cursor_position);
+
+    let appearance = if state.is_focused() {
+        theme.focused(style)
+    } else if is_mouse_over {
+        theme.hovered(style)
+    } else {
+        theme.active(style)
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: appearance.border_radius.into(),
+            border_width: appearance.border_width,
+            border_color: appearance.border_color,
+            ..renderer::Quad::default()
+        },
+        appearance.background,
+    );
+
+    let mut rows = state.rows.borrow_mut();
+    rows.update(renderer, content, text_bounds.width, size, font);
+
+    let line_height = rows.line_height();
+    let offset = state
+        .offset
+        .min((rows.height() - text_bounds.height).max(0.0));
+
+    let first = (offset / line_height).floor() as usize;
+    let last = ((offset + text_bounds.height) / line_height).ceil() as usize;
+
+    let selection = content.selection();
+    let value_color = theme.value_color(style);
+    let selection_color = theme.selection_color(style);
+
+    let quad = |bounds| renderer::Quad {
+        bounds,
+        border_radius: 0.0.into(),
+        border_width: 0.0,
+        border_color: Color::TRANSPARENT,
+        ..renderer::Quad::default()
+    };
+
+    renderer.with_layer(text_bounds, |renderer| {
+        renderer.with_translation(Vector::new(0.0, -offset), |renderer| {
+            for (index, row) in rows
+                .rows()
+                .iter()
+                .enumerate()
+                .take(last.min(rows.rows().len()))
+                .skip(first)
+            {
+                let line = content.line(row.line).unwrap_or_default();
+                let y = text_bounds.y + index as f32 * line_height;
+
+                let measure = |column: usize| {
+                    renderer.measure_width(
+                        &line[content::byte_offset(line, row.start)
+                            ..content::byte_offset(line, column)],
+                        size,
+                        font.clone(),
+                    )
+                };
+
+                if let Some((start, end)) = selection {
+                    let row_start = Position::new(row.line, row.start);
+                    let row_end = Position::new(row.line, row.end);
+
+                    if start <= row_end && end >= row_start {
+                        let left = if start > row_start {
+                            measure(start.column)
+                        } else {
+                            0.0
+                        };
+
+                        let right = if end < row_end {
+                            measure(end.column)
+                        } else {
+                            // Selected line breaks are shown as a space
+                            measure(row.end)
+                                + if end.line > row.line {
+                                    f32::from(size) / 3.0
+                                } else {
+                                    0.0
+                                }
+                        };
+
+                        renderer.fill_quad(
+                            quad(Rectangle {
+                                x: text_bounds.x + left,
+                                y,
+                                width: right - left,
+                                height: line_height,
+                            }),
+                            selection_color,
+                        );
+                    }
+                }
+
+                renderer.fill_text(Text {
+                    content: &line[content::byte_offset(line, row.start)
+                        ..content::byte_offset(line, row.end)],
+                    bounds: Rectangle {
+                        x: text_bounds.x,
+                        y,
+                        width: f32::INFINITY,
+                        height: line_height,
+                    },
+                    size: f32::from(size),
+                    color: value_color,
+                    font: font.clone(),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    line_height: 1.0,
+                    wrap: text::Wrap::default(),
+                    overflow: text::Overflow::default(),
+                });
+            }
+
+            if let Some(focus) = &state.is_focused {
+                let is_cursor_visible = selection.is_none()
+                    && ((focus.now - focus.updated_at).as_millis()
+                        / CURSOR_BLINK_INTERVAL_MILLIS)
+                        % 2
+                        == 0;
+
+                if is_cursor_visible {
+                    let position = rows.locate(
+                        renderer,
+                        content,
+                        content.cursor(),
+                        size,
+                        font,
+                    );
+
+                    renderer.fill_quad(
+                        quad(Rectangle {
+                            x: text_bounds.x + position.x,
+                            y: text_bounds.y + position.y,
+                            width: 1.0,
+                            height: line_height,
+                        }),
+                        value_color,
+                    );
+                }
+            }
+        });
+    });
+}
+
+/// Computes the current [`mouse::Interaction`] of the [`TextEditor`].
+pub fn mouse_interaction(
+    layout: Layout<'_>,
+    cursor_position: Point,
+    is_editable: bool,
+) -> mouse::Interaction {
+    if is_editable && layout.bounds().contains(cursor_position) {
+        mouse::Interaction::Text
+    } else {
+        mouse::Interaction::default()
+    }
+}
+
+/// The state of a [`TextEditor`].
+#[derive(Debug, Default)]
+pub struct State {
+    is_focused: Option<Focus>,
+    is_dragging: bool,
+    keyboard_modifiers: keyboard::Modifiers,
+    offset: f32,
+    last_cursor: Option<(Position, (u64, u64))>,
+    vertical: Option<(Position, f32)>,
+    rows: RefCell<Rows>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Focus {
+    updated_at: Instant,
+    now: Instant,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`TextEditor`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`TextEditor`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused.is_some()
+    }
+
+    /// Focuses the [`TextEditor`].
+    pub fn focus(&mut self) {
+        let now = Instant::now();
+
+        self.is_focused = Some(Focus {
+            updated_at: now,
+            now,
+        });
+    }
+
+    /// Unfocuses the [`TextEditor`].
+    pub fn unfocus(&mut self) {
+        self.is_focused = None;
+    }
+
+    fn hit_test<Renderer>(
+        &self,
+        renderer: &Renderer,
+        content: &Content,
+        text_bounds: Rectangle,
+        cursor_position: Point,
+        size: u16,
+        font: &Renderer::Font,
+    ) -> Position
+    where
+        Renderer: text::Renderer,
+    {
+        self.rows.borrow().hit_test(
+            renderer,
+            content,
+            Point::new(
+                cursor_position.x - text_bounds.x,
+                cursor_position.y - text_bounds.y + self.offset,
+            ),
+            size,
+            font,
+        )
+    }
+
+    /// Computes the [`Motion`] that moves the cursor the given amount of
+    /// visual rows, keeping its horizontal position.
+    fn vertical_motion<Renderer>(
+        &mut self,
+        renderer: &Renderer,
+        content: &Content,
+        rows: isize,
+        size: u16,
+        font: &Renderer::Font,
+    ) -> Motion
+    where
+        Renderer: text::Renderer,
+    {
+        let layout = self.rows.borrow();
+        let cursor = content.cursor();
+        let row = layout.row_of(cursor) as isize + rows;
+
+        if row < 0 {
+            return Motion::DocumentStart;
+        } else if row as usize >= layout.rows().len() {
+            return Motion::DocumentEnd;
+        }
+
+        let x = match self.vertical {
+            Some((position, x)) if position == cursor => x,
+            _ => layout.locate(renderer, content, cursor, size, font).x,
+        };
+
+        let line_height = layout.line_height();
+
+        let target = layout.hit_test(
+            renderer,
+            content,
+            Point::new(x, (row as f32 + 0.5) * line_height),
+            size,
+            font,
+        );
+
+        drop(layout);
+        self.vertical = Some((target, x));
+
+        Motion::To(target)
+    }
+
+    fn scroll(
+        &mut self,
+        delta: mouse::ScrollDelta,
+        text_bounds: Rectangle,
+    ) -> event::Status {
+        let rows = self.rows.borrow();
+        let max_offset = (rows.height() - text_bounds.height).max(0.0);
+
+        if max_offset == 0.0 {
+            return event::Status::Ignored;
+        }
+
+        let delta = match delta {
+            mouse::ScrollDelta::Lines { y, .. } => y * rows.line_height() * 3.0,
+            mouse::ScrollDelta::Pixels { y, .. } => y,
+        };
+
+        self.offset =
+            (self.offset.min(max_offset) - delta).clamp(0.0, max_offset);
+
+        event::Status::Captured
+    }
+
+    /// Scrolls the [`TextEditor`] to keep the cursor visible, if it moved
+    /// or the text changed since the last time.
+    fn scroll_to_cursor<Renderer>(
+        &mut self,
+        renderer: &Renderer,
+        content: &Content,
+        text_bounds: Rectangle,
+        size: u16,
+        font: &Renderer::Font,
+    ) where
+        Renderer: text::Renderer,
+    {
+        let cursor = (content.cursor(), content.version());
+
+        if self.last_cursor == Some(cursor) {
+            return;
+        }
+
+        let rows = self.rows.borrow();
+        let position = rows.locate(renderer, content, cursor.0, size, font);
+        let max_offset = (rows.height() - text_bounds.height).max(0.0);
+
+        // Nothing to do if the editor was just created
+        if self.last_cursor.is_some() {
+            self.offset = self
+                .offset
+                .min(position.y)
+                .max(position.y + rows.line_height() - text_bounds.height)
+                .clamp(0.0, max_offset);
+        }
+
+        drop(rows);
+        self.last_cursor = Some(cursor);
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        State::focus(self)
+    }
+
+    fn unfocus(&mut self) {
+        State::unfocus(self)
+    }
+}
+
+mod platform {
+    use crate::keyboard;
+
+    pub fn is_jump_modifier_pressed(modifiers: keyboard::Modifiers) -> bool {
+        if cfg!(target_os = "macos") {
+            modifiers.alt()
+        } else {
+            modifiers.control()
+        }
+    }
+}
+
+const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
@@ -0,0 +1,639 @@
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::Arc;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// The contents of a [`TextEditor`], including its cursor, selection and
+/// history.
+///
+/// A [`Content`] is owned by the application and borrowed by the
+/// [`TextEditor`] during `view`, which means large documents are never
+/// copied. The [`TextEditor`] produces [`Action`]s that must be applied with
+/// [`Content::perform`].
+///
+/// [`TextEditor`]: crate::widget::TextEditor
+#[derive(Debug)]
+pub struct Content {
+    id: u64,
+    version: u64,
+    lines: Vec<String>,
+    cursor: Position,
+    anchor: Option<Position>,
+    preferred_column: Option<usize>,
+    undo: Vec<Change>,
+    redo: Vec<Change>,
+}
+
+/// A position in a [`Content`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Position {
+    /// The index of the line.
+    pub line: usize,
+    /// The index of the character in the line.
+    pub column: usize,
+}
+
+impl Position {
+    /// Creates a new [`Position`] with the given line and column.
+    pub const fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+}
+
+/// An interaction with a [`Content`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Moves the cursor with the given [`Motion`], clearing the selection.
+    Move(Motion),
+    /// Extends the selection with the given [`Motion`].
+    Select(Motion),
+    /// Selects the word at the cursor.
+    SelectWord,
+    /// Selects the line at the cursor.
+    SelectLine,
+    /// Selects the whole [`Content`].
+    SelectAll,
+    /// Performs an [`Edit`] at the cursor.
+    Edit(Edit),
+    /// Reverts the last [`Edit`].
+    Undo,
+    /// Reapplies the last reverted [`Edit`].
+    Redo,
+}
+
+impl Action {
+    /// Returns whether the [`Action`] changes the text of a [`Content`].
+    pub fn is_edit(&self) -> bool {
+        matches!(self, Self::Edit(_) | Self::Undo | Self::Redo)
+    }
+}
+
+/// A movement of the cursor of a [`Content`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Motion {
+    /// One character to the left.
+    Left,
+    /// One character to the right.
+    Right,
+    /// One line up.
+    Up,
+    /// One line down.
+    Down,
+    /// To the start of the previous word.
+    WordLeft,
+    /// To the end of the next word.
+    WordRight,
+    /// To the start of the line.
+    Home,
+    /// To the end of the line.
+    End,
+    /// To the start of the [`Content`].
+    DocumentStart,
+    /// To the end of the [`Content`].
+    DocumentEnd,
+    /// To the given [`Position`].
+    To(Position),
+}
+
+/// A modification of the text of a [`Content`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edit {
+    /// Inserts a character.
+    Insert(char),
+    /// Inserts some text.
+    Paste(Arc<String>),
+    /// Breaks the line.
+    Enter,
+    /// Deletes the selection or the character before the cursor.
+    Backspace,
+    /// Deletes the selection or the character after the cursor.
+    Delete,
+}
+
+/// A reversible replacement of text.
+#[derive(Debug, Clone)]
+struct Change {
+    start: Position,
+    removed: String,
+    inserted: String,
+    cursor: Position,
+    anchor: Option<Position>,
+}
+
+impl Content {
+    /// Creates an empty [`Content`].
+    pub fn new() -> Self {
+        Self::with_text("")
+    }
+
+    /// Creates a [`Content`] with the given text.
+    pub fn with_text(text: &str) -> Self {
+        Self {
+            id: NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+            version: 0,
+            lines: split(text),
+            cursor: Position::default(),
+            anchor: None,
+            preferred_column: None,
+            undo: Vec::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    /// Returns the full text of the [`Content`].
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Returns the amount of lines of the [`Content`].
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns the line with the given index, if it exists.
+    pub fn line(&self, index: usize) -> Option<&str> {
+        self.lines.get(index).map(String::as_str)
+    }
+
+    /// Returns an iterator over the lines of the [`Content`].
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(String::as_str)
+    }
+
+    /// Returns the [`Position`] of the cursor.
+    pub fn cursor(&self) -> Position {
+        self.cursor
+    }
+
+    /// Returns the start and end [`Position`] of the selection, if any.
+    pub fn selection(&self) -> Option<(Position, Position)> {
+        let anchor = self.anchor?;
+
+        if anchor == self.cursor {
+            None
+        } else {
+            Some((anchor.min(self.cursor), anchor.max(self.cursor)))
+        }
+    }
+
+    /// Returns the selected text, if any.
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.selection()?;
+
+        Some(self.slice(start, end))
+    }
+
+    /// Returns whether there is an [`Edit`] that can be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns whether there is an [`Edit`] that can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Performs an [`Action`] on the [`Content`].
+    pub fn perform(&mut self, action: Action) {
+        match action {
+            Action::Move(motion) => {
+                let column = self.cursor.column;
+
+                if let (Some((start, end)), Motion::Left | Motion::Right) =
+                    (self.selection(), motion)
+                {
+                    self.cursor =
+                        if motion == Motion::Left { start } else { end };
+                } else {
+                    self.cursor = self.motion(motion);
+                }
+
+                self.anchor = None;
+                self.update_preferred_column(motion, column);
+            }
+            Action::Select(motion) => {
+                let column = self.cursor.column;
+
+                if self.anchor.is_none() {
+                    self.anchor = Some(self.cursor);
+                }
+
+                self.cursor = self.motion(motion);
+                self.update_preferred_column(motion, column);
+            }
+            Action::SelectWord => {
+                let line = &self.lines[self.cursor.line];
+                let (start, end) = word_at(line, self.cursor.column);
+
+                self.anchor = Some(Position::new(self.cursor.line, start));
+                self.cursor = Position::new(self.cursor.line, end);
+                self.preferred_column = None;
+            }
+            Action::SelectLine => {
+                let line = self.cursor.line;
+
+                self.anchor = Some(Position::new(line, 0));
+                self.cursor = if line + 1 < self.lines.len() {
+                    Position::new(line + 1, 0)
+                } else {
+                    Position::new(line, self.line_length(line))
+                };
+                self.preferred_column = None;
+            }
+            Action::SelectAll => {
+                self.anchor = Some(Position::default());
+                self.cursor = self.end();
+                self.preferred_column = None;
+            }
+            Action::Edit(edit) => {
+                self.edit(edit);
+                self.preferred_column = None;
+            }
+            Action::Undo => {
+                if let Some(change) = self.undo.pop() {
+                    let end = end_of(change.start, &change.inserted);
+                    let _ = self.replace(change.start, end, &change.removed);
+
+                    self.cursor = change.cursor;
+                    self.anchor = change.anchor;
+                    self.redo.push(change);
+                }
+
+                self.preferred_column = None;
+            }
+            Action::Redo => {
+                if let Some(change) = self.redo.pop() {
+                    let end = end_of(change.start, &change.removed);
+
+                    self.cursor =
+                        self.replace(change.start, end, &change.inserted);
+                    self.anchor = None;
+                    self.undo.push(change);
+                }
+
+                self.preferred_column = None;
+            }
+        }
+    }
+
+    /// Returns the unique identifier and the version of the [`Content`].
+    ///
+    /// The version changes every time the text changes.
+    pub(crate) fn version(&self) -> (u64, u64) {
+        (self.id, self.version)
+    }
+
+    fn edit(&mut self, edit: Edit) {
+        let (start, end) =
+            self.selection().unwrap_or((self.cursor, self.cursor));
+
+        let (start, end, inserted) = match edit {
+            Edit::Insert(c) => (start, end, c.to_string()),
+            Edit::Paste(text) => (start, end, text.replace('\r', "")),
+            Edit::Enter => (start, end, String::from("\n")),
+            Edit::Backspace if start == end => {
+                (self.previous(start), end, String::new())
+            }
+            Edit::Delete if start == end => {
+                (start, self.next(end), String::new())
+            }
+            Edit::Backspace | Edit::Delete => (start, end, String::new()),
+        };
+
+        if start == end && inserted.is_empty() {
+            return;
+        }
+
+        let change = Change {
+            start,
+            removed: self.slice(start, end),
+            inserted,
+            cursor: self.cursor,
+            anchor: self.anchor,
+        };
+
+        self.cursor = self.replace(change.start, end, &change.inserted);
+        self.anchor = None;
+        self.redo.clear();
+
+        // Consecutive characters of the same word are undone together
+        let merged = match self.undo.last_mut() {
+            Some(last)
+                if last.removed.is_empty()
+                    && change.removed.is_empty()
+                    && !last.inserted.ends_with(char::is_whitespace)
+                    && change.inserted.chars().count() == 1
+                    && !change.inserted.contains(char::is_whitespace)
+                    && end_of(last.start, &last.inserted) == change.start =>
+            {
+                last.inserted.push_str(&change.inserted);
+                true
+            }
+            _ => false,
+        };
+
+        if !merged {
+            self.undo.push(change);
+        }
+    }
+
+    /// Replaces the text between the given positions, returning the end of
+    /// the inserted text.
+    fn replace(
+        &mut self,
+        start: Position,
+        end: Position,
+        text: &str,
+    ) -> Position {
+        let suffix = {
+            let line = &self.lines[end.line];
+
+            line[byte_offset(line, end.column)..].to_string()
+        };
+
+        let mut new_lines = split(text);
+
+        let inserted_end = Position::new(
+            start.line + new_lines.len() - 1,
+            new_lines
+                .last()
+                .map(|line| line.chars().count())
+                .unwrap_or(0)
+                + if new_lines.len() == 1 {
+                    start.column
+                } else {
+                    0
+                },
+        );
+
+        {
+            let line = &mut self.lines[start.line];
+            let offset = byte_offset(line, start.column);
+
+            line.truncate(offset);
+            line.push_str(&new_lines.remove(0));
+        }
+
+        let _ = self.lines.splice(start.line + 1..=end.line, new_lines);
+        self.lines[inserted_end.line].push_str(&suffix);
+
+        self.version += 1;
+
+        inserted_end
+    }
+
+    fn slice(&self, start: Position, end: Position) -> String {
+        if start.line == end.line {
+            let line = &self.lines[start.line];
+
+            return line[byte_offset(line, start.column)
+                ..byte_offset(line, end.column)]
+                .to_string();
+        }
+
+        let first = &self.lines[start.line];
+        let last = &self.lines[end.line];
+
+        let mut text = first[byte_offset(first, start.column)..].to_string();
+
+        for line in &self.lines[start.line + 1..end.line] {
+            text.push('\n');
+            text.push_str(line);
+        }
+
+        text.push('\n');
+        text.push_str(&last[..byte_offset(last, end.column)]);
+
+        text
+    }
+
+    fn motion(&self, motion: Motion) -> Position {
+        let Position { line, column } = self.cursor;
+
+        match motion {
+            Motion::Left => self.previous(self.cursor),
+            Motion::Right => self.next(self.cursor),
+            Motion::Up if line == 0 => Position::default(),
+            Motion::Up => Position::new(
+                line - 1,
+                self.preferred_column
+                    .unwrap_or(column)
+                    .min(self.line_length(line - 1)),
+            ),
+            Motion::Down if line + 1 >= self.lines.len() => self.end(),
+            Motion::Down => Position::new(
+                line + 1,
+                self.preferred_column
+                    .unwrap_or(column)
+                    .min(self.line_length(line + 1)),
+            ),
+            Motion::WordLeft => {
+                if column == 0 {
+                    return self.previous(self.cursor);
+                }
+
+                let chars: Vec<char> = self.lines[line].chars().collect();
+                let mut index = column;
+
+                while index > 0 && chars[index - 1].is_whitespace() {
+                    index -= 1;
+                }
+
+                while index > 0 && !chars[index - 1].is_whitespace() {
+                    index -= 1;
+                }
+
+                Position::new(line, index)
+            }
+            Motion::WordRight => {
+                let chars: Vec<char> = self.lines[line].chars().collect();
+
+                if column >= chars.len() {
+                    return self.next(self.cursor);
+                }
+
+                let mut index = column;
+
+                while index < chars.len() && chars[index].is_whitespace() {
+                    index += 1;
+                }
+
+                while index < chars.len() && !chars[index].is_whitespace() {
+                    index += 1;
+                }
+
+                Position::new(line, index)
+            }
+            Motion::Home => Position::new(line, 0),
+            Motion::End => Position::new(line, self.line_length(line)),
+            Motion::DocumentStart => Position::default(),
+            Motion::DocumentEnd => self.end(),
+            Motion::To(position) => self.clamp(position),
+        }
+    }
+
+    fn update_preferred_column(&mut self, motion: Motion, column: usize) {
+        self.preferred_column = match motion {
+            Motion::Up | Motion::Down => {
+                Some(self.preferred_column.unwrap_or(column))
+            }
+            _ => None,
+        };
+    }
+
+    fn previous(&self, position: Position) -> Position {
+        if position.column > 0 {
+            Position::new(position.line, position.column - 1)
+        } else if position.line > 0 {
+            Position::new(
+                position.line - 1,
+                self.line_length(position.line - 1),
+            )
+        } else {
+            position
+        }
+    }
+
+    fn next(&self, position: Position) -> Position {
+        if position.column < self.line_length(position.line) {
+            Position::new(position.line, position.column + 1)
+        } else if position.line + 1 < self.lines.len() {
+            Position::new(position.line + 1, 0)
+        } else {
+            position
+        }
+    }
+
+    fn end(&self) -> Position {
+        let line = self.lines.len() - 1;
+
+        Position::new(line, self.line_length(line))
+    }
+
+    fn clamp(&self, position: Position) -> Position {
+        let line = position.line.min(self.lines.len() - 1);
+
+        Position::new(line, position.column.min(self.line_length(line)))
+    }
+
+    fn line_length(&self, line: usize) -> usize {
+        self.lines[line].chars().count()
+    }
+}
+
+impl Default for Content {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for Content {
+    fn clone(&self) -> Self {
+        Self {
+            id: NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+            version: self.version,
+            lines: self.lines.clone(),
+            cursor: self.cursor,
+            anchor: self.anchor,
+            preferred_column: self.preferred_column,
+            undo: self.undo.clone(),
+            redo: self.redo.clone(),
+        }
+    }
+}
+
+fn split(text: &str) -> Vec<String> {
+    text.split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line).to_string())
+        .collect()
+}
+
+/// Returns the byte offset of the character with the given index.
+pub(crate) fn byte_offset(line: &str, column: usize) -> usize {
+    line.char_indices()
+        .nth(column)
+        .map(|(offset, _)| offset)
+        .unwrap_or(line.len())
+}
+
+/// Returns the [`Position`] at the end of the given text, if it were
+/// inserted at `start`.
+fn end_of(start: Position, text: &str) -> Position {
+    let lines = text.split('\n').count();
+    let last = text.rsplit('\n').next().unwrap_or("").chars().count();
+
+    if lines == 1 {
+        Position::new(start.line, start.column + last)
+    } else {
+        Position::new(start.line + lines - 1, last)
+    }
+}
+
+/// Returns the start and end columns of the word at the given column.
+fn word_at(line: &str, column: usize) -> (usize, usize) {
+    let chars: Vec<char> = line.chars().collect();
+
+    if chars.is_empty() {
+        return (0, 0);
+    }
+
+    let column = column.min(chars.len() - 1);
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let kind = is_word(chars[column]);
+
+    let mut start = column;
+    let mut end = column + 1;
+
+    while start > 0 && is_word(chars[start - 1]) == kind {
+        start -= 1;
+    }
+
+    while end < chars.len() && is_word(chars[end]) == kind {
+        end += 1;
+    }
+
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edits_can_be_undone_and_redone() {
+        let mut content = Content::with_text("Hello\nworld");
+
+        content.perform(Action::Move(Motion::DocumentEnd));
+        content.perform(Action::Edit(Edit::Enter));
+        content.perform(Action::Edit(Edit::Insert('!')));
+
+        assert_eq!(content.text(), "Hello\nworld\n!");
+        assert_eq!(content.cursor(), Position::new(2, 1));
+
+        content.perform(Action::Undo);
+        assert_eq!(content.text(), "Hello\nworld\n");
+
+        content.perform(Action::Undo);
+        assert_eq!(content.text(), "Hello\nworld");
+        assert_eq!(content.cursor(), Position::new(1, 5));
+
+        content.perform(Action::Redo);
+        content.perform(Action::Redo);
+        assert_eq!(content.text(), "Hello\nworld\n!");
+    }
+
+    #[test]
+    fn selection_spans_multiple_lines() {
+        let mut content = Content::with_text("one\ntwo\nthree");
+
+        content.perform(Action::Move(Motion::To(Position::new(0, 1))));
+        content.perform(Action::Select(Motion::To(Position::new(2, 2))));
+
+        assert_eq!(content.selected_text().as_deref(), Some("ne\ntwo\nth"));
+
+        content.perform(Action::Edit(Edit::Backspace));
+
+        assert_eq!(content.text(), "oree");
+        assert_eq!(content.cursor(), Position::new(0, 1));
+    }
+}
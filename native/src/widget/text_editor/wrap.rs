@@ -0,0 +1,325 @@
+//! Break the lines of a [`Content`] into visual rows.
+use crate::alignment;
+use crate::text;
+use crate::widget::text_editor::content::{byte_offset, Content, Position};
+use crate::{Point, Size};
+
+use std::collections::hash_map::{self, DefaultHasher, HashMap};
+use std::hash::{Hash, Hasher};
+
+/// The visual rows of a [`Content`], wrapped to a certain width.
+///
+/// The rows of every line are cached by the hash of its contents, so lines
+/// are only wrapped again when they change—even if they move around.
+#[derive(Debug, Default)]
+pub struct Rows {
+    key: Option<Key>,
+    cache: HashMap<u64, Vec<(usize, usize)>>,
+    first_row: Vec<usize>,
+    rows: Vec<Row>,
+    line_height: f32,
+}
+
+/// A visual row of a [`Content`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Row {
+    /// The index of the line of the [`Row`].
+    pub line: usize,
+    /// The column where the [`Row`] starts.
+    pub start: usize,
+    /// The column where the [`Row`] ends.
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Key {
+    content: (u64, u64),
+    width: f32,
+    size: u16,
+}
+
+impl Rows {
+    /// Wraps the lines of the [`Content`] to the given width, reusing the
+    /// rows of any line that has not changed.
+    pub fn update<Renderer>(
+        &mut self,
+        renderer: &Renderer,
+        content: &Content,
+        width: f32,
+        size: u16,
+        font: &Renderer::Font,
+    ) where
+        Renderer: text::Renderer,
+    {
+        let key = Key {
+            content: content.version(),
+            width,
+            size,
+        };
+
+        if self.key == Some(key) {
+            return;
+        }
+
+        let is_layout_valid = self
+            .key
+            .map(|old| old.width == width && old.size == size)
+            .unwrap_or(false);
+
+        if !is_layout_valid {
+            self.cache.clear();
+        }
+
+        // Only the lines still in the content are kept in the new cache
+        let mut cache = HashMap::with_capacity(content.line_count());
+
+        self.first_row.clear();
+        self.rows.clear();
+
+        for (i, line) in content.lines().enumerate() {
+            let hash = {
+                let mut hasher = DefaultHasher::new();
+                line.hash(&mut hasher);
+
+                hasher.finish()
+            };
+
+            let rows = match cache.entry(hash) {
+                hash_map::Entry::Occupied(entry) => entry.into_mut(),
+                hash_map::Entry::Vacant(entry) => {
+                    entry.insert(self.cache.remove(&hash).unwrap_or_else(
+                        || wrap(renderer, line, width, size, font),
+                    ))
+                }
+            };
+
+            self.first_row.push(self.rows.len());
+            self.rows.extend(rows.iter().map(|&(start, end)| Row {
+                line: i,
+                start,
+                end,
+            }));
+        }
+
+        self.cache = cache;
+        self.line_height = renderer
+            .measure(
+                " ",
+                size,
+                1.0,
+                font.clone(),
+                Size::INFINITY,
+                text::Wrap::None,
+                text::Overflow::Clip,
+            )
+            .1;
+        self.key = Some(key);
+    }
+
+    /// Returns the visual rows.
+    pub fn rows(&self) -> &[Row] {
+        &self.rows
+    }
+
+    /// Returns the height of a single row.
+    pub fn line_height(&self) -> f32 {
+        self.line_height
+    }
+
+    /// Returns the total height of the rows.
+    pub fn height(&self) -> f32 {
+        self.rows.len() as f32 * self.line_height
+    }
+
+    /// Returns the index of the row containing the given [`Position`].
+    pub fn row_of(&self, position: Position) -> usize {
+        let first = self.first_row[position.line];
+        let last = self
+            .first_row
+            .get(position.line + 1)
+            .copied()
+            .unwrap_or(self.rows.len());
+
+        self.rows[first..last]
+            .iter()
+            .rposition(|row| row.start <= position.column)
+            .map(|index| first + index)
+            .unwrap_or(first)
+    }
+
+    /// Returns the location of the given [`Position`], relative to the
+    /// top left corner of the first row.
+    pub fn locate<Renderer>(
+        &self,
+        renderer: &Renderer,
+        content: &Content,
+        position: Position,
+        size: u16,
+        font: &Renderer::Font,
+    ) -> Point
+    where
+        Renderer: text::Renderer,
+    {
+        let index = self.row_of(position);
+        let row = self.rows[index];
+        let line = content.line(row.line).unwrap_or_default();
+
+        let x = renderer.measure_width(
+            &line[byte_offset(line, row.start)
+                ..byte_offset(line, position.column)],
+            size,
+            font.clone(),
+        );
+
+        Point::new(x, index as f32 * self.line_height)
+    }
+
+    /// Returns the [`Position`] closest to the given point, relative to the
+    /// top left corner of the first row.
+    pub fn hit_test<Renderer>(
+        &self,
+        renderer: &Renderer,
+        content: &Content,
+        point: Point,
+        size: u16,
+        font: &Renderer::Font,
+    ) -> Position
+    where
+        Renderer: text::Renderer,
+    {
+        if self.rows.is_empty() {
+            return Position::default();
+        }
+
+        let index = ((point.y / self.line_height).max(0.0) as usize)
+            .min(self.rows.len() - 1);
+
+        let row = self.rows[index];
+        let line = content.line(row.line).unwrap_or_default();
+        let text =
+            &line[byte_offset(line, row.start)..byte_offset(line, row.end)];
+
+        let offset = renderer
+            .hit_test(
+                text::Paragraph {
+                    content: text,
+                    bounds: Size::INFINITY,
+                    size: size.into(),
+                    font: font.clone(),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    line_height: 1.0,
+                    wrap: text::Wrap::None,
+                    overflow: text::Overflow::Clip,
+                },
+                Point::new(point.x, self.line_height / 2.0),
+                true,
+            )
+            .map(text::Hit::cursor)
+            .unwrap_or(if point.x > 0.0 {
+                row.end - row.start
+            } else {
+                0
+            });
+
+        let is_last_row = self
+            .rows
+            .get(index + 1)
+            .map(|next| next.line != row.line)
+            .unwrap_or(true);
+
+        // The end of a wrapped row is drawn at the start of the next one
+        let column = if is_last_row {
+            row.start + offset
+        } else {
+            (row.start + offset).min(row.end.saturating_sub(1).max(row.start))
+        };
+
+        Position::new(row.line, column)
+    }
+}
+
+/// Breaks a line into rows that fit the given width, returning the start
+/// and end columns of every row.
+///
+/// Lines are broken at whitespace. Words that do not fit in a row on their
+/// own are broken at any character.
+fn wrap<Renderer>(
+    renderer: &Renderer,
+    line: &str,
+    width: f32,
+    size: u16,
+    font: &Renderer::Font,
+) -> Vec<(usize, usize)>
+where
+    Renderer: text::Renderer,
+{
+    let chars: Vec<char> = line.chars().collect();
+
+    if !width.is_finite() || chars.is_empty() {
+        return vec![(0, chars.len())];
+    }
+
+    let measure = |start: usize, end: usize| {
+        renderer.measure_width(
+            &line[byte_offset(line, start)..byte_offset(line, end)],
+            size,
+            font.clone(),
+        )
+    };
+
+    let mut rows = Vec::new();
+    let mut start = 0;
+    let mut index = 0;
+
+    while index < chars.len() {
+        let mut word_end = index;
+
+        while word_end < chars.len() && !chars[word_end].is_whitespace() {
+            word_end += 1;
+        }
+
+        let mut end = word_end;
+
+        while end < chars.len() && chars[end].is_whitespace() {
+            end += 1;
+        }
+
+        if word_end > index && measure(start, word_end) > width {
+            if index > start {
+                rows.push((start, index));
+                start = index;
+
+                continue;
+            }
+
+            // Find the longest prefix of the word that fits, keeping at
+            // least one character per row
+            let (mut low, mut high) = (index + 2, word_end + 1);
+
+            while low < high {
+                let middle = (low + high) / 2;
+
+                if measure(start, middle) <= width {
+                    low = middle + 1;
+                } else {
+                    high = middle;
+                }
+            }
+
+            let split = low - 1;
+
+            rows.push((start, split));
+            start = split;
+            index = split;
+
+            continue;
+        }
+
+        index = end;
+    }
+
+    rows.push((start, chars.len()));
+
+    rows
+}
@@ -11,6 +11,7 @@ pub use value::Value;
 
 use editor::Editor;
 
+use crate::accessibility;
 use crate::alignment;
 use crate::event::{self, Event};
 use crate::keyboard;
@@ -229,14 +230,30 @@ where
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
         let state = tree.state.downcast_mut::<State>();
 
+        let value = if self.is_secure {
+            self.value.secure().to_string()
+        } else {
+            self.value.to_string()
+        };
+
+        let node = accessibility::Node::new(
+            accessibility::Role::TextInput,
+            layout.bounds(),
+        )
+        .id(self.id.as_ref().map(|id| &id.0))
+        .label(self.placeholder.as_str())
+        .value(accessibility::Value::Text(value))
+        .focused(state.is_focused());
+
         operation.focusable(state, self.id.as_ref().map(|id| &id.0));
         operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+        operation.accessible(&node, &mut |_| {});
     }
 
     fn on_event(
@@ -422,7 +439,10 @@ where
     Renderer: text::Renderer,
 {
     match event {
-        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        Event::Mouse(mouse::Event::ButtonPressed {
+            button: mouse::Button::Left,
+            ..
+        })
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
             let state = state();
             let is_clicked = layout.bounds().contains(cursor_position);
@@ -444,10 +464,14 @@ where
                 let text_layout = layout.children().next().unwrap();
                 let target = cursor_position.x - text_layout.bounds().x;
 
-                let click =
-                    mouse::Click::new(cursor_position, state.last_click);
+                let kind = match event {
+                    Event::Mouse(mouse::Event::ButtonPressed {
+                        click, ..
+                    }) => click.kind(),
+                    _ => click::Kind::Single,
+                };
 
-                match click.kind() {
+                match kind {
                     click::Kind::Single => {
                         let position = if target > 0.0 {
                             let value = if is_secure {
@@ -509,8 +533,6 @@ where
                     }
                 }
 
-                state.last_click = Some(click);
-
                 return event::Status::Captured;
             }
         }
@@ -782,7 +804,7 @@ where
 
             state.keyboard_modifiers = modifiers;
         }
-        Event::Window(window::Event::RedrawRequested(now)) => {
+        Event::Window(_, window::Event::RedrawRequested(now)) => {
             let state = state();
 
             if let Some(focus) = &mut state.is_focused {
@@ -845,6 +867,7 @@ pub fn draw<Renderer>(
             border_radius: appearance.border_radius.into(),
             border_width: appearance.border_width,
             border_color: appearance.border_color,
+            ..renderer::Quad::default()
         },
         appearance.background,
     );
@@ -883,6 +906,7 @@ pub fn draw<Renderer>(
                             border_radius: 0.0.into(),
                             border_width: 0.0,
                             border_color: Color::TRANSPARENT,
+                            ..renderer::Quad::default()
                         },
                         theme.value_color(style),
                     ))
@@ -930,6 +954,7 @@ pub fn draw<Renderer>(
                             border_radius: 0.0.into(),
                             border_width: 0.0,
                             border_color: Color::TRANSPARENT,
+                            ..renderer::Quad::default()
                         },
                         theme.selection_color(style),
                     )),
@@ -972,6 +997,9 @@ pub fn draw<Renderer>(
             size: f32::from(size),
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Center,
+            line_height: 1.0,
+            wrap: text::Wrap::default(),
+            overflow: text::Overflow::default(),
         });
     };
 
@@ -1002,7 +1030,6 @@ pub struct State {
     is_focused: Option<Focus>,
     is_dragging: bool,
     is_pasting: Option<Value>,
-    last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
     // TODO: Add stateful horizontal scrolling offset
@@ -1026,7 +1053,6 @@ impl State {
             is_focused: None,
             is_dragging: false,
             is_pasting: None,
-            last_click: None,
             cursor: Cursor::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
         }
@@ -1200,10 +1226,17 @@ where
 
     renderer
         .hit_test(
-            &value.to_string(),
-            size.into(),
-            font,
-            Size::INFINITY,
+            text::Paragraph {
+                content: &value.to_string(),
+                bounds: Size::INFINITY,
+                size: size.into(),
+                font,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                line_height: 1.0,
+                wrap: text::Wrap::None,
+                overflow: text::Overflow::Clip,
+            },
             Point::new(x + offset, text_bounds.height / 2.0),
             true,
         )
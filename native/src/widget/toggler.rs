@@ -1,11 +1,16 @@
 //! Show toggle controls using togglers.
+use crate::accessibility;
 use crate::alignment;
 use crate::event;
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
-use crate::widget::{self, Row, Text, Tree};
+use crate::widget::focus_ring;
+use crate::widget::operation::{self, Operation};
+use crate::widget::tree::{self, Tree};
+use crate::widget::{self, Row, Text};
 use crate::{
     Alignment, Clipboard, Element, Event, Layout, Length, Point, Rectangle,
     Shell, Widget,
@@ -138,6 +143,14 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet + widget::text::StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -178,9 +191,33 @@ where
         row.layout(renderer, limits)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        let mut node = accessibility::Node::new(
+            accessibility::Role::Switch,
+            layout.bounds(),
+        )
+        .checked(self.is_toggled)
+        .focused(state.is_focused);
+
+        if let Some(label) = &self.label {
+            node = node.label(label.as_str());
+        }
+
+        operation.accessible(&node, &mut |_| {});
+        operation.focusable(state, None);
+    }
+
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor_position: Point,
@@ -189,7 +226,12 @@ where
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+            Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Left,
+                ..
+            }) => {
+                tree.state.downcast_mut::<State>().is_focused = false;
+
                 let mouse_over = layout.bounds().contains(cursor_position);
 
                 if mouse_over {
@@ -200,6 +242,18 @@ where
                     event::Status::Ignored
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space | keyboard::KeyCode::Enter,
+                ..
+            }) => {
+                if tree.state.downcast_ref::<State>().is_focused {
+                    shell.publish((self.on_toggle)(!self.is_toggled));
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
             _ => event::Status::Ignored,
         }
     }
@@ -221,7 +275,7 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         style: &renderer::Style,
@@ -251,6 +305,9 @@ where
                 Default::default(),
                 self.text_alignment,
                 alignment::Vertical::Center,
+                1.0,
+                crate::text::Wrap::default(),
+                crate::text::Overflow::default(),
             );
         }
 
@@ -283,10 +340,24 @@ where
                 border_color: style
                     .background_border
                     .unwrap_or(style.background),
+                ..renderer::Quad::default()
             },
             style.background,
         );
 
+        if tree.state.downcast_ref::<State>().is_focused {
+            let ring = theme.focus_ring(&self.style);
+
+            focus_ring::draw(
+                renderer,
+                toggler_background_bounds,
+                focus_ring::Ring {
+                    border_radius: border_radius + ring.offset + ring.width,
+                    ..ring
+                },
+            );
+        }
+
         let toggler_foreground_bounds = Rectangle {
             x: bounds.x
                 + if self.is_toggled {
@@ -307,12 +378,32 @@ where
                 border_color: style
                     .foreground_border
                     .unwrap_or(style.foreground),
+                ..renderer::Quad::default()
             },
             style.foreground,
         );
     }
 }
 
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    is_focused: bool,
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 impl<'a, Message, Renderer> From<Toggler<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
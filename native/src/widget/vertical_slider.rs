@@ -5,7 +5,11 @@ use std::ops::RangeInclusive;
 
 pub use iced_style::slider::{Appearance, Handle, HandleShape, StyleSheet};
 
+use crate::accessibility;
 use crate::event::{self, Event};
+use crate::keyboard;
+use crate::widget::focus_ring;
+use crate::widget::operation::{self, Operation};
 use crate::widget::tree::{self, Tree};
 use crate::{
     layout, mouse, renderer, touch, Background, Clipboard, Color, Element,
@@ -174,6 +178,33 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.accessible(
+            &accessibility::Node::new(
+                accessibility::Role::Slider,
+                layout.bounds(),
+            )
+            .value(accessibility::Value::Numeric {
+                value: self.value.into(),
+                min: (*self.range.start()).into(),
+                max: (*self.range.end()).into(),
+                step: Some(self.step.into()),
+            })
+            .focused(state.is_focused),
+            &mut |_| {},
+        );
+
+        operation.focusable(state, None);
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -304,8 +335,13 @@ where
     };
 
     match event {
-        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        Event::Mouse(mouse::Event::ButtonPressed {
+            button: mouse::Button::Left,
+            ..
+        })
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            state.is_focused = false;
+
             if layout.bounds().contains(cursor_position) {
                 change();
                 state.is_dragging = true;
@@ -333,6 +369,43 @@ where
                 return event::Status::Captured;
             }
         }
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+            if state.is_focused =>
+        {
+            let step = step.into();
+            let start = (*range.start()).into();
+            let end = (*range.end()).into();
+            let current = (*value).into();
+
+            let new_value = match key_code {
+                keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                    Some(current - step)
+                }
+                keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                    Some(current + step)
+                }
+                keyboard::KeyCode::Home => Some(start),
+                keyboard::KeyCode::End => Some(end),
+                _ => None,
+            };
+
+            if let Some(new_value) = new_value
+                .map(|new_value| new_value.max(start).min(end))
+                .and_then(T::from_f64)
+            {
+                if (current - new_value.into()).abs() > f64::EPSILON {
+                    shell.publish((on_change)(new_value));
+
+                    *value = new_value;
+
+                    if let Some(on_release) = on_release.clone() {
+                        shell.publish(on_release);
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+        }
         _ => {}
     }
 
@@ -356,6 +429,7 @@ pub fn draw<T, R>(
 {
     let bounds = layout.bounds();
     let is_mouse_over = bounds.contains(cursor_position);
+    let focus_ring = style_sheet.focus_ring(style);
 
     let style = if state.is_dragging {
         style_sheet.dragging(style)
@@ -378,6 +452,7 @@ pub fn draw<T, R>(
             border_radius: 0.0.into(),
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            ..renderer::Quad::default()
         },
         style.rail_colors.0,
     );
@@ -393,6 +468,7 @@ pub fn draw<T, R>(
             border_radius: 0.0.into(),
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            ..renderer::Quad::default()
         },
         Background::Color(style.rail_colors.1),
     );
@@ -422,20 +498,36 @@ pub fn draw<T, R>(
             - handle_width / 2.0
     };
 
+    let handle_bounds = Rectangle {
+        x: rail_x - (handle_height / 2.0),
+        y: bounds.y + handle_offset.round(),
+        width: handle_height,
+        height: handle_width,
+    };
+
     renderer.fill_quad(
         renderer::Quad {
-            bounds: Rectangle {
-                x: rail_x - (handle_height / 2.0),
-                y: bounds.y + handle_offset.round(),
-                width: handle_height,
-                height: handle_width,
-            },
+            bounds: handle_bounds,
             border_radius: handle_border_radius.into(),
             border_width: style.handle.border_width,
             border_color: style.handle.border_color,
+            ..renderer::Quad::default()
         },
         style.handle.color,
     );
+
+    if state.is_focused {
+        focus_ring::draw(
+            renderer,
+            handle_bounds,
+            focus_ring::Ring {
+                border_radius: handle_border_radius
+                    + focus_ring.offset
+                    + focus_ring.width,
+                ..focus_ring
+            },
+        );
+    }
 }
 
 /// Computes the current [`mouse::Interaction`] of a [`VerticalSlider`].
@@ -460,6 +552,7 @@ pub fn mouse_interaction(
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_dragging: bool,
+    is_focused: bool,
 }
 
 impl State {
@@ -467,4 +560,23 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`VerticalSlider`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
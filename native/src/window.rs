@@ -1,20 +1,28 @@
 //! Build window-based GUI applications.
 mod action;
 mod event;
+mod id;
 mod mode;
+mod position;
 mod redraw_request;
+mod screenshot;
+mod settings;
 mod user_attention;
 
 pub use action::Action;
 pub use event::Event;
+pub use id::Id;
 pub use mode::Mode;
+pub use position::Position;
 pub use redraw_request::RedrawRequest;
+pub use screenshot::Screenshot;
+pub use settings::Settings;
 pub use user_attention::UserAttention;
 
 use crate::subscription::{self, Subscription};
 use crate::time::Instant;
 
-/// Subscribes to the frames of the window of the running application.
+/// Subscribes to the frames of the windows of the running application.
 ///
 /// The resulting [`Subscription`] will produce items at a rate equal to the
 /// refresh rate of the window. Note that this rate may be variable, as it is
@@ -24,7 +32,7 @@ use crate::time::Instant;
 /// animations without missing any frames.
 pub fn frames() -> Subscription<Instant> {
     subscription::raw_events(|event, _status| match event {
-        crate::Event::Window(Event::RedrawRequested(at)) => Some(at),
+        crate::Event::Window(_id, Event::RedrawRequested(at)) => Some(at),
         _ => None,
     })
 }
@@ -1,12 +1,21 @@
-use crate::window::{Mode, UserAttention};
+use crate::window::{Mode, Screenshot, Settings, UserAttention};
 
 use iced_futures::MaybeSend;
 use std::fmt;
 
 /// An operation to be performed on some window.
 pub enum Action<T> {
-    /// Closes the current window and exits the application.
+    /// Closes the window.
+    ///
+    /// Closing the main window exits the application.
     Close,
+    /// Spawns a new window with the given [`Settings`].
+    ///
+    /// The new window will be identified by the [`Id`] this action is run
+    /// with. Only supported by multi-window runtimes.
+    ///
+    /// [`Id`]: crate::window::Id
+    Spawn(Settings),
     /// Moves the window with the left mouse button until the button is
     /// released.
     ///
@@ -69,6 +78,8 @@ pub enum Action<T> {
     ///
     /// - **Web / Wayland:** Unsupported.
     GainFocus,
+    /// Captures a [`Screenshot`] of the next frame rendered in the window.
+    Screenshot(Box<dyn FnOnce(Screenshot) -> T + 'static>),
 }
 
 impl<T> Action<T> {
@@ -82,6 +93,7 @@ impl<T> Action<T> {
     {
         match self {
             Self::Close => Action::Close,
+            Self::Spawn(settings) => Action::Spawn(settings),
             Self::Drag => Action::Drag,
             Self::Resize { width, height } => Action::Resize { width, height },
             Self::Maximize(bool) => Action::Maximize(bool),
@@ -95,6 +107,11 @@ impl<T> Action<T> {
                 Action::RequestUserAttention(attention_type)
             }
             Self::GainFocus => Action::GainFocus,
+            Self::Screenshot(tag) => {
+                Action::Screenshot(Box::new(move |screenshot| {
+                    f(tag(screenshot))
+                }))
+            }
         }
     }
 }
@@ -103,6 +120,9 @@ impl<T> fmt::Debug for Action<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Close => write!(f, "Action::Close"),
+            Self::Spawn(settings) => {
+                write!(f, "Action::Spawn({:?})", settings)
+            }
             Self::Drag => write!(f, "Action::Drag"),
             Self::Resize { width, height } => write!(
                 f,
@@ -122,6 +142,7 @@ impl<T> fmt::Debug for Action<T> {
                 write!(f, "Action::RequestUserAttention")
             }
             Self::GainFocus => write!(f, "Action::GainFocus"),
+            Self::Screenshot(_) => write!(f, "Action::Screenshot"),
         }
     }
 }
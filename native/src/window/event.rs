@@ -32,6 +32,12 @@ pub enum Event {
     /// occurs.
     CloseRequested,
 
+    /// A window was closed.
+    ///
+    /// Closing the main window exits the application, so this event is only
+    /// emitted for the other windows of a multi-window application.
+    Closed,
+
     /// A window was focused.
     Focused,
 
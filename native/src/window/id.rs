@@ -0,0 +1,48 @@
+use std::hash::Hash;
+use std::sync::atomic::{self, AtomicU64};
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// The identifier of a window.
+///
+/// Every application has a [`MAIN`] window, which is the window created on
+/// startup. Additional windows can be spawned with their own [`Id`].
+///
+/// [`MAIN`]: Self::MAIN
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(u64);
+
+impl Id {
+    /// The [`Id`] of the main window of an application.
+    pub const MAIN: Self = Id(0);
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        let id = NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed);
+
+        Self(id)
+    }
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::MAIN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Id;
+
+    #[test]
+    fn unique_generates_different_ids() {
+        let a = Id::unique();
+        let b = Id::unique();
+
+        assert_ne!(a, b);
+        assert_ne!(a, Id::MAIN);
+        assert_ne!(b, Id::MAIN);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::Size;
+
+use std::fmt;
+use std::sync::Arc;
+
+/// The rendered contents of a window.
+#[derive(Clone)]
+pub struct Screenshot {
+    /// The bytes of the [`Screenshot`], in RGBA order and the sRGB color
+    /// space.
+    ///
+    /// Rows are stored from top to bottom.
+    pub bytes: Arc<Vec<u8>>,
+
+    /// The physical size of the [`Screenshot`].
+    pub size: Size<u32>,
+}
+
+impl Screenshot {
+    /// Creates a new [`Screenshot`] from the given RGBA bytes and physical
+    /// size.
+    pub fn new(bytes: Vec<u8>, size: Size<u32>) -> Self {
+        debug_assert_eq!(
+            bytes.len(),
+            size.width as usize * size.height as usize * 4
+        );
+
+        Self {
+            bytes: Arc::new(bytes),
+            size,
+        }
+    }
+}
+
+impl AsRef<[u8]> for Screenshot {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl fmt::Debug for Screenshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Screenshot {{ bytes: {} bytes, size: {:?} }}",
+            self.bytes.len(),
+            self.size
+        )
+    }
+}
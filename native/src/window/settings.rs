@@ -0,0 +1,54 @@
+use crate::window::Position;
+
+/// The settings of a window.
+///
+/// These settings are platform-agnostic, which allows them to be used to
+/// [`Spawn`] new windows from any [`Command`].
+///
+/// [`Spawn`]: crate::window::Action::Spawn
+/// [`Command`]: crate::Command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    /// The initial logical size of the window.
+    pub size: (u32, u32),
+
+    /// The initial position of the window.
+    pub position: Position,
+
+    /// The minimum logical size of the window.
+    pub min_size: Option<(u32, u32)>,
+
+    /// The maximum logical size of the window.
+    pub max_size: Option<(u32, u32)>,
+
+    /// Whether the window should be visible or not.
+    pub visible: bool,
+
+    /// Whether the window should be resizable or not.
+    pub resizable: bool,
+
+    /// Whether the window should have a border, a title bar, etc.
+    pub decorations: bool,
+
+    /// Whether the window should be transparent.
+    pub transparent: bool,
+
+    /// Whether the window will always be on top of other windows.
+    pub always_on_top: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            size: (1024, 768),
+            position: Position::default(),
+            min_size: None,
+            max_size: None,
+            visible: true,
+            resizable: true,
+            decorations: true,
+            transparent: false,
+            always_on_top: false,
+        }
+    }
+}
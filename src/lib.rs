@@ -182,6 +182,9 @@ pub mod touch;
 pub mod widget;
 pub mod window;
 
+#[cfg(feature = "multi-window")]
+pub mod multi_window;
+
 #[cfg(all(not(feature = "glow"), feature = "wgpu"))]
 use iced_winit as runtime;
 
@@ -194,8 +197,12 @@ use iced_wgpu as renderer;
 #[cfg(feature = "glow")]
 use iced_glow as renderer;
 
+pub use iced_native::accessibility;
+pub use iced_native::gradient;
 pub use iced_native::theme;
+pub use runtime::drag;
 pub use runtime::event;
+pub use runtime::gesture;
 pub use runtime::subscription;
 
 pub use application::Application;
@@ -203,6 +210,7 @@ pub use element::Element;
 pub use error::Error;
 pub use event::Event;
 pub use executor::Executor;
+pub use gradient::Gradient;
 pub use renderer::Renderer;
 pub use result::Result;
 pub use sandbox::Sandbox;
@@ -211,10 +219,11 @@ pub use subscription::Subscription;
 pub use theme::Theme;
 
 pub use runtime::alignment;
+pub use runtime::font;
 pub use runtime::futures;
 pub use runtime::{
     color, Alignment, Background, Color, Command, ContentFit, Font, Length,
-    Padding, Point, Rectangle, Size, Vector,
+    Padding, Point, Rectangle, Shadow, Size, Vector,
 };
 
 #[cfg(feature = "system")]
@@ -56,6 +56,14 @@ pub struct Settings<Flags> {
     /// [`Application`]: crate::Application
     pub exit_on_close_request: bool,
 
+    /// Whether the [`Application`] should move the keyboard focus between
+    /// widgets when the user presses Tab or Shift+Tab.
+    ///
+    /// By default, it is enabled.
+    ///
+    /// [`Application`]: crate::Application
+    pub focus_navigation: bool,
+
     /// Whether the [`Application`] should try to build the context
     /// using OpenGL ES first then OpenGL.
     ///
@@ -82,6 +90,7 @@ impl<Flags> Settings<Flags> {
             text_multithreading: default_settings.text_multithreading,
             antialiasing: default_settings.antialiasing,
             exit_on_close_request: default_settings.exit_on_close_request,
+            focus_navigation: default_settings.focus_navigation,
             try_opengles_first: default_settings.try_opengles_first,
         }
     }
@@ -101,6 +110,7 @@ where
             text_multithreading: false,
             antialiasing: false,
             exit_on_close_request: true,
+            focus_navigation: true,
             try_opengles_first: false,
         }
     }
@@ -113,6 +123,7 @@ impl<Flags> From<Settings<Flags>> for iced_winit::Settings<Flags> {
             window: settings.window.into(),
             flags: settings.flags,
             exit_on_close_request: settings.exit_on_close_request,
+            focus_navigation: settings.focus_navigation,
             try_opengles_first: settings.try_opengles_first,
         }
     }
@@ -1,7 +1,7 @@
 //! Display information and interactive controls in your application.
 pub use iced_native::widget::helpers::*;
 
-pub use iced_native::{column, row};
+pub use iced_native::{column, row, stack};
 
 /// A container that distributes its contents vertically.
 pub type Column<'a, Message, Renderer = crate::Renderer> =
@@ -11,8 +11,18 @@ pub type Column<'a, Message, Renderer = crate::Renderer> =
 pub type Row<'a, Message, Renderer = crate::Renderer> =
     iced_native::widget::Row<'a, Message, Renderer>;
 
+/// A container that displays its children on top of each other.
+pub type Stack<'a, Message, Renderer = crate::Renderer> =
+    iced_native::widget::Stack<'a, Message, Renderer>;
+
+/// A container that distributes its contents horizontally, breaking them onto
+/// new lines when they do not fit.
+pub type Wrap<'a, Message, Renderer = crate::Renderer> =
+    iced_native::widget::Wrap<'a, Message, Renderer>;
+
 pub mod text {
     //! Write some text for your users to read.
+    pub use iced_native::text::{Overflow, Wrap};
     pub use iced_native::widget::text::{Appearance, StyleSheet};
 
     /// A paragraph of text.
@@ -47,6 +57,45 @@ pub mod container {
         iced_native::widget::Container<'a, Message, Renderer>;
 }
 
+pub mod context_menu {
+    //! Open a menu of actions on some content with a right click.
+    pub use iced_native::widget::context_menu::{
+        Appearance, Item, State, StyleSheet,
+    };
+
+    /// A widget that opens a menu of actions when right clicked.
+    pub type ContextMenu<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::ContextMenu<'a, Message, Renderer>;
+}
+
+pub mod drag_source {
+    //! Drag some content around to carry data with it.
+    pub use iced_native::widget::drag_source::State;
+
+    /// A widget that starts a drag and drop session when dragged.
+    pub type DragSource<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::DragSource<'a, T, Message, Renderer>;
+}
+
+pub mod drop_target {
+    //! Receive the data dropped on some content.
+    pub use iced_native::widget::drop_target::State;
+
+    /// A widget that receives the payloads dropped on it.
+    pub type DropTarget<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::DropTarget<'a, T, Message, Renderer>;
+}
+
+pub mod grid {
+    //! Distribute content in a grid of rows and columns.
+    pub use iced_native::widget::grid::Cell;
+
+    /// A container that distributes its contents in a grid of rows and
+    /// columns.
+    pub type Grid<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Grid<'a, Message, Renderer>;
+}
+
 pub mod pane_grid {
     //! Let your users split regions of your application and organize layout dynamically.
     //!
@@ -96,6 +145,15 @@ pub mod radio {
         iced_native::widget::Radio<Message, Renderer>;
 }
 
+pub mod rich_text {
+    //! Write paragraphs of text made of spans with their own style.
+    pub use iced_native::widget::rich_text::{Appearance, Span, StyleSheet};
+
+    /// A paragraph of text made of spans with their own style.
+    pub type RichText<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::RichText<'a, Message, Renderer>;
+}
+
 pub mod scrollable {
     //! Navigate an endless amount of content with a scrollbar.
     pub use iced_native::widget::scrollable::{
@@ -109,6 +167,17 @@ pub mod scrollable {
         iced_native::widget::Scrollable<'a, Message, Renderer>;
 }
 
+pub mod table {
+    //! Display large amounts of data in rows and columns.
+    pub use iced_native::widget::table::{
+        Appearance, Column, Order, ResizeEvent, StyleSheet,
+    };
+
+    /// A widget that displays a slice of rows in typed columns.
+    pub type Table<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::Table<'a, T, Message, Renderer>;
+}
+
 pub mod toggler {
     //! Show toggle controls using togglers.
     pub use iced_native::widget::toggler::{Appearance, StyleSheet};
@@ -118,6 +187,18 @@ pub mod toggler {
         iced_native::widget::Toggler<'a, Message, Renderer>;
 }
 
+pub mod text_editor {
+    //! Display a multi-line text editor.
+    pub use iced_native::widget::text_editor::{
+        focus, Action, Appearance, Content, Edit, Id, Motion, Position,
+        StyleSheet,
+    };
+
+    /// A multi-line text editor.
+    pub type TextEditor<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::TextEditor<'a, Message, Renderer>;
+}
+
 pub mod text_input {
     //! Display fields that can be filled with text.
     pub use iced_native::widget::text_input::{
@@ -148,14 +229,21 @@ pub use iced_native::widget::Space;
 pub use button::Button;
 pub use checkbox::Checkbox;
 pub use container::Container;
+pub use context_menu::ContextMenu;
+pub use drag_source::DragSource;
+pub use drop_target::DropTarget;
+pub use grid::Grid;
 pub use pane_grid::PaneGrid;
 pub use pick_list::PickList;
 pub use progress_bar::ProgressBar;
 pub use radio::Radio;
+pub use rich_text::RichText;
 pub use rule::Rule;
 pub use scrollable::Scrollable;
 pub use slider::Slider;
+pub use table::Table;
 pub use text::Text;
+pub use text_editor::TextEditor;
 pub use text_input::TextInput;
 pub use toggler::Toggler;
 pub use tooltip::Tooltip;
@@ -179,7 +267,8 @@ where
 #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
 pub mod image {
     //! Display images in your user interface.
-    pub use iced_native::image::Handle;
+    pub use iced_graphics::image::load;
+    pub use iced_native::image::{Error, FilterMethod, Handle};
 
     /// A frame that displays an image.
     pub type Image = iced_native::widget::Image<Handle>;
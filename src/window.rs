@@ -10,3 +10,12 @@ pub use settings::Settings;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use crate::runtime::window::*;
+
+/// Spawns a new window with the given [`Id`] and [`Settings`].
+///
+/// Only supported by multi-window applications. The [`Icon`] of the
+/// [`Settings`] is currently ignored.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn spawn<Message>(id: Id, settings: Settings) -> crate::Command<Message> {
+    crate::runtime::window::spawn(id, settings.into())
+}
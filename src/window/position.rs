@@ -30,3 +30,13 @@ impl From<Position> for iced_winit::Position {
         }
     }
 }
+
+impl From<Position> for iced_native::window::Position {
+    fn from(position: Position) -> Self {
+        match position {
+            Position::Default => Self::Default,
+            Position::Centered => Self::Centered,
+            Position::Specific(x, y) => Self::Specific(x, y),
+        }
+    }
+}
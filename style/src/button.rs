@@ -1,10 +1,16 @@
 //! Change the apperance of a button.
-use iced_core::{Background, Color, Vector};
+use crate::focus;
+
+use iced_core::{Background, Color, Shadow, Vector};
 
 /// The appearance of a button.
 #[derive(Debug, Clone, Copy)]
 pub struct Appearance {
-    /// The amount of offset to apply to the shadow of the button.
+    /// The amount of offset to apply to the shadow of the button, on top of
+    /// the offset of its [`Shadow`].
+    ///
+    /// If the [`Shadow`] is transparent, a non-zero offset draws a
+    /// translucent black shadow instead.
     pub shadow_offset: Vector,
     /// The [`Background`] of the button.
     pub background: Option<Background>,
@@ -16,6 +22,8 @@ pub struct Appearance {
     pub border_color: Color,
     /// The text [`Color`] of the button.
     pub text_color: Color,
+    /// The [`Shadow`] of the button.
+    pub shadow: Shadow,
 }
 
 impl std::default::Default for Appearance {
@@ -27,6 +35,7 @@ impl std::default::Default for Appearance {
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
             text_color: Color::BLACK,
+            shadow: Shadow::default(),
         }
     }
 }
@@ -68,6 +77,9 @@ pub trait StyleSheet {
                     a: color.a * 0.5,
                     ..color
                 }),
+                Background::Gradient(gradient) => {
+                    Background::Gradient(gradient.mul_alpha(0.5))
+                }
             }),
             text_color: Color {
                 a: active.text_color.a * 0.5,
@@ -76,4 +88,11 @@ pub trait StyleSheet {
             ..active
         }
     }
+
+    /// Produces the focus [`Ring`] drawn around a focused button.
+    ///
+    /// [`Ring`]: crate::focus::Ring
+    fn focus_ring(&self, _style: &Self::Style) -> focus::Ring {
+        focus::Ring::default()
+    }
 }
@@ -1,4 +1,6 @@
 //! Change the appearance of a checkbox.
+use crate::focus;
+
 use iced_core::{Background, Color};
 
 /// The appearance of a checkbox.
@@ -28,4 +30,11 @@ pub trait StyleSheet {
 
     /// Produces the hovered [`Appearance`] of a checkbox.
     fn hovered(&self, style: &Self::Style, is_checked: bool) -> Appearance;
+
+    /// Produces the focus [`Ring`] drawn around a focused checkbox.
+    ///
+    /// [`Ring`]: crate::focus::Ring
+    fn focus_ring(&self, _style: &Self::Style) -> focus::Ring {
+        focus::Ring::default()
+    }
 }
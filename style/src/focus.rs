@@ -0,0 +1,26 @@
+//! Change the appearance of the ring drawn around focused widgets.
+use iced_core::Color;
+
+/// The appearance of the ring drawn around a focused widget.
+#[derive(Debug, Clone, Copy)]
+pub struct Ring {
+    /// The [`Color`] of the ring.
+    pub color: Color,
+    /// The width of the ring.
+    pub width: f32,
+    /// The distance between the ring and the bounds of the widget.
+    pub offset: f32,
+    /// The border radius of the ring.
+    pub border_radius: f32,
+}
+
+impl std::default::Default for Ring {
+    fn default() -> Self {
+        Self {
+            color: Color::from_rgb(0.37, 0.52, 0.94),
+            width: 2.0,
+            offset: 2.0,
+            border_radius: 4.0,
+        }
+    }
+}
@@ -24,6 +24,7 @@ pub mod application;
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod focus;
 pub mod menu;
 pub mod pane_grid;
 pub mod pick_list;
@@ -33,6 +34,7 @@ pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod svg;
+pub mod table;
 pub mod text;
 pub mod text_input;
 pub mod theme;
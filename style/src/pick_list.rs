@@ -1,4 +1,6 @@
 //! Change the appearance of a pick list.
+use crate::focus;
+
 use iced_core::{Background, Color};
 
 /// The appearance of a pick list.
@@ -30,4 +32,11 @@ pub trait StyleSheet {
 
     /// Produces the hovered [`Appearance`] of a pick list.
     fn hovered(&self, style: &<Self as StyleSheet>::Style) -> Appearance;
+
+    /// Produces the focus [`Ring`] drawn around a focused pick list.
+    ///
+    /// [`Ring`]: crate::focus::Ring
+    fn focus_ring(&self, _style: &<Self as StyleSheet>::Style) -> focus::Ring {
+        focus::Ring::default()
+    }
 }
@@ -1,4 +1,6 @@
 //! Change the appearance of radio buttons.
+use crate::focus;
+
 use iced_core::{Background, Color};
 
 /// The appearance of a radio button.
@@ -26,4 +28,11 @@ pub trait StyleSheet {
 
     /// Produces the hovered [`Appearance`] of a radio button.
     fn hovered(&self, style: &Self::Style, is_selected: bool) -> Appearance;
+
+    /// Produces the focus [`Ring`] drawn around a focused radio button.
+    ///
+    /// [`Ring`]: crate::focus::Ring
+    fn focus_ring(&self, _style: &Self::Style) -> focus::Ring {
+        focus::Ring::default()
+    }
 }
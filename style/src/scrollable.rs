@@ -1,4 +1,6 @@
 //! Change the appearance of a scrollable.
+use crate::focus;
+
 use iced_core::{Background, Color};
 
 /// The appearance of a scrollable.
@@ -59,4 +61,11 @@ pub trait StyleSheet {
     fn dragging_horizontal(&self, style: &Self::Style) -> Scrollbar {
         self.hovered_horizontal(style)
     }
+
+    /// Produces the focus [`Ring`] drawn around a focused scrollable.
+    ///
+    /// [`Ring`]: crate::focus::Ring
+    fn focus_ring(&self, _style: &Self::Style) -> focus::Ring {
+        focus::Ring::default()
+    }
 }
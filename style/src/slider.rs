@@ -1,4 +1,6 @@
 //! Change the apperance of a slider.
+use crate::focus;
+
 use iced_core::Color;
 
 /// The appearance of a slider.
@@ -53,4 +55,11 @@ pub trait StyleSheet {
 
     /// Produces the style of a slider that is being dragged.
     fn dragging(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the focus [`Ring`] drawn around a focused slider.
+    ///
+    /// [`Ring`]: crate::focus::Ring
+    fn focus_ring(&self, _style: &Self::Style) -> focus::Ring {
+        focus::Ring::default()
+    }
 }
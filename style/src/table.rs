@@ -0,0 +1,40 @@
+//! Change the appearance of a table.
+use iced_core::{Background, Color};
+
+/// The appearance of a table.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The text [`Color`] of the rows of the table.
+    pub text_color: Color,
+    /// The [`Background`] of the table.
+    pub background: Background,
+    /// The border radius of the table.
+    pub border_radius: f32,
+    /// The border width of the table.
+    pub border_width: f32,
+    /// The border [`Color`] of the table.
+    pub border_color: Color,
+    /// The text [`Color`] of the header of the table.
+    pub header_text_color: Color,
+    /// The [`Background`] of the header of the table.
+    pub header_background: Background,
+    /// The [`Color`] of the lines dividing the columns of the header.
+    pub divider_color: Color,
+    /// The [`Background`] of every other row of the table, if any.
+    pub alternate_row_background: Option<Background>,
+    /// The [`Background`] of a hovered row of the table, if any.
+    pub hovered_row_background: Option<Background>,
+    /// The text [`Color`] of a selected row of the table.
+    pub selected_text_color: Color,
+    /// The [`Background`] of a selected row of the table.
+    pub selected_row_background: Background,
+}
+
+/// A set of rules that dictate the style of a table.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the table.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
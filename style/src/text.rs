@@ -8,6 +8,13 @@ pub trait StyleSheet {
 
     /// Produces the [`Appearance`] of some text.
     fn appearance(&self, style: Self::Style) -> Appearance;
+
+    /// Produces the [`Color`] of the selection of some selectable text.
+    ///
+    /// By default, selections are light blue.
+    fn selection_color(&self, _style: Self::Style) -> Color {
+        Color::from_rgb(0.8, 0.8, 1.0)
+    }
 }
 
 /// The apperance of some text.
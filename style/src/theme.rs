@@ -8,6 +8,7 @@ use crate::application;
 use crate::button;
 use crate::checkbox;
 use crate::container;
+use crate::focus;
 use crate::menu;
 use crate::pane_grid;
 use crate::pick_list;
@@ -17,11 +18,12 @@ use crate::rule;
 use crate::scrollable;
 use crate::slider;
 use crate::svg;
+use crate::table;
 use crate::text;
 use crate::text_input;
 use crate::toggler;
 
-use iced_core::{Background, Color, Vector};
+use iced_core::{Background, Color, Shadow, Vector};
 
 use std::rc::Rc;
 
@@ -217,6 +219,9 @@ impl button::StyleSheet for Theme {
                     a: color.a * 0.5,
                     ..color
                 }),
+                Background::Gradient(gradient) => {
+                    Background::Gradient(gradient.mul_alpha(0.5))
+                }
             }),
             text_color: Color {
                 a: active.text_color.a * 0.5,
@@ -225,6 +230,14 @@ impl button::StyleSheet for Theme {
             ..active
         }
     }
+
+    fn focus_ring(&self, style: &Self::Style) -> focus::Ring {
+        if let Button::Custom(custom) = style {
+            return custom.focus_ring(self);
+        }
+
+        focus_ring(self.extended_palette())
+    }
 }
 
 /// The style of a checkbox.
@@ -317,6 +330,14 @@ impl checkbox::StyleSheet for Theme {
             Checkbox::Custom(custom) => custom.hovered(self, is_checked),
         }
     }
+
+    fn focus_ring(&self, style: &Self::Style) -> focus::Ring {
+        if let Checkbox::Custom(custom) = style {
+            return custom.focus_ring(self);
+        }
+
+        focus_ring(self.extended_palette())
+    }
 }
 
 fn checkbox_appearance(
@@ -372,6 +393,7 @@ impl container::StyleSheet for Theme {
                     border_radius: 2.0,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
                 }
             }
             Container::Custom(custom) => custom.appearance(self),
@@ -466,6 +488,14 @@ impl slider::StyleSheet for Theme {
             Slider::Custom(custom) => custom.dragging(self),
         }
     }
+
+    fn focus_ring(&self, style: &Self::Style) -> focus::Ring {
+        if let Slider::Custom(custom) = style {
+            return custom.focus_ring(self);
+        }
+
+        focus_ring(self.extended_palette())
+    }
 }
 
 /// The style of a menu.
@@ -563,6 +593,14 @@ impl pick_list::StyleSheet for Theme {
             PickList::Custom(custom, _) => custom.hovered(self),
         }
     }
+
+    fn focus_ring(&self, style: &Self::Style) -> focus::Ring {
+        if let PickList::Custom(custom, _) = style {
+            return custom.focus_ring(self);
+        }
+
+        focus_ring(self.extended_palette())
+    }
 }
 
 /// The style of a radio button.
@@ -618,6 +656,14 @@ impl radio::StyleSheet for Theme {
             Radio::Custom(custom) => custom.hovered(self, is_selected),
         }
     }
+
+    fn focus_ring(&self, style: &Self::Style) -> focus::Ring {
+        if let Radio::Custom(custom) = style {
+            return custom.focus_ring(self);
+        }
+
+        focus_ring(self.extended_palette())
+    }
 }
 
 /// The style of a toggler.
@@ -685,6 +731,14 @@ impl toggler::StyleSheet for Theme {
             Toggler::Custom(custom) => custom.hovered(self, is_active),
         }
     }
+
+    fn focus_ring(&self, style: &Self::Style) -> focus::Ring {
+        if let Toggler::Custom(custom) = style {
+            return custom.focus_ring(self);
+        }
+
+        focus_ring(self.extended_palette())
+    }
 }
 
 /// The style of a pane grid.
@@ -958,6 +1012,78 @@ impl scrollable::StyleSheet for Theme {
             Scrollable::Custom(custom) => custom.dragging_horizontal(self),
         }
     }
+
+    fn focus_ring(&self, style: &Self::Style) -> focus::Ring {
+        if let Scrollable::Custom(custom) = style {
+            return custom.focus_ring(self);
+        }
+
+        focus_ring(self.extended_palette())
+    }
+}
+
+/// The style of a table.
+#[derive(Default)]
+pub enum Table {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn table::StyleSheet<Style = Theme>>),
+}
+
+impl From<fn(&Theme) -> table::Appearance> for Table {
+    fn from(f: fn(&Theme) -> table::Appearance) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl table::StyleSheet for Theme {
+    type Style = Table;
+
+    fn appearance(&self, style: &Self::Style) -> table::Appearance {
+        match style {
+            Table::Default => {
+                let palette = self.extended_palette();
+
+                table::Appearance {
+                    text_color: palette.background.base.text,
+                    background: palette.background.base.color.into(),
+                    border_radius: 2.0,
+                    border_width: 1.0,
+                    border_color: palette.background.strong.color,
+                    header_text_color: palette.background.weak.text,
+                    header_background: palette.background.weak.color.into(),
+                    divider_color: palette.background.strong.color,
+                    alternate_row_background: Some(
+                        Color {
+                            a: 0.5,
+                            ..palette.background.weak.color
+                        }
+                        .into(),
+                    ),
+                    hovered_row_background: Some(
+                        palette.primary.weak.color.into(),
+                    ),
+                    selected_text_color: palette.primary.strong.text,
+                    selected_row_background: palette
+                        .primary
+                        .strong
+                        .color
+                        .into(),
+                }
+            }
+            Table::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl table::StyleSheet for fn(&Theme) -> table::Appearance {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> table::Appearance {
+        (self)(style)
+    }
 }
 
 /// The style of text.
@@ -985,6 +1111,10 @@ impl text::StyleSheet for Theme {
             Text::Color(c) => text::Appearance { color: Some(c) },
         }
     }
+
+    fn selection_color(&self, _style: Self::Style) -> Color {
+        self.extended_palette().primary.weak.color
+    }
 }
 
 /// The style of a text input.
@@ -1075,3 +1205,10 @@ impl text_input::StyleSheet for Theme {
         palette.primary.weak.color
     }
 }
+
+fn focus_ring(palette: &Extended) -> focus::Ring {
+    focus::Ring {
+        color: palette.primary.strong.color,
+        ..focus::Ring::default()
+    }
+}
@@ -1,4 +1,6 @@
 //! Change the appearance of a toggler.
+use crate::focus;
+
 use iced_core::Color;
 
 /// The appearance of a toggler.
@@ -28,4 +30,11 @@ pub trait StyleSheet {
     ///
     /// [`Style`]: Self::Style
     fn hovered(&self, style: &Self::Style, is_active: bool) -> Appearance;
+
+    /// Produces the focus [`Ring`] drawn around a focused toggler.
+    ///
+    /// [`Ring`]: crate::focus::Ring
+    fn focus_ring(&self, _style: &Self::Style) -> focus::Ring {
+        focus::Ring::default()
+    }
 }
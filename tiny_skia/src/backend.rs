@@ -0,0 +1,1030 @@
+#[cfg(any(feature = "image", feature = "svg"))]
+use crate::image;
+use crate::pixel;
+use crate::text;
+use crate::triangle;
+use crate::{Settings, Viewport};
+
+use iced_graphics::backend;
+use iced_graphics::font;
+use iced_graphics::layer;
+use iced_graphics::text::Layout;
+use iced_graphics::{Layer, Primitive};
+use iced_native::{
+    Background, Color, Font, Point, Rectangle, Shadow, Size, Vector,
+};
+
+/// A software graphics backend for [`iced`].
+///
+/// [`iced`]: https://github.com/iced-rs/iced
+#[derive(Debug)]
+pub struct Backend {
+    #[cfg(any(feature = "image", feature = "svg"))]
+    image_pipeline: image::Pipeline,
+    text_pipeline: text::Pipeline,
+    default_text_size: u16,
+}
+
+impl Backend {
+    /// Creates a new [`Backend`].
+    pub fn new(settings: Settings) -> Self {
+        Self {
+            #[cfg(any(feature = "image", feature = "svg"))]
+            image_pipeline: image::Pipeline::new(),
+            text_pipeline: text::Pipeline::new(settings.default_font),
+            default_text_size: settings.default_text_size,
+        }
+    }
+
+    /// Draws the provided primitives in the given pixels.
+    ///
+    /// The pixels are cleared with the `background_color` first and end up
+    /// containing __premultiplied__ RGBA in __sRGB__.
+    ///
+    /// The text provided as overlay will be rendered on top of the primitives.
+    /// This is useful for rendering debug information.
+    pub fn draw<T: AsRef<str>>(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        primitives: &[Primitive],
+        viewport: &Viewport,
+        background_color: Color,
+        overlay_text: &[T],
+    ) {
+        let scale_factor = viewport.scale_factor() as f32;
+        let viewport_bounds = Rectangle::with_size(viewport.logical_size());
+
+        pixels.fill(into_color(background_color));
+
+        for primitive in primitives {
+            self.draw_primitive(
+                primitive,
+                pixels,
+                Vector::new(0.0, 0.0),
+                scale_factor,
+                viewport_bounds,
+                None,
+            );
+        }
+
+        let overlay = Layer::overlay(overlay_text, viewport);
+
+        for text in &overlay.text {
+            self.draw_text(text, pixels, scale_factor, viewport_bounds);
+        }
+
+        #[cfg(any(feature = "image", feature = "svg"))]
+        self.image_pipeline.trim_cache();
+
+        self.text_pipeline.measurer().trim_measurement_cache();
+    }
+
+    fn draw_primitive(
+        &mut self,
+        primitive: &Primitive,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        translation: Vector,
+        scale_factor: f32,
+        clip_bounds: Rectangle,
+        clip_mask: Option<&tiny_skia::ClipMask>,
+    ) {
+        match primitive {
+            Primitive::None => {}
+            Primitive::Group { primitives } => {
+                for primitive in primitives {
+                    self.draw_primitive(
+                        primitive,
+                        pixels,
+                        translation,
+                        scale_factor,
+                        clip_bounds,
+                        clip_mask,
+                    );
+                }
+            }
+            Primitive::Text {
+                content,
+                bounds,
+                color,
+                size,
+                font,
+                horizontal_alignment,
+                vertical_alignment,
+                line_height,
+                wrap,
+                overflow,
+            } => {
+                let text = layer::Text {
+                    content,
+                    bounds: *bounds + translation,
+                    color: color.into_linear(),
+                    size: *size,
+                    font: *font,
+                    horizontal_alignment: *horizontal_alignment,
+                    vertical_alignment: *vertical_alignment,
+                    line_height: *line_height,
+                    wrap: *wrap,
+                    overflow: *overflow,
+                };
+
+                self.draw_text(&text, pixels, scale_factor, clip_bounds);
+            }
+            Primitive::RichText { spans, bounds } => {
+                let text = layer::RichText {
+                    spans: spans
+                        .iter()
+                        .map(|span| layer::Span {
+                            content: &span.content,
+                            color: span.color.into_linear(),
+                            size: span.size,
+                            font: span.font,
+                        })
+                        .collect(),
+                    bounds: *bounds + translation,
+                };
+
+                self.draw_rich_text(&text, pixels, scale_factor, clip_bounds);
+            }
+            Primitive::Quad {
+                bounds,
+                background,
+                border_radius,
+                border_width,
+                border_color,
+                shadow,
+            } => {
+                let bounds = *bounds + translation;
+                let shadow_bounds = shadow_bounds(bounds, shadow);
+
+                if clip_bounds.intersection(&shadow_bounds).is_none() {
+                    return;
+                }
+
+                if shadow.color.a > 0.0 {
+                    draw_shadow(
+                        pixels,
+                        bounds,
+                        *border_radius,
+                        shadow,
+                        scale_factor,
+                        clip_mask,
+                    );
+                }
+
+                let transform = tiny_skia::Transform::from_scale(
+                    scale_factor,
+                    scale_factor,
+                );
+
+                let path = match rounded_rectangle(bounds, *border_radius) {
+                    Some(path) => path,
+                    None => return,
+                };
+
+                let _ = pixels.fill_path(
+                    &path,
+                    &tiny_skia::Paint {
+                        shader: into_shader(background, bounds),
+                        anti_alias: true,
+                        ..tiny_skia::Paint::default()
+                    },
+                    tiny_skia::FillRule::EvenOdd,
+                    transform,
+                    clip_mask,
+                );
+
+                if *border_width > 0.0 && border_color.a > 0.0 {
+                    let inner_bounds = Rectangle {
+                        x: bounds.x + border_width,
+                        y: bounds.y + border_width,
+                        width: (bounds.width - border_width * 2.0).max(0.0),
+                        height: (bounds.height - border_width * 2.0).max(0.0),
+                    };
+
+                    let inner_radius = border_radius
+                        .map(|radius| (radius - border_width).max(0.0));
+
+                    // The border is the area between the outer and inner
+                    // rectangles
+                    let mut builder = tiny_skia::PathBuilder::new();
+                    push_rounded_rectangle(
+                        &mut builder,
+                        bounds,
+                        *border_radius,
+                    );
+                    push_rounded_rectangle(
+                        &mut builder,
+                        inner_bounds,
+                        inner_radius,
+                    );
+
+                    if let Some(border) = builder.finish() {
+                        let _ = pixels.fill_path(
+                            &border,
+                            &tiny_skia::Paint {
+                                shader: tiny_skia::Shader::SolidColor(
+                                    into_color(*border_color),
+                                ),
+                                anti_alias: true,
+                                ..tiny_skia::Paint::default()
+                            },
+                            tiny_skia::FillRule::EvenOdd,
+                            transform,
+                            clip_mask,
+                        );
+                    }
+                }
+            }
+            #[cfg(feature = "image")]
+            Primitive::Image {
+                handle,
+                filter_method,
+                bounds,
+                rotation,
+                opacity,
+            } => {
+                self.image_pipeline.draw_raster(
+                    handle,
+                    *filter_method,
+                    *bounds + translation,
+                    *rotation,
+                    *opacity,
+                    pixels,
+                    scale_factor,
+                    clip_mask,
+                );
+            }
+            #[cfg(not(feature = "image"))]
+            Primitive::Image { .. } => {}
+            #[cfg(feature = "svg")]
+            Primitive::Svg {
+                handle,
+                color,
+                bounds,
+            } => {
+                self.image_pipeline.draw_vector(
+                    handle,
+                    *color,
+                    *bounds + translation,
+                    pixels,
+                    scale_factor,
+                    clip_mask,
+                );
+            }
+            #[cfg(not(feature = "svg"))]
+            Primitive::Svg { .. } => {}
+            Primitive::Clip { bounds, content } => {
+                let translated_bounds = *bounds + translation;
+
+                // Only draw visible content
+                if let Some(clip_bounds) =
+                    clip_bounds.intersection(&translated_bounds)
+                {
+                    let clip_mask = match clip_mask_of(
+                        pixels,
+                        clip_bounds * scale_factor,
+                    ) {
+                        Some(clip_mask) => clip_mask,
+                        None => return,
+                    };
+
+                    self.draw_primitive(
+                        content,
+                        pixels,
+                        translation,
+                        scale_factor,
+                        clip_bounds,
+                        Some(&clip_mask),
+                    );
+                }
+            }
+            Primitive::Translate {
+                translation: new_translation,
+                content,
+            } => {
+                self.draw_primitive(
+                    content,
+                    pixels,
+                    translation + *new_translation,
+                    scale_factor,
+                    clip_bounds,
+                    clip_mask,
+                );
+            }
+            Primitive::Cached { cache } => {
+                self.draw_primitive(
+                    cache,
+                    pixels,
+                    translation,
+                    scale_factor,
+                    clip_bounds,
+                    clip_mask,
+                );
+            }
+            Primitive::SolidMesh { buffers, size } => {
+                let bounds = Rectangle::new(
+                    Point::new(translation.x, translation.y),
+                    *size,
+                );
+
+                // Only draw visible content
+                if let Some(clip_bounds) = clip_bounds
+                    .intersection(&bounds)
+                    .and_then(|bounds| physical(pixels, bounds, scale_factor))
+                {
+                    triangle::draw_solid(
+                        pixels,
+                        buffers,
+                        translation,
+                        scale_factor,
+                        clip_bounds,
+                    );
+                }
+            }
+            Primitive::GradientMesh {
+                buffers,
+                size,
+                gradient,
+            } => {
+                let bounds = Rectangle::new(
+                    Point::new(translation.x, translation.y),
+                    *size,
+                );
+
+                // Only draw visible content
+                if let Some(clip_bounds) = clip_bounds
+                    .intersection(&bounds)
+                    .and_then(|bounds| physical(pixels, bounds, scale_factor))
+                {
+                    triangle::draw_gradient(
+                        pixels,
+                        buffers,
+                        gradient,
+                        *size,
+                        translation,
+                        scale_factor,
+                        clip_bounds,
+                    );
+                }
+            }
+        }
+    }
+
+    fn draw_text(
+        &mut self,
+        text: &layer::Text<'_>,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        scale_factor: f32,
+        clip_bounds: Rectangle,
+    ) {
+        let clip_bounds = match physical(pixels, clip_bounds, scale_factor) {
+            Some(clip_bounds) => clip_bounds,
+            None => return,
+        };
+
+        // Target physical coordinates directly to avoid blurry text
+        let section = glyph_brush::Section {
+            // TODO: We `round` here to avoid rerasterizing text when
+            // its position changes slightly. This can make text feel a
+            // bit "jumpy". We may be able to do better once we improve
+            // our text rendering/caching pipeline.
+            screen_position: (
+                (text.bounds.x * scale_factor).round(),
+                (text.bounds.y * scale_factor).round(),
+            ),
+            bounds: (
+                (text.bounds.width * scale_factor).ceil(),
+                (text.bounds.height * scale_factor).ceil(),
+            ),
+            text: self
+                .text_pipeline
+                .measurer()
+                .runs(text.content, text.font)
+                .into_iter()
+                .map(|(_, run, font_id)| glyph_brush::Text {
+                    text: run,
+                    scale: glyph_brush::ab_glyph::PxScale {
+                        x: text.size * scale_factor,
+                        y: text.size * scale_factor,
+                    },
+                    font_id,
+                    extra: glyph_brush::Extra {
+                        color: pixel::to_srgb(text.color),
+                        z: 0.0,
+                    },
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        let layout = Layout {
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+            wrap: text.wrap,
+            line_height: text.line_height,
+            overflow: text.overflow,
+        };
+
+        self.text_pipeline
+            .draw(&section, &layout, pixels, clip_bounds);
+    }
+
+    fn draw_rich_text(
+        &mut self,
+        text: &layer::RichText<'_>,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        scale_factor: f32,
+        clip_bounds: Rectangle,
+    ) {
+        let clip_bounds = match physical(pixels, clip_bounds, scale_factor) {
+            Some(clip_bounds) => clip_bounds,
+            None => return,
+        };
+
+        let section = glyph_brush::Section {
+            screen_position: (
+                (text.bounds.x * scale_factor).round(),
+                (text.bounds.y * scale_factor).round(),
+            ),
+            bounds: (
+                (text.bounds.width * scale_factor).ceil(),
+                (text.bounds.height * scale_factor).ceil(),
+            ),
+            text: text
+                .spans
+                .iter()
+                .flat_map(|span| {
+                    self.text_pipeline
+                        .measurer()
+                        .runs(span.content, span.font)
+                        .into_iter()
+                        .map(move |(_, run, font_id)| glyph_brush::Text {
+                            text: run,
+                            scale: glyph_brush::ab_glyph::PxScale {
+                                x: span.size * scale_factor,
+                                y: span.size * scale_factor,
+                            },
+                            font_id,
+                            extra: glyph_brush::Extra {
+                                color: pixel::to_srgb(span.color),
+                                z: 0.0,
+                            },
+                        })
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        self.text_pipeline.draw(
+            &section,
+            &Layout::default(),
+            pixels,
+            clip_bounds,
+        );
+    }
+}
+
+fn into_shader(
+    background: &Background,
+    bounds: Rectangle,
+) -> tiny_skia::Shader<'static> {
+    match background {
+        Background::Color(color) => {
+            tiny_skia::Shader::SolidColor(into_color(*color))
+        }
+        Background::Gradient(gradient) => {
+            let first = gradient
+                .stops
+                .iter()
+                .flatten()
+                .next()
+                .map(|stop| stop.color)
+                .unwrap_or(Color::TRANSPARENT);
+
+            let (start, end) = gradient.points(bounds);
+
+            let stops: Vec<_> = gradient
+                .stops
+                .iter()
+                .flatten()
+                .map(|stop| {
+                    tiny_skia::GradientStop::new(
+                        stop.offset,
+                        into_color(stop.color),
+                    )
+                })
+                .collect();
+
+            tiny_skia::LinearGradient::new(
+                tiny_skia::Point::from_xy(start.x, start.y),
+                tiny_skia::Point::from_xy(end.x, end.y),
+                stops,
+                tiny_skia::SpreadMode::Pad,
+                tiny_skia::Transform::identity(),
+            )
+            .unwrap_or(tiny_skia::Shader::SolidColor(into_color(first)))
+        }
+    }
+}
+
+/// Returns the bounds covered by a quad with the given bounds and its
+/// [`Shadow`].
+fn shadow_bounds(bounds: Rectangle, shadow: &Shadow) -> Rectangle {
+    if shadow.color.a <= 0.0 {
+        return bounds;
+    }
+
+    let spread = shadow.blur_radius.max(0.0);
+
+    let left = (shadow.offset.x - spread).min(0.0);
+    let top = (shadow.offset.y - spread).min(0.0);
+    let right = (shadow.offset.x + spread).max(0.0);
+    let bottom = (shadow.offset.y + spread).max(0.0);
+
+    Rectangle {
+        x: bounds.x + left,
+        y: bounds.y + top,
+        width: bounds.width - left + right,
+        height: bounds.height - top + bottom,
+    }
+}
+
+/// Draws the [`Shadow`] of a quad by computing the coverage of every pixel
+/// with the same signed distance function used by the GPU backends.
+fn draw_shadow(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    bounds: Rectangle,
+    border_radius: [f32; 4],
+    shadow: &Shadow,
+    scale_factor: f32,
+    clip_mask: Option<&tiny_skia::ClipMask>,
+) {
+    let region =
+        match physical(pixels, shadow_bounds(bounds, shadow), scale_factor) {
+            Some(region) => region,
+            None => return,
+        };
+
+    let mut shadow_pixels =
+        match tiny_skia::Pixmap::new(region.width, region.height) {
+            Some(pixmap) => pixmap,
+            None => return,
+        };
+
+    let color = into_color(shadow.color);
+    let smoothing = shadow.blur_radius.max(0.5 / scale_factor);
+    let half_size = Vector::new(bounds.width / 2.0, bounds.height / 2.0);
+    let center = bounds.center() + shadow.offset;
+    let max_radius = half_size.x.min(half_size.y).max(0.0);
+
+    for (i, pixel) in shadow_pixels.pixels_mut().iter_mut().enumerate() {
+        let x = (region.x + i as u32 % region.width) as f32 + 0.5;
+        let y = (region.y + i as u32 / region.width) as f32 + 0.5;
+
+        let position = Point::new(x / scale_factor, y / scale_factor);
+        let [top_left, top_right, bottom_right, bottom_left] = border_radius;
+
+        let radius = match (position.x > center.x, position.y > center.y) {
+            (false, false) => top_left,
+            (true, false) => top_right,
+            (true, true) => bottom_right,
+            (false, true) => bottom_left,
+        }
+        .clamp(0.0, max_radius);
+
+        let distance = {
+            let x = (position.x - center.x).abs() - half_size.x + radius;
+            let y = (position.y - center.y).abs() - half_size.y + radius;
+
+            x.max(0.0).hypot(y.max(0.0)) + x.max(y).min(0.0) - radius
+        };
+
+        let t = ((distance + smoothing) / (smoothing * 2.0)).clamp(0.0, 1.0);
+        let alpha = 1.0 - t * t * (3.0 - 2.0 * t);
+
+        let mut color = color;
+        color.apply_opacity(alpha);
+
+        *pixel = color.premultiply().to_color_u8();
+    }
+
+    let _ = pixels.draw_pixmap(
+        region.x as i32,
+        region.y as i32,
+        shadow_pixels.as_ref(),
+        &tiny_skia::PixmapPaint::default(),
+        tiny_skia::Transform::identity(),
+        clip_mask,
+    );
+}
+
+fn into_color(color: Color) -> tiny_skia::Color {
+    // Casting to `u8` saturates, which clamps out-of-range components
+    let [r, g, b, a] = color.into_rgba8();
+
+    tiny_skia::Color::from_rgba8(r, g, b, a)
+}
+
+/// Returns the given logical bounds in physical pixels, clamped to the
+/// dimensions of the pixels.
+fn physical(
+    pixels: &tiny_skia::PixmapMut<'_>,
+    bounds: Rectangle,
+    scale_factor: f32,
+) -> Option<Rectangle<u32>> {
+    let target = Rectangle::with_size(Size::new(
+        pixels.width() as f32,
+        pixels.height() as f32,
+    ));
+
+    let bounds = (bounds * scale_factor).intersection(&target)?.snap();
+
+    if bounds.width < 1 || bounds.height < 1 {
+        None
+    } else {
+        Some(bounds)
+    }
+}
+
+fn clip_mask_of(
+    pixels: &tiny_skia::PixmapMut<'_>,
+    bounds: Rectangle,
+) -> Option<tiny_skia::ClipMask> {
+    let path = tiny_skia::PathBuilder::from_rect(tiny_skia::Rect::from_xywh(
+        bounds.x,
+        bounds.y,
+        bounds.width,
+        bounds.height,
+    )?);
+
+    let mut clip_mask = tiny_skia::ClipMask::new();
+
+    clip_mask.set_path(
+        pixels.width(),
+        pixels.height(),
+        &path,
+        tiny_skia::FillRule::EvenOdd,
+        false,
+    )?;
+
+    Some(clip_mask)
+}
+
+fn rounded_rectangle(
+    bounds: Rectangle,
+    border_radius: [f32; 4],
+) -> Option<tiny_skia::Path> {
+    let mut builder = tiny_skia::PathBuilder::new();
+
+    push_rounded_rectangle(&mut builder, bounds, border_radius);
+
+    builder.finish()
+}
+
+/// Appends a rectangle with the given radii, in the order top-left,
+/// top-right, bottom-right and bottom-left.
+fn push_rounded_rectangle(
+    builder: &mut tiny_skia::PathBuilder,
+    bounds: Rectangle,
+    border_radius: [f32; 4],
+) {
+    if bounds.width <= 0.0 || bounds.height <= 0.0 {
+        return;
+    }
+
+    let max_radius = bounds.width.min(bounds.height) / 2.0;
+    let [top_left, top_right, bottom_right, bottom_left] =
+        border_radius.map(|radius| radius.clamp(0.0, max_radius));
+
+    // The distance of the control points of a cubic Bézier approximating a
+    // quarter of a circle
+    const KAPPA: f32 = 0.552_284_8;
+
+    let (left, top) = (bounds.x, bounds.y);
+    let (right, bottom) = (bounds.x + bounds.width, bounds.y + bounds.height);
+
+    builder.move_to(left + top_left, top);
+    builder.line_to(right - top_right, top);
+
+    if top_right > 0.0 {
+        builder.cubic_to(
+            right - top_right * (1.0 - KAPPA),
+            top,
+            right,
+            top + top_right * (1.0 - KAPPA),
+            right,
+            top + top_right,
+        );
+    }
+
+    builder.line_to(right, bottom - bottom_right);
+
+    if bottom_right > 0.0 {
+        builder.cubic_to(
+            right,
+            bottom - bottom_right * (1.0 - KAPPA),
+            right - bottom_right * (1.0 - KAPPA),
+            bottom,
+            right - bottom_right,
+            bottom,
+        );
+    }
+
+    builder.line_to(left + bottom_left, bottom);
+
+    if bottom_left > 0.0 {
+        builder.cubic_to(
+            left + bottom_left * (1.0 - KAPPA),
+            bottom,
+            left,
+            bottom - bottom_left * (1.0 - KAPPA),
+            left,
+            bottom - bottom_left,
+        );
+    }
+
+    builder.line_to(left, top + top_left);
+
+    if top_left > 0.0 {
+        builder.cubic_to(
+            left,
+            top + top_left * (1.0 - KAPPA),
+            left + top_left * (1.0 - KAPPA),
+            top,
+            left + top_left,
+            top,
+        );
+    }
+
+    builder.close();
+}
+
+impl iced_graphics::Backend for Backend {
+    fn trim_measurements(&mut self) {
+        self.text_pipeline.measurer().trim_measurement_cache()
+    }
+}
+
+impl backend::Text for Backend {
+    const ICON_FONT: Font = font::ICONS;
+    const CHECKMARK_ICON: char = font::CHECKMARK_ICON;
+    const ARROW_DOWN_ICON: char = font::ARROW_DOWN_ICON;
+
+    fn default_size(&self) -> u16 {
+        self.default_text_size
+    }
+
+    fn measure(
+        &self,
+        contents: &str,
+        size: f32,
+        line_height: f32,
+        font: Font,
+        bounds: Size,
+        wrap: iced_native::text::Wrap,
+        overflow: iced_native::text::Overflow,
+    ) -> (f32, f32) {
+        self.text_pipeline.measurer().measure(
+            contents,
+            size,
+            line_height,
+            font,
+            bounds,
+            wrap,
+            overflow,
+        )
+    }
+
+    fn hit_test(
+        &self,
+        paragraph: iced_native::text::Paragraph<'_, Font>,
+        point: iced_native::Point,
+        nearest_only: bool,
+    ) -> Option<text::Hit> {
+        self.text_pipeline
+            .measurer()
+            .hit_test(paragraph, point, nearest_only)
+    }
+
+    fn measure_spans(
+        &self,
+        spans: &[iced_native::text::Span<'_, Font>],
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measurer().measure_spans(spans, bounds)
+    }
+
+    fn hit_test_spans(
+        &self,
+        spans: &[iced_native::text::Span<'_, Font>],
+        bounds: Size,
+        point: Point,
+        nearest_only: bool,
+    ) -> Option<text::Hit> {
+        self.text_pipeline.measurer().hit_test_spans(
+            spans,
+            bounds,
+            point,
+            nearest_only,
+        )
+    }
+
+    fn span_bounds(
+        &self,
+        spans: &[iced_native::text::Span<'_, Font>],
+        bounds: Size,
+        index: usize,
+    ) -> Vec<Rectangle> {
+        self.text_pipeline
+            .measurer()
+            .span_bounds(spans, bounds, index)
+    }
+
+    fn selection_bounds(
+        &self,
+        paragraph: iced_native::text::Paragraph<'_, Font>,
+        selection: std::ops::Range<usize>,
+    ) -> Vec<iced_native::Rectangle> {
+        self.text_pipeline
+            .measurer()
+            .selection_bounds(paragraph, selection)
+    }
+
+    fn selection_bounds_spans(
+        &self,
+        spans: &[iced_native::text::Span<'_, Font>],
+        bounds: Size,
+        selection: std::ops::Range<usize>,
+    ) -> Vec<Rectangle> {
+        self.text_pipeline
+            .measurer()
+            .selection_bounds_spans(spans, bounds, selection)
+    }
+}
+
+#[cfg(feature = "image")]
+impl backend::Image for Backend {
+    fn dimensions(&self, handle: &iced_native::image::Handle) -> Size<u32> {
+        self.image_pipeline.dimensions(handle)
+    }
+
+    fn frame_delays(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> Option<Vec<iced_native::time::Duration>> {
+        self.image_pipeline.frame_delays(handle)
+    }
+}
+
+#[cfg(feature = "svg")]
+impl backend::Svg for Backend {
+    fn viewport_dimensions(
+        &self,
+        handle: &iced_native::svg::Handle,
+    ) -> Size<u32> {
+        self.image_pipeline.viewport_dimensions(handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_native::alignment;
+    use iced_native::text;
+
+    const WHITE: [u8; 4] = [255, 255, 255, 255];
+
+    fn render(size: Size<u32>, primitives: &[Primitive]) -> tiny_skia::Pixmap {
+        let mut backend = Backend::new(Settings::default());
+        let mut pixmap = tiny_skia::Pixmap::new(size.width, size.height)
+            .expect("Create pixmap");
+
+        backend.draw::<&str>(
+            &mut pixmap.as_mut(),
+            primitives,
+            &Viewport::with_physical_size(size, 1.0),
+            Color::WHITE,
+            &[],
+        );
+
+        pixmap
+    }
+
+    fn pixel(pixmap: &tiny_skia::Pixmap, x: u32, y: u32) -> [u8; 4] {
+        let pixel = pixmap.pixel(x, y).expect("Pixel in bounds");
+
+        [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()]
+    }
+
+    fn quad(bounds: Rectangle, color: Color) -> Primitive {
+        Primitive::Quad {
+            bounds,
+            background: Background::Color(color),
+            border_radius: [0.0; 4],
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
+        }
+    }
+
+    #[test]
+    fn quads_are_filled_within_their_bounds() {
+        let pixmap = render(
+            Size::new(20, 20),
+            &[quad(
+                Rectangle::new(Point::new(5.0, 5.0), Size::new(10.0, 10.0)),
+                Color::from_rgb(1.0, 0.0, 0.0),
+            )],
+        );
+
+        assert_eq!(pixel(&pixmap, 10, 10), [255, 0, 0, 255]);
+        assert_eq!(pixel(&pixmap, 2, 2), WHITE);
+        assert_eq!(pixel(&pixmap, 17, 17), WHITE);
+    }
+
+    #[test]
+    fn out_of_range_colors_are_clamped() {
+        let pixmap = render(
+            Size::new(10, 10),
+            &[quad(
+                Rectangle::with_size(Size::new(10.0, 10.0)),
+                Color {
+                    r: 2.0,
+                    g: -1.0,
+                    b: f32::NAN,
+                    a: 1.5,
+                },
+            )],
+        );
+
+        assert_eq!(pixel(&pixmap, 5, 5), [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn text_is_drawn_with_its_color() {
+        let pixmap = render(
+            Size::new(100, 40),
+            &[Primitive::Text {
+                content: String::from("Iced"),
+                bounds: Rectangle::with_size(Size::new(100.0, 40.0)),
+                color: Color::BLACK,
+                size: 30.0,
+                font: Font::DEFAULT,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                line_height: 1.2,
+                wrap: text::Wrap::default(),
+                overflow: text::Overflow::default(),
+            }],
+        );
+
+        let pixels: Vec<[u8; 4]> = (0..40)
+            .flat_map(|y| (0..100).map(move |x| (x, y)))
+            .map(|(x, y)| pixel(&pixmap, x, y))
+            .collect();
+
+        assert!(pixels
+            .iter()
+            .any(|&[r, g, b, _]| r == g && g == b && b < 64));
+        assert!(pixels
+            .iter()
+            .all(|&[r, g, b, a]| r == g && g == b && a == 255));
+        assert_eq!(pixel(&pixmap, 99, 39), WHITE);
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn images_are_scaled_to_their_bounds() {
+        use iced_native::image;
+
+        let handle = image::Handle::from_pixels(
+            2,
+            2,
+            [
+                [0, 0, 255, 255],
+                [0, 255, 0, 255],
+                [255, 0, 0, 255],
+                [0, 0, 0, 255],
+            ]
+            .concat(),
+        );
+
+        let pixmap = render(
+            Size::new(20, 20),
+            &[Primitive::Image {
+                handle,
+                filter_method: image::FilterMethod::Nearest,
+                bounds: Rectangle::with_size(Size::new(10.0, 10.0)),
+                rotation: 0.0,
+                opacity: 1.0,
+            }],
+        );
+
+        assert_eq!(pixel(&pixmap, 2, 2), [0, 0, 255, 255]);
+        assert_eq!(pixel(&pixmap, 7, 2), [0, 255, 0, 255]);
+        assert_eq!(pixel(&pixmap, 2, 7), [255, 0, 0, 255]);
+        assert_eq!(pixel(&pixmap, 7, 7), [0, 0, 0, 255]);
+        assert_eq!(pixel(&pixmap, 15, 15), WHITE);
+    }
+}
@@ -0,0 +1,196 @@
+mod storage;
+
+use storage::Storage;
+
+#[cfg(feature = "image")]
+use iced_graphics::image::raster;
+
+#[cfg(feature = "svg")]
+use iced_graphics::image::vector;
+
+use iced_graphics::{Rectangle, Size};
+
+use std::cell::RefCell;
+
+#[derive(Debug, Default)]
+pub(crate) struct Pipeline {
+    storage: Storage,
+    #[cfg(feature = "image")]
+    raster_cache: RefCell<raster::Cache<Storage>>,
+    #[cfg(feature = "svg")]
+    vector_cache: RefCell<vector::Cache<Storage>>,
+}
+
+impl Pipeline {
+    pub fn new() -> Pipeline {
+        Pipeline::default()
+    }
+
+    #[cfg(feature = "image")]
+    pub fn dimensions(&self, handle: &iced_native::image::Handle) -> Size<u32> {
+        self.raster_cache.borrow_mut().load(handle).dimensions()
+    }
+
+    #[cfg(feature = "image")]
+    pub fn frame_delays(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> Option<Vec<iced_native::time::Duration>> {
+        self.raster_cache
+            .borrow_mut()
+            .frames(handle)
+            .map(|frames| frames.iter().map(|frame| frame.delay).collect())
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn viewport_dimensions(
+        &self,
+        handle: &iced_native::svg::Handle,
+    ) -> Size<u32> {
+        let mut cache = self.vector_cache.borrow_mut();
+        let svg = cache.load(handle);
+
+        svg.viewport_dimensions()
+    }
+
+    #[cfg(feature = "image")]
+    pub fn draw_raster(
+        &mut self,
+        handle: &iced_native::image::Handle,
+        filter_method: iced_native::image::FilterMethod,
+        bounds: Rectangle,
+        rotation: f32,
+        opacity: f32,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        scale_factor: f32,
+        clip_mask: Option<&tiny_skia::ClipMask>,
+    ) {
+        let mut raster_cache = self.raster_cache.borrow_mut();
+
+        if let Some(entry) =
+            raster_cache.upload(handle, &mut (), &mut self.storage)
+        {
+            let quality = match filter_method {
+                iced_native::image::FilterMethod::Linear => {
+                    tiny_skia::FilterQuality::Bilinear
+                }
+                iced_native::image::FilterMethod::Nearest => {
+                    tiny_skia::FilterQuality::Nearest
+                }
+            };
+
+            draw(
+                entry,
+                quality,
+                bounds,
+                rotation,
+                opacity,
+                pixels,
+                scale_factor,
+                clip_mask,
+            );
+        }
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn draw_vector(
+        &mut self,
+        handle: &iced_native::svg::Handle,
+        color: Option<iced_graphics::Color>,
+        bounds: Rectangle,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        scale_factor: f32,
+        clip_mask: Option<&tiny_skia::ClipMask>,
+    ) {
+        let mut vector_cache = self.vector_cache.borrow_mut();
+
+        if let Some(entry) = vector_cache.upload(
+            handle,
+            color,
+            [bounds.width, bounds.height],
+            scale_factor,
+            &mut (),
+            &mut self.storage,
+        ) {
+            draw(
+                entry,
+                tiny_skia::FilterQuality::Bilinear,
+                bounds,
+                0.0,
+                1.0,
+                pixels,
+                scale_factor,
+                clip_mask,
+            );
+        }
+    }
+
+    pub fn trim_cache(&mut self) {
+        #[cfg(feature = "image")]
+        self.raster_cache
+            .borrow_mut()
+            .trim(&mut self.storage, &mut ());
+
+        #[cfg(feature = "svg")]
+        self.vector_cache
+            .borrow_mut()
+            .trim(&mut self.storage, &mut ());
+    }
+}
+
+fn draw(
+    entry: &storage::Entry,
+    quality: tiny_skia::FilterQuality,
+    bounds: Rectangle,
+    rotation: f32,
+    opacity: f32,
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    scale_factor: f32,
+    clip_mask: Option<&tiny_skia::ClipMask>,
+) {
+    let rect = match tiny_skia::Rect::from_xywh(
+        bounds.x,
+        bounds.y,
+        bounds.width,
+        bounds.height,
+    ) {
+        Some(rect) => rect,
+        None => return,
+    };
+
+    let size = &entry.pixmap;
+
+    // Stretch the image to fill its bounds
+    let pattern = tiny_skia::Transform::from_row(
+        bounds.width / size.width() as f32,
+        0.0,
+        0.0,
+        bounds.height / size.height() as f32,
+        bounds.x,
+        bounds.y,
+    );
+
+    let paint = tiny_skia::Paint {
+        shader: tiny_skia::Pattern::new(
+            entry.pixmap.as_ref(),
+            tiny_skia::SpreadMode::Pad,
+            quality,
+            opacity,
+            pattern,
+        ),
+        anti_alias: true,
+        ..tiny_skia::Paint::default()
+    };
+
+    let center = bounds.center();
+
+    let transform =
+        tiny_skia::Transform::from_scale(scale_factor, scale_factor)
+            .pre_concat(tiny_skia::Transform::from_rotate_at(
+                rotation.to_degrees(),
+                center.x,
+                center.y,
+            ));
+
+    let _ = pixels.fill_rect(rect, &paint, transform, clip_mask);
+}
@@ -0,0 +1,46 @@
+use tiny_skia::PremultipliedColorU8;
+
+/// Blends the given __sRGB__ color over a premultiplied pixel, scaling its
+/// alpha by the provided coverage.
+pub fn blend(pixel: &mut PremultipliedColorU8, color: [f32; 4], coverage: f32) {
+    let alpha = (color[3] * coverage).clamp(0.0, 1.0);
+
+    if alpha <= 0.0 {
+        return;
+    }
+
+    let inverse = 1.0 - alpha;
+
+    let alpha_u8 = (alpha * 255.0 + f32::from(pixel.alpha()) * inverse)
+        .round()
+        .min(255.0) as u8;
+
+    let channel = |source: f32, destination: u8| {
+        ((source.clamp(0.0, 1.0) * alpha * 255.0
+            + f32::from(destination) * inverse)
+            .round() as u8)
+            .min(alpha_u8)
+    };
+
+    if let Some(blended) = PremultipliedColorU8::from_rgba(
+        channel(color[0], pixel.red()),
+        channel(color[1], pixel.green()),
+        channel(color[2], pixel.blue()),
+        alpha_u8,
+    ) {
+        *pixel = blended;
+    }
+}
+
+/// Converts a color in __linear RGB__ to __sRGB__.
+pub fn to_srgb([r, g, b, a]: [f32; 4]) -> [f32; 4] {
+    fn gamma(u: f32) -> f32 {
+        if u <= 0.0031308 {
+            12.92 * u
+        } else {
+            1.055 * u.powf(1.0 / 2.4) - 0.055
+        }
+    }
+
+    [gamma(r), gamma(g), gamma(b), a]
+}
@@ -0,0 +1,109 @@
+use crate::pixel;
+
+use iced_graphics::font;
+use iced_graphics::text::{Layout, Measurer};
+use iced_native::Rectangle;
+
+use glyph_brush::ab_glyph;
+
+pub use iced_native::text::Hit;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    measurer: Measurer,
+}
+
+impl Pipeline {
+    pub fn new(default_font: Option<&[u8]>) -> Self {
+        let default_font = default_font.map(|slice| slice.to_vec());
+
+        // TODO: Font customization
+        #[cfg(feature = "default_system_font")]
+        let default_font = {
+            default_font.or_else(|| {
+                font::Source::new()
+                    .load(&[font::Family::SansSerif, font::Family::Serif])
+                    .ok()
+            })
+        };
+
+        let default_font =
+            default_font.unwrap_or_else(|| font::FALLBACK.to_vec());
+
+        let font = ab_glyph::FontArc::try_from_vec(default_font)
+            .unwrap_or_else(|_| {
+                log::warn!(
+                    "System font failed to load. Falling back to \
+                    embedded font..."
+                );
+
+                ab_glyph::FontArc::try_from_slice(font::FALLBACK)
+                    .expect("Load fallback font")
+            });
+
+        Pipeline {
+            measurer: Measurer::new(font),
+        }
+    }
+
+    pub fn measurer(&self) -> &Measurer {
+        &self.measurer
+    }
+
+    /// Rasterizes the given [`glyph_brush::Section`] into the pixels using the
+    /// given [`Layout`], discarding any coverage outside of the provided clip
+    /// bounds.
+    ///
+    /// The color of each glyph is taken from the `extra` data of its text and
+    /// is expected to be in __sRGB__.
+    pub fn draw(
+        &self,
+        section: &glyph_brush::Section<'_>,
+        layout: &Layout,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        clip_bounds: Rectangle<u32>,
+    ) {
+        use ab_glyph::Font;
+        use glyph_brush::GlyphCruncher;
+
+        let width = pixels.width();
+        let pixels = pixels.pixels_mut();
+
+        let mut brush = self.measurer.brush();
+        let fonts = brush.fonts().to_vec();
+
+        for glyph_brush::SectionGlyph {
+            section_index,
+            font_id,
+            glyph,
+            ..
+        } in brush.glyphs_custom_layout(section, layout)
+        {
+            let color = section.text[*section_index].extra.color;
+
+            let outline = match fonts[font_id.0].outline_glyph(glyph.clone()) {
+                Some(outline) => outline,
+                None => continue,
+            };
+
+            let origin = outline.px_bounds().min;
+
+            outline.draw(|x, y, coverage| {
+                let x = origin.x as i64 + i64::from(x);
+                let y = origin.y as i64 + i64::from(y);
+
+                if x < i64::from(clip_bounds.x)
+                    || y < i64::from(clip_bounds.y)
+                    || x >= i64::from(clip_bounds.x + clip_bounds.width)
+                    || y >= i64::from(clip_bounds.y + clip_bounds.height)
+                {
+                    return;
+                }
+
+                let index = y as usize * width as usize + x as usize;
+
+                pixel::blend(&mut pixels[index], color, coverage);
+            });
+        }
+    }
+}
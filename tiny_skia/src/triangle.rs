@@ -0,0 +1,240 @@
+//! Rasterize meshes of triangles.
+use crate::pixel;
+
+use iced_graphics::gradient::{ColorStop, Gradient};
+use iced_graphics::triangle::{ColoredVertex2D, Mesh2D, Vertex2D};
+use iced_native::{Point, Rectangle, Size, Vector};
+
+/// Rasterizes a mesh of triangles with per-vertex colors.
+pub fn draw_solid(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    buffers: &Mesh2D<ColoredVertex2D>,
+    translation: Vector,
+    scale_factor: f32,
+    clip_bounds: Rectangle<u32>,
+) {
+    for indices in buffers.indices.chunks_exact(3) {
+        let [a, b, c] = [
+            buffers.vertices[indices[0] as usize],
+            buffers.vertices[indices[1] as usize],
+            buffers.vertices[indices[2] as usize],
+        ];
+
+        fill(
+            pixels,
+            [
+                project(a.position, translation, scale_factor),
+                project(b.position, translation, scale_factor),
+                project(c.position, translation, scale_factor),
+            ],
+            clip_bounds,
+            |[u, v, w], _| {
+                let mut linear = [0.0; 4];
+
+                for (i, channel) in linear.iter_mut().enumerate() {
+                    *channel = a.color[i] * u + b.color[i] * v + c.color[i] * w;
+                }
+
+                pixel::to_srgb(linear)
+            },
+        );
+    }
+}
+
+/// Rasterizes a mesh of triangles filled with a [`Gradient`].
+pub fn draw_gradient(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    buffers: &Mesh2D<Vertex2D>,
+    gradient: &Gradient,
+    size: Size,
+    translation: Vector,
+    scale_factor: f32,
+    clip_bounds: Rectangle<u32>,
+) {
+    let mut gradient = *gradient;
+
+    if let Gradient::Linear(linear) = &mut gradient {
+        linear.direction = linear.points(Rectangle::with_size(size)).into();
+    }
+
+    let stops: Vec<ColorStop> = gradient.stops().collect();
+
+    for indices in buffers.indices.chunks_exact(3) {
+        let [a, b, c] = [
+            buffers.vertices[indices[0] as usize],
+            buffers.vertices[indices[1] as usize],
+            buffers.vertices[indices[2] as usize],
+        ];
+
+        fill(
+            pixels,
+            [
+                project(a.position, translation, scale_factor),
+                project(b.position, translation, scale_factor),
+                project(c.position, translation, scale_factor),
+            ],
+            clip_bounds,
+            |_, position| {
+                // Gradients are defined in the coordinate space of the mesh
+                let local = Point::new(
+                    position.x / scale_factor - translation.x,
+                    position.y / scale_factor - translation.y,
+                );
+
+                pixel::to_srgb(gradient_color(&gradient, &stops, local))
+            },
+        );
+    }
+}
+
+/// Returns the color of the [`Gradient`] at the given point, in
+/// __linear RGB__.
+fn gradient_color(
+    gradient: &Gradient,
+    stops: &[ColorStop],
+    point: Point,
+) -> [f32; 4] {
+    match gradient {
+        Gradient::Linear(linear) => {
+            let (start, end) = linear.points(Rectangle::default());
+            let direction = end - start;
+            let length_squared =
+                direction.x * direction.x + direction.y * direction.y;
+
+            let offset = if length_squared > 0.0 {
+                let current = point - start;
+
+                (current.x * direction.x + current.y * direction.y)
+                    / length_squared
+            } else {
+                0.0
+            };
+
+            interpolate(stops, offset)
+        }
+        Gradient::Radial(radial) => {
+            let distance = point - radial.center;
+
+            let offset = if radial.radius > 0.0 {
+                (distance.x * distance.x + distance.y * distance.y).sqrt()
+                    / radial.radius
+            } else {
+                0.0
+            };
+
+            interpolate(stops, offset)
+        }
+        Gradient::Conic(conic) => {
+            let direction = point - conic.center;
+            let angle = direction.y.atan2(direction.x) - conic.angle;
+
+            interpolate(stops, (angle / std::f32::consts::TAU).rem_euclid(1.0))
+        }
+    }
+}
+
+fn interpolate(stops: &[ColorStop], offset: f32) -> [f32; 4] {
+    let (first, last) = match (stops.first(), stops.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return [0.0; 4],
+    };
+
+    if offset <= first.offset {
+        return first.color.into_linear();
+    }
+
+    if offset >= last.offset {
+        return last.color.into_linear();
+    }
+
+    for pair in stops.windows(2) {
+        let (current, next) = (pair[0], pair[1]);
+
+        if current.offset <= offset && offset <= next.offset {
+            let t = (offset - current.offset) / (next.offset - current.offset);
+            let from = current.color.into_linear();
+            let to = next.color.into_linear();
+
+            return [
+                from[0] + (to[0] - from[0]) * t,
+                from[1] + (to[1] - from[1]) * t,
+                from[2] + (to[2] - from[2]) * t,
+                from[3] + (to[3] - from[3]) * t,
+            ];
+        }
+    }
+
+    last.color.into_linear()
+}
+
+fn project(
+    position: [f32; 2],
+    translation: Vector,
+    scale_factor: f32,
+) -> Point {
+    Point::new(
+        (position[0] + translation.x) * scale_factor,
+        (position[1] + translation.y) * scale_factor,
+    )
+}
+
+/// Fills a triangle by sampling the center of every pixel it covers.
+///
+/// The closure receives the barycentric coordinates and the position of each
+/// sample and must return its color in __sRGB__.
+fn fill(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    [a, b, c]: [Point; 3],
+    clip_bounds: Rectangle<u32>,
+    color: impl Fn([f32; 3], Point) -> [f32; 4],
+) {
+    let area = edge(a, b, c);
+
+    if area.abs() < f32::EPSILON {
+        return;
+    }
+
+    let min_x = a.x.min(b.x).min(c.x).floor().max(clip_bounds.x as f32);
+    let min_y = a.y.min(b.y).min(c.y).floor().max(clip_bounds.y as f32);
+    let max_x =
+        a.x.max(b.x)
+            .max(c.x)
+            .ceil()
+            .min((clip_bounds.x + clip_bounds.width) as f32);
+    let max_y =
+        a.y.max(b.y)
+            .max(c.y)
+            .ceil()
+            .min((clip_bounds.y + clip_bounds.height) as f32);
+
+    if min_x >= max_x || min_y >= max_y {
+        return;
+    }
+
+    let width = pixels.width() as usize;
+    let pixels = pixels.pixels_mut();
+
+    for y in min_y as u32..max_y as u32 {
+        for x in min_x as u32..max_x as u32 {
+            let sample = Point::new(x as f32 + 0.5, y as f32 + 0.5);
+
+            let u = edge(b, c, sample) / area;
+            let v = edge(c, a, sample) / area;
+            let w = edge(a, b, sample) / area;
+
+            if u < 0.0 || v < 0.0 || w < 0.0 {
+                continue;
+            }
+
+            pixel::blend(
+                &mut pixels[y as usize * width + x as usize],
+                color([u, v, w], sample),
+                1.0,
+            );
+        }
+    }
+}
+
+fn edge(a: Point, b: Point, point: Point) -> f32 {
+    (b.x - a.x) * (point.y - a.y) - (b.y - a.y) * (point.x - a.x)
+}
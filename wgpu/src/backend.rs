@@ -6,8 +6,8 @@ use crate::{Settings, Transformation};
 use iced_graphics::backend;
 use iced_graphics::font;
 use iced_graphics::layer::Layer;
+use iced_graphics::text::Layout;
 use iced_graphics::{Primitive, Viewport};
-use iced_native::alignment;
 use iced_native::{Font, Size};
 
 #[cfg(feature = "tracing")]
@@ -172,8 +172,16 @@ impl Backend {
             }
         }
 
-        if !layer.text.is_empty() {
+        if !layer.text.is_empty() || !layer.rich_text.is_empty() {
             for text in layer.text.iter() {
+                let layout = Layout {
+                    horizontal_alignment: text.horizontal_alignment,
+                    vertical_alignment: text.vertical_alignment,
+                    wrap: text.wrap,
+                    line_height: text.line_height,
+                    overflow: text.overflow,
+                };
+
                 // Target physical coordinates directly to avoid blurry text
                 let text = wgpu_glyph::Section {
                     // TODO: We `round` here to avoid rerasterizing text when
@@ -197,44 +205,68 @@ impl Backend {
                         (text.bounds.width * scale_factor).ceil(),
                         (text.bounds.height * scale_factor).ceil(),
                     ),
-                    text: vec![wgpu_glyph::Text {
-                        text: text.content,
-                        scale: wgpu_glyph::ab_glyph::PxScale {
-                            x: text.size * scale_factor,
-                            y: text.size * scale_factor,
-                        },
-                        font_id: self.text_pipeline.find_font(text.font),
-                        extra: wgpu_glyph::Extra {
-                            color: text.color,
-                            z: 0.0,
-                        },
-                    }],
-                    layout: wgpu_glyph::Layout::default()
-                        .h_align(match text.horizontal_alignment {
-                            alignment::Horizontal::Left => {
-                                wgpu_glyph::HorizontalAlign::Left
-                            }
-                            alignment::Horizontal::Center => {
-                                wgpu_glyph::HorizontalAlign::Center
-                            }
-                            alignment::Horizontal::Right => {
-                                wgpu_glyph::HorizontalAlign::Right
-                            }
+                    text: self
+                        .text_pipeline
+                        .measurer()
+                        .runs(text.content, text.font)
+                        .into_iter()
+                        .map(|(_, run, font_id)| wgpu_glyph::Text {
+                            text: run,
+                            scale: wgpu_glyph::ab_glyph::PxScale {
+                                x: text.size * scale_factor,
+                                y: text.size * scale_factor,
+                            },
+                            font_id,
+                            extra: wgpu_glyph::Extra {
+                                color: text.color,
+                                z: 0.0,
+                            },
+                        })
+                        .collect(),
+                    ..Default::default()
+                };
+
+                self.text_pipeline.queue(text, &layout);
+            }
+
+            for text in layer.rich_text.iter() {
+                let text = wgpu_glyph::Section {
+                    screen_position: (
+                        (text.bounds.x * scale_factor).round(),
+                        (text.bounds.y * scale_factor).round(),
+                    ),
+                    bounds: (
+                        (text.bounds.width * scale_factor).ceil(),
+                        (text.bounds.height * scale_factor).ceil(),
+                    ),
+                    text: text
+                        .spans
+                        .iter()
+                        .flat_map(|span| {
+                            self.text_pipeline
+                                .measurer()
+                                .runs(span.content, span.font)
+                                .into_iter()
+                                .map(move |(_, run, font_id)| {
+                                    wgpu_glyph::Text {
+                                        text: run,
+                                        scale: wgpu_glyph::ab_glyph::PxScale {
+                                            x: span.size * scale_factor,
+                                            y: span.size * scale_factor,
+                                        },
+                                        font_id,
+                                        extra: wgpu_glyph::Extra {
+                                            color: span.color,
+                                            z: 0.0,
+                                        },
+                                    }
+                                })
                         })
-                        .v_align(match text.vertical_alignment {
-                            alignment::Vertical::Top => {
-                                wgpu_glyph::VerticalAlign::Top
-                            }
-                            alignment::Vertical::Center => {
-                                wgpu_glyph::VerticalAlign::Center
-                            }
-                            alignment::Vertical::Bottom => {
-                                wgpu_glyph::VerticalAlign::Bottom
-                            }
-                        }),
+                        .collect(),
+                    ..Default::default()
                 };
 
-                self.text_pipeline.queue(text);
+                self.text_pipeline.queue(text, &Layout::default());
             }
 
             self.text_pipeline.draw_queued(
@@ -256,7 +288,7 @@ impl Backend {
 
 impl iced_graphics::Backend for Backend {
     fn trim_measurements(&mut self) {
-        self.text_pipeline.trim_measurement_cache()
+        self.text_pipeline.measurer().trim_measurement_cache()
     }
 }
 
@@ -273,30 +305,88 @@ impl backend::Text for Backend {
         &self,
         contents: &str,
         size: f32,
+        line_height: f32,
         font: Font,
         bounds: Size,
+        wrap: iced_native::text::Wrap,
+        overflow: iced_native::text::Overflow,
     ) -> (f32, f32) {
-        self.text_pipeline.measure(contents, size, font, bounds)
+        self.text_pipeline.measurer().measure(
+            contents,
+            size,
+            line_height,
+            font,
+            bounds,
+            wrap,
+            overflow,
+        )
     }
 
     fn hit_test(
         &self,
-        contents: &str,
-        size: f32,
-        font: Font,
+        paragraph: iced_native::text::Paragraph<'_, Font>,
+        point: iced_native::Point,
+        nearest_only: bool,
+    ) -> Option<text::Hit> {
+        self.text_pipeline
+            .measurer()
+            .hit_test(paragraph, point, nearest_only)
+    }
+
+    fn measure_spans(
+        &self,
+        spans: &[iced_native::text::Span<'_, Font>],
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measurer().measure_spans(spans, bounds)
+    }
+
+    fn hit_test_spans(
+        &self,
+        spans: &[iced_native::text::Span<'_, Font>],
         bounds: Size,
         point: iced_native::Point,
         nearest_only: bool,
     ) -> Option<text::Hit> {
-        self.text_pipeline.hit_test(
-            contents,
-            size,
-            font,
+        self.text_pipeline.measurer().hit_test_spans(
+            spans,
             bounds,
             point,
             nearest_only,
         )
     }
+
+    fn span_bounds(
+        &self,
+        spans: &[iced_native::text::Span<'_, Font>],
+        bounds: Size,
+        index: usize,
+    ) -> Vec<iced_native::Rectangle> {
+        self.text_pipeline
+            .measurer()
+            .span_bounds(spans, bounds, index)
+    }
+
+    fn selection_bounds(
+        &self,
+        paragraph: iced_native::text::Paragraph<'_, Font>,
+        selection: std::ops::Range<usize>,
+    ) -> Vec<iced_native::Rectangle> {
+        self.text_pipeline
+            .measurer()
+            .selection_bounds(paragraph, selection)
+    }
+
+    fn selection_bounds_spans(
+        &self,
+        spans: &[iced_native::text::Span<'_, Font>],
+        bounds: Size,
+        selection: std::ops::Range<usize>,
+    ) -> Vec<iced_native::Rectangle> {
+        self.text_pipeline
+            .measurer()
+            .selection_bounds_spans(spans, bounds, selection)
+    }
 }
 
 #[cfg(feature = "image")]
@@ -304,6 +394,13 @@ impl backend::Image for Backend {
     fn dimensions(&self, handle: &iced_native::image::Handle) -> Size<u32> {
         self.image_pipeline.dimensions(handle)
     }
+
+    fn frame_delays(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> Option<Vec<iced_native::time::Duration>> {
+        self.image_pipeline.frame_delays(handle)
+    }
 }
 
 #[cfg(feature = "svg")]
@@ -10,16 +10,21 @@ use crate::Transformation;
 use atlas::Atlas;
 
 use iced_graphics::layer;
+use iced_native::image::FilterMethod;
 use iced_native::{Rectangle, Size};
 
 use std::cell::RefCell;
 use std::mem;
+use std::ops::Range;
 
 use bytemuck::{Pod, Zeroable};
 
 #[cfg(feature = "image")]
 use iced_native::image;
 
+#[cfg(feature = "image")]
+use iced_native::time::Duration;
+
 #[cfg(feature = "svg")]
 use iced_native::svg;
 
@@ -38,7 +43,8 @@ pub struct Pipeline {
     vertices: wgpu::Buffer,
     indices: wgpu::Buffer,
     instances: wgpu::Buffer,
-    constants: wgpu::BindGroup,
+    linear_constants: wgpu::BindGroup,
+    nearest_constants: wgpu::BindGroup,
     texture: wgpu::BindGroup,
     texture_version: usize,
     texture_layout: wgpu::BindGroupLayout,
@@ -49,15 +55,20 @@ impl Pipeline {
     pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
         use wgpu::util::DeviceExt;
 
-        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Linear,
-            min_filter: wgpu::FilterMode::Linear,
-            mipmap_filter: wgpu::FilterMode::Linear,
-            ..Default::default()
-        });
+        let sampler = |filter_mode| {
+            device.create_sampler(&wgpu::SamplerDescriptor {
+                address_mode_u: wgpu::AddressMode::ClampToEdge,
+                address_mode_v: wgpu::AddressMode::ClampToEdge,
+                address_mode_w: wgpu::AddressMode::ClampToEdge,
+                mag_filter: filter_mode,
+                min_filter: filter_mode,
+                mipmap_filter: filter_mode,
+                ..Default::default()
+            })
+        };
+
+        let linear_sampler = sampler(wgpu::FilterMode::Linear);
+        let nearest_sampler = sampler(wgpu::FilterMode::Nearest);
 
         let constant_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -93,7 +104,7 @@ impl Pipeline {
             mapped_at_creation: false,
         });
 
-        let constant_bind_group =
+        let constant_bind_group = |sampler: &wgpu::Sampler| {
             device.create_bind_group(&wgpu::BindGroupDescriptor {
                 label: Some("iced_wgpu::image constants bind group"),
                 layout: &constant_layout,
@@ -110,10 +121,14 @@ impl Pipeline {
                     },
                     wgpu::BindGroupEntry {
                         binding: 1,
-                        resource: wgpu::BindingResource::Sampler(&sampler),
+                        resource: wgpu::BindingResource::Sampler(sampler),
                     },
                 ],
-            });
+            })
+        };
+
+        let linear_constants = constant_bind_group(&linear_sampler);
+        let nearest_constants = constant_bind_group(&nearest_sampler);
 
         let texture_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -173,6 +188,9 @@ impl Pipeline {
                                 3 => Float32x2,
                                 4 => Float32x2,
                                 5 => Sint32,
+                                6 => Float32x2,
+                                7 => Float32,
+                                8 => Float32,
                             ),
                         },
                     ],
@@ -257,7 +275,8 @@ impl Pipeline {
             vertices,
             indices,
             instances,
-            constants: constant_bind_group,
+            linear_constants,
+            nearest_constants,
             texture,
             texture_version: texture_atlas.layer_count(),
             texture_layout,
@@ -273,6 +292,18 @@ impl Pipeline {
         memory.dimensions()
     }
 
+    #[cfg(feature = "image")]
+    pub fn frame_delays(
+        &self,
+        handle: &image::Handle,
+    ) -> Option<Vec<Duration>> {
+        let mut cache = self.raster_cache.borrow_mut();
+
+        cache
+            .frames(handle)
+            .map(|frames| frames.iter().map(|frame| frame.delay).collect())
+    }
+
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(&self, handle: &svg::Handle) -> Size<u32> {
         let mut cache = self.vector_cache.borrow_mut();
@@ -295,7 +326,8 @@ impl Pipeline {
         #[cfg(feature = "tracing")]
         let _ = info_span!("Wgpu::Image", "DRAW").entered();
 
-        let instances: &mut Vec<Instance> = &mut Vec::new();
+        let mut instances: Vec<Instance> = Vec::new();
+        let mut batches: Vec<Batch> = Vec::new();
 
         #[cfg(feature = "image")]
         let mut raster_cache = self.raster_cache.borrow_mut();
@@ -306,17 +338,33 @@ impl Pipeline {
         for image in images {
             match &image {
                 #[cfg(feature = "image")]
-                layer::Image::Raster { handle, bounds } => {
+                layer::Image::Raster {
+                    handle,
+                    filter_method,
+                    bounds,
+                    rotation,
+                    opacity,
+                } => {
                     if let Some(atlas_entry) = raster_cache.upload(
                         handle,
                         &mut (device, encoder),
                         &mut self.texture_atlas,
                     ) {
+                        let start = instances.len();
+
                         add_instances(
                             [bounds.x, bounds.y],
                             [bounds.width, bounds.height],
+                            *rotation,
+                            *opacity,
                             atlas_entry,
-                            instances,
+                            &mut instances,
+                        );
+
+                        Batch::extend(
+                            &mut batches,
+                            *filter_method,
+                            start..instances.len(),
                         );
                     }
                 }
@@ -339,11 +387,21 @@ impl Pipeline {
                         &mut (device, encoder),
                         &mut self.texture_atlas,
                     ) {
+                        let start = instances.len();
+
                         add_instances(
                             [bounds.x, bounds.y],
                             size,
+                            0.0,
+                            1.0,
                             atlas_entry,
-                            instances,
+                            &mut instances,
+                        );
+
+                        Batch::extend(
+                            &mut batches,
+                            FilterMethod::Linear,
+                            start..instances.len(),
                         );
                     }
                 }
@@ -391,68 +449,79 @@ impl Pipeline {
             }));
         }
 
-        let mut i = 0;
-        let total = instances.len();
-
-        while i < total {
-            let end = (i + Instance::MAX).min(total);
-            let amount = end - i;
-
-            let mut instances_buffer = staging_belt.write_buffer(
-                encoder,
-                &self.instances,
-                0,
-                wgpu::BufferSize::new(
-                    (amount * std::mem::size_of::<Instance>()) as u64,
-                )
-                .unwrap(),
-                device,
-            );
-
-            instances_buffer.copy_from_slice(bytemuck::cast_slice(
-                &instances[i..i + amount],
-            ));
-
-            let mut render_pass =
-                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                    label: Some("iced_wgpu::image render pass"),
-                    color_attachments: &[Some(
-                        wgpu::RenderPassColorAttachment {
-                            view: target,
-                            resolve_target: None,
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Load,
-                                store: true,
+        // Batches are drawn in order, so images keep their painter's order
+        // even when they use different filter methods.
+        for batch in batches {
+            let constants = match batch.filter_method {
+                FilterMethod::Linear => &self.linear_constants,
+                FilterMethod::Nearest => &self.nearest_constants,
+            };
+
+            let instances = &instances[batch.instances];
+
+            let mut i = 0;
+            let total = instances.len();
+
+            while i < total {
+                let end = (i + Instance::MAX).min(total);
+                let amount = end - i;
+
+                let mut instances_buffer = staging_belt.write_buffer(
+                    encoder,
+                    &self.instances,
+                    0,
+                    wgpu::BufferSize::new(
+                        (amount * std::mem::size_of::<Instance>()) as u64,
+                    )
+                    .unwrap(),
+                    device,
+                );
+
+                instances_buffer.copy_from_slice(bytemuck::cast_slice(
+                    &instances[i..i + amount],
+                ));
+
+                let mut render_pass =
+                    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                        label: Some("iced_wgpu::image render pass"),
+                        color_attachments: &[Some(
+                            wgpu::RenderPassColorAttachment {
+                                view: target,
+                                resolve_target: None,
+                                ops: wgpu::Operations {
+                                    load: wgpu::LoadOp::Load,
+                                    store: true,
+                                },
                             },
-                        },
-                    )],
-                    depth_stencil_attachment: None,
-                });
-
-            render_pass.set_pipeline(&self.pipeline);
-            render_pass.set_bind_group(0, &self.constants, &[]);
-            render_pass.set_bind_group(1, &self.texture, &[]);
-            render_pass.set_index_buffer(
-                self.indices.slice(..),
-                wgpu::IndexFormat::Uint16,
-            );
-            render_pass.set_vertex_buffer(0, self.vertices.slice(..));
-            render_pass.set_vertex_buffer(1, self.instances.slice(..));
-
-            render_pass.set_scissor_rect(
-                bounds.x,
-                bounds.y,
-                bounds.width,
-                bounds.height,
-            );
-
-            render_pass.draw_indexed(
-                0..QUAD_INDICES.len() as u32,
-                0,
-                0..amount as u32,
-            );
-
-            i += Instance::MAX;
+                        )],
+                        depth_stencil_attachment: None,
+                    });
+
+                render_pass.set_pipeline(&self.pipeline);
+                render_pass.set_bind_group(0, constants, &[]);
+                render_pass.set_bind_group(1, &self.texture, &[]);
+                render_pass.set_index_buffer(
+                    self.indices.slice(..),
+                    wgpu::IndexFormat::Uint16,
+                );
+                render_pass.set_vertex_buffer(0, self.vertices.slice(..));
+                render_pass.set_vertex_buffer(1, self.instances.slice(..));
+
+                render_pass.set_scissor_rect(
+                    bounds.x,
+                    bounds.y,
+                    bounds.width,
+                    bounds.height,
+                );
+
+                render_pass.draw_indexed(
+                    0..QUAD_INDICES.len() as u32,
+                    0,
+                    0..amount as u32,
+                );
+
+                i += Instance::MAX;
+            }
         }
     }
 
@@ -504,12 +573,46 @@ struct Instance {
     _position_in_atlas: [f32; 2],
     _size_in_atlas: [f32; 2],
     _layer: u32,
+    _center: [f32; 2],
+    _rotation: f32,
+    _opacity: f32,
 }
 
 impl Instance {
     pub const MAX: usize = 1_000;
 }
 
+/// A run of consecutive instances sampled with the same [`FilterMethod`].
+#[derive(Debug)]
+struct Batch {
+    filter_method: FilterMethod,
+    instances: Range<usize>,
+}
+
+impl Batch {
+    /// Adds the given instances to the last batch, if it uses the same
+    /// [`FilterMethod`], or to a new one.
+    fn extend(
+        batches: &mut Vec<Batch>,
+        filter_method: FilterMethod,
+        instances: Range<usize>,
+    ) {
+        if instances.is_empty() {
+            return;
+        }
+
+        match batches.last_mut() {
+            Some(batch) if batch.filter_method == filter_method => {
+                batch.instances.end = instances.end;
+            }
+            _ => batches.push(Batch {
+                filter_method,
+                instances,
+            }),
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Zeroable, Pod)]
 struct Uniforms {
@@ -519,12 +622,27 @@ struct Uniforms {
 fn add_instances(
     image_position: [f32; 2],
     image_size: [f32; 2],
+    rotation: f32,
+    opacity: f32,
     entry: &atlas::Entry,
     instances: &mut Vec<Instance>,
 ) {
+    let center = [
+        image_position[0] + image_size[0] / 2.0,
+        image_position[1] + image_size[1] / 2.0,
+    ];
+
     match entry {
         atlas::Entry::Contiguous(allocation) => {
-            add_instance(image_position, image_size, allocation, instances);
+            add_instance(
+                image_position,
+                image_size,
+                center,
+                rotation,
+                opacity,
+                allocation,
+                instances,
+            );
         }
         atlas::Entry::Fragmented { fragments, size } => {
             let scaling_x = image_size[0] / size.width as f32;
@@ -550,7 +668,10 @@ fn add_instances(
                     fragment_height as f32 * scaling_y,
                 ];
 
-                add_instance(position, size, allocation, instances);
+                add_instance(
+                    position, size, center, rotation, opacity, allocation,
+                    instances,
+                );
             }
         }
     }
@@ -560,6 +681,9 @@ fn add_instances(
 fn add_instance(
     position: [f32; 2],
     size: [f32; 2],
+    center: [f32; 2],
+    rotation: f32,
+    opacity: f32,
     allocation: &atlas::Allocation,
     instances: &mut Vec<Instance>,
 ) {
@@ -579,6 +703,9 @@ fn add_instance(
             (height as f32 - 1.0) / atlas::SIZE as f32,
         ],
         _layer: layer as u32,
+        _center: center,
+        _rotation: rotation,
+        _opacity: opacity,
     };
 
     instances.push(instance);
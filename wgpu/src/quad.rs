@@ -96,6 +96,16 @@ impl Pipeline {
                                 4 => Float32x4,
                                 5 => Float32x4,
                                 6 => Float32,
+                                7 => Float32x4,
+                                8 => Uint32x4,
+                                9 => Uint32x4,
+                                10 => Uint32x4,
+                                11 => Uint32x4,
+                                12 => Uint32x4,
+                                13 => Uint32,
+                                14 => Float32x4,
+                                // Shadow offset and blur radius
+                                15 => Float32x3,
                             ),
                         },
                     ],
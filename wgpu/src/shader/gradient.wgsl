@@ -1,11 +1,17 @@
 struct Uniforms {
     transform: mat4x4<f32>,
-    //xy = start, wz = end
+    //linear: xy = start, zw = end
+    //radial: xy = center, z = radius
+    //conic: xy = center, z = angle
     position: vec4<f32>,
-    //x = start stop, y = end stop, zw = padding
+    //x = start stop, y = end stop, z = kind, w = padding
     stop_range: vec4<i32>,
 }
 
+let LINEAR: i32 = 0;
+let RADIAL: i32 = 1;
+let PI: f32 = 3.14159265358979323846264338327950288;
+
 struct Stop {
     color: vec4<f32>,
     offset: f32,
@@ -34,15 +40,49 @@ fn vs_main(@location(0) input: vec2<f32>) -> VertexOutput {
 //TODO: rewrite without branching
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
-    let start = uniforms.position.xy;
-    let end = uniforms.position.zw;
     let start_stop = uniforms.stop_range.x;
     let end_stop = uniforms.stop_range.y;
+    let kind = uniforms.stop_range.z;
+
+    var offset: f32;
+
+    if (kind == LINEAR) {
+        let start = uniforms.position.xy;
+        let end = uniforms.position.zw;
 
-    let v1 = end - start;
-    let v2 = input.raw_position.xy - start;
-    let unit = normalize(v1);
-    let offset = dot(unit, v2) / length(v1);
+        let v1 = end - start;
+        let v2 = input.raw_position.xy - start;
+        let length_squared = dot(v1, v1);
+
+        // Degenerate gradients use their first stop, like in tiny-skia
+        if (length_squared > 0.0) {
+            offset = dot(v1, v2) / length_squared;
+        } else {
+            offset = 0.0;
+        }
+    } else if (kind == RADIAL) {
+        let center = uniforms.position.xy;
+        let radius = uniforms.position.z;
+
+        if (radius > 0.0) {
+            offset = distance(input.raw_position.xy, center) / radius;
+        } else {
+            offset = 0.0;
+        }
+    } else {
+        let center = uniforms.position.xy;
+        let angle = uniforms.position.z;
+
+        let v = input.raw_position.xy - center;
+
+        // The angle is undefined at the center itself
+        if (any(v != vec2<f32>(0.0, 0.0))) {
+            let turn = (atan2(v.y, v.x) - angle) / (2.0 * PI);
+            offset = turn - floor(turn);
+        } else {
+            offset = 0.0;
+        }
+    }
 
     let min_stop = color_stops[start_stop];
     let max_stop = color_stops[end_stop];
@@ -77,11 +117,11 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
             }
         }
 
-        color = mix(min.color, max.color, smoothstep(
-            min.offset,
-            max.offset,
-            offset
-        ));
+        color = mix(
+            min.color,
+            max.color,
+            (offset - min.offset) / (max.offset - min.offset)
+        );
     }
 
     return color;
@@ -13,6 +13,15 @@ struct VertexInput {
     @location(4) border_color: vec4<f32>,
     @location(5) border_radius: vec4<f32>,
     @location(6) border_width: f32,
+    @location(7) gradient_direction: vec4<f32>,
+    @location(8) gradient_colors_1: vec4<u32>,
+    @location(9) gradient_colors_2: vec4<u32>,
+    @location(10) gradient_colors_3: vec4<u32>,
+    @location(11) gradient_colors_4: vec4<u32>,
+    @location(12) gradient_offsets: vec4<u32>,
+    @location(13) gradient_stops: u32,
+    @location(14) shadow_color: vec4<f32>,
+    @location(15) shadow: vec3<f32>,
 }
 
 struct VertexOutput {
@@ -23,6 +32,15 @@ struct VertexOutput {
     @location(3) scale: vec2<f32>,
     @location(4) border_radius: vec4<f32>,
     @location(5) border_width: f32,
+    @location(6) gradient_direction: vec4<f32>,
+    @location(7) @interpolate(flat) gradient_colors_1: vec4<u32>,
+    @location(8) @interpolate(flat) gradient_colors_2: vec4<u32>,
+    @location(9) @interpolate(flat) gradient_colors_3: vec4<u32>,
+    @location(10) @interpolate(flat) gradient_colors_4: vec4<u32>,
+    @location(11) @interpolate(flat) gradient_offsets: vec4<u32>,
+    @location(12) @interpolate(flat) gradient_stops: u32,
+    @location(13) shadow_color: vec4<f32>,
+    @location(14) shadow: vec3<f32>,
 }
 
 @vertex
@@ -40,11 +58,26 @@ fn vs_main(input: VertexInput) -> VertexOutput {
         min(input.border_radius.w, min_border_radius)
     );
 
+    // Grow the quad to cover its shadow
+    var shadow_min: vec2<f32> = vec2<f32>(0.0, 0.0);
+    var shadow_max: vec2<f32> = vec2<f32>(0.0, 0.0);
+
+    if (input.shadow_color.a > 0.0) {
+        let shadow_offset = input.shadow.xy;
+        let shadow_blur_radius = vec2<f32>(input.shadow.z, input.shadow.z);
+
+        shadow_min = min(shadow_offset - shadow_blur_radius, shadow_min) * globals.scale;
+        shadow_max = max(shadow_offset + shadow_blur_radius, shadow_max) * globals.scale;
+    }
+
+    var area_pos: vec2<f32> = pos + shadow_min;
+    var area_scale: vec2<f32> = scale - shadow_min + shadow_max;
+
     var transform: mat4x4<f32> = mat4x4<f32>(
-        vec4<f32>(scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4<f32>(0.0, scale.y + 1.0, 0.0, 0.0),
+        vec4<f32>(area_scale.x + 1.0, 0.0, 0.0, 0.0),
+        vec4<f32>(0.0, area_scale.y + 1.0, 0.0, 0.0),
         vec4<f32>(0.0, 0.0, 1.0, 0.0),
-        vec4<f32>(pos - vec2<f32>(0.5, 0.5), 0.0, 1.0)
+        vec4<f32>(area_pos - vec2<f32>(0.5, 0.5), 0.0, 1.0)
     );
 
     out.color = input.color;
@@ -53,6 +86,15 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     out.scale = scale;
     out.border_radius = border_radius * globals.scale;
     out.border_width = input.border_width * globals.scale;
+    out.gradient_direction = input.gradient_direction * globals.scale;
+    out.gradient_colors_1 = input.gradient_colors_1;
+    out.gradient_colors_2 = input.gradient_colors_2;
+    out.gradient_colors_3 = input.gradient_colors_3;
+    out.gradient_colors_4 = input.gradient_colors_4;
+    out.gradient_offsets = input.gradient_offsets;
+    out.gradient_stops = input.gradient_stops;
+    out.shadow_color = input.shadow_color;
+    out.shadow = input.shadow * globals.scale;
     out.position = globals.transform * transform * vec4<f32>(input.v_pos, 0.0, 1.0);
 
     return out;
@@ -91,12 +133,57 @@ fn select_border_radius(radi: vec4<f32>, position: vec2<f32>, center: vec2<f32>)
     return rx;
 }
 
+// Returns the color of the linear gradient of the quad at the given position.
+// Colors are packed as 16-bit normalized RGBA, two channels per u32.
+fn gradient_color(input: VertexOutput, position: vec2<f32>) -> vec4<f32> {
+    var colors = array<vec4<f32>, 8>(
+        vec4<f32>(unpack2x16unorm(input.gradient_colors_1.x), unpack2x16unorm(input.gradient_colors_1.y)),
+        vec4<f32>(unpack2x16unorm(input.gradient_colors_1.z), unpack2x16unorm(input.gradient_colors_1.w)),
+        vec4<f32>(unpack2x16unorm(input.gradient_colors_2.x), unpack2x16unorm(input.gradient_colors_2.y)),
+        vec4<f32>(unpack2x16unorm(input.gradient_colors_2.z), unpack2x16unorm(input.gradient_colors_2.w)),
+        vec4<f32>(unpack2x16unorm(input.gradient_colors_3.x), unpack2x16unorm(input.gradient_colors_3.y)),
+        vec4<f32>(unpack2x16unorm(input.gradient_colors_3.z), unpack2x16unorm(input.gradient_colors_3.w)),
+        vec4<f32>(unpack2x16unorm(input.gradient_colors_4.x), unpack2x16unorm(input.gradient_colors_4.y)),
+        vec4<f32>(unpack2x16unorm(input.gradient_colors_4.z), unpack2x16unorm(input.gradient_colors_4.w))
+    );
+
+    var offsets = array<f32, 8>(
+        unpack2x16unorm(input.gradient_offsets.x).x,
+        unpack2x16unorm(input.gradient_offsets.x).y,
+        unpack2x16unorm(input.gradient_offsets.y).x,
+        unpack2x16unorm(input.gradient_offsets.y).y,
+        unpack2x16unorm(input.gradient_offsets.z).x,
+        unpack2x16unorm(input.gradient_offsets.z).y,
+        unpack2x16unorm(input.gradient_offsets.w).x,
+        unpack2x16unorm(input.gradient_offsets.w).y
+    );
+
+    let start = input.gradient_direction.xy;
+    let direction = input.gradient_direction.zw - start;
+    let offset = dot(position - start, direction) / max(dot(direction, direction), 0.0001);
+
+    var color = colors[0];
+
+    for (var i = 1u; i < input.gradient_stops; i = i + 1u) {
+        let range = max(offsets[i] - offsets[i - 1u], 0.0001);
+
+        color = mix(color, colors[i], clamp((offset - offsets[i - 1u]) / range, 0.0, 1.0));
+    }
+
+    return color;
+}
 
 @fragment
 fn fs_main(
     input: VertexOutput
 ) -> @location(0) vec4<f32> {
-    var mixed_color: vec4<f32> = input.color;
+    var background: vec4<f32> = input.color;
+
+    if (input.gradient_stops > 0u) {
+        background = gradient_color(input, input.position.xy);
+    }
+
+    var mixed_color: vec4<f32> = background;
 
     var border_radius = select_border_radius(
         input.border_radius,
@@ -120,7 +207,7 @@ fn fs_main(
             internal_distance
         );
 
-        mixed_color = mix(input.color, input.border_color, vec4<f32>(border_mix, border_mix, border_mix, border_mix));
+        mixed_color = mix(background, input.border_color, vec4<f32>(border_mix, border_mix, border_mix, border_mix));
     }
 
     var dist: f32 = distance_alg(
@@ -136,5 +223,45 @@ fn fs_main(
         dist
     );
 
-    return vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha);
+    var quad_color: vec4<f32> = vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha);
+
+    if (input.shadow_color.a > 0.0) {
+        let shadow_offset = input.shadow.xy;
+        let shadow_blur_radius = max(input.shadow.z, 0.5);
+
+        var shadow_border_radius = select_border_radius(
+            input.border_radius,
+            input.position.xy - shadow_offset,
+            (input.pos + input.scale * 0.5).xy
+        );
+
+        var shadow_distance: f32 = distance_alg(
+            input.position.xy,
+            input.pos + shadow_offset,
+            input.scale,
+            shadow_border_radius
+        ) - shadow_border_radius;
+
+        var shadow_alpha: f32 = input.shadow_color.a * (1.0 - smoothstep(
+            -shadow_blur_radius,
+            shadow_blur_radius,
+            shadow_distance
+        ));
+
+        // Composite the quad over its shadow
+        var alpha: f32 = quad_color.a + shadow_alpha * (1.0 - quad_color.a);
+
+        if (alpha <= 0.0) {
+            discard;
+        }
+
+        var color: vec3<f32> = (
+            quad_color.rgb * quad_color.a
+            + input.shadow_color.rgb * shadow_alpha * (1.0 - quad_color.a)
+        ) / alpha;
+
+        return vec4<f32>(color, alpha);
+    }
+
+    return quad_color;
 }
@@ -7,7 +7,7 @@ use crate::Transformation;
 
 use iced_graphics::layer::mesh::{self, Mesh};
 use iced_graphics::triangle::ColoredVertex2D;
-use iced_graphics::Size;
+use iced_graphics::{Rectangle, Size};
 #[cfg(feature = "tracing")]
 use tracing::info_span;
 
@@ -127,7 +127,10 @@ impl Pipeline {
                 }
                 #[cfg(not(target_arch = "wasm32"))]
                 Mesh::Gradient {
-                    buffers, gradient, ..
+                    buffers,
+                    size,
+                    gradient,
+                    ..
                 } => {
                     let written_bytes = self.gradient.vertices.write(
                         device,
@@ -139,52 +142,79 @@ impl Pipeline {
 
                     gradient_vertex_offset += written_bytes;
 
-                    match gradient {
-                        iced_graphics::Gradient::Linear(linear) => {
-                            use glam::{IVec4, Vec4};
-
-                            let start_offset = self.gradient.color_stop_offset;
-                            let end_offset = (linear.color_stops.len() as i32)
-                                + start_offset
-                                - 1;
-
-                            self.gradient.uniforms.push(&gradient::Uniforms {
-                                transform: transform.into(),
-                                direction: Vec4::new(
-                                    linear.start.x,
-                                    linear.start.y,
-                                    linear.end.x,
-                                    linear.end.y,
-                                ),
-                                stop_range: IVec4::new(
-                                    start_offset,
-                                    end_offset,
-                                    0,
-                                    0,
-                                ),
-                            });
-
-                            self.gradient.color_stop_offset = end_offset + 1;
-
-                            let stops: Vec<gradient::ColorStop> = linear
-                                .color_stops
-                                .iter()
-                                .map(|stop| {
-                                    let [r, g, b, a] = stop.color.into_linear();
-
-                                    gradient::ColorStop {
-                                        offset: stop.offset,
-                                        color: Vec4::new(r, g, b, a),
-                                    }
-                                })
-                                .collect();
+                    use glam::{IVec4, Vec4};
+                    use iced_graphics::Gradient;
 
-                            self.gradient
-                                .color_stops_pending_write
-                                .color_stops
-                                .extend(stops);
+                    let (direction, kind) = match gradient {
+                        Gradient::Linear(linear) => {
+                            let (start, end) =
+                                linear.points(Rectangle::with_size(*size));
+
+                            (
+                                Vec4::new(start.x, start.y, end.x, end.y),
+                                gradient::LINEAR,
+                            )
                         }
+                        Gradient::Radial(radial) => (
+                            Vec4::new(
+                                radial.center.x,
+                                radial.center.y,
+                                radial.radius,
+                                0.0,
+                            ),
+                            gradient::RADIAL,
+                        ),
+                        Gradient::Conic(conic) => (
+                            Vec4::new(
+                                conic.center.x,
+                                conic.center.y,
+                                conic.angle,
+                                0.0,
+                            ),
+                            gradient::CONIC,
+                        ),
+                    };
+
+                    let mut stops: Vec<gradient::ColorStop> = gradient
+                        .stops()
+                        .map(|stop| {
+                            let [r, g, b, a] = stop.color.into_linear();
+
+                            gradient::ColorStop {
+                                offset: stop.offset,
+                                color: Vec4::new(r, g, b, a),
+                            }
+                        })
+                        .collect();
+
+                    // A gradient without stops is transparent
+                    if stops.is_empty() {
+                        stops.push(gradient::ColorStop {
+                            offset: 0.0,
+                            color: Vec4::ZERO,
+                        });
                     }
+
+                    let start_offset = self.gradient.color_stop_offset;
+                    let end_offset = (stops.len() as i32) + start_offset - 1;
+
+                    self.gradient.uniforms.push(&gradient::Uniforms {
+                        transform: transform.into(),
+                        direction,
+                        stop_range: IVec4::new(
+                            start_offset,
+                            end_offset,
+                            kind,
+                            0,
+                        ),
+                    });
+
+                    self.gradient.color_stop_offset = end_offset + 1;
+
+                    self.gradient
+                        .color_stops_pending_write
+                        .color_stops
+                        .extend(stops);
                 }
                 #[cfg(target_arch = "wasm32")]
                 Mesh::Gradient { .. } => {}
@@ -559,12 +589,18 @@ mod gradient {
     #[derive(Debug, ShaderType)]
     pub struct Uniforms {
         pub transform: glam::Mat4,
-        //xy = start, zw = end
+        //linear: xy = start, zw = end
+        //radial: xy = center, z = radius
+        //conic: xy = center, z = angle
         pub direction: Vec4,
-        //x = start stop, y = end stop, zw = padding
+        //x = start stop, y = end stop, z = kind, w = padding
         pub stop_range: IVec4,
     }
 
+    pub const LINEAR: i32 = 0;
+    pub const RADIAL: i32 = 1;
+    pub const CONIC: i32 = 2;
+
     #[derive(Debug, ShaderType)]
     pub struct ColorStop {
         pub color: Vec4,
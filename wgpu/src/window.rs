@@ -1,4 +1,5 @@
 //! Display rendering results on windows.
+mod capture;
 mod compositor;
 
 pub use compositor::Compositor;
@@ -1,3 +1,4 @@
+use crate::window::capture::Capture;
 use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
 
 use futures::stream::{self, StreamExt};
@@ -7,6 +8,7 @@ use iced_native::futures;
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 
 use std::marker::PhantomData;
+use std::sync::mpsc;
 
 /// A window graphics backend for iced powered by `wgpu`.
 #[allow(missing_debug_implementations)]
@@ -18,6 +20,7 @@ pub struct Compositor<Theme> {
     queue: wgpu::Queue,
     staging_belt: wgpu::util::StagingBelt,
     format: wgpu::TextureFormat,
+    capture: Option<Capture>,
     theme: PhantomData<Theme>,
 }
 
@@ -108,6 +111,7 @@ impl<Theme> Compositor<Theme> {
             queue,
             staging_belt,
             format,
+            capture: None,
             theme: PhantomData,
         })
     }
@@ -116,6 +120,66 @@ impl<Theme> Compositor<Theme> {
     pub fn create_backend(&self) -> Backend {
         Backend::new(&self.device, self.settings, self.format)
     }
+
+    /// Renders the primitives of the [`Renderer`] to the given texture view
+    /// and submits the work to the queue.
+    fn render<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Renderer<Theme>,
+        view: &wgpu::TextureView,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> wgpu::SubmissionIndex {
+        let mut encoder = self.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor {
+                label: Some("iced_wgpu encoder"),
+            },
+        );
+
+        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("iced_wgpu::window::Compositor render pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear({
+                        let [r, g, b, a] = background_color.into_linear();
+
+                        wgpu::Color {
+                            r: f64::from(r),
+                            g: f64::from(g),
+                            b: f64::from(b),
+                            a: f64::from(a),
+                        }
+                    }),
+                    store: true,
+                },
+            })],
+            depth_stencil_attachment: None,
+        });
+
+        renderer.with_primitives(|backend, primitives| {
+            backend.present(
+                &self.device,
+                &mut self.staging_belt,
+                &mut encoder,
+                view,
+                primitives,
+                viewport,
+                overlay,
+            );
+        });
+
+        // Submit work
+        self.staging_belt.finish();
+        let submission = self.queue.submit(Some(encoder.finish()));
+
+        // Recall staging buffers
+        self.staging_belt.recall();
+
+        submission
+    }
 }
 
 impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
@@ -186,78 +250,164 @@ impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
     ) -> Result<(), compositor::SurfaceError> {
         match surface.get_current_texture() {
             Ok(frame) => {
-                let mut encoder = self.device.create_command_encoder(
-                    &wgpu::CommandEncoderDescriptor {
-                        label: Some("iced_wgpu encoder"),
-                    },
-                );
-
                 let view = &frame
                     .texture
                     .create_view(&wgpu::TextureViewDescriptor::default());
 
-                let _ =
-                    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                        label: Some(
-                            "iced_wgpu::window::Compositor render pass",
-                        ),
-                        color_attachments: &[Some(
-                            wgpu::RenderPassColorAttachment {
-                                view,
-                                resolve_target: None,
-                                ops: wgpu::Operations {
-                                    load: wgpu::LoadOp::Clear({
-                                        let [r, g, b, a] =
-                                            background_color.into_linear();
-
-                                        wgpu::Color {
-                                            r: f64::from(r),
-                                            g: f64::from(g),
-                                            b: f64::from(b),
-                                            a: f64::from(a),
-                                        }
-                                    }),
-                                    store: true,
-                                },
-                            },
-                        )],
-                        depth_stencil_attachment: None,
-                    });
-
-                renderer.with_primitives(|backend, primitives| {
-                    backend.present(
-                        &self.device,
-                        &mut self.staging_belt,
-                        &mut encoder,
-                        view,
-                        primitives,
-                        viewport,
-                        overlay,
-                    );
-                });
-
-                // Submit work
-                self.staging_belt.finish();
-                let _submission = self.queue.submit(Some(encoder.finish()));
-                frame.present();
+                let _ = self.render(
+                    renderer,
+                    view,
+                    viewport,
+                    background_color,
+                    overlay,
+                );
 
-                // Recall staging buffers
-                self.staging_belt.recall();
+                frame.present();
 
                 Ok(())
             }
-            Err(error) => match error {
-                wgpu::SurfaceError::Timeout => {
-                    Err(compositor::SurfaceError::Timeout)
-                }
-                wgpu::SurfaceError::Outdated => {
-                    Err(compositor::SurfaceError::Outdated)
-                }
-                wgpu::SurfaceError::Lost => Err(compositor::SurfaceError::Lost),
-                wgpu::SurfaceError::OutOfMemory => {
-                    Err(compositor::SurfaceError::OutOfMemory)
-                }
+            Err(error) => Err(surface_error(error)),
+        }
+    }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Result<Vec<u8>, compositor::SurfaceError> {
+        let frame = surface.get_current_texture().map_err(surface_error)?;
+
+        let size = viewport.physical_size();
+
+        let mut capture = self
+            .capture
+            .take()
+            .unwrap_or_else(|| Capture::new(&self.device, self.format));
+
+        let view = capture.target(&self.device, size.width, size.height);
+
+        let _ =
+            self.render(renderer, view, viewport, background_color, overlay);
+
+        // Rows of a buffer copy must be aligned
+        let row_size = size.width * 4;
+        let padded_row_size = (row_size + wgpu::COPY_BYTES_PER_ROW_ALIGNMENT
+            - 1)
+            / wgpu::COPY_BYTES_PER_ROW_ALIGNMENT
+            * wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::window::Compositor screenshot buffer"),
+            size: u64::from(padded_row_size * size.height),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor {
+                label: Some("iced_wgpu screenshot encoder"),
+            },
+        );
+
+        // The rendered texture is both presented and read back
+        capture.draw(
+            &mut encoder,
+            &frame
+                .texture
+                .create_view(&wgpu::TextureViewDescriptor::default()),
+        );
+
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: capture.texture(),
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: std::num::NonZeroU32::new(padded_row_size),
+                    rows_per_image: None,
+                },
+            },
+            wgpu::Extent3d {
+                width: size.width,
+                height: size.height,
+                depth_or_array_layers: 1,
             },
+        );
+
+        let submission = self.queue.submit(Some(encoder.finish()));
+
+        frame.present();
+
+        self.capture = Some(capture);
+
+        let slice = buffer.slice(..);
+        let (sender, receiver) = mpsc::channel();
+
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+
+        let _ = self
+            .device
+            .poll(wgpu::Maintain::WaitForSubmissionIndex(submission));
+
+        match receiver.try_recv() {
+            Ok(Ok(())) => {}
+            Ok(Err(error)) => {
+                log::warn!("Failed to read back screenshot: {}", error);
+
+                // Mapping a buffer only fails once the device is gone
+                return Err(compositor::SurfaceError::Lost);
+            }
+            Err(_) => {
+                log::warn!("Screenshot buffer was not mapped after polling");
+
+                return Err(compositor::SurfaceError::Timeout);
+            }
+        }
+
+        let is_bgra = matches!(
+            self.format,
+            wgpu::TextureFormat::Bgra8Unorm
+                | wgpu::TextureFormat::Bgra8UnormSrgb
+        );
+
+        let mut bytes = Vec::with_capacity((row_size * size.height) as usize);
+
+        for row in slice
+            .get_mapped_range()
+            .chunks_exact(padded_row_size as usize)
+        {
+            for pixel in row[..row_size as usize].chunks_exact(4) {
+                if is_bgra {
+                    bytes.extend([pixel[2], pixel[1], pixel[0], pixel[3]]);
+                } else {
+                    bytes.extend_from_slice(pixel);
+                }
+            }
+        }
+
+        buffer.unmap();
+
+        Ok(bytes)
+    }
+}
+
+fn surface_error(error: wgpu::SurfaceError) -> compositor::SurfaceError {
+    match error {
+        wgpu::SurfaceError::Timeout => compositor::SurfaceError::Timeout,
+        wgpu::SurfaceError::Outdated => compositor::SurfaceError::Outdated,
+        wgpu::SurfaceError::Lost => compositor::SurfaceError::Lost,
+        wgpu::SurfaceError::OutOfMemory => {
+            compositor::SurfaceError::OutOfMemory
         }
     }
 }
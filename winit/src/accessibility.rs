@@ -0,0 +1,93 @@
+//! Feed the accessibility tree of a window to assistive technologies.
+//!
+//! The shells collect the accessibility [`Tree`] of every window after each
+//! frame and give it to a [`Bridge`], which exposes it to the assistive
+//! technologies of the platform—like screen readers, through AccessKit.
+//!
+//! A [`Bridge`] is created for every window with the function set in
+//! [`connect`]. Nothing is collected while no function is set.
+pub use iced_native::accessibility::*;
+
+use crate::window;
+use crate::{Renderer, UserInterface};
+
+use iced_native::application::StyleSheet;
+
+use std::sync::Mutex;
+
+/// A connection between the accessibility [`Tree`] of a window and the
+/// assistive technologies of the platform.
+pub trait Bridge {
+    /// Updates the assistive technologies with the new [`Tree`] of the
+    /// window.
+    fn update(&mut self, tree: &Tree);
+}
+
+static CONNECT: Mutex<
+    Option<
+        Box<
+            dyn Fn(window::Id, &winit::window::Window) -> Box<dyn Bridge>
+                + Send,
+        >,
+    >,
+> = Mutex::new(None);
+
+/// Sets the function that creates the [`Bridge`] of every window of the
+/// application.
+///
+/// It should be set before running the application, since windows only
+/// connect when they are created. It replaces any function set before.
+pub fn connect(
+    f: impl Fn(window::Id, &winit::window::Window) -> Box<dyn Bridge>
+        + Send
+        + 'static,
+) {
+    *CONNECT.lock().unwrap_or_else(|e| e.into_inner()) = Some(Box::new(f));
+}
+
+/// The [`Bridge`] of a window, if one was connected, along with the last
+/// [`Tree`] given to it.
+#[allow(missing_debug_implementations)]
+#[derive(Default)]
+pub struct Connection {
+    bridge: Option<Box<dyn Bridge>>,
+    tree: Option<Tree>,
+}
+
+impl Connection {
+    /// Creates the [`Connection`] of a new window, using the function set in
+    /// [`connect`].
+    pub fn new(id: window::Id, window: &winit::window::Window) -> Self {
+        let bridge = CONNECT
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .map(|connect| connect(id, window));
+
+        Self { bridge, tree: None }
+    }
+
+    /// Collects the accessibility [`Tree`] of the [`UserInterface`] and
+    /// gives it to the [`Bridge`], if it changed since the last time.
+    pub fn update<Message, R>(
+        &mut self,
+        user_interface: &mut UserInterface<'_, Message, R>,
+        renderer: &R,
+    ) where
+        R: Renderer,
+        R::Theme: StyleSheet,
+    {
+        let bridge = match &mut self.bridge {
+            Some(bridge) => bridge,
+            None => return,
+        };
+
+        let tree = user_interface.accessibility(renderer);
+
+        if self.tree.as_ref() != Some(&tree) {
+            bridge.update(&tree);
+
+            self.tree = Some(tree);
+        }
+    }
+}
@@ -5,8 +5,11 @@ mod state;
 
 pub use state::State;
 
+use crate::accessibility;
 use crate::clipboard::{self, Clipboard};
 use crate::conversion;
+use crate::event;
+use crate::keyboard;
 use crate::mouse;
 use crate::renderer;
 use crate::widget::operation;
@@ -26,6 +29,8 @@ use iced_native::user_interface::{self, UserInterface};
 pub use iced_native::application::{Appearance, StyleSheet};
 
 use std::mem::ManuallyDrop;
+use std::sync::atomic::{self, AtomicBool};
+use std::sync::Arc;
 
 #[cfg(feature = "trace")]
 pub use profiler::Profiler;
@@ -203,6 +208,7 @@ where
             init_command,
             window,
             settings.exit_on_close_request,
+            settings.focus_navigation,
         );
 
         #[cfg(feature = "trace")]
@@ -269,6 +275,7 @@ async fn run_instance<A, E, C>(
     init_command: Command<A::Message>,
     window: winit::window::Window,
     exit_on_close_request: bool,
+    focus_navigation: bool,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
@@ -279,13 +286,21 @@ async fn run_instance<A, E, C>(
     use winit::event;
     use winit::event_loop::ControlFlow;
 
+    // The window must outlive both the clipboard and the surface, so it is
+    // declared first and dropped last.
+    let window = Arc::new(window);
+    let image_loaded = redraw_on_load(&window);
+
     let mut clipboard = Clipboard::connect(&window);
     let mut cache = user_interface::Cache::default();
-    let mut surface = compositor.create_surface(&window);
+    let mut surface = compositor.create_surface(window.as_ref());
     let mut should_exit = false;
+    let mut screenshots = Vec::new();
 
     let mut state = State::new(&application, &window);
     let mut viewport_version = state.viewport_version();
+    let mut accessibility =
+        accessibility::Connection::new(crate::window::Id::MAIN, &window);
 
     let physical_size = state.physical_size();
 
@@ -304,6 +319,7 @@ async fn run_instance<A, E, C>(
         &mut runtime,
         &mut clipboard,
         &mut should_exit,
+        &mut screenshots,
         &mut proxy,
         &mut debug,
         &window,
@@ -320,6 +336,7 @@ async fn run_instance<A, E, C>(
     ));
 
     let mut mouse_interaction = mouse::Interaction::default();
+    let mut last_click = None;
     let mut events = Vec::new();
     let mut messages = Vec::new();
     let mut redraw_pending = false;
@@ -334,7 +351,8 @@ async fn run_instance<A, E, C>(
                     event::StartCause::Init
                         | event::StartCause::Poll
                         | event::StartCause::ResumeTimeReached { .. }
-                );
+                ) || image_loaded
+                    .swap(false, atomic::Ordering::Relaxed);
             }
             event::Event::MainEventsCleared => {
                 if !redraw_pending && events.is_empty() && messages.is_empty() {
@@ -353,8 +371,19 @@ async fn run_instance<A, E, C>(
 
                 debug.event_processing_finished();
 
-                for event in events.drain(..).zip(statuses.into_iter()) {
-                    runtime.broadcast(event);
+                for (event, status) in
+                    events.drain(..).zip(statuses.into_iter())
+                {
+                    if focus_navigation {
+                        navigate_focus(
+                            &mut user_interface,
+                            &renderer,
+                            &event,
+                            status,
+                        );
+                    }
+
+                    runtime.broadcast((event, status));
                 }
 
                 if !messages.is_empty()
@@ -375,6 +404,7 @@ async fn run_instance<A, E, C>(
                         &mut runtime,
                         &mut clipboard,
                         &mut should_exit,
+                        &mut screenshots,
                         &mut proxy,
                         &mut debug,
                         &mut messages,
@@ -404,6 +434,7 @@ async fn run_instance<A, E, C>(
                 // Then, we can use the `interface_state` here to decide if a redraw
                 // is needed right away, or simply wait until a specific time.
                 let redraw_event = Event::Window(
+                    crate::window::Id::MAIN,
                     crate::window::Event::RedrawRequested(Instant::now()),
                 );
 
@@ -426,10 +457,15 @@ async fn run_instance<A, E, C>(
                 );
                 debug.draw_finished();
 
+                accessibility.update(&mut user_interface, &renderer);
+
                 if new_mouse_interaction != mouse_interaction {
                     window.set_cursor_icon(conversion::mouse_interaction(
                         new_mouse_interaction,
                     ));
+                    window.set_cursor_visible(conversion::cursor_visible(
+                        new_mouse_interaction,
+                    ));
 
                     mouse_interaction = new_mouse_interaction;
                 }
@@ -505,11 +541,16 @@ async fn run_instance<A, E, C>(
                         window.set_cursor_icon(conversion::mouse_interaction(
                             new_mouse_interaction,
                         ));
+                        window.set_cursor_visible(conversion::cursor_visible(
+                            new_mouse_interaction,
+                        ));
 
                         mouse_interaction = new_mouse_interaction;
                     }
                     debug.draw_finished();
 
+                    accessibility.update(&mut user_interface, &renderer);
+
                     compositor.configure_surface(
                         &mut surface,
                         physical_size.width,
@@ -519,13 +560,38 @@ async fn run_instance<A, E, C>(
                     viewport_version = current_viewport_version;
                 }
 
-                match compositor.present(
-                    &mut renderer,
-                    &mut surface,
-                    state.viewport(),
-                    state.background_color(),
-                    &debug.overlay(),
-                ) {
+                let result = if screenshots.is_empty() {
+                    compositor.present(
+                        &mut renderer,
+                        &mut surface,
+                        state.viewport(),
+                        state.background_color(),
+                        &debug.overlay(),
+                    )
+                } else {
+                    compositor
+                        .screenshot(
+                            &mut renderer,
+                            &mut surface,
+                            state.viewport(),
+                            state.background_color(),
+                            &debug.overlay(),
+                        )
+                        .map(|bytes| {
+                            let screenshot = crate::window::Screenshot::new(
+                                bytes,
+                                physical_size,
+                            );
+
+                            for tag in screenshots.drain(..) {
+                                proxy
+                                    .send_event(tag(screenshot.clone()))
+                                    .expect("Send message to event loop");
+                            }
+                        })
+                };
+
+                match result {
                     Ok(()) => {
                         debug.render_finished();
 
@@ -559,9 +625,12 @@ async fn run_instance<A, E, C>(
                 state.update(&window, &window_event, &mut debug);
 
                 if let Some(event) = conversion::window_event(
+                    crate::window::Id::MAIN,
                     &window_event,
                     state.scale_factor(),
                     state.modifiers(),
+                    state.cursor_position(),
+                    &mut last_click,
                 ) {
                     events.push(event);
                 }
@@ -598,6 +667,46 @@ pub fn requests_exit(
     }
 }
 
+/// Moves the keyboard focus of the [`UserInterface`] to the next focusable
+/// widget—or the previous one, if Shift is held—when Tab is pressed and no
+/// widget has captured the key.
+pub fn navigate_focus<Message, Renderer>(
+    user_interface: &mut UserInterface<'_, Message, Renderer>,
+    renderer: &Renderer,
+    event: &Event,
+    status: event::Status,
+) where
+    Message: 'static,
+    Renderer: crate::Renderer,
+    <Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    if status == event::Status::Captured {
+        return;
+    }
+
+    if let Event::Keyboard(keyboard::Event::KeyPressed {
+        key_code: keyboard::KeyCode::Tab,
+        modifiers,
+    }) = event
+    {
+        let mut current_operation: Option<
+            Box<dyn operation::Operation<Message>>,
+        > = Some(if modifiers.shift() {
+            Box::new(operation::focusable::focus_previous())
+        } else {
+            Box::new(operation::focusable::focus_next())
+        });
+
+        while let Some(mut operation) = current_operation.take() {
+            user_interface.operate(renderer, operation.as_mut());
+
+            if let operation::Outcome::Chain(next) = operation.finish() {
+                current_operation = Some(next);
+            }
+        }
+    }
+}
+
 /// Builds a [`UserInterface`] for the provided [`Application`], logging
 /// [`struct@Debug`] information accordingly.
 pub fn build_user_interface<'a, A: Application>(
@@ -643,6 +752,9 @@ pub fn update<A: Application, E: Executor>(
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
     clipboard: &mut Clipboard,
     should_exit: &mut bool,
+    screenshots: &mut Vec<
+        Box<dyn FnOnce(crate::window::Screenshot) -> A::Message>,
+    >,
     proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
@@ -673,6 +785,7 @@ pub fn update<A: Application, E: Executor>(
             runtime,
             clipboard,
             should_exit,
+            screenshots,
             proxy,
             debug,
             window,
@@ -694,6 +807,9 @@ pub fn run_command<A, E>(
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
     clipboard: &mut Clipboard,
     should_exit: &mut bool,
+    screenshots: &mut Vec<
+        Box<dyn FnOnce(crate::window::Screenshot) -> A::Message>,
+    >,
     proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
     debug: &mut Debug,
     window: &winit::window::Window,
@@ -724,61 +840,34 @@ pub fn run_command<A, E>(
                     clipboard.write(contents);
                 }
             },
-            command::Action::Window(action) => match action {
-                window::Action::Close => {
-                    *should_exit = true;
-                }
-                window::Action::Drag => {
-                    let _res = window.drag_window();
-                }
-                window::Action::Resize { width, height } => {
-                    window.set_inner_size(winit::dpi::LogicalSize {
-                        width,
-                        height,
-                    });
-                }
-                window::Action::Maximize(value) => {
-                    window.set_maximized(value);
-                }
-                window::Action::Minimize(value) => {
-                    window.set_minimized(value);
-                }
-                window::Action::Move { x, y } => {
-                    window.set_outer_position(winit::dpi::LogicalPosition {
-                        x,
-                        y,
-                    });
-                }
-                window::Action::SetMode(mode) => {
-                    window.set_visible(conversion::visible(mode));
-                    window.set_fullscreen(conversion::fullscreen(
-                        window.primary_monitor(),
-                        mode,
-                    ));
-                }
-                window::Action::FetchMode(tag) => {
-                    let mode = if window.is_visible().unwrap_or(true) {
-                        conversion::mode(window.fullscreen())
-                    } else {
-                        window::Mode::Hidden
-                    };
+            command::Action::Window(id, action) => {
+                if id != window::Id::MAIN {
+                    log::warn!(
+                        "Window {:?} does not exist in a single-window \
+                        application",
+                        id
+                    );
 
-                    proxy
-                        .send_event(tag(mode))
-                        .expect("Send message to event loop");
-                }
-                window::Action::ToggleMaximize => {
-                    window.set_maximized(!window.is_maximized())
+                    continue;
                 }
-                window::Action::ToggleDecorations => {
-                    window.set_decorations(!window.is_decorated())
+
+                match action {
+                    window::Action::Close => {
+                        *should_exit = true;
+                    }
+                    window::Action::Spawn(_) => {
+                        log::warn!(
+                            "Spawning windows is only supported in \
+                            multi-window applications"
+                        );
+                    }
+                    window::Action::Screenshot(tag) => {
+                        screenshots.push(tag);
+                        window.request_redraw();
+                    }
+                    action => run_window_action(action, window, proxy),
                 }
-                window::Action::RequestUserAttention(user_attention) => window
-                    .request_user_attention(
-                        user_attention.map(conversion::user_attention),
-                    ),
-                window::Action::GainFocus => window.focus_window(),
-            },
+            }
             command::Action::System(action) => match action {
                 system::Action::QueryInformation(_tag) => {
                     #[cfg(feature = "system")]
@@ -834,6 +923,98 @@ pub fn run_command<A, E>(
     }
 }
 
+/// Redraws the given window whenever an image finishes loading in the
+/// background.
+///
+/// The returned flag is raised when that happens, so that the user interface
+/// is updated and drawn again too.
+pub fn redraw_on_load(window: &Arc<winit::window::Window>) -> Arc<AtomicBool> {
+    let image_loaded = Arc::new(AtomicBool::new(false));
+    let window = Arc::downgrade(window);
+
+    iced_graphics::image::on_load({
+        let image_loaded = image_loaded.clone();
+
+        move || {
+            if let Some(window) = window.upgrade() {
+                image_loaded.store(true, atomic::Ordering::Relaxed);
+                window.request_redraw();
+            }
+        }
+    });
+
+    image_loaded
+}
+
+/// Runs a [`window::Action`] on the given window.
+///
+/// The [`window::Action::Close`] and [`window::Action::Spawn`] actions must be
+/// handled by the caller, since they change the set of windows of the
+/// application. The same applies to [`window::Action::Screenshot`], which
+/// needs the compositor.
+///
+/// [`window::Action`]: iced_native::window::Action
+/// [`window::Action::Close`]: iced_native::window::Action::Close
+/// [`window::Action::Spawn`]: iced_native::window::Action::Spawn
+/// [`window::Action::Screenshot`]: iced_native::window::Action::Screenshot
+pub fn run_window_action<Message: std::fmt::Debug + 'static>(
+    action: iced_native::window::Action<Message>,
+    window: &winit::window::Window,
+    proxy: &mut winit::event_loop::EventLoopProxy<Message>,
+) {
+    use iced_native::window;
+
+    match action {
+        window::Action::Close
+        | window::Action::Spawn(_)
+        | window::Action::Screenshot(_) => {}
+        window::Action::Drag => {
+            let _res = window.drag_window();
+        }
+        window::Action::Resize { width, height } => {
+            window.set_inner_size(winit::dpi::LogicalSize { width, height });
+        }
+        window::Action::Maximize(value) => {
+            window.set_maximized(value);
+        }
+        window::Action::Minimize(value) => {
+            window.set_minimized(value);
+        }
+        window::Action::Move { x, y } => {
+            window.set_outer_position(winit::dpi::LogicalPosition { x, y });
+        }
+        window::Action::SetMode(mode) => {
+            window.set_visible(conversion::visible(mode));
+            window.set_fullscreen(conversion::fullscreen(
+                window.primary_monitor(),
+                mode,
+            ));
+        }
+        window::Action::FetchMode(tag) => {
+            let mode = if window.is_visible().unwrap_or(true) {
+                conversion::mode(window.fullscreen())
+            } else {
+                window::Mode::Hidden
+            };
+
+            proxy
+                .send_event(tag(mode))
+                .expect("Send message to event loop");
+        }
+        window::Action::ToggleMaximize => {
+            window.set_maximized(!window.is_maximized())
+        }
+        window::Action::ToggleDecorations => {
+            window.set_decorations(!window.is_decorated())
+        }
+        window::Action::RequestUserAttention(user_attention) => window
+            .request_user_attention(
+                user_attention.map(conversion::user_attention),
+            ),
+        window::Action::GainFocus => window.focus_window(),
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     pub fn run<T, F>(
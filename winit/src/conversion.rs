@@ -9,10 +9,16 @@ use crate::window;
 use crate::{Event, Point, Position};
 
 /// Converts a winit window event into an iced event.
+///
+/// The last [`mouse::Click`] is updated when a mouse button is pressed at the
+/// given cursor position.
 pub fn window_event(
+    id: window::Id,
     event: &winit::event::WindowEvent<'_>,
     scale_factor: f64,
     modifiers: winit::event::ModifiersState,
+    cursor_position: Point,
+    last_click: &mut Option<mouse::Click>,
 ) -> Option<Event> {
     use winit::event::WindowEvent;
 
@@ -20,21 +26,27 @@ pub fn window_event(
         WindowEvent::Resized(new_size) => {
             let logical_size = new_size.to_logical(scale_factor);
 
-            Some(Event::Window(window::Event::Resized {
-                width: logical_size.width,
-                height: logical_size.height,
-            }))
+            Some(Event::Window(
+                id,
+                window::Event::Resized {
+                    width: logical_size.width,
+                    height: logical_size.height,
+                },
+            ))
         }
         WindowEvent::ScaleFactorChanged { new_inner_size, .. } => {
             let logical_size = new_inner_size.to_logical(scale_factor);
 
-            Some(Event::Window(window::Event::Resized {
-                width: logical_size.width,
-                height: logical_size.height,
-            }))
+            Some(Event::Window(
+                id,
+                window::Event::Resized {
+                    width: logical_size.width,
+                    height: logical_size.height,
+                },
+            ))
         }
         WindowEvent::CloseRequested => {
-            Some(Event::Window(window::Event::CloseRequested))
+            Some(Event::Window(id, window::Event::CloseRequested))
         }
         WindowEvent::CursorMoved { position, .. } => {
             let position = position.to_logical::<f64>(scale_factor);
@@ -54,7 +66,17 @@ pub fn window_event(
 
             Some(Event::Mouse(match state {
                 winit::event::ElementState::Pressed => {
-                    mouse::Event::ButtonPressed(button)
+                    let click =
+                        mouse::Click::new(cursor_position, button, *last_click);
+
+                    *last_click = Some(click);
+
+                    mouse::Event::ButtonPressed {
+                        button,
+                        position: cursor_position,
+                        modifiers: self::modifiers(modifiers),
+                        click,
+                    }
                 }
                 winit::event::ElementState::Released => {
                     mouse::Event::ButtonReleased(button)
@@ -112,19 +134,22 @@ pub fn window_event(
         WindowEvent::ModifiersChanged(new_modifiers) => Some(Event::Keyboard(
             keyboard::Event::ModifiersChanged(self::modifiers(*new_modifiers)),
         )),
-        WindowEvent::Focused(focused) => Some(Event::Window(if *focused {
-            window::Event::Focused
-        } else {
-            window::Event::Unfocused
-        })),
+        WindowEvent::Focused(focused) => Some(Event::Window(
+            id,
+            if *focused {
+                window::Event::Focused
+            } else {
+                window::Event::Unfocused
+            },
+        )),
         WindowEvent::HoveredFile(path) => {
-            Some(Event::Window(window::Event::FileHovered(path.clone())))
+            Some(Event::Window(id, window::Event::FileHovered(path.clone())))
         }
         WindowEvent::DroppedFile(path) => {
-            Some(Event::Window(window::Event::FileDropped(path.clone())))
+            Some(Event::Window(id, window::Event::FileDropped(path.clone())))
         }
         WindowEvent::HoveredFileCancelled => {
-            Some(Event::Window(window::Event::FilesHoveredLeft))
+            Some(Event::Window(id, window::Event::FilesHoveredLeft))
         }
         WindowEvent::Touch(touch) => {
             Some(Event::Touch(touch_event(*touch, scale_factor)))
@@ -133,7 +158,7 @@ pub fn window_event(
             let winit::dpi::LogicalPosition { x, y } =
                 position.to_logical(scale_factor);
 
-            Some(Event::Window(window::Event::Moved { x, y }))
+            Some(Event::Window(id, window::Event::Moved { x, y }))
         }
         _ => None,
     }
@@ -217,6 +242,9 @@ pub fn mode(mode: Option<winit::window::Fullscreen>) -> window::Mode {
 
 /// Converts a `MouseCursor` from [`iced_native`] to a [`winit`] cursor icon.
 ///
+/// [`mouse::Interaction::Hidden`] has no icon and is converted to the default
+/// one. Use [`cursor_visible`] to hide the cursor instead.
+///
 /// [`winit`]: https://github.com/rust-windowing/winit
 /// [`iced_native`]: https://github.com/iced-rs/iced/tree/0.7/native
 pub fn mouse_interaction(
@@ -236,9 +264,37 @@ pub fn mouse_interaction(
             winit::window::CursorIcon::EwResize
         }
         Interaction::ResizingVertically => winit::window::CursorIcon::NsResize,
+        Interaction::ResizingDiagonallyUp => {
+            winit::window::CursorIcon::NeswResize
+        }
+        Interaction::ResizingDiagonallyDown => {
+            winit::window::CursorIcon::NwseResize
+        }
+        Interaction::ResizingColumn => winit::window::CursorIcon::ColResize,
+        Interaction::ResizingRow => winit::window::CursorIcon::RowResize,
+        Interaction::Move => winit::window::CursorIcon::Move,
+        Interaction::AllScroll => winit::window::CursorIcon::AllScroll,
+        Interaction::NotAllowed => winit::window::CursorIcon::NotAllowed,
+        Interaction::NoDrop => winit::window::CursorIcon::NoDrop,
+        Interaction::Copy => winit::window::CursorIcon::Copy,
+        Interaction::Alias => winit::window::CursorIcon::Alias,
+        Interaction::Wait => winit::window::CursorIcon::Wait,
+        Interaction::Help => winit::window::CursorIcon::Help,
+        Interaction::ContextMenu => winit::window::CursorIcon::ContextMenu,
+        Interaction::Cell => winit::window::CursorIcon::Cell,
+        Interaction::VerticalText => winit::window::CursorIcon::VerticalText,
+        Interaction::ZoomIn => winit::window::CursorIcon::ZoomIn,
+        Interaction::ZoomOut => winit::window::CursorIcon::ZoomOut,
+        Interaction::Hidden => winit::window::CursorIcon::Default,
     }
 }
 
+/// Returns whether the cursor should be visible for the given
+/// [`mouse::Interaction`].
+pub fn cursor_visible(interaction: mouse::Interaction) -> bool {
+    interaction != mouse::Interaction::Hidden
+}
+
 /// Converts a `MouseButton` from [`winit`] to an [`iced_native`] mouse button.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -35,10 +35,13 @@
 pub use iced_native::*;
 pub use winit;
 
+pub mod accessibility;
 #[cfg(feature = "application")]
 pub mod application;
 pub mod clipboard;
 pub mod conversion;
+#[cfg(feature = "multi-window")]
+pub mod multi_window;
 pub mod settings;
 pub mod window;
 
@@ -0,0 +1,1065 @@
+//! Create interactive, native cross-platform applications with multiple
+//! windows.
+mod state;
+
+pub use state::State;
+
+use crate::accessibility;
+use crate::application::{self, redraw_on_load, run_window_action, StyleSheet};
+use crate::clipboard::{self, Clipboard};
+use crate::conversion;
+use crate::mouse;
+use crate::renderer;
+use crate::settings;
+use crate::widget::operation;
+use crate::window;
+use crate::{
+    Command, Debug, Element, Error, Event, Executor, Proxy, Runtime, Settings,
+    Size, Subscription,
+};
+
+use iced_futures::futures;
+use iced_futures::futures::channel::mpsc;
+use iced_graphics::compositor;
+use iced_graphics::window::Compositor;
+use iced_native::time::Instant;
+use iced_native::user_interface::{self, UserInterface};
+
+use std::collections::HashMap;
+use std::mem::ManuallyDrop;
+use std::sync::atomic;
+use std::sync::Arc;
+
+/// An interactive, native cross-platform application with multiple windows.
+///
+/// This trait is the main entrypoint of multi-window applications. Once
+/// implemented, you can run your GUI application by simply calling [`run`].
+/// It will start with a single window, the [`window::Id::MAIN`], and
+/// additional windows can be opened with [`window::spawn`].
+///
+/// An [`Application`] can execute asynchronous actions by returning a
+/// [`Command`] in some of its methods.
+///
+/// [`window::spawn`]: crate::window::spawn
+pub trait Application: Sized
+where
+    <Self::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    /// The graphics backend to use to draw the [`Application`].
+    type Renderer: crate::Renderer;
+
+    /// The type of __messages__ your [`Application`] will produce.
+    type Message: std::fmt::Debug + Send;
+
+    /// The data needed to initialize your [`Application`].
+    type Flags;
+
+    /// Initializes the [`Application`] with the flags provided to
+    /// [`run`] as part of the [`Settings`].
+    ///
+    /// Here is where you should return the initial state of your app.
+    ///
+    /// Additionally, you can return a [`Command`] if you need to perform some
+    /// async action in the background on startup. This is useful if you want to
+    /// load state from a file, perform an initial HTTP request, etc.
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>);
+
+    /// Returns the current title of the window with the given
+    /// [`window::Id`].
+    ///
+    /// This title can be dynamic! The runtime will automatically update the
+    /// title of the window when necessary.
+    fn title(&self, window: window::Id) -> String;
+
+    /// Handles a __message__ and updates the state of the [`Application`].
+    ///
+    /// This is where you define your __update logic__. All the __messages__,
+    /// produced by either user interactions or commands, will be handled by
+    /// this method.
+    ///
+    /// Any [`Command`] returned will be executed immediately in the
+    /// background by shells.
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message>;
+
+    /// Returns the widgets to display in the window with the given
+    /// [`window::Id`].
+    ///
+    /// These widgets can produce __messages__ based on user interaction.
+    fn view(
+        &self,
+        window: window::Id,
+    ) -> Element<'_, Self::Message, Self::Renderer>;
+
+    /// Returns the current `Theme` of the window with the given
+    /// [`window::Id`].
+    fn theme(
+        &self,
+        window: window::Id,
+    ) -> <Self::Renderer as crate::Renderer>::Theme;
+
+    /// Returns the `Style` variation of the `Theme`.
+    fn style(
+        &self,
+    ) -> <<Self::Renderer as crate::Renderer>::Theme as StyleSheet>::Style {
+        Default::default()
+    }
+
+    /// Returns the event `Subscription` for the current state of the
+    /// application.
+    ///
+    /// The messages produced by the `Subscription` will be handled by
+    /// [`update`](#tymethod.update).
+    ///
+    /// A `Subscription` will be kept alive as long as you keep returning it!
+    ///
+    /// By default, it returns an empty subscription.
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
+    /// Returns the scale factor of the window with the given [`window::Id`].
+    ///
+    /// It can be used to dynamically control the size of the UI at runtime
+    /// (i.e. zooming).
+    ///
+    /// By default, it returns `1.0`.
+    fn scale_factor(&self, _window: window::Id) -> f64 {
+        1.0
+    }
+}
+
+/// A request of the application instance to the event loop.
+enum Control {
+    /// Changes the [`ControlFlow`] of the event loop.
+    ///
+    /// [`ControlFlow`]: winit::event_loop::ControlFlow
+    ChangeFlow(winit::event_loop::ControlFlow),
+
+    /// Creates a new window.
+    CreateWindow {
+        id: window::Id,
+        title: String,
+        settings: settings::Window,
+    },
+}
+
+/// An event received by the application instance.
+enum Input<Message: 'static> {
+    /// An event of the event loop.
+    EventLoop(winit::event::Event<'static, Message>),
+
+    /// A window has been created by the event loop.
+    WindowCreated {
+        id: window::Id,
+        window: winit::window::Window,
+    },
+}
+
+/// A window of a running [`Application`].
+struct Window<A, C>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    // Fields are dropped in declaration order, and neither the surface nor
+    // the accessibility bridge must outlive the window they were created for.
+    surface: C::Surface,
+    accessibility: accessibility::Connection,
+    raw: Arc<winit::window::Window>,
+    state: State<A>,
+    viewport_version: usize,
+    mouse_interaction: mouse::Interaction,
+    last_click: Option<mouse::Click>,
+    screenshots: Vec<Box<dyn FnOnce(window::Screenshot) -> A::Message>>,
+}
+
+impl<A, C> Window<A, C>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    fn new(
+        application: &A,
+        compositor: &mut C,
+        id: window::Id,
+        raw: winit::window::Window,
+    ) -> Self {
+        let state = State::new(application, id, &raw);
+        let accessibility = accessibility::Connection::new(id, &raw);
+        let mut surface = compositor.create_surface(&raw);
+        let physical_size = state.physical_size();
+
+        compositor.configure_surface(
+            &mut surface,
+            physical_size.width,
+            physical_size.height,
+        );
+
+        Self {
+            viewport_version: state.viewport_version(),
+            raw: Arc::new(raw),
+            state,
+            surface,
+            mouse_interaction: mouse::Interaction::default(),
+            last_click: None,
+            screenshots: Vec::new(),
+            accessibility,
+        }
+    }
+}
+
+/// Runs a multi-window [`Application`] with an executor, compositor, and the
+/// provided settings.
+///
+/// The [`Settings::window`] are used to create the [`window::Id::MAIN`].
+pub fn run<A, E, C>(
+    settings: Settings<A::Flags>,
+    compositor_settings: C::Settings,
+) -> Result<(), Error>
+where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    use futures::task;
+    use futures::Future;
+    use winit::event_loop::EventLoopBuilder;
+
+    let mut debug = Debug::new();
+    debug.startup_started();
+
+    let event_loop = EventLoopBuilder::with_user_event().build();
+    let proxy = event_loop.create_proxy();
+
+    let runtime = {
+        let proxy = Proxy::new(event_loop.create_proxy());
+        let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
+
+        Runtime::new(executor, proxy)
+    };
+
+    let (application, init_command) = {
+        let flags = settings.flags;
+
+        runtime.enter(|| A::new(flags))
+    };
+
+    let builder = settings.window.into_builder(
+        &application.title(window::Id::MAIN),
+        event_loop.primary_monitor(),
+        settings.id,
+    );
+
+    log::info!("Window builder: {:#?}", builder);
+
+    let window = builder
+        .build(&event_loop)
+        .map_err(Error::WindowCreationFailed)?;
+
+    let (compositor, renderer) = C::new(compositor_settings, Some(&window))?;
+
+    let (mut event_sender, event_receiver) = mpsc::unbounded();
+    let (control_sender, mut control_receiver) = mpsc::unbounded();
+
+    let mut instance = Box::pin(run_instance::<A, E, C>(
+        application,
+        compositor,
+        renderer,
+        runtime,
+        proxy,
+        debug,
+        event_receiver,
+        control_sender,
+        init_command,
+        window,
+        settings.exit_on_close_request,
+        settings.focus_navigation,
+    ));
+
+    let mut context = task::Context::from_waker(task::noop_waker_ref());
+
+    event_loop.run(move |event, window_target, control_flow| {
+        use winit::event_loop::ControlFlow;
+
+        if let ControlFlow::ExitWithCode(_) = control_flow {
+            return;
+        }
+
+        let event = match event {
+            winit::event::Event::WindowEvent {
+                event:
+                    winit::event::WindowEvent::ScaleFactorChanged {
+                        new_inner_size,
+                        ..
+                    },
+                window_id,
+            } => Some(winit::event::Event::WindowEvent {
+                event: winit::event::WindowEvent::Resized(*new_inner_size),
+                window_id,
+            }),
+            _ => event.to_static(),
+        };
+
+        if let Some(event) = event {
+            event_sender
+                .start_send(Input::EventLoop(event))
+                .expect("Send event");
+
+            loop {
+                let poll = instance.as_mut().poll(&mut context);
+
+                if let task::Poll::Ready(_) = poll {
+                    *control_flow = ControlFlow::Exit;
+                    break;
+                }
+
+                let mut windows_created = false;
+
+                while let Ok(Some(control)) = control_receiver.try_next() {
+                    match control {
+                        Control::ChangeFlow(flow) => {
+                            *control_flow = flow;
+                        }
+                        Control::CreateWindow {
+                            id,
+                            title,
+                            settings,
+                        } => {
+                            let builder = settings.into_builder(
+                                &title,
+                                window_target.primary_monitor(),
+                                None,
+                            );
+
+                            match builder.build(window_target) {
+                                Ok(window) => {
+                                    event_sender
+                                        .start_send(Input::WindowCreated {
+                                            id,
+                                            window,
+                                        })
+                                        .expect("Send event");
+
+                                    windows_created = true;
+                                }
+                                Err(error) => {
+                                    log::error!(
+                                        "Failed to create window {:?}: {}",
+                                        id,
+                                        error
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if !windows_created {
+                    break;
+                }
+            }
+        }
+    })
+}
+
+async fn run_instance<A, E, C>(
+    mut application: A,
+    mut compositor: C,
+    mut renderer: A::Renderer,
+    mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
+    mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
+    mut debug: Debug,
+    mut event_receiver: mpsc::UnboundedReceiver<Input<A::Message>>,
+    mut control_sender: mpsc::UnboundedSender<Control>,
+    init_command: Command<A::Message>,
+    window: winit::window::Window,
+    exit_on_close_request: bool,
+    focus_navigation: bool,
+) where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    use iced_futures::futures::stream::StreamExt;
+    use winit::event;
+    use winit::event_loop::ControlFlow;
+
+    let mut clipboard = Clipboard::connect(&window);
+    let mut should_exit = false;
+
+    let mut window_ids = HashMap::from([(window.id(), window::Id::MAIN)]);
+    let mut windows = HashMap::from([(
+        window::Id::MAIN,
+        Window::<A, C>::new(
+            &application,
+            &mut compositor,
+            window::Id::MAIN,
+            window,
+        ),
+    )]);
+
+    let image_loaded = redraw_on_load(&windows[&window::Id::MAIN].raw);
+
+    let mut caches =
+        HashMap::from([(window::Id::MAIN, user_interface::Cache::default())]);
+
+    run_command(
+        &application,
+        &mut caches,
+        &mut windows,
+        &mut renderer,
+        init_command,
+        &mut runtime,
+        &mut clipboard,
+        &mut should_exit,
+        &mut proxy,
+        &mut control_sender,
+        &mut debug,
+        || compositor.fetch_information(),
+    );
+    runtime.track(application.subscription());
+
+    let mut user_interfaces = ManuallyDrop::new(build_user_interfaces(
+        &application,
+        caches,
+        &windows,
+        &mut renderer,
+        &mut debug,
+    ));
+
+    let mut events = Vec::new();
+    let mut messages = Vec::new();
+    let mut redraw_pending = false;
+
+    debug.startup_finished();
+
+    while let Some(input) = event_receiver.next().await {
+        let event = match input {
+            Input::EventLoop(event) => event,
+            Input::WindowCreated { id, window } => {
+                let _ = window_ids.insert(window.id(), id);
+                let _ = windows.insert(
+                    id,
+                    Window::new(&application, &mut compositor, id, window),
+                );
+
+                let user_interface = build_user_interface(
+                    &application,
+                    user_interface::Cache::default(),
+                    &mut renderer,
+                    windows[&id].state.logical_size(),
+                    &mut debug,
+                    id,
+                );
+
+                let _ = user_interfaces.insert(id, user_interface);
+
+                continue;
+            }
+        };
+
+        match event {
+            event::Event::NewEvents(start_cause) => {
+                redraw_pending = matches!(
+                    start_cause,
+                    event::StartCause::Init
+                        | event::StartCause::Poll
+                        | event::StartCause::ResumeTimeReached { .. }
+                ) || image_loaded
+                    .swap(false, atomic::Ordering::Relaxed);
+            }
+            event::Event::MainEventsCleared => {
+                if !redraw_pending && events.is_empty() && messages.is_empty() {
+                    continue;
+                }
+
+                debug.event_processing_started();
+
+                let mut outdated = false;
+
+                for (id, user_interface) in user_interfaces.iter_mut() {
+                    let window = &windows[id];
+
+                    let window_events: Vec<Event> = events
+                        .iter()
+                        .filter(|(window_id, _)| window_id == id)
+                        .map(|(_, event)| Clone::clone(event))
+                        .collect();
+
+                    let (interface_state, statuses) = user_interface.update(
+                        &window_events,
+                        window.state.cursor_position(),
+                        &mut renderer,
+                        &mut clipboard,
+                        &mut messages,
+                    );
+
+                    for (event, status) in
+                        window_events.into_iter().zip(statuses)
+                    {
+                        if focus_navigation {
+                            application::navigate_focus(
+                                user_interface,
+                                &renderer,
+                                &event,
+                                status,
+                            );
+                        }
+
+                        runtime.broadcast((event, status));
+                    }
+
+                    outdated = outdated
+                        || matches!(
+                            interface_state,
+                            user_interface::State::Outdated
+                        );
+                }
+
+                events.clear();
+
+                debug.event_processing_finished();
+
+                if !messages.is_empty() || outdated {
+                    let mut caches: HashMap<_, _> =
+                        ManuallyDrop::into_inner(user_interfaces)
+                            .into_iter()
+                            .map(|(id, user_interface)| {
+                                (id, user_interface.into_cache())
+                            })
+                            .collect();
+
+                    // Update application
+                    update(
+                        &mut application,
+                        &mut caches,
+                        &mut windows,
+                        &mut renderer,
+                        &mut runtime,
+                        &mut clipboard,
+                        &mut should_exit,
+                        &mut proxy,
+                        &mut control_sender,
+                        &mut debug,
+                        &mut messages,
+                        || compositor.fetch_information(),
+                    );
+
+                    // Update windows
+                    window_ids.retain(|_, id| windows.contains_key(id));
+
+                    for window in windows.values_mut() {
+                        window.state.synchronize(&application, &window.raw);
+                    }
+
+                    user_interfaces = ManuallyDrop::new(build_user_interfaces(
+                        &application,
+                        caches,
+                        &windows,
+                        &mut renderer,
+                        &mut debug,
+                    ));
+
+                    if should_exit {
+                        break;
+                    }
+                }
+
+                // TODO: Avoid redrawing all the time by forcing widgets to
+                // request redraws on state changes
+                //
+                // Then, we can use the `interface_state` here to decide if a redraw
+                // is needed right away, or simply wait until a specific time.
+                let now = Instant::now();
+                let mut control_flow = ControlFlow::Wait;
+
+                for (id, user_interface) in user_interfaces.iter_mut() {
+                    let window = &windows[id];
+
+                    let redraw_event = Event::Window(
+                        *id,
+                        crate::window::Event::RedrawRequested(now),
+                    );
+
+                    let (interface_state, _) = user_interface.update(
+                        &[redraw_event.clone()],
+                        window.state.cursor_position(),
+                        &mut renderer,
+                        &mut clipboard,
+                        &mut messages,
+                    );
+
+                    window.raw.request_redraw();
+                    runtime.broadcast((
+                        redraw_event,
+                        crate::event::Status::Ignored,
+                    ));
+
+                    if let user_interface::State::Updated {
+                        redraw_request: Some(redraw_request),
+                    } = interface_state
+                    {
+                        control_flow = match (control_flow, redraw_request) {
+                            (ControlFlow::Poll, _)
+                            | (_, window::RedrawRequest::NextFrame) => {
+                                ControlFlow::Poll
+                            }
+                            (
+                                ControlFlow::WaitUntil(current),
+                                window::RedrawRequest::At(at),
+                            ) => ControlFlow::WaitUntil(current.min(at)),
+                            (_, window::RedrawRequest::At(at)) => {
+                                ControlFlow::WaitUntil(at)
+                            }
+                        };
+                    }
+                }
+
+                let _ = control_sender
+                    .start_send(Control::ChangeFlow(control_flow));
+
+                redraw_pending = false;
+            }
+            event::Event::PlatformSpecific(event::PlatformSpecific::MacOS(
+                event::MacOS::ReceivedUrl(url),
+            )) => {
+                use iced_native::event;
+
+                events.push((
+                    window::Id::MAIN,
+                    iced_native::Event::PlatformSpecific(
+                        event::PlatformSpecific::MacOS(
+                            event::MacOS::ReceivedUrl(url),
+                        ),
+                    ),
+                ));
+            }
+            event::Event::UserEvent(message) => {
+                messages.push(message);
+            }
+            event::Event::RedrawRequested(window_id) => {
+                let id = match window_ids.get(&window_id) {
+                    Some(id) => *id,
+                    None => continue,
+                };
+
+                let window = match windows.get_mut(&id) {
+                    Some(window) => window,
+                    None => continue,
+                };
+
+                let physical_size = window.state.physical_size();
+
+                if physical_size.width == 0 || physical_size.height == 0 {
+                    continue;
+                }
+
+                debug.render_started();
+                let current_viewport_version = window.state.viewport_version();
+
+                if window.viewport_version != current_viewport_version {
+                    let logical_size = window.state.logical_size();
+
+                    debug.layout_started();
+                    if let Some(user_interface) = user_interfaces.remove(&id) {
+                        let _ = user_interfaces.insert(
+                            id,
+                            user_interface
+                                .relayout(logical_size, &mut renderer),
+                        );
+                    }
+                    debug.layout_finished();
+
+                    compositor.configure_surface(
+                        &mut window.surface,
+                        physical_size.width,
+                        physical_size.height,
+                    );
+
+                    window.viewport_version = current_viewport_version;
+                }
+
+                let user_interface = match user_interfaces.get_mut(&id) {
+                    Some(user_interface) => user_interface,
+                    None => continue,
+                };
+
+                // All the windows share the same renderer, so the user
+                // interface of a window needs to be drawn right before
+                // presenting it.
+                debug.draw_started();
+                let new_mouse_interaction = user_interface.draw(
+                    &mut renderer,
+                    window.state.theme(),
+                    &renderer::Style {
+                        text_color: window.state.text_color(),
+                    },
+                    window.state.cursor_position(),
+                );
+                debug.draw_finished();
+
+                window.accessibility.update(user_interface, &renderer);
+
+                if new_mouse_interaction != window.mouse_interaction {
+                    window.raw.set_cursor_icon(conversion::mouse_interaction(
+                        new_mouse_interaction,
+                    ));
+                    window.raw.set_cursor_visible(conversion::cursor_visible(
+                        new_mouse_interaction,
+                    ));
+
+                    window.mouse_interaction = new_mouse_interaction;
+                }
+
+                let result = if window.screenshots.is_empty() {
+                    compositor.present(
+                        &mut renderer,
+                        &mut window.surface,
+                        window.state.viewport(),
+                        window.state.background_color(),
+                        &debug.overlay(),
+                    )
+                } else {
+                    compositor
+                        .screenshot(
+                            &mut renderer,
+                            &mut window.surface,
+                            window.state.viewport(),
+                            window.state.background_color(),
+                            &debug.overlay(),
+                        )
+                        .map(|bytes| {
+                            let screenshot =
+                                window::Screenshot::new(bytes, physical_size);
+
+                            for tag in window.screenshots.drain(..) {
+                                proxy
+                                    .send_event(tag(screenshot.clone()))
+                                    .expect("Send message to event loop");
+                            }
+                        })
+                };
+
+                match result {
+                    Ok(()) => {
+                        debug.render_finished();
+                    }
+                    Err(error) => match error {
+                        // This is an unrecoverable error.
+                        compositor::SurfaceError::OutOfMemory => {
+                            panic!("{:?}", error);
+                        }
+                        _ => {
+                            debug.render_finished();
+
+                            // Try rendering again next frame.
+                            window.raw.request_redraw();
+                        }
+                    },
+                }
+            }
+            event::Event::WindowEvent {
+                event: window_event,
+                window_id,
+            } => {
+                let id = match window_ids.get(&window_id) {
+                    Some(id) => *id,
+                    None => continue,
+                };
+
+                let is_closed =
+                    matches!(window_event, event::WindowEvent::Destroyed)
+                        || (exit_on_close_request
+                            && crate::application::requests_exit(
+                                &window_event,
+                                windows[&id].state.modifiers(),
+                            ));
+
+                if is_closed {
+                    if id == window::Id::MAIN {
+                        break;
+                    }
+
+                    let _ = window_ids.remove(&window_id);
+                    let _ = windows.remove(&id);
+                    let _ = user_interfaces.remove(&id);
+
+                    runtime.broadcast((
+                        Event::Window(id, window::Event::Closed),
+                        crate::event::Status::Ignored,
+                    ));
+
+                    continue;
+                }
+
+                let window = match windows.get_mut(&id) {
+                    Some(window) => window,
+                    None => continue,
+                };
+
+                window.state.update(&window.raw, &window_event, &mut debug);
+
+                if let Some(event) = conversion::window_event(
+                    id,
+                    &window_event,
+                    window.state.scale_factor(),
+                    window.state.modifiers(),
+                    window.state.cursor_position(),
+                    &mut window.last_click,
+                ) {
+                    events.push((id, event));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Manually drop the user interfaces
+    drop(ManuallyDrop::into_inner(user_interfaces));
+}
+
+/// Builds a [`UserInterface`] for the window with the given [`window::Id`]
+/// of the provided [`Application`], logging [`struct@Debug`] information
+/// accordingly.
+pub fn build_user_interface<'a, A: Application>(
+    application: &'a A,
+    cache: user_interface::Cache,
+    renderer: &mut A::Renderer,
+    size: Size,
+    debug: &mut Debug,
+    id: window::Id,
+) -> UserInterface<'a, A::Message, A::Renderer>
+where
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    debug.view_started();
+    let view = application.view(id);
+    debug.view_finished();
+
+    debug.layout_started();
+    let user_interface = UserInterface::build(view, size, cache, renderer);
+    debug.layout_finished();
+
+    user_interface
+}
+
+fn build_user_interfaces<'a, A, C>(
+    application: &'a A,
+    mut caches: HashMap<window::Id, user_interface::Cache>,
+    windows: &HashMap<window::Id, Window<A, C>>,
+    renderer: &mut A::Renderer,
+    debug: &mut Debug,
+) -> HashMap<window::Id, UserInterface<'a, A::Message, A::Renderer>>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    windows
+        .iter()
+        .map(|(&id, window)| {
+            let user_interface = build_user_interface(
+                application,
+                caches.remove(&id).unwrap_or_default(),
+                renderer,
+                window.state.logical_size(),
+                debug,
+                id,
+            );
+
+            (id, user_interface)
+        })
+        .collect()
+}
+
+/// Updates an [`Application`] by feeding it the provided messages, spawning any
+/// resulting [`Command`], and tracking its [`Subscription`].
+fn update<A, E, C>(
+    application: &mut A,
+    caches: &mut HashMap<window::Id, user_interface::Cache>,
+    windows: &mut HashMap<window::Id, Window<A, C>>,
+    renderer: &mut A::Renderer,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    clipboard: &mut Clipboard,
+    should_exit: &mut bool,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    control_sender: &mut mpsc::UnboundedSender<Control>,
+    debug: &mut Debug,
+    messages: &mut Vec<A::Message>,
+    graphics_info: impl FnOnce() -> compositor::Information + Copy,
+) where
+    A: Application,
+    E: Executor,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    for message in messages.drain(..) {
+        debug.log_message(&message);
+
+        debug.update_started();
+        let command = runtime.enter(|| application.update(message));
+        debug.update_finished();
+
+        run_command(
+            application,
+            caches,
+            windows,
+            renderer,
+            command,
+            runtime,
+            clipboard,
+            should_exit,
+            proxy,
+            control_sender,
+            debug,
+            graphics_info,
+        );
+    }
+
+    let subscription = application.subscription();
+    runtime.track(subscription);
+}
+
+/// Runs the actions of a [`Command`].
+fn run_command<A, E, C>(
+    application: &A,
+    caches: &mut HashMap<window::Id, user_interface::Cache>,
+    windows: &mut HashMap<window::Id, Window<A, C>>,
+    renderer: &mut A::Renderer,
+    command: Command<A::Message>,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    clipboard: &mut Clipboard,
+    should_exit: &mut bool,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    control_sender: &mut mpsc::UnboundedSender<Control>,
+    debug: &mut Debug,
+    _graphics_info: impl FnOnce() -> compositor::Information + Copy,
+) where
+    A: Application,
+    E: Executor,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    use iced_native::command;
+    use iced_native::system;
+    use iced_native::window;
+
+    for action in command.actions() {
+        match action {
+            command::Action::Future(future) => {
+                runtime.spawn(future);
+            }
+            command::Action::Clipboard(action) => match action {
+                clipboard::Action::Read(tag) => {
+                    let message = tag(clipboard.read());
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+                clipboard::Action::Write(contents) => {
+                    clipboard.write(contents);
+                }
+            },
+            command::Action::Window(id, action) => match action {
+                window::Action::Spawn(settings) => {
+                    if windows.contains_key(&id) {
+                        log::warn!("Window {:?} already exists", id);
+                        continue;
+                    }
+
+                    let _ = control_sender.start_send(Control::CreateWindow {
+                        id,
+                        title: application.title(id),
+                        settings: settings.into(),
+                    });
+                }
+                window::Action::Close if id == window::Id::MAIN => {
+                    *should_exit = true;
+                }
+                window::Action::Close => {
+                    // Dropping a window closes it
+                    if windows.remove(&id).is_some() {
+                        let _ = caches.remove(&id);
+
+                        runtime.broadcast((
+                            Event::Window(id, window::Event::Closed),
+                            crate::event::Status::Ignored,
+                        ));
+                    }
+                }
+                window::Action::Screenshot(tag) => match windows.get_mut(&id) {
+                    Some(window) => {
+                        window.screenshots.push(tag);
+                        window.raw.request_redraw();
+                    }
+                    None => log::warn!("Window {:?} does not exist", id),
+                },
+                action => match windows.get(&id) {
+                    Some(window) => {
+                        run_window_action(action, &window.raw, proxy)
+                    }
+                    None => log::warn!("Window {:?} does not exist", id),
+                },
+            },
+            command::Action::System(action) => match action {
+                system::Action::QueryInformation(_tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let graphics_info = _graphics_info();
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let information =
+                                crate::system::information(graphics_info);
+
+                            let message = _tag(information);
+
+                            proxy
+                                .send_event(message)
+                                .expect("Send message to event loop")
+                        });
+                    }
+                }
+            },
+            command::Action::Widget(action) => {
+                let mut current_operation = Some(action.into_operation());
+
+                while let Some(mut operation) = current_operation.take() {
+                    for (&id, window) in windows.iter() {
+                        let cache = caches.remove(&id).unwrap_or_default();
+
+                        let mut user_interface = build_user_interface(
+                            application,
+                            cache,
+                            renderer,
+                            window.state.logical_size(),
+                            debug,
+                            id,
+                        );
+
+                        user_interface.operate(renderer, operation.as_mut());
+
+                        let _ = caches.insert(id, user_interface.into_cache());
+                    }
+
+                    match operation.finish() {
+                        operation::Outcome::None => {}
+                        operation::Outcome::Some(message) => {
+                            proxy
+                                .send_event(message)
+                                .expect("Send message to event loop");
+                        }
+                        operation::Outcome::Chain(next) => {
+                            current_operation = Some(next);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -20,3 +20,13 @@ impl Default for Position {
         Self::Default
     }
 }
+
+impl From<crate::window::Position> for Position {
+    fn from(position: crate::window::Position) -> Self {
+        match position {
+            crate::window::Position::Default => Self::Default,
+            crate::window::Position::Centered => Self::Centered,
+            crate::window::Position::Specific(x, y) => Self::Specific(x, y),
+        }
+    }
+}
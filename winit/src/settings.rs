@@ -49,6 +49,12 @@ pub struct Settings<Flags> {
     /// [`Application`]: crate::Application
     pub exit_on_close_request: bool,
 
+    /// Whether the [`Application`] should move the keyboard focus between
+    /// widgets when the user presses Tab or Shift+Tab.
+    ///
+    /// [`Application`]: crate::Application
+    pub focus_navigation: bool,
+
     /// Whether the [`Application`] should try to build the context
     /// using OpenGL ES first then OpenGL.
     ///
@@ -197,3 +203,21 @@ impl Default for Window {
         }
     }
 }
+
+impl From<crate::window::Settings> for Window {
+    fn from(settings: crate::window::Settings) -> Window {
+        Window {
+            size: settings.size,
+            position: settings.position.into(),
+            min_size: settings.min_size,
+            max_size: settings.max_size,
+            visible: settings.visible,
+            resizable: settings.resizable,
+            decorations: settings.decorations,
+            transparent: settings.transparent,
+            always_on_top: settings.always_on_top,
+            icon: None,
+            platform_specific: Default::default(),
+        }
+    }
+}
@@ -13,9 +13,9 @@ pub fn fetch_information<Message>(
     )))
 }
 
-pub(crate) fn information(
-    graphics_info: compositor::Information,
-) -> Information {
+/// Queries the [`Information`] of the system, given the one of the graphics
+/// backend.
+pub fn information(graphics_info: compositor::Information) -> Information {
     use sysinfo::{ProcessExt, ProcessorExt, System, SystemExt};
     let mut system = System::new_all();
     system.refresh_all();
@@ -2,56 +2,151 @@
 use crate::command::{self, Command};
 use iced_native::window;
 
-pub use window::{frames, Event, Mode, RedrawRequest, UserAttention};
+pub use window::{
+    frames, Event, Id, Mode, Position, RedrawRequest, Screenshot, Settings,
+    UserAttention,
+};
 
-/// Closes the current window and exits the application.
+/// Closes the main window and exits the application.
 pub fn close<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Close))
+    close_window(window::Id::MAIN)
 }
 
-/// Begins dragging the window while the left mouse button is held.
+/// Begins dragging the main window while the left mouse button is held.
 pub fn drag<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Drag))
+    drag_window(window::Id::MAIN)
 }
 
-/// Resizes the window to the given logical dimensions.
+/// Begins dragging the window with the given [`Id`] while the left mouse
+/// button is held.
+pub fn drag_window<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(id, window::Action::Drag))
+}
+
+/// Resizes the main window to the given logical dimensions.
 pub fn resize<Message>(width: u32, height: u32) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Resize {
-        width,
-        height,
-    }))
+    resize_window(window::Id::MAIN, width, height)
+}
+
+/// Resizes the window with the given [`Id`] to the given logical dimensions.
+pub fn resize_window<Message>(
+    id: Id,
+    width: u32,
+    height: u32,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::Resize { width, height },
+    ))
 }
 
-/// Sets the window to maximized or back.
+/// Sets the main window to maximized or back.
 pub fn maximize<Message>(value: bool) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Maximize(value)))
+    maximize_window(window::Id::MAIN, value)
+}
+
+/// Sets the window with the given [`Id`] to maximized or back.
+pub fn maximize_window<Message>(id: Id, value: bool) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::Maximize(value),
+    ))
 }
 
-/// Set the window to minimized or back.
+/// Set the main window to minimized or back.
 pub fn minimize<Message>(value: bool) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Minimize(value)))
+    minimize_window(window::Id::MAIN, value)
 }
 
-/// Moves a window to the given logical coordinates.
+/// Set the window with the given [`Id`] to minimized or back.
+pub fn minimize_window<Message>(id: Id, value: bool) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::Minimize(value),
+    ))
+}
+
+/// Moves the main window to the given logical coordinates.
 pub fn move_to<Message>(x: i32, y: i32) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Move { x, y }))
+    move_window(window::Id::MAIN, x, y)
+}
+
+/// Moves the window with the given [`Id`] to the given logical coordinates.
+pub fn move_window<Message>(id: Id, x: i32, y: i32) -> Command<Message> {
+    Command::single(command::Action::Window(id, window::Action::Move { x, y }))
 }
 
-/// Sets the [`Mode`] of the window.
+/// Sets the [`Mode`] of the main window.
 pub fn set_mode<Message>(mode: Mode) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::SetMode(mode)))
+    set_window_mode(window::Id::MAIN, mode)
 }
 
-/// Sets the window to maximized or back.
+/// Sets the [`Mode`] of the window with the given [`Id`].
+pub fn set_window_mode<Message>(id: Id, mode: Mode) -> Command<Message> {
+    Command::single(command::Action::Window(id, window::Action::SetMode(mode)))
+}
+
+/// Toggles the main window to maximized or back.
 pub fn toggle_maximize<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::ToggleMaximize))
+    toggle_maximize_window(window::Id::MAIN)
+}
+
+/// Toggles the window with the given [`Id`] to maximized or back.
+pub fn toggle_maximize_window<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(id, window::Action::ToggleMaximize))
 }
 
-/// Fetches the current [`Mode`] of the window.
+/// Fetches the current [`Mode`] of the main window.
 pub fn fetch_mode<Message>(
     f: impl FnOnce(Mode) -> Message + 'static,
 ) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::FetchMode(
-        Box::new(f),
-    )))
+    fetch_window_mode(window::Id::MAIN, f)
+}
+
+/// Fetches the current [`Mode`] of the window with the given [`Id`].
+pub fn fetch_window_mode<Message>(
+    id: Id,
+    f: impl FnOnce(Mode) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::FetchMode(Box::new(f)),
+    ))
+}
+
+/// Captures a [`Screenshot`] of the next frame rendered in the main window.
+pub fn screenshot<Message>(
+    f: impl FnOnce(Screenshot) -> Message + 'static,
+) -> Command<Message> {
+    screenshot_window(window::Id::MAIN, f)
+}
+
+/// Captures a [`Screenshot`] of the next frame rendered in the window with the
+/// given [`Id`].
+pub fn screenshot_window<Message>(
+    id: Id,
+    f: impl FnOnce(Screenshot) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::Screenshot(Box::new(f)),
+    ))
+}
+
+/// Spawns a new window with the given [`Id`] and [`Settings`].
+///
+/// Only supported by multi-window applications.
+pub fn spawn<Message>(id: Id, settings: Settings) -> Command<Message> {
+    Command::single(command::Action::Window(
+        id,
+        window::Action::Spawn(settings),
+    ))
+}
+
+/// Closes the window with the given [`Id`].
+///
+/// Closing the main window exits the application. Closing any other window
+/// emits a [`Event::Closed`] for it.
+pub fn close_window<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(id, window::Action::Close))
 }